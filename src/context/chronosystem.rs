@@ -237,6 +237,13 @@ impl NormativeTransition {
         self.actual_age.is_some() && self.timing_deviation.abs() <= 1.0
     }
 
+    /// Returns whether this transition has not occurred by an age past its
+    /// expected window.
+    #[must_use]
+    pub fn is_overdue(&self, age_years: f64) -> bool {
+        self.actual_age.is_none() && age_years > self.expected_age + self.timing_window
+    }
+
     /// Returns the appropriate stress multiplier based on timing deviation.
     ///
     /// - Returns `early_transition_multiplier` for early transitions
@@ -507,6 +514,32 @@ impl ChronosystemContext {
         &self.normative_transitions
     }
 
+    /// Completes a normative transition at the given age.
+    ///
+    /// Only the first occurrence counts: returns false, leaving the
+    /// transition unchanged, if it is already completed or does not exist.
+    pub fn complete_normative_transition(&mut self, name: &str, age: f64) -> bool {
+        match self.get_normative_transition_mut(name) {
+            Some(transition) if !transition.is_completed() => {
+                transition.complete(age);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Returns the transitions that are off-time at the given age.
+    ///
+    /// A transition is off-time if it occurred late or has not occurred
+    /// by the end of its window.
+    #[must_use]
+    pub fn off_time_transitions(&self, age_years: f64) -> Vec<&NormativeTransition> {
+        self.normative_transitions
+            .iter()
+            .filter(|t| t.is_late() || t.is_overdue(age_years))
+            .collect()
+    }
+
     /// Computes total stress increment from off-time transitions.
     ///
     /// Returns an aggregate stress value in [0.0, 1.0] representing
@@ -906,7 +939,7 @@ mod tests {
         let chrono = ChronosystemContext::default();
         for path in ChronosystemPath::all() {
             let value = chrono.get_value(&path);
-            assert!(value >= 0.0 && value <= 1.0);
+            assert!((0.0..=1.0).contains(&value));
         }
     }
 
@@ -961,6 +994,41 @@ mod tests {
         assert!(marriage.is_completed());
     }
 
+    #[test]
+    fn chronosystem_complete_normative_transition_counts_first_occurrence() {
+        let mut chrono = ChronosystemContext::default();
+        chrono.add_normative_transition(NormativeTransition::new("first_job", 20.0, 4.0, true));
+
+        assert!(chrono.complete_normative_transition("first_job", 22.0));
+        assert!(!chrono.complete_normative_transition("first_job", 35.0));
+        assert!(!chrono.complete_normative_transition("retirement", 65.0));
+
+        let first_job = chrono.get_normative_transition("first_job").unwrap();
+        assert_eq!(first_job.actual_age, Some(22.0));
+    }
+
+    #[test]
+    fn chronosystem_off_time_transitions_lists_late_and_missing() {
+        let mut chrono = ChronosystemContext::default();
+        let mut late = NormativeTransition::new("first_job", 20.0, 4.0, true);
+        late.complete(30.0);
+        let mut on_time = NormativeTransition::new("school_entry", 6.0, 1.0, true);
+        on_time.complete(6.0);
+        chrono.add_normative_transition(late);
+        chrono.add_normative_transition(on_time);
+        chrono.add_normative_transition(NormativeTransition::new("partnership", 28.0, 5.0, false));
+
+        let names = |age: f64| -> Vec<String> {
+            chrono
+                .off_time_transitions(age)
+                .iter()
+                .map(|t| t.name.clone())
+                .collect()
+        };
+        assert_eq!(names(32.0), vec!["first_job"]);
+        assert_eq!(names(34.0), vec!["first_job", "partnership"]);
+    }
+
     #[test]
     fn chronosystem_total_off_time_stress() {
         let mut chrono = ChronosystemContext::default();
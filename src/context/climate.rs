@@ -0,0 +1,158 @@
+//! Emergent microsystem climate.
+//!
+//! A microsystem's warmth, hostility and stress are usually authored
+//! values. In emergent mode they are derived from the people in it, so
+//! members shape their shared context as well as being shaped by it
+//! (Bronfenbrenner's bidirectionality):
+//!
+//! - **Hostility** follows members' mean aggression and grievance.
+//! - **Warmth** follows the mean warmth members feel toward each other.
+//! - **Workload stress** (work microsystems) follows the supervisor's stress.
+//!
+//! A dimension with no contributing members keeps its authored value.
+
+use crate::context::Microsystem;
+use crate::state::IndividualState;
+
+/// Climate derived from a microsystem's members.
+///
+/// # Examples
+///
+/// ```
+/// use eventsim_rs::context::{Microsystem, MicrosystemClimate, WorkContext};
+/// use eventsim_rs::state::IndividualState;
+///
+/// let mut bully = IndividualState::new();
+/// bully.disposition_mut().add_aggression_delta(0.6);
+/// bully.disposition_mut().add_grievance_delta(0.6);
+///
+/// let climate = MicrosystemClimate::new().with_member(&bully);
+/// let mut office = Microsystem::new_work(WorkContext::default());
+/// climate.apply_to(&mut office);
+///
+/// assert!(office.hostility() > 0.6);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct MicrosystemClimate {
+    hostility_total: f64,
+    member_count: usize,
+    warmth_total: f64,
+    tie_count: usize,
+    supervisor_stress: Option<f64>,
+}
+
+impl MicrosystemClimate {
+    /// Creates a climate with no contributions.
+    #[must_use]
+    pub fn new() -> Self {
+        MicrosystemClimate::default()
+    }
+
+    /// Adds a member's projected state.
+    #[must_use]
+    pub fn with_member(mut self, state: &IndividualState) -> Self {
+        let disposition = state.disposition();
+        let hostility =
+            (disposition.aggression_effective() + disposition.grievance_effective()) / 2.0;
+        self.hostility_total += f64::from(hostility);
+        self.member_count += 1;
+        self
+    }
+
+    /// Adds one member's warmth toward another member (0-1).
+    #[must_use]
+    pub fn with_tie_warmth(mut self, warmth: f32) -> Self {
+        self.warmth_total += f64::from(warmth);
+        self.tie_count += 1;
+        self
+    }
+
+    /// Sets the supervisor's projected state.
+    #[must_use]
+    pub fn with_supervisor(mut self, state: &IndividualState) -> Self {
+        self.supervisor_stress = Some(f64::from(state.needs().stress_effective()));
+        self
+    }
+
+    /// Returns the derived hostility, if any member contributed.
+    #[must_use]
+    pub fn hostility(&self) -> Option<f64> {
+        (self.member_count > 0).then(|| self.hostility_total / self.member_count as f64)
+    }
+
+    /// Returns the derived warmth, if any member-to-member tie contributed.
+    #[must_use]
+    pub fn warmth(&self) -> Option<f64> {
+        (self.tie_count > 0).then(|| self.warmth_total / self.tie_count as f64)
+    }
+
+    /// Returns the derived workload stress, if a supervisor contributed.
+    #[must_use]
+    pub fn workload_stress(&self) -> Option<f64> {
+        self.supervisor_stress
+    }
+
+    /// Overwrites the microsystem's climate with the derived values.
+    ///
+    /// Dimensions without contributions are left unchanged. Workload stress
+    /// only applies to work microsystems.
+    pub fn apply_to(&self, microsystem: &mut Microsystem) {
+        if let Some(hostility) = self.hostility() {
+            microsystem.set_hostility(hostility);
+        }
+        if let Some(warmth) = self.warmth() {
+            microsystem.set_warmth(warmth);
+        }
+        if let (Some(stress), Some(work)) = (self.workload_stress(), microsystem.work_mut()) {
+            work.workload_stress = stress.clamp(0.0, 1.0);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::context::{FamilyContext, WorkContext};
+
+    #[test]
+    fn empty_climate_keeps_authored_values() {
+        let mut office = Microsystem::new_work(WorkContext::default());
+        let authored = office.clone();
+        MicrosystemClimate::new().apply_to(&mut office);
+        assert_eq!(office, authored);
+    }
+
+    #[test]
+    fn hostility_is_mean_of_members() {
+        let calm = IndividualState::new();
+        let mut angry = IndividualState::new();
+        angry.disposition_mut().add_aggression_delta(0.8);
+        angry.disposition_mut().add_grievance_delta(1.0);
+
+        let climate = MicrosystemClimate::new()
+            .with_member(&calm)
+            .with_member(&angry);
+        // calm: (0.2 + 0.0) / 2 = 0.1, angry: (1.0 + 1.0) / 2 = 1.0
+        assert!((climate.hostility().unwrap() - 0.55).abs() < 1e-6);
+    }
+
+    #[test]
+    fn supervisor_stress_only_sets_work_load() {
+        let mut stressed = IndividualState::new();
+        stressed.needs_mut().add_stress_delta(0.6);
+        let climate = MicrosystemClimate::new()
+            .with_supervisor(&stressed)
+            .with_tie_warmth(0.9)
+            .with_tie_warmth(0.5);
+
+        let mut office = Microsystem::new_work(WorkContext::default());
+        climate.apply_to(&mut office);
+        assert!((office.stress_level() - 0.8).abs() < 1e-6);
+        assert!((office.warmth() - 0.7).abs() < 1e-6);
+
+        let mut family = Microsystem::new_family(FamilyContext::default());
+        let stress_before = family.stress_level();
+        climate.apply_to(&mut family);
+        assert!((family.stress_level() - stress_before).abs() < 1e-9);
+    }
+}
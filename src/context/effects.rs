@@ -19,7 +19,7 @@ use crate::context::mesosystem::{
     check_proximal_process_gate, MesosystemState, INTERACTION_COMPLEXITY_THRESHOLD,
     INTERACTION_FREQUENCY_THRESHOLD,
 };
-use crate::context::EcologicalContext;
+use crate::context::{ContextTimeline, EcologicalContext};
 use crate::enums::{BirthEra, LifeStage};
 use crate::state::IndividualState;
 use crate::types::{Duration, Timestamp};
@@ -82,6 +82,88 @@ pub(crate) fn apply_context_effects(
         return state;
     }
 
+    state = apply_time_scaled_effects(
+        state,
+        context,
+        relationship_quality,
+        time_scale,
+        life_stage,
+        current_timestamp,
+    );
+    apply_cohort_personality_effects(&mut state, context);
+    state
+}
+
+/// Applies context effects piecewise over a context timeline.
+///
+/// The interval from `start` to `end` is split wherever the timeline
+/// changes the context, and each segment contributes effects in proportion
+/// to its length using the context in force during it. Cohort personality
+/// shifts are not time-scaled, so they are applied once, from the context
+/// in force at `current_timestamp`.
+///
+/// With an empty timeline this is equivalent to `apply_context_effects`
+/// over the whole interval.
+#[must_use]
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn apply_context_timeline_effects(
+    mut state: IndividualState,
+    base: &EcologicalContext,
+    timeline: &ContextTimeline,
+    relationship_quality: f64,
+    start: Timestamp,
+    end: Timestamp,
+    life_stage: LifeStage,
+    current_timestamp: Timestamp,
+) -> IndividualState {
+    if duration_scale(end - start) <= 0.0 {
+        return state;
+    }
+
+    for segment in timeline.segments(base, start, end) {
+        let time_scale = duration_scale(segment.duration());
+        if time_scale > 0.0 {
+            state = apply_time_scaled_effects(
+                state,
+                segment.context(),
+                relationship_quality,
+                time_scale,
+                life_stage,
+                current_timestamp,
+            );
+        }
+    }
+    apply_cohort_personality_effects(&mut state, &timeline.context_at(base, current_timestamp));
+    state
+}
+
+fn apply_time_scaled_effects(
+    mut state: IndividualState,
+    context: &EcologicalContext,
+    relationship_quality: f64,
+    time_scale: f32,
+    life_stage: LifeStage,
+    current_timestamp: Timestamp,
+) -> IndividualState {
+    // A time budget replaces authored interaction frequencies, and each
+    // microsystem must then pass the proximal process gate on its own.
+    // An evolving macrosystem's shift applies on top of the authored culture.
+    let derived;
+    let context = if context.time_budget().is_some() || context.macrosystem_shift().is_some() {
+        let mut resolved = context.clone();
+        if let Some(budget) = context.time_budget() {
+            budget.apply_to(&mut resolved);
+        }
+        if let Some(shift) = context.macrosystem_shift() {
+            *resolved.macrosystem_mut() = shift.apply_to(context.macrosystem());
+        }
+        derived = resolved;
+        &derived
+    } else {
+        context
+    };
+    let gate_each = context.time_budget().is_some();
+
     let (avg_frequency, avg_complexity) = compute_aggregate_interaction_metrics(context);
     let microsystem_multiplier = match check_proximal_process_gate(
         avg_frequency,
@@ -94,7 +176,13 @@ pub(crate) fn apply_context_effects(
     };
 
     if microsystem_multiplier > 0.0 {
-        apply_microsystem_effects(&mut state, context, time_scale, microsystem_multiplier);
+        apply_microsystem_effects(
+            &mut state,
+            context,
+            time_scale,
+            microsystem_multiplier,
+            gate_each,
+        );
         apply_mesosystem_spillover(&mut state, context, time_scale, microsystem_multiplier);
 
         let (stress_adj, loneliness_adj) =
@@ -105,6 +193,7 @@ pub(crate) fn apply_context_effects(
             .add_loneliness_delta(loneliness_adj * time_scale);
     }
 
+    apply_time_budget_effects(&mut state, context, time_scale);
     apply_exosystem_effects(&mut state, context, time_scale, life_stage);
     apply_macrosystem_effects(&mut state, context, time_scale, relationship_quality);
     apply_chronosystem_effects(&mut state, context, time_scale, current_timestamp);
@@ -142,8 +231,21 @@ fn apply_microsystem_effects(
     context: &EcologicalContext,
     time_scale: f32,
     microsystem_multiplier: f64,
+    gate_each: bool,
 ) {
     for (_, microsystem) in context.microsystems_iter() {
+        if gate_each
+            && check_proximal_process_gate(
+                microsystem.interaction_frequency(),
+                microsystem.interaction_complexity(),
+                INTERACTION_FREQUENCY_THRESHOLD,
+                INTERACTION_COMPLEXITY_THRESHOLD,
+            )
+            .is_err()
+        {
+            continue;
+        }
+
         if let Some(work) = microsystem.work() {
             let workload_excess = (work.workload_stress - 0.7).max(0.0);
             let stress_delta = (workload_excess * 0.15 * microsystem_multiplier) as f32;
@@ -256,9 +358,30 @@ fn compute_role_conflict(context: &EcologicalContext) -> f64 {
         }
     }
 
+    if let Some(budget) = context.time_budget() {
+        conflict = conflict.max(budget.role_conflict());
+    }
+
     conflict.clamp(0.0, 1.0)
 }
 
+/// Adds fatigue from committing more hours than a sustainable week.
+fn apply_time_budget_effects(
+    state: &mut IndividualState,
+    context: &EcologicalContext,
+    time_scale: f32,
+) {
+    let Some(budget) = context.time_budget() else {
+        return;
+    };
+    let fatigue_delta = (budget.overcommitment() * 0.1) as f32;
+    if fatigue_delta > 0.0 {
+        state
+            .needs_mut()
+            .add_fatigue_delta(fatigue_delta * time_scale);
+    }
+}
+
 fn apply_exosystem_effects(
     state: &mut IndividualState,
     context: &EcologicalContext,
@@ -288,6 +411,23 @@ fn apply_exosystem_effects(
             .add_loneliness_delta(cohesion_loneliness * time_scale);
     }
 
+    // Unmet care needs add stress; missing community services isolate
+    let care_deficit = (0.5 - exosystem.health_system_access).max(0.0) * reliability_multiplier;
+    if care_deficit > 0.0 {
+        let stress_delta = (care_deficit * 0.05) as f32;
+        state
+            .needs_mut()
+            .add_stress_delta(stress_delta * time_scale);
+    }
+    let services_deficit =
+        (0.5 - exosystem.community_services_availability).max(0.0) * reliability_multiplier;
+    if services_deficit > 0.0 {
+        let loneliness_delta = (services_deficit * 0.05) as f32;
+        state
+            .social_cognition_mut()
+            .add_loneliness_delta(loneliness_delta * time_scale);
+    }
+
     let institutional_support = exosystem.institutional_support;
     if institutional_support < 0.4 {
         let pressure = (0.4 - institutional_support) * 0.5 * reliability_multiplier;
@@ -332,6 +472,11 @@ fn apply_macrosystem_effects(
         .needs_mut()
         .add_stress_delta(stress_adjustment * time_scale);
 
+    let strain_adjustment = (context.macrosystem().societal_strain() * 0.05) as f32;
+    state
+        .needs_mut()
+        .add_stress_delta(strain_adjustment * time_scale);
+
     let hierarchy_penalty = context
         .macrosystem()
         .constraint_set()
@@ -427,6 +572,16 @@ fn apply_chronosystem_effects(
             .experience_diversity_mut()
             .add_delta(boost_delta * time_scale);
     }
+}
+
+/// Shifts personality toward the entity's birth cohort.
+///
+/// Unlike the other context effects this is not scaled by elapsed time.
+fn apply_cohort_personality_effects(state: &mut IndividualState, context: &EcologicalContext) {
+    let chronosystem = context.chronosystem();
+    let historical = chronosystem.historical_period();
+    let birth_era = chronosystem.cohort_effects().birth_era;
+    let current_era = BirthEra::from_label(&historical.era_name).unwrap_or(BirthEra::Unknown);
 
     let cohort_weight = chronosystem.cohort_effect_weight(current_era);
     // Apply cohort effects based on birth era (skip for Unknown or zero weight)
@@ -474,9 +629,11 @@ mod tests {
         let mut context = EcologicalContext::default();
         context.macrosystem_mut().cultural_stress = 0.0;
         let family_id = MicrosystemId::new("family").unwrap();
-        let mut family = FamilyContext::default();
-        family.family_satisfaction = 0.9;
-        family.warmth = 0.9;
+        let mut family = FamilyContext {
+            family_satisfaction: 0.9,
+            warmth: 0.9,
+            ..Default::default()
+        };
         family.interaction_profile.interaction_frequency = 0.8;
         family.interaction_profile.interaction_complexity = 0.8;
         context.add_microsystem(family_id, Microsystem::new_family(family));
@@ -526,15 +683,19 @@ mod tests {
         let work_id = MicrosystemId::new("work").unwrap();
         let family_id = MicrosystemId::new("family").unwrap();
 
-        let mut work = WorkContext::default();
-        work.workload_stress = 0.9;
+        let mut work = WorkContext {
+            workload_stress: 0.9,
+            ..Default::default()
+        };
         work.interaction_profile.interaction_frequency = 0.8;
         work.interaction_profile.interaction_complexity = 0.8;
         context.add_microsystem(work_id.clone(), Microsystem::new_work(work));
 
-        let mut family = FamilyContext::default();
-        family.predictability = 0.3;
-        family.stability = 0.3;
+        let mut family = FamilyContext {
+            predictability: 0.3,
+            stability: 0.3,
+            ..Default::default()
+        };
         family.interaction_profile.interaction_frequency = 0.8;
         family.interaction_profile.interaction_complexity = 0.8;
         context.add_microsystem(family_id.clone(), Microsystem::new_family(family));
@@ -560,15 +721,19 @@ mod tests {
         let work_id = MicrosystemId::new("work").unwrap();
         let family_id = MicrosystemId::new("family").unwrap();
 
-        let mut work = WorkContext::default();
-        work.workload_stress = 0.9;
+        let mut work = WorkContext {
+            workload_stress: 0.9,
+            ..Default::default()
+        };
         work.interaction_profile.interaction_frequency = 0.8;
         work.interaction_profile.interaction_complexity = 0.8;
         context.add_microsystem(work_id, Microsystem::new_work(work));
 
-        let mut family = FamilyContext::default();
-        family.predictability = 0.2;
-        family.stability = 0.2;
+        let mut family = FamilyContext {
+            predictability: 0.2,
+            stability: 0.2,
+            ..Default::default()
+        };
         family.interaction_profile.interaction_frequency = 0.8;
         family.interaction_profile.interaction_complexity = 0.8;
         context.add_microsystem(family_id, Microsystem::new_family(family));
@@ -607,15 +772,19 @@ mod tests {
         assert_eq!(linkages.len(), 1);
         let (from_id, to_id) = (linkages[0].0.clone(), linkages[0].1.clone());
 
-        let mut family = FamilyContext::default();
-        family.caregiving_burden = 0.9;
-        family.hostility = 0.6;
+        let mut family = FamilyContext {
+            caregiving_burden: 0.9,
+            hostility: 0.6,
+            ..Default::default()
+        };
         family.interaction_profile.interaction_frequency = 0.8;
         family.interaction_profile.interaction_complexity = 0.8;
 
-        let mut work = WorkContext::default();
-        work.predictability = 0.2;
-        work.stability = 0.2;
+        let mut work = WorkContext {
+            predictability: 0.2,
+            stability: 0.2,
+            ..Default::default()
+        };
         work.interaction_profile.interaction_frequency = 0.8;
         work.interaction_profile.interaction_complexity = 0.8;
 
@@ -640,16 +809,20 @@ mod tests {
         let social_id = MicrosystemId::new("social").unwrap();
         let education_id = MicrosystemId::new("education").unwrap();
 
-        let mut social = SocialContext::default();
-        social.hostility = 0.8;
-        social.group_standing = 0.2;
+        let mut social = SocialContext {
+            hostility: 0.8,
+            group_standing: 0.2,
+            ..Default::default()
+        };
         social.interaction_profile.interaction_frequency = 0.8;
         social.interaction_profile.interaction_complexity = 0.8;
         context.add_microsystem(social_id.clone(), Microsystem::new_social(social));
 
-        let mut education = EducationContext::default();
-        education.cognitive_demand = 0.7;
-        education.hostility = 0.7;
+        let mut education = EducationContext {
+            cognitive_demand: 0.7,
+            hostility: 0.7,
+            ..Default::default()
+        };
         education.interaction_profile.interaction_frequency = 0.8;
         education.interaction_profile.interaction_complexity = 0.8;
         context.add_microsystem(education_id.clone(), Microsystem::new_education(education));
@@ -667,20 +840,24 @@ mod tests {
     fn mesosystem_role_conflict_adds_stress() {
         let mut baseline = EcologicalContext::default();
 
-        let mut work = WorkContext::default();
-        work.workload_stress = 0.7;
-        work.role_clarity = 0.5;
-        work.predictability = 0.5;
-        work.warmth = 0.5;
-        work.hostility = 0.2;
+        let mut work = WorkContext {
+            workload_stress: 0.7,
+            role_clarity: 0.5,
+            predictability: 0.5,
+            warmth: 0.5,
+            hostility: 0.2,
+            ..Default::default()
+        };
         work.interaction_profile.interaction_frequency = 0.0;
 
-        let mut family = FamilyContext::default();
-        family.caregiving_burden = 0.3;
-        family.role_clarity = 0.5;
-        family.predictability = 0.5;
-        family.warmth = 0.5;
-        family.hostility = 0.2;
+        let mut family = FamilyContext {
+            caregiving_burden: 0.3,
+            role_clarity: 0.5,
+            predictability: 0.5,
+            warmth: 0.5,
+            hostility: 0.2,
+            ..Default::default()
+        };
         family.interaction_profile.interaction_frequency = 0.0;
 
         baseline.add_microsystem(
@@ -712,26 +889,32 @@ mod tests {
     fn mesosystem_consistency_buffers_stress() {
         let mut consistent = EcologicalContext::default();
 
-        let mut work = WorkContext::default();
-        work.workload_stress = 0.4;
-        work.role_clarity = 0.7;
-        work.predictability = 0.7;
-        work.warmth = 0.6;
-        work.hostility = 0.1;
+        let mut work = WorkContext {
+            workload_stress: 0.4,
+            role_clarity: 0.7,
+            predictability: 0.7,
+            warmth: 0.6,
+            hostility: 0.1,
+            ..Default::default()
+        };
         work.interaction_profile.interaction_frequency = 0.0;
 
-        let mut family = FamilyContext::default();
-        family.caregiving_burden = 0.3;
-        family.role_clarity = 0.7;
-        family.predictability = 0.7;
-        family.warmth = 0.6;
-        family.hostility = 0.1;
+        let mut family = FamilyContext {
+            caregiving_burden: 0.3,
+            role_clarity: 0.7,
+            predictability: 0.7,
+            warmth: 0.6,
+            hostility: 0.1,
+            ..Default::default()
+        };
         family.interaction_profile.interaction_frequency = 0.0;
 
-        let mut social = SocialContext::default();
-        social.predictability = 0.7;
-        social.warmth = 0.6;
-        social.hostility = 0.1;
+        let mut social = SocialContext {
+            predictability: 0.7,
+            warmth: 0.6,
+            hostility: 0.1,
+            ..Default::default()
+        };
         social.interaction_profile.interaction_frequency = 0.0;
 
         consistent.add_microsystem(
@@ -752,26 +935,32 @@ mod tests {
 
         let mut inconsistent = EcologicalContext::default();
 
-        let mut work = WorkContext::default();
-        work.workload_stress = 0.4;
-        work.role_clarity = 0.2;
-        work.predictability = 0.2;
-        work.warmth = 0.2;
-        work.hostility = 0.7;
+        let mut work = WorkContext {
+            workload_stress: 0.4,
+            role_clarity: 0.2,
+            predictability: 0.2,
+            warmth: 0.2,
+            hostility: 0.7,
+            ..Default::default()
+        };
         work.interaction_profile.interaction_frequency = 0.0;
 
-        let mut family = FamilyContext::default();
-        family.caregiving_burden = 0.3;
-        family.role_clarity = 0.9;
-        family.predictability = 0.9;
-        family.warmth = 0.9;
-        family.hostility = 0.1;
+        let mut family = FamilyContext {
+            caregiving_burden: 0.3,
+            role_clarity: 0.9,
+            predictability: 0.9,
+            warmth: 0.9,
+            hostility: 0.1,
+            ..Default::default()
+        };
         family.interaction_profile.interaction_frequency = 0.0;
 
-        let mut social = SocialContext::default();
-        social.predictability = 0.1;
-        social.warmth = 0.9;
-        social.hostility = 0.1;
+        let mut social = SocialContext {
+            predictability: 0.1,
+            warmth: 0.9,
+            hostility: 0.1,
+            ..Default::default()
+        };
         social.interaction_profile.interaction_frequency = 0.0;
 
         inconsistent.add_microsystem(
@@ -802,22 +991,26 @@ mod tests {
         let mut no_overlap = EcologicalContext::default();
         let mut overlap = EcologicalContext::default();
 
-        let mut work = WorkContext::default();
-        work.workload_stress = 0.8;
-        work.role_clarity = 0.6;
-        work.predictability = 0.6;
-        work.warmth = 0.6;
-        work.hostility = 0.1;
-        work.peer_ids = vec![EntityId::new("coworker").unwrap()];
+        let mut work = WorkContext {
+            workload_stress: 0.8,
+            role_clarity: 0.6,
+            predictability: 0.6,
+            warmth: 0.6,
+            hostility: 0.1,
+            peer_ids: vec![EntityId::new("coworker").unwrap()],
+            ..Default::default()
+        };
         work.interaction_profile.interaction_frequency = 0.8;
 
-        let mut family = FamilyContext::default();
-        family.caregiving_burden = 0.3;
-        family.role_clarity = 0.6;
-        family.predictability = 0.6;
-        family.warmth = 0.6;
-        family.hostility = 0.1;
-        family.family_unit = vec![EntityId::new("relative").unwrap()];
+        let mut family = FamilyContext {
+            caregiving_burden: 0.3,
+            role_clarity: 0.6,
+            predictability: 0.6,
+            warmth: 0.6,
+            hostility: 0.1,
+            family_unit: vec![EntityId::new("relative").unwrap()],
+            ..Default::default()
+        };
         family.interaction_profile.interaction_frequency = 0.8;
 
         no_overlap.add_microsystem(
@@ -877,6 +1070,32 @@ mod tests {
         );
     }
 
+    #[test]
+    fn low_health_access_and_community_services_add_stress_and_loneliness() {
+        let mut underserved = EcologicalContext::default();
+        underserved.exosystem_mut().health_system_access = 0.1;
+        underserved.exosystem_mut().community_services_availability = 0.1;
+
+        let apply = |context: &EcologicalContext| {
+            apply_context_effects(
+                IndividualState::new(),
+                context,
+                0.6,
+                Duration::days(30),
+                LifeStage::Adult,
+                test_timestamp(),
+            )
+        };
+        let served = apply(&EcologicalContext::default());
+        let underserved = apply(&underserved);
+
+        assert!(underserved.needs().stress().delta() > served.needs().stress().delta());
+        assert!(
+            underserved.social_cognition().loneliness().delta()
+                > served.social_cognition().loneliness().delta()
+        );
+    }
+
     #[test]
     fn low_institutional_support_increases_reactance() {
         let mut context = EcologicalContext::default();
@@ -1042,8 +1261,10 @@ mod tests {
             .uncertainty_avoidance = 0.8;
 
         let work_id = MicrosystemId::new("work").unwrap();
-        let mut work = WorkContext::default();
-        work.predictability = 0.2;
+        let mut work = WorkContext {
+            predictability: 0.2,
+            ..Default::default()
+        };
         work.interaction_profile.interaction_frequency = 0.0;
         work.interaction_profile.interaction_complexity = 0.0;
         high_ua.add_microsystem(work_id, Microsystem::new_work(work));
@@ -1234,8 +1455,10 @@ mod tests {
         let mut context = EcologicalContext::default();
         context.macrosystem_mut().cultural_stress = 0.0;
         let work_id = MicrosystemId::new("work").unwrap();
-        let mut work = WorkContext::default();
-        work.workload_stress = 0.9;
+        let mut work = WorkContext {
+            workload_stress: 0.9,
+            ..Default::default()
+        };
         work.interaction_profile.interaction_frequency = 0.2;
         work.interaction_profile.interaction_complexity = 0.2;
         context.add_microsystem(work_id, Microsystem::new_work(work));
@@ -1253,8 +1476,10 @@ mod tests {
         let mut open_context = EcologicalContext::default();
         open_context.macrosystem_mut().cultural_stress = 0.0;
         let work_id = MicrosystemId::new("work_open").unwrap();
-        let mut work = WorkContext::default();
-        work.workload_stress = 0.9;
+        let mut work = WorkContext {
+            workload_stress: 0.9,
+            ..Default::default()
+        };
         work.interaction_profile.interaction_frequency = 0.8;
         work.interaction_profile.interaction_complexity = 0.8;
         open_context.add_microsystem(work_id, Microsystem::new_work(work));
@@ -1277,8 +1502,10 @@ mod tests {
         let mut context = EcologicalContext::default();
         context.macrosystem_mut().cultural_stress = 0.0;
         let work_id = MicrosystemId::new("work").unwrap();
-        let mut work = WorkContext::default();
-        work.workload_stress = 0.9;
+        let mut work = WorkContext {
+            workload_stress: 0.9,
+            ..Default::default()
+        };
         work.interaction_profile.interaction_frequency = 0.8;
         work.interaction_profile.interaction_complexity = 0.1;
         context.add_microsystem(work_id, Microsystem::new_work(work));
@@ -1301,8 +1528,10 @@ mod tests {
         let mut context = EcologicalContext::default();
         context.macrosystem_mut().cultural_stress = 0.0;
         let work_id = MicrosystemId::new("work").unwrap();
-        let mut work = WorkContext::default();
-        work.workload_stress = 0.9;
+        let mut work = WorkContext {
+            workload_stress: 0.9,
+            ..Default::default()
+        };
         work.interaction_profile.interaction_frequency = 0.1;
         work.interaction_profile.interaction_complexity = 0.1;
         context.add_microsystem(work_id, Microsystem::new_work(work));
@@ -1324,8 +1553,10 @@ mod tests {
     fn parent_experiences_higher_family_stress_impact() {
         let mut context = EcologicalContext::default();
         let family_id = MicrosystemId::new("family").unwrap();
-        let mut family = FamilyContext::default();
-        family.caregiving_burden = 0.8;
+        let mut family = FamilyContext {
+            caregiving_burden: 0.8,
+            ..Default::default()
+        };
         family.interaction_profile.interaction_frequency = 0.8;
         family.interaction_profile.interaction_complexity = 0.8;
         family.family_role = FamilyRole::Parent;
@@ -1342,8 +1573,10 @@ mod tests {
 
         let mut context = EcologicalContext::default();
         let family_id = MicrosystemId::new("family_extended").unwrap();
-        let mut family = FamilyContext::default();
-        family.caregiving_burden = 0.8;
+        let mut family = FamilyContext {
+            caregiving_burden: 0.8,
+            ..Default::default()
+        };
         family.interaction_profile.interaction_frequency = 0.8;
         family.interaction_profile.interaction_complexity = 0.8;
         family.family_role = FamilyRole::Extended;
@@ -1365,8 +1598,10 @@ mod tests {
     fn child_absorbs_parental_stress_at_higher_rate() {
         let mut context = EcologicalContext::default();
         let family_id = MicrosystemId::new("family").unwrap();
-        let mut family = FamilyContext::default();
-        family.caregiving_burden = 0.8;
+        let mut family = FamilyContext {
+            caregiving_burden: 0.8,
+            ..Default::default()
+        };
         family.interaction_profile.interaction_frequency = 0.8;
         family.interaction_profile.interaction_complexity = 0.8;
         family.family_role = FamilyRole::Child;
@@ -1383,8 +1618,10 @@ mod tests {
 
         let mut context = EcologicalContext::default();
         let family_id = MicrosystemId::new("family_extended").unwrap();
-        let mut family = FamilyContext::default();
-        family.caregiving_burden = 0.8;
+        let mut family = FamilyContext {
+            caregiving_burden: 0.8,
+            ..Default::default()
+        };
         family.interaction_profile.interaction_frequency = 0.8;
         family.interaction_profile.interaction_complexity = 0.8;
         family.family_role = FamilyRole::Extended;
@@ -1406,11 +1643,13 @@ mod tests {
     fn role_multipliers_affect_all_family_dimensions() {
         let mut context = EcologicalContext::default();
         let family_id = MicrosystemId::new("family").unwrap();
-        let mut family = FamilyContext::default();
-        family.family_satisfaction = 0.9;
-        family.warmth = 0.9;
-        family.caregiving_burden = 0.8;
-        family.hostility = 0.6;
+        let mut family = FamilyContext {
+            family_satisfaction: 0.9,
+            warmth: 0.9,
+            caregiving_burden: 0.8,
+            hostility: 0.6,
+            ..Default::default()
+        };
         family.interaction_profile.interaction_frequency = 0.8;
         family.interaction_profile.interaction_complexity = 0.8;
         family.family_role = FamilyRole::Parent;
@@ -1427,11 +1666,13 @@ mod tests {
 
         let mut context = EcologicalContext::default();
         let family_id = MicrosystemId::new("family_extended").unwrap();
-        let mut family = FamilyContext::default();
-        family.family_satisfaction = 0.9;
-        family.warmth = 0.9;
-        family.caregiving_burden = 0.8;
-        family.hostility = 0.6;
+        let mut family = FamilyContext {
+            family_satisfaction: 0.9,
+            warmth: 0.9,
+            caregiving_burden: 0.8,
+            hostility: 0.6,
+            ..Default::default()
+        };
         family.interaction_profile.interaction_frequency = 0.8;
         family.interaction_profile.interaction_complexity = 0.8;
         family.family_role = FamilyRole::Extended;
@@ -1480,8 +1721,10 @@ mod tests {
         let mut context = EcologicalContext::default();
         context.macrosystem_mut().cultural_stress = 0.0;
         let work_id = MicrosystemId::new("work").unwrap();
-        let mut work = WorkContext::default();
-        work.workload_stress = 0.9;
+        let mut work = WorkContext {
+            workload_stress: 0.9,
+            ..Default::default()
+        };
         work.interaction_profile.interaction_frequency = 0.0;
         work.interaction_profile.interaction_complexity = 0.0;
         context.add_microsystem(work_id, Microsystem::new_work(work));
@@ -1504,8 +1747,10 @@ mod tests {
         let mut context = EcologicalContext::default();
         context.macrosystem_mut().cultural_stress = 0.0;
         let social_id = MicrosystemId::new("social").unwrap();
-        let mut social = SocialContext::default();
-        social.hostility = 0.6;
+        let mut social = SocialContext {
+            hostility: 0.6,
+            ..Default::default()
+        };
         social.interaction_profile.interaction_frequency = 0.8;
         social.interaction_profile.interaction_complexity = 0.8;
         context.add_microsystem(social_id, Microsystem::new_social(social));
@@ -1525,15 +1770,19 @@ mod tests {
         let mut context_with_work = EcologicalContext::default();
         context_with_work.macrosystem_mut().cultural_stress = 0.0;
         let social_id = MicrosystemId::new("social").unwrap();
-        let mut social = SocialContext::default();
-        social.hostility = 0.6;
+        let mut social = SocialContext {
+            hostility: 0.6,
+            ..Default::default()
+        };
         social.interaction_profile.interaction_frequency = 0.8;
         social.interaction_profile.interaction_complexity = 0.8;
         context_with_work.add_microsystem(social_id, Microsystem::new_social(social));
 
         let work_id = MicrosystemId::new("work").unwrap();
-        let mut work = WorkContext::default();
-        work.workload_stress = 0.8;
+        let mut work = WorkContext {
+            workload_stress: 0.8,
+            ..Default::default()
+        };
         work.interaction_profile.interaction_frequency = 0.8;
         work.interaction_profile.interaction_complexity = 0.8;
         context_with_work.add_microsystem(work_id, Microsystem::new_work(work));
@@ -1555,8 +1804,10 @@ mod tests {
         let mut context = EcologicalContext::default();
         context.macrosystem_mut().cultural_stress = 0.0;
         let work_id = MicrosystemId::new("work").unwrap();
-        let mut work = WorkContext::default();
-        work.workload_stress = 0.5;
+        let mut work = WorkContext {
+            workload_stress: 0.5,
+            ..Default::default()
+        };
         work.interaction_profile.interaction_frequency = 0.8;
         work.interaction_profile.interaction_complexity = 0.8;
         context.add_microsystem(work_id, Microsystem::new_work(work));
@@ -1580,14 +1831,18 @@ mod tests {
         let work_id = MicrosystemId::new("work").unwrap();
         let family_id = MicrosystemId::new("family").unwrap();
 
-        let mut work = WorkContext::default();
-        work.workload_stress = 0.0;
+        let mut work = WorkContext {
+            workload_stress: 0.0,
+            ..Default::default()
+        };
         work.interaction_profile.interaction_frequency = 0.8;
         work.interaction_profile.interaction_complexity = 0.8;
         context.add_microsystem(work_id, Microsystem::new_work(work));
 
-        let mut family = FamilyContext::default();
-        family.caregiving_burden = 0.0;
+        let mut family = FamilyContext {
+            caregiving_burden: 0.0,
+            ..Default::default()
+        };
         family.interaction_profile.interaction_frequency = 0.8;
         family.interaction_profile.interaction_complexity = 0.8;
         context.add_microsystem(family_id, Microsystem::new_family(family));
@@ -1604,13 +1859,17 @@ mod tests {
         let work_id = MicrosystemId::new("work").unwrap();
         let family_id = MicrosystemId::new("family").unwrap();
 
-        let mut work = WorkContext::default();
-        work.workload_stress = 0.5;
+        let mut work = WorkContext {
+            workload_stress: 0.5,
+            ..Default::default()
+        };
         work.interaction_profile.interaction_frequency = 0.0;
         context.add_microsystem(work_id, Microsystem::new_work(work));
 
-        let mut family = FamilyContext::default();
-        family.caregiving_burden = 0.3;
+        let mut family = FamilyContext {
+            caregiving_burden: 0.3,
+            ..Default::default()
+        };
         family.interaction_profile.interaction_frequency = 0.0;
         context.add_microsystem(family_id, Microsystem::new_family(family));
 
@@ -1625,22 +1884,26 @@ mod tests {
     fn mesosystem_consistency_perfect_skips_effect() {
         let mut consistent = EcologicalContext::default();
 
-        let mut work = WorkContext::default();
-        work.role_clarity = 1.0;
-        work.predictability = 1.0;
-        work.warmth = 1.0;
-        work.hostility = 0.0;
+        let mut work = WorkContext {
+            role_clarity: 1.0,
+            predictability: 1.0,
+            warmth: 1.0,
+            hostility: 0.0,
+            ..Default::default()
+        };
         work.interaction_profile.interaction_frequency = 0.0;
         consistent.add_microsystem(
             MicrosystemId::new("work").unwrap(),
             Microsystem::new_work(work),
         );
 
-        let mut family = FamilyContext::default();
-        family.role_clarity = 1.0;
-        family.predictability = 1.0;
-        family.warmth = 1.0;
-        family.hostility = 0.0;
+        let mut family = FamilyContext {
+            role_clarity: 1.0,
+            predictability: 1.0,
+            warmth: 1.0,
+            hostility: 0.0,
+            ..Default::default()
+        };
         family.interaction_profile.interaction_frequency = 0.0;
         consistent.add_microsystem(
             MicrosystemId::new("family").unwrap(),
@@ -1968,9 +2231,11 @@ mod tests {
         let mut context = EcologicalContext::default();
         context.macrosystem_mut().cultural_stress = 0.0;
         let family_id = MicrosystemId::new("family").unwrap();
-        let mut family = FamilyContext::default();
-        family.family_satisfaction = 0.5;
-        family.warmth = 0.5;
+        let mut family = FamilyContext {
+            family_satisfaction: 0.5,
+            warmth: 0.5,
+            ..Default::default()
+        };
         family.interaction_profile.interaction_frequency = 0.8;
         family.interaction_profile.interaction_complexity = 0.8;
         context.add_microsystem(family_id, Microsystem::new_family(family));
@@ -1993,8 +2258,10 @@ mod tests {
         let mut low_context = EcologicalContext::default();
         low_context.macrosystem_mut().cultural_stress = 0.0;
         let work_id = MicrosystemId::new("work").unwrap();
-        let mut work = WorkContext::default();
-        work.workload_stress = 0.5;
+        let mut work = WorkContext {
+            workload_stress: 0.5,
+            ..Default::default()
+        };
         work.interaction_profile.interaction_frequency = 0.8;
         work.interaction_profile.interaction_complexity = 0.8;
         low_context.add_microsystem(work_id, Microsystem::new_work(work));
@@ -2011,8 +2278,10 @@ mod tests {
         let mut high_context = EcologicalContext::default();
         high_context.macrosystem_mut().cultural_stress = 0.0;
         let work_id = MicrosystemId::new("work").unwrap();
-        let mut work = WorkContext::default();
-        work.workload_stress = 0.9;
+        let mut work = WorkContext {
+            workload_stress: 0.9,
+            ..Default::default()
+        };
         work.interaction_profile.interaction_frequency = 0.8;
         work.interaction_profile.interaction_complexity = 0.8;
         high_context.add_microsystem(work_id, Microsystem::new_work(work));
@@ -2034,8 +2303,10 @@ mod tests {
         let mut context = EcologicalContext::default();
         context.macrosystem_mut().cultural_stress = 0.0;
         let family_id = MicrosystemId::new("family").unwrap();
-        let mut family = FamilyContext::default();
-        family.caregiving_burden = 0.3;
+        let mut family = FamilyContext {
+            caregiving_burden: 0.3,
+            ..Default::default()
+        };
         family.interaction_profile.interaction_frequency = 0.8;
         family.interaction_profile.interaction_complexity = 0.8;
         context.add_microsystem(family_id, Microsystem::new_family(family));
@@ -2058,8 +2329,10 @@ mod tests {
         let mut context = EcologicalContext::default();
         context.macrosystem_mut().cultural_stress = 0.0;
         let family_id = MicrosystemId::new("family").unwrap();
-        let mut family = FamilyContext::default();
-        family.hostility = 0.4;
+        let mut family = FamilyContext {
+            hostility: 0.4,
+            ..Default::default()
+        };
         family.interaction_profile.interaction_frequency = 0.8;
         family.interaction_profile.interaction_complexity = 0.8;
         context.add_microsystem(family_id, Microsystem::new_family(family));
@@ -2082,8 +2355,10 @@ mod tests {
         let mut context = EcologicalContext::default();
         context.macrosystem_mut().cultural_stress = 0.0;
         let family_id = MicrosystemId::new("family").unwrap();
-        let mut family = FamilyContext::default();
-        family.warmth = 0.4;
+        let mut family = FamilyContext {
+            warmth: 0.4,
+            ..Default::default()
+        };
         family.interaction_profile.interaction_frequency = 0.8;
         family.interaction_profile.interaction_complexity = 0.8;
         context.add_microsystem(family_id, Microsystem::new_family(family));
@@ -2133,8 +2408,10 @@ mod tests {
             .uncertainty_avoidance = 0.3;
 
         let work_id = MicrosystemId::new("work").unwrap();
-        let mut work = WorkContext::default();
-        work.predictability = 0.8;
+        let mut work = WorkContext {
+            predictability: 0.8,
+            ..Default::default()
+        };
         work.interaction_profile.interaction_frequency = 0.0;
         work.interaction_profile.interaction_complexity = 0.0;
         context.add_microsystem(work_id, Microsystem::new_work(work));
@@ -2248,29 +2525,37 @@ mod tests {
         context.macrosystem_mut().cultural_stress = 0.0;
 
         let work1_id = MicrosystemId::new("work1").unwrap();
-        let mut work1 = WorkContext::default();
-        work1.workload_stress = 0.8;
+        let mut work1 = WorkContext {
+            workload_stress: 0.8,
+            ..Default::default()
+        };
         work1.interaction_profile.interaction_frequency = 0.8;
         work1.interaction_profile.interaction_complexity = 0.8;
         context.add_microsystem(work1_id, Microsystem::new_work(work1));
 
         let family1_id = MicrosystemId::new("family1").unwrap();
-        let mut family1 = FamilyContext::default();
-        family1.caregiving_burden = 0.8;
+        let mut family1 = FamilyContext {
+            caregiving_burden: 0.8,
+            ..Default::default()
+        };
         family1.interaction_profile.interaction_frequency = 0.8;
         family1.interaction_profile.interaction_complexity = 0.8;
         context.add_microsystem(family1_id, Microsystem::new_family(family1));
 
         let work2_id = MicrosystemId::new("work2").unwrap();
-        let mut work2 = WorkContext::default();
-        work2.workload_stress = 0.9;
+        let mut work2 = WorkContext {
+            workload_stress: 0.9,
+            ..Default::default()
+        };
         work2.interaction_profile.interaction_frequency = 0.8;
         work2.interaction_profile.interaction_complexity = 0.8;
         context.add_microsystem(work2_id, Microsystem::new_work(work2));
 
         let family2_id = MicrosystemId::new("family2").unwrap();
-        let mut family2 = FamilyContext::default();
-        family2.caregiving_burden = 0.9;
+        let mut family2 = FamilyContext {
+            caregiving_burden: 0.9,
+            ..Default::default()
+        };
         family2.interaction_profile.interaction_frequency = 0.8;
         family2.interaction_profile.interaction_complexity = 0.8;
         context.add_microsystem(family2_id, Microsystem::new_family(family2));
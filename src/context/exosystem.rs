@@ -9,7 +9,10 @@
 //! - Local school board policies affect education quality
 //! - Healthcare system access affects available care
 
+use crate::context::EcologicalContext;
 use crate::enums::ExosystemPath;
+use crate::state::IndividualState;
+use crate::types::EntityId;
 
 /// Parent work environment quality.
 ///
@@ -48,6 +51,62 @@ impl ParentWorkQuality {
             1.0 - (self.stress_level * 0.3 + (1.0 - self.schedule_flexibility) * 0.2);
         (base_capacity * self.income_stability).clamp(0.0, 1.0)
     }
+
+    /// Derives work quality from a parent's projected state and context.
+    ///
+    /// Work microsystems are averaged when the parent has several. Without
+    /// any, the parent's own needs and material security stand alone:
+    ///
+    /// - Stress: mean of workload stress and the parent's stress.
+    /// - Schedule flexibility: 1 - mean of workload stress and fatigue.
+    /// - Income stability: mean of job stability and material security.
+    #[must_use]
+    pub fn from_parent(state: &IndividualState, context: &EcologicalContext) -> Self {
+        let needs = state.needs();
+        let stress = f64::from(needs.stress_effective());
+        let fatigue = f64::from(needs.fatigue_effective());
+        let material_security =
+            f64::from(state.person_characteristics().material_security_effective());
+
+        let jobs: Vec<_> = context
+            .microsystems_iter()
+            .filter_map(|(_, microsystem)| microsystem.work())
+            .collect();
+        if jobs.is_empty() {
+            return ParentWorkQuality {
+                stress_level: stress.clamp(0.0, 1.0),
+                schedule_flexibility: (1.0 - fatigue).clamp(0.0, 1.0),
+                income_stability: material_security.clamp(0.0, 1.0),
+            };
+        }
+
+        let count = jobs.len() as f64;
+        let workload = jobs.iter().map(|work| work.workload_stress).sum::<f64>() / count;
+        let job_stability = jobs.iter().map(|work| work.stability).sum::<f64>() / count;
+        ParentWorkQuality {
+            stress_level: ((workload + stress) / 2.0).clamp(0.0, 1.0),
+            schedule_flexibility: (1.0 - (workload + fatigue) / 2.0).clamp(0.0, 1.0),
+            income_stability: ((job_stability + material_security) / 2.0).clamp(0.0, 1.0),
+        }
+    }
+
+    /// Averages the work quality of several parents.
+    ///
+    /// Returns None if there are no parents.
+    #[must_use]
+    pub fn average(parents: &[ParentWorkQuality]) -> Option<Self> {
+        if parents.is_empty() {
+            return None;
+        }
+        let count = parents.len() as f64;
+        let mean =
+            |value: fn(&ParentWorkQuality) -> f64| parents.iter().map(value).sum::<f64>() / count;
+        Some(ParentWorkQuality {
+            stress_level: mean(|p| p.stress_level),
+            schedule_flexibility: mean(|p| p.schedule_flexibility),
+            income_stability: mean(|p| p.income_stability),
+        })
+    }
 }
 
 /// Exosystem context for indirect environmental influences.
@@ -73,6 +132,13 @@ pub struct ExosystemContext {
 
     /// Parent work environment (for children).
     pub parent_work_environment: Option<ParentWorkQuality>,
+
+    /// Parents simulated as entities (for children).
+    ///
+    /// When set, a simulation derives `parent_work_environment` from these
+    /// parents' projected states at each queried timestamp, replacing any
+    /// hand-entered value.
+    pub parent_ids: Vec<EntityId>,
 }
 
 impl Default for ExosystemContext {
@@ -84,6 +150,7 @@ impl Default for ExosystemContext {
             resource_availability: 0.6,
             institutional_support: 0.5,
             parent_work_environment: None,
+            parent_ids: Vec::new(),
         }
     }
 }
@@ -233,7 +300,7 @@ mod tests {
         let exo = ExosystemContext::default();
         for path in ExosystemPath::all() {
             let value = exo.get_value(&path);
-            assert!(value >= 0.0 && value <= 1.0);
+            assert!((0.0..=1.0).contains(&value));
         }
     }
 
@@ -290,8 +357,10 @@ mod tests {
 
     #[test]
     fn exosystem_parent_capacity_some() {
-        let mut exo = ExosystemContext::default();
-        exo.parent_work_environment = Some(ParentWorkQuality::default());
+        let exo = ExosystemContext {
+            parent_work_environment: Some(ParentWorkQuality::default()),
+            ..Default::default()
+        };
         let capacity = exo.parent_capacity();
         assert!(capacity.is_some());
         assert!(capacity.unwrap() > 0.4);
@@ -343,12 +412,14 @@ mod tests {
 
     #[test]
     fn exosystem_with_parent_environment() {
-        let mut exo = ExosystemContext::default();
-        exo.parent_work_environment = Some(ParentWorkQuality {
-            stress_level: 0.8,
-            schedule_flexibility: 0.2,
-            income_stability: 0.4,
-        });
+        let exo = ExosystemContext {
+            parent_work_environment: Some(ParentWorkQuality {
+                stress_level: 0.8,
+                schedule_flexibility: 0.2,
+                income_stability: 0.4,
+            }),
+            ..Default::default()
+        };
 
         assert!((exo.get_value(&ExosystemPath::ParentWorkStress) - 0.8).abs() < f64::EPSILON);
         assert!(
@@ -443,11 +514,15 @@ mod tests {
         // Institutional support buffers stress
         // Higher support = more stress buffering capacity
 
-        let mut low_support = ExosystemContext::default();
-        low_support.institutional_support = 0.2;
+        let low_support = ExosystemContext {
+            institutional_support: 0.2,
+            ..Default::default()
+        };
 
-        let mut high_support = ExosystemContext::default();
-        high_support.institutional_support = 0.9;
+        let high_support = ExosystemContext {
+            institutional_support: 0.9,
+            ..Default::default()
+        };
 
         // Verify values are set correctly
         assert!(
@@ -474,4 +549,60 @@ mod tests {
         let buffer_difference = high_buffer_effect - low_buffer_effect;
         assert!((buffer_difference - 0.07).abs() < 0.001);
     }
+
+    #[test]
+    fn parent_work_quality_from_parent_without_job() {
+        let mut state = IndividualState::new();
+        state
+            .person_characteristics_mut()
+            .material_security_mut()
+            .set_base(0.8);
+        let quality = ParentWorkQuality::from_parent(&state, &EcologicalContext::default());
+
+        // Default stress 0.2 and fatigue 0.2
+        assert!((quality.stress_level - 0.2).abs() < 1e-6);
+        assert!((quality.schedule_flexibility - 0.8).abs() < 1e-6);
+        assert!((quality.income_stability - 0.8).abs() < 1e-6);
+    }
+
+    #[test]
+    fn parent_work_quality_from_parent_with_job() {
+        use crate::context::{Microsystem, WorkContext};
+        use crate::types::MicrosystemId;
+
+        let mut context = EcologicalContext::default();
+        let work = WorkContext {
+            workload_stress: 0.8,
+            stability: 0.2,
+            ..Default::default()
+        };
+        context.add_microsystem(
+            MicrosystemId::new("work").unwrap(),
+            Microsystem::new_work(work),
+        );
+        let quality = ParentWorkQuality::from_parent(&IndividualState::new(), &context);
+
+        assert!((quality.stress_level - 0.5).abs() < 1e-6);
+        assert!((quality.schedule_flexibility - 0.5).abs() < 1e-6);
+        // Mean of job stability 0.2 and default material security 0.5
+        assert!((quality.income_stability - 0.35).abs() < 1e-6);
+    }
+
+    #[test]
+    fn parent_work_quality_average() {
+        assert!(ParentWorkQuality::average(&[]).is_none());
+        let calm = ParentWorkQuality {
+            stress_level: 0.2,
+            schedule_flexibility: 0.8,
+            income_stability: 0.9,
+        };
+        let stressed = ParentWorkQuality {
+            stress_level: 0.8,
+            schedule_flexibility: 0.2,
+            income_stability: 0.3,
+        };
+        let average = ParentWorkQuality::average(&[calm, stressed]).unwrap();
+        assert!((average.stress_level - 0.5).abs() < 1e-9);
+        assert!((average.income_stability - 0.6).abs() < 1e-9);
+    }
 }
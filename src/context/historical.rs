@@ -0,0 +1,262 @@
+//! Simulation-wide historical events.
+//!
+//! A pandemic, recession or war is declared once for the simulation and
+//! reaches every entity in its scope. Each exposed entity experiences it as
+//! an `EventType::HistoricalEvent` at the event's start, weighted by:
+//!
+//! - **Critical periods**: the entity's `"historical"` critical periods at
+//!   its age on exposure.
+//! - **Cohort resonance**: entities born into an era of the same kind (per
+//!   `ChronosystemContext::cohort_effect_weight`) react more strongly.
+//!
+//! Life-stage plasticity at exposure is applied on top, as for any event.
+//! Entities born while an event is underway take its era as their
+//! `BirthEra` unless one was set explicitly.
+
+use crate::context::{ChronosystemContext, NonNormativeEvent};
+use crate::enums::{BirthEra, HistoricalEventType, HistoricalScope};
+use crate::types::{MicrosystemId, Timestamp};
+
+/// Critical period domain consulted when weighting historical exposure.
+pub const HISTORICAL_CRITICAL_PERIOD_DOMAIN: &str = "historical";
+
+/// Exposure multiplier for entities born into an era of the same kind.
+const COHORT_RESONANCE: f64 = 1.5;
+
+/// Who a shared historical event reaches.
+#[derive(Debug, Clone, PartialEq)]
+pub enum HistoricalReach {
+    /// Every entity in the simulation.
+    Global,
+    /// Members of a shared microsystem, such as a neighborhood.
+    Region(MicrosystemId),
+    /// Entities born within `[born_from, born_until)`.
+    Cohort {
+        /// Earliest birth date included.
+        born_from: Timestamp,
+        /// Birth date at which the cohort ends (exclusive).
+        born_until: Timestamp,
+    },
+}
+
+impl HistoricalReach {
+    /// Returns the scope reported in the event payload.
+    ///
+    /// Cohorts are defined within a society, so they report national scope.
+    #[must_use]
+    pub fn scope(&self) -> HistoricalScope {
+        match self {
+            HistoricalReach::Global => HistoricalScope::Global,
+            HistoricalReach::Region(_) => HistoricalScope::Regional,
+            HistoricalReach::Cohort { .. } => HistoricalScope::National,
+        }
+    }
+}
+
+/// A historical event declared for the whole simulation.
+///
+/// # Examples
+///
+/// ```
+/// use eventsim_rs::context::{HistoricalReach, SharedHistoricalEvent};
+/// use eventsim_rs::enums::{BirthEra, HistoricalEventType};
+/// use eventsim_rs::types::{MicrosystemId, Timestamp};
+///
+/// let pandemic = SharedHistoricalEvent::new(
+///     "pandemic",
+///     HistoricalEventType::Pandemic,
+///     Timestamp::from_ymd_hms(2020, 3, 1, 0, 0, 0),
+///     0.7,
+/// )
+/// .with_end(Timestamp::from_ymd_hms(2022, 3, 1, 0, 0, 0))
+/// .with_reach(HistoricalReach::Region(MicrosystemId::new("city").unwrap()));
+///
+/// assert_eq!(pandemic.era(), BirthEra::Crisis);
+/// assert!(pandemic.is_underway(Timestamp::from_ymd_hms(2021, 1, 1, 0, 0, 0)));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct SharedHistoricalEvent {
+    name: String,
+    event_type: HistoricalEventType,
+    start: Timestamp,
+    end: Option<Timestamp>,
+    severity: f64,
+    reach: HistoricalReach,
+}
+
+impl SharedHistoricalEvent {
+    /// Creates an ongoing, global historical event.
+    ///
+    /// Severity is clamped to 0-1.
+    #[must_use]
+    pub fn new(
+        name: impl Into<String>,
+        event_type: HistoricalEventType,
+        start: Timestamp,
+        severity: f64,
+    ) -> Self {
+        SharedHistoricalEvent {
+            name: name.into(),
+            event_type,
+            start,
+            end: None,
+            severity: severity.clamp(0.0, 1.0),
+            reach: HistoricalReach::Global,
+        }
+    }
+
+    /// Sets when the event ends.
+    #[must_use]
+    pub fn with_end(mut self, end: Timestamp) -> Self {
+        self.end = Some(end.max(self.start));
+        self
+    }
+
+    /// Sets who the event reaches.
+    #[must_use]
+    pub fn with_reach(mut self, reach: HistoricalReach) -> Self {
+        self.reach = reach;
+        self
+    }
+
+    /// Returns the event's name.
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the kind of historical event.
+    #[must_use]
+    pub fn event_type(&self) -> HistoricalEventType {
+        self.event_type
+    }
+
+    /// Returns when the event starts.
+    #[must_use]
+    pub fn start(&self) -> Timestamp {
+        self.start
+    }
+
+    /// Returns when the event ends, or None if it is ongoing.
+    #[must_use]
+    pub fn end(&self) -> Option<Timestamp> {
+        self.end
+    }
+
+    /// Returns the event's severity (0-1).
+    #[must_use]
+    pub fn severity(&self) -> f64 {
+        self.severity
+    }
+
+    /// Returns who the event reaches.
+    #[must_use]
+    pub fn reach(&self) -> &HistoricalReach {
+        &self.reach
+    }
+
+    /// Returns the birth era of entities born during the event.
+    #[must_use]
+    pub fn era(&self) -> BirthEra {
+        match self.event_type {
+            HistoricalEventType::War
+            | HistoricalEventType::Pandemic
+            | HistoricalEventType::NaturalDisaster
+            | HistoricalEventType::PoliticalChange => BirthEra::Crisis,
+            HistoricalEventType::EconomicCrisis => BirthEra::Scarcity,
+            HistoricalEventType::TechnologicalShift => BirthEra::Expansion,
+        }
+    }
+
+    /// Returns true if the event is underway at the timestamp.
+    ///
+    /// The start is included and the end is excluded.
+    #[must_use]
+    pub fn is_underway(&self, timestamp: Timestamp) -> bool {
+        timestamp >= self.start && self.end.is_none_or(|end| timestamp < end)
+    }
+
+    /// Returns how strongly an entity exposed at `age_years` is affected,
+    /// relative to the event's severity.
+    ///
+    /// `chronosystem` should carry the entity's resolved birth era.
+    #[must_use]
+    pub fn exposure_weight(&self, chronosystem: &ChronosystemContext, age_years: f64) -> f64 {
+        let critical = chronosystem
+            .get_sensitive_period_multiplier(age_years, HISTORICAL_CRITICAL_PERIOD_DOMAIN);
+        let resonance = if chronosystem.cohort_effect_weight(self.era()) >= 1.0 {
+            COHORT_RESONANCE
+        } else {
+            1.0
+        };
+        critical * resonance
+    }
+
+    /// Returns the event as a chronosystem record seen at `timestamp`.
+    ///
+    /// The end year is only reported once the event has ended.
+    #[must_use]
+    pub fn as_non_normative(&self, timestamp: Timestamp) -> NonNormativeEvent {
+        match self.end {
+            Some(end) if end <= timestamp => NonNormativeEvent::completed(
+                self.name.clone(),
+                self.start.year(),
+                end.year(),
+                self.severity,
+            ),
+            _ => NonNormativeEvent::new(self.name.clone(), self.start.year(), self.severity),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::context::CriticalPeriod;
+
+    fn recession() -> SharedHistoricalEvent {
+        SharedHistoricalEvent::new(
+            "recession",
+            HistoricalEventType::EconomicCrisis,
+            Timestamp::from_ymd_hms(2008, 9, 1, 0, 0, 0),
+            0.6,
+        )
+        .with_end(Timestamp::from_ymd_hms(2010, 6, 1, 0, 0, 0))
+    }
+
+    #[test]
+    fn underway_window_excludes_end() {
+        let event = recession();
+        assert!(!event.is_underway(Timestamp::from_ymd_hms(2008, 8, 31, 0, 0, 0)));
+        assert!(event.is_underway(event.start()));
+        assert!(!event.is_underway(Timestamp::from_ymd_hms(2010, 6, 1, 0, 0, 0)));
+        assert_eq!(event.era(), BirthEra::Scarcity);
+    }
+
+    #[test]
+    fn exposure_weight_combines_critical_period_and_cohort() {
+        let event = recession();
+        let mut chrono = ChronosystemContext::default();
+        assert!((event.exposure_weight(&chrono, 16.0) - 1.0).abs() < f64::EPSILON);
+
+        chrono.add_critical_period(CriticalPeriod::new("historical", 12.0, 25.0, 1.6));
+        assert!((event.exposure_weight(&chrono, 16.0) - 1.6).abs() < f64::EPSILON);
+        assert!((event.exposure_weight(&chrono, 40.0) - 1.0).abs() < f64::EPSILON);
+
+        chrono.cohort_effects_mut().birth_era = BirthEra::Scarcity;
+        assert!((event.exposure_weight(&chrono, 40.0) - 1.5).abs() < f64::EPSILON);
+        chrono.cohort_effects_mut().birth_era = BirthEra::Expansion;
+        assert!((event.exposure_weight(&chrono, 40.0) - 1.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn non_normative_record_reports_end_once_over() {
+        let event = recession();
+        let during = event.as_non_normative(Timestamp::from_ymd_hms(2009, 1, 1, 0, 0, 0));
+        assert!(during.is_ongoing());
+        assert_eq!(during.start_year, 2008);
+
+        let after = event.as_non_normative(Timestamp::from_ymd_hms(2012, 1, 1, 0, 0, 0));
+        assert_eq!(after.end_year, Some(2010));
+    }
+}
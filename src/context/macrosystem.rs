@@ -176,6 +176,9 @@ pub struct MacrosystemContext {
 
     /// Subculture-specific overrides keyed by group label.
     pub subculture_overrides: HashMap<String, MacrosystemModifier>,
+
+    /// Subculture the entity belongs to, keyed into `subculture_overrides`.
+    pub subculture: Option<String>,
 }
 
 impl Default for MacrosystemContext {
@@ -187,6 +190,7 @@ impl Default for MacrosystemContext {
             collective_trauma: 0.1,
             economic_inequality: 0.4,
             subculture_overrides: HashMap::new(),
+            subculture: None,
         }
     }
 }
@@ -207,6 +211,18 @@ impl MacrosystemContext {
             .unwrap_or_else(|| self.clone())
     }
 
+    /// Returns the macrosystem context as experienced by its subculture.
+    ///
+    /// Without a subculture, or with one that has no overrides, this is a
+    /// clone of the context.
+    #[must_use]
+    pub fn resolved(&self) -> MacrosystemContext {
+        match &self.subculture {
+            Some(key) => self.for_subculture(key),
+            None => self.clone(),
+        }
+    }
+
     /// Gets a value by macrosystem path.
     #[must_use]
     pub fn get_value(&self, path: &MacrosystemPath) -> f64 {
@@ -340,6 +356,48 @@ impl MacrosystemContext {
             1.0 - ic * 0.3
         }
     }
+
+    /// Returns the institutional distrust (0-1) implied by the rule of law
+    /// and corruption.
+    #[must_use]
+    pub fn institutional_distrust(&self) -> f64 {
+        let institutions = &self.institutional_structure;
+        ((1.0 - institutions.rule_of_law) + institutions.corruption_level) / 2.0
+    }
+
+    /// Computes societal strain (0-1) beyond that of the default society.
+    ///
+    /// Collective trauma, institutional distrust and economic inequality each
+    /// contribute how far they exceed their default levels, so a society at
+    /// or below the defaults adds no strain.
+    #[must_use]
+    pub fn societal_strain(&self) -> f64 {
+        let neutral = MacrosystemContext::default();
+        let excess = (self.collective_trauma - neutral.collective_trauma).max(0.0)
+            + (self.institutional_distrust() - neutral.institutional_distrust()).max(0.0)
+            + (self.economic_inequality - neutral.economic_inequality).max(0.0);
+        (excess / 3.0).clamp(0.0, 1.0)
+    }
+
+    /// Computes how heavily shaming and family discord weigh on perceived
+    /// burdensomeness.
+    ///
+    /// In collectivist cultures standing is shared with the family, so
+    /// shame and family conflict read as a burden on others.
+    #[must_use]
+    pub fn family_shame_weight(&self) -> f64 {
+        1.0 - self.cultural_orientation.individualism_collectivism * 0.4
+    }
+
+    /// Computes how heavily humiliation by an authority figure lands.
+    ///
+    /// Where power distance is high the status gap is wide and a superior's
+    /// judgment is hard to contest; where it is low, the same humiliation
+    /// is more easily dismissed as an abuse of position.
+    #[must_use]
+    pub fn authority_humiliation_weight(&self) -> f64 {
+        1.0 + (self.cultural_orientation.power_distance - 0.5) * 0.8
+    }
 }
 
 #[cfg(test)]
@@ -399,9 +457,11 @@ mod tests {
     #[test]
     fn macrosystem_subculture_override_applies() {
         let mut macro_ctx = MacrosystemContext::default();
-        let mut modifier = MacrosystemModifier::default();
-        modifier.power_distance = Some(0.9);
-        modifier.cultural_stress = Some(0.75);
+        let modifier = MacrosystemModifier {
+            power_distance: Some(0.9),
+            cultural_stress: Some(0.75),
+            ..Default::default()
+        };
 
         macro_ctx
             .subculture_overrides
@@ -422,9 +482,9 @@ mod tests {
             let value = macro_ctx.get_value(&path);
             // Most values are 0-1, except individualism_collectivism which is -1 to 1
             if matches!(path, MacrosystemPath::IndividualismCollectivism) {
-                assert!(value >= -1.0 && value <= 1.0);
+                assert!((-1.0..=1.0).contains(&value));
             } else {
-                assert!(value >= 0.0 && value <= 1.0);
+                assert!((0.0..=1.0).contains(&value));
             }
         }
     }
@@ -809,11 +869,12 @@ mod tests {
     #[test]
     fn macrosystem_modifier_apply_to_partial_overrides() {
         let base = MacrosystemContext::default();
-        let mut modifier = MacrosystemModifier::default();
-
-        // Set only some fields
-        modifier.power_distance = Some(0.8);
-        modifier.cultural_stress = Some(0.75);
+        let modifier = MacrosystemModifier {
+            // Set only some fields
+            power_distance: Some(0.8),
+            cultural_stress: Some(0.75),
+            ..Default::default()
+        };
 
         let modified = modifier.apply_to(&base);
 
@@ -832,9 +893,10 @@ mod tests {
     #[test]
     fn macrosystem_modifier_preserves_power_distance_when_none() {
         let base = MacrosystemContext::default();
-        let mut modifier = MacrosystemModifier::default();
-
-        modifier.cultural_stress = Some(0.42);
+        let modifier = MacrosystemModifier {
+            cultural_stress: Some(0.42),
+            ..Default::default()
+        };
 
         let modified = modifier.apply_to(&base);
 
@@ -847,17 +909,17 @@ mod tests {
     #[test]
     fn macrosystem_modifier_apply_to_all_fields() {
         let base = MacrosystemContext::default();
-        let mut modifier = MacrosystemModifier::default();
-
-        modifier.individualism_collectivism = Some(0.5);
-        modifier.power_distance = Some(0.8);
-        modifier.uncertainty_avoidance = Some(0.7);
-        modifier.rule_of_law = Some(0.9);
-        modifier.social_mobility = Some(0.4);
-        modifier.corruption_level = Some(0.2);
-        modifier.cultural_stress = Some(0.6);
-        modifier.collective_trauma = Some(0.3);
-        modifier.economic_inequality = Some(0.5);
+        let modifier = MacrosystemModifier {
+            individualism_collectivism: Some(0.5),
+            power_distance: Some(0.8),
+            uncertainty_avoidance: Some(0.7),
+            rule_of_law: Some(0.9),
+            social_mobility: Some(0.4),
+            corruption_level: Some(0.2),
+            cultural_stress: Some(0.6),
+            collective_trauma: Some(0.3),
+            economic_inequality: Some(0.5),
+        };
 
         let modified = modifier.apply_to(&base);
 
@@ -877,12 +939,13 @@ mod tests {
     #[test]
     fn macrosystem_modifier_apply_to_with_clamping() {
         let base = MacrosystemContext::default();
-        let mut modifier = MacrosystemModifier::default();
-
-        // Test out-of-range clamping
-        modifier.individualism_collectivism = Some(1.5); // Should clamp to 1.0
-        modifier.power_distance = Some(-0.5); // Should clamp to 0.0
-        modifier.cultural_stress = Some(2.0); // Should clamp to 1.0
+        let modifier = MacrosystemModifier {
+            // Test out-of-range clamping
+            individualism_collectivism: Some(1.5), // Should clamp to 1.0
+            power_distance: Some(-0.5),            // Should clamp to 0.0
+            cultural_stress: Some(2.0),            // Should clamp to 1.0
+            ..Default::default()
+        };
 
         let modified = modifier.apply_to(&base);
 
@@ -1148,13 +1211,17 @@ mod tests {
     fn macrosystem_subculture_multiple_overrides() {
         let mut ctx = MacrosystemContext::default();
 
-        let mut modifier1 = MacrosystemModifier::default();
-        modifier1.power_distance = Some(0.9);
-        modifier1.cultural_stress = Some(0.8);
+        let modifier1 = MacrosystemModifier {
+            power_distance: Some(0.9),
+            cultural_stress: Some(0.8),
+            ..Default::default()
+        };
 
-        let mut modifier2 = MacrosystemModifier::default();
-        modifier2.power_distance = Some(0.2);
-        modifier2.economic_inequality = Some(0.1);
+        let modifier2 = MacrosystemModifier {
+            power_distance: Some(0.2),
+            economic_inequality: Some(0.1),
+            ..Default::default()
+        };
 
         ctx.subculture_overrides
             .insert("elite".to_string(), modifier1);
@@ -1183,8 +1250,10 @@ mod tests {
         let mut ctx = MacrosystemContext::default();
         ctx.cultural_orientation.power_distance = 0.3;
 
-        let mut modifier = MacrosystemModifier::default();
-        modifier.power_distance = Some(0.9);
+        let modifier = MacrosystemModifier {
+            power_distance: Some(0.9),
+            ..Default::default()
+        };
 
         ctx.subculture_overrides
             .insert("override".to_string(), modifier);
@@ -1192,4 +1261,49 @@ mod tests {
         let overridden = ctx.for_subculture("override");
         assert!((overridden.cultural_orientation.power_distance - 0.9).abs() < f64::EPSILON);
     }
+
+    #[test]
+    fn resolved_applies_own_subculture() {
+        let mut ctx = MacrosystemContext::default();
+        ctx.subculture_overrides.insert(
+            "diaspora".to_string(),
+            MacrosystemModifier {
+                individualism_collectivism: Some(-0.7),
+                ..MacrosystemModifier::default()
+            },
+        );
+        assert_eq!(ctx.resolved(), ctx);
+
+        ctx.subculture = Some("diaspora".to_string());
+        let resolved = ctx.resolved();
+        assert!((resolved.cultural_orientation.individualism_collectivism + 0.7).abs() < 1e-9);
+        assert!(resolved.is_collectivist());
+    }
+
+    #[test]
+    fn appraisal_weights_are_neutral_by_default() {
+        let mut ctx = MacrosystemContext::default();
+        assert!((ctx.family_shame_weight() - 1.0).abs() < f64::EPSILON);
+        assert!((ctx.authority_humiliation_weight() - 1.0).abs() < f64::EPSILON);
+
+        ctx.cultural_orientation.individualism_collectivism = -1.0;
+        ctx.cultural_orientation.power_distance = 1.0;
+        assert!((ctx.family_shame_weight() - 1.4).abs() < 1e-9);
+        assert!((ctx.authority_humiliation_weight() - 1.4).abs() < 1e-9);
+    }
+
+    #[test]
+    fn societal_strain_counts_only_excess_over_default() {
+        let mut ctx = MacrosystemContext::default();
+        assert!(ctx.societal_strain().abs() < f64::EPSILON);
+
+        ctx.collective_trauma = 0.0;
+        ctx.economic_inequality = 0.0;
+        assert!(ctx.societal_strain().abs() < f64::EPSILON);
+
+        ctx.collective_trauma = 0.7;
+        ctx.institutional_structure.rule_of_law = 0.2;
+        // trauma +0.6, distrust (0.8 + 0.3) / 2 - 0.35 = +0.2
+        assert!((ctx.societal_strain() - 0.8 / 3.0).abs() < 1e-9);
+    }
 }
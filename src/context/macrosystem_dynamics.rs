@@ -0,0 +1,468 @@
+//! Macrosystem evolution over time.
+//!
+//! An authored macrosystem is fixed. With dynamics enabled, a simulation's
+//! culture drifts with what happens to its population, and every member's
+//! macrosystem effects follow the drift:
+//!
+//! - **Collective trauma** rises with violence and historical crises.
+//! - **Cultural stress** rises with crises and unfavorable policy changes,
+//!   and is pulled toward members' mean stress, which it follows with the
+//!   stress half-life rather than at once.
+//! - **Institutions** lose rule of law after violence and political
+//!   upheaval; economic inequality follows economic crises and policy.
+//!
+//! Event contributions are divided by the population size, so an event
+//! dispatched to every member counts once, and each dimension decays back
+//! toward its authored value with its own half-life. Collective trauma is
+//! also pulled up toward members' mean acquired capability, which never
+//! decays.
+
+use crate::context::MacrosystemContext;
+use crate::enums::{EventPayload, EventType, HistoricalEventType, PolicyArea};
+use crate::event::Event;
+use crate::state::IndividualState;
+use crate::types::{Duration, Timestamp};
+
+/// Member stress samples taken per stress half-life.
+const MEMBER_SAMPLES_PER_HALF_LIFE: u64 = 4;
+
+/// Half-lives of member history that lagged stress looks back over.
+const MEMBER_LAG_HALF_LIVES: u64 = 5;
+
+/// Rates and half-lives governing how a macrosystem evolves.
+///
+/// # Examples
+///
+/// ```
+/// use eventsim_rs::context::{MacrosystemContext, MacrosystemDynamics};
+/// use eventsim_rs::enums::EventType;
+/// use eventsim_rs::event::EventBuilder;
+/// use eventsim_rs::types::{Duration, Timestamp};
+///
+/// let assault_at = Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+/// let assault = EventBuilder::new(EventType::Violence).severity(0.9).build().unwrap();
+/// let dynamics = MacrosystemDynamics::new();
+///
+/// // One assault among five people
+/// let soon = dynamics.shift_at([(assault_at, &assault)], 5, assault_at + Duration::days(30));
+/// let later = dynamics.shift_at([(assault_at, &assault)], 5, assault_at + Duration::years(10));
+///
+/// let base = MacrosystemContext::default();
+/// assert!(soon.apply_to(&base).collective_trauma > later.apply_to(&base).collective_trauma);
+/// assert!(later.apply_to(&base).collective_trauma > base.collective_trauma);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct MacrosystemDynamics {
+    trauma_half_life_years: f64,
+    stress_half_life_years: f64,
+    institutional_half_life_years: f64,
+    member_weight: f64,
+}
+
+impl Default for MacrosystemDynamics {
+    fn default() -> Self {
+        MacrosystemDynamics {
+            trauma_half_life_years: 10.0,
+            stress_half_life_years: 2.0,
+            institutional_half_life_years: 5.0,
+            member_weight: 0.5,
+        }
+    }
+}
+
+impl MacrosystemDynamics {
+    /// Creates dynamics with default rates.
+    #[must_use]
+    pub fn new() -> Self {
+        MacrosystemDynamics::default()
+    }
+
+    /// Sets the half-life of collective trauma, in years.
+    #[must_use]
+    pub fn with_trauma_half_life(mut self, years: f64) -> Self {
+        self.trauma_half_life_years = years.max(0.0);
+        self
+    }
+
+    /// Sets the half-life of cultural stress, in years.
+    #[must_use]
+    pub fn with_stress_half_life(mut self, years: f64) -> Self {
+        self.stress_half_life_years = years.max(0.0);
+        self
+    }
+
+    /// Sets the half-life of institutional and inequality changes, in years.
+    #[must_use]
+    pub fn with_institutional_half_life(mut self, years: f64) -> Self {
+        self.institutional_half_life_years = years.max(0.0);
+        self
+    }
+
+    /// Sets how strongly (0-1) member states pull the culture toward them.
+    #[must_use]
+    pub fn with_member_weight(mut self, weight: f64) -> Self {
+        self.member_weight = weight.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Returns the shift left at `timestamp` by population events.
+    ///
+    /// Events after `timestamp` are ignored. `population` is the number of
+    /// entities the culture spans; it is treated as at least one.
+    #[must_use]
+    pub fn shift_at<'e>(
+        &self,
+        events: impl IntoIterator<Item = (Timestamp, &'e Event)>,
+        population: usize,
+        timestamp: Timestamp,
+    ) -> MacrosystemShift {
+        let per_capita = 1.0 / population.max(1) as f64;
+        let mut shift = MacrosystemShift {
+            member_weight: self.member_weight,
+            ..MacrosystemShift::default()
+        };
+        for (occurred, event) in events {
+            if occurred > timestamp {
+                continue;
+            }
+            let years = (timestamp - occurred).as_years_f64();
+            let impact = event_impact(event);
+            let weight = event.severity() * per_capita;
+            shift.collective_trauma +=
+                impact.collective_trauma * weight * decay(years, self.trauma_half_life_years);
+            shift.cultural_stress +=
+                impact.cultural_stress * weight * decay(years, self.stress_half_life_years);
+            let institutional = weight * decay(years, self.institutional_half_life_years);
+            shift.rule_of_law += impact.rule_of_law * institutional;
+            shift.economic_inequality += impact.economic_inequality * institutional;
+        }
+        shift
+    }
+
+    /// Returns when members' stress is sampled to lag cultural stress at
+    /// `timestamp`.
+    ///
+    /// Samples fall before `timestamp` on a grid of a quarter stress
+    /// half-life from `start`, so queries at different timestamps share
+    /// them, and cover the last five half-lives. At or before `start`, or
+    /// without a stress half-life, `timestamp` itself is the only sample.
+    #[must_use]
+    pub fn member_sample_times(&self, start: Timestamp, timestamp: Timestamp) -> Vec<Timestamp> {
+        let step = (self.stress_half_life_years * Duration::years(1).as_seconds() as f64
+            / MEMBER_SAMPLES_PER_HALF_LIFE as f64)
+            .round() as u64;
+        if step == 0 || timestamp <= start {
+            return vec![timestamp];
+        }
+        let last = ((timestamp - start).as_seconds() - 1) / step;
+        let first = last.saturating_sub(MEMBER_SAMPLES_PER_HALF_LIFE * MEMBER_LAG_HALF_LIVES);
+        (first..=last)
+            .map(|k| start + Duration::seconds(k * step))
+            .collect()
+    }
+
+    /// Returns members' mean stress as the culture has absorbed it by
+    /// `timestamp`.
+    ///
+    /// `samples` are (time, mean member stress) pairs in time order, none
+    /// after `timestamp`. The result starts at the first sample and relaxes
+    /// with the stress half-life toward each sample over the time until the
+    /// next one (or `timestamp`), so it changes gradually even when members'
+    /// stress jumps. Returns None without samples.
+    #[must_use]
+    pub fn lagged_member_stress(
+        &self,
+        samples: impl IntoIterator<Item = (Timestamp, f64)>,
+        timestamp: Timestamp,
+    ) -> Option<f64> {
+        let relax = |value: f64, toward: f64, from: Timestamp, to: Timestamp| {
+            let years = (to - from).as_years_f64();
+            toward + (value - toward) * decay(years, self.stress_half_life_years)
+        };
+        let mut lagged: Option<(Timestamp, f64, f64)> = None;
+        for (at, stress) in samples {
+            let value = match lagged {
+                Some((previous, value, toward)) => relax(value, toward, previous, at),
+                None => stress,
+            };
+            lagged = Some((at, value, stress));
+        }
+        lagged.map(|(at, value, toward)| relax(value, toward, at, timestamp))
+    }
+}
+
+/// Change to a macrosystem produced by its population.
+///
+/// Built by `MacrosystemDynamics::shift_at`, with members added by
+/// `with_member` and their lagged stress by `with_lagged_member_stress`,
+/// and applied on top of an authored macrosystem.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct MacrosystemShift {
+    collective_trauma: f64,
+    cultural_stress: f64,
+    rule_of_law: f64,
+    economic_inequality: f64,
+    member_weight: f64,
+    stress_total: f64,
+    capability_total: f64,
+    member_count: usize,
+    lagged_member_stress: Option<f64>,
+}
+
+impl MacrosystemShift {
+    /// Adds a member's projected state.
+    #[must_use]
+    pub fn with_member(self, state: &IndividualState) -> Self {
+        self.with_member_values(
+            f64::from(state.needs().stress_effective()),
+            f64::from(state.mental_health().acquired_capability_effective()),
+        )
+    }
+
+    /// Adds a member's stress and acquired capability.
+    #[must_use]
+    pub(crate) fn with_member_values(mut self, stress: f64, acquired_capability: f64) -> Self {
+        self.stress_total += stress;
+        self.capability_total += acquired_capability;
+        self.member_count += 1;
+        self
+    }
+
+    /// Sets members' mean stress as the culture has absorbed it.
+    ///
+    /// See `MacrosystemDynamics::lagged_member_stress`.
+    #[must_use]
+    pub fn with_lagged_member_stress(mut self, stress: Option<f64>) -> Self {
+        self.lagged_member_stress = stress;
+        self
+    }
+
+    /// Returns members' mean stress, if any member contributed.
+    #[must_use]
+    pub fn member_stress(&self) -> Option<f64> {
+        (self.member_count > 0).then(|| self.stress_total / self.member_count as f64)
+    }
+
+    /// Returns the evolved macrosystem for an authored one.
+    ///
+    /// Event shifts are added first. Cultural stress is then pulled toward
+    /// members' lagged mean stress (their current mean if no lag was set),
+    /// and collective trauma up toward their mean acquired capability.
+    /// Values are clamped to their ranges.
+    #[must_use]
+    pub fn apply_to(&self, base: &MacrosystemContext) -> MacrosystemContext {
+        let mut evolved = base.clone();
+        let mut cultural_stress = base.cultural_stress + self.cultural_stress;
+        let mut collective_trauma = base.collective_trauma + self.collective_trauma;
+        if let Some(stress) = self.lagged_member_stress.or_else(|| self.member_stress()) {
+            cultural_stress += (stress - cultural_stress) * self.member_weight;
+        }
+        if self.member_count > 0 {
+            let capability = self.capability_total / self.member_count as f64;
+            if capability > collective_trauma {
+                collective_trauma += (capability - collective_trauma) * self.member_weight;
+            }
+        }
+        evolved.cultural_stress = cultural_stress.clamp(0.0, 1.0);
+        evolved.collective_trauma = collective_trauma.clamp(0.0, 1.0);
+        let institutions = &mut evolved.institutional_structure;
+        institutions.rule_of_law = (institutions.rule_of_law + self.rule_of_law).clamp(0.0, 1.0);
+        evolved.economic_inequality =
+            (base.economic_inequality + self.economic_inequality).clamp(0.0, 1.0);
+        evolved
+    }
+}
+
+/// Per-capita change one event of full severity makes to a macrosystem.
+#[derive(Debug, Clone, Copy, Default)]
+struct EventImpact {
+    collective_trauma: f64,
+    cultural_stress: f64,
+    rule_of_law: f64,
+    economic_inequality: f64,
+}
+
+fn event_impact(event: &Event) -> EventImpact {
+    match (event.event_type(), event.payload()) {
+        (EventType::Violence, _) => EventImpact {
+            collective_trauma: 0.2,
+            rule_of_law: -0.1,
+            ..EventImpact::default()
+        },
+        (EventType::HistoricalEvent, EventPayload::HistoricalEvent { event_type, .. }) => {
+            match event_type {
+                HistoricalEventType::War
+                | HistoricalEventType::Pandemic
+                | HistoricalEventType::NaturalDisaster => EventImpact {
+                    collective_trauma: 0.3,
+                    cultural_stress: 0.3,
+                    ..EventImpact::default()
+                },
+                HistoricalEventType::PoliticalChange => EventImpact {
+                    cultural_stress: 0.2,
+                    rule_of_law: -0.1,
+                    ..EventImpact::default()
+                },
+                HistoricalEventType::EconomicCrisis => EventImpact {
+                    cultural_stress: 0.3,
+                    economic_inequality: 0.2,
+                    ..EventImpact::default()
+                },
+                HistoricalEventType::TechnologicalShift => EventImpact {
+                    cultural_stress: 0.1,
+                    ..EventImpact::default()
+                },
+            }
+        }
+        (EventType::HistoricalEvent, _) => EventImpact {
+            collective_trauma: 0.2,
+            cultural_stress: 0.2,
+            ..EventImpact::default()
+        },
+        (
+            EventType::PolicyChange,
+            EventPayload::PolicyChange {
+                policy_area,
+                favorability,
+                ..
+            },
+        ) => EventImpact {
+            cultural_stress: 0.1 * (-favorability).max(0.0),
+            economic_inequality: if *policy_area == PolicyArea::Economic {
+                -0.2 * favorability
+            } else {
+                0.0
+            },
+            ..EventImpact::default()
+        },
+        _ => EventImpact::default(),
+    }
+}
+
+/// Returns the fraction (0-1) of a change remaining after `years`.
+fn decay(years: f64, half_life_years: f64) -> f64 {
+    if half_life_years <= 0.0 {
+        return if years > 0.0 { 0.0 } else { 1.0 };
+    }
+    0.5_f64.powf(years / half_life_years)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::enums::{HistoricalScope, PolicyJurisdiction};
+    use crate::event::EventBuilder;
+    use crate::types::Duration;
+
+    fn start() -> Timestamp {
+        Timestamp::from_ymd_hms(2020, 1, 1, 0, 0, 0)
+    }
+
+    #[test]
+    fn dispatched_crisis_counts_once_and_decays() {
+        let pandemic = EventBuilder::new(EventType::HistoricalEvent)
+            .severity(1.0)
+            .payload(EventPayload::HistoricalEvent {
+                event_type: HistoricalEventType::Pandemic,
+                scope: HistoricalScope::Global,
+            })
+            .build()
+            .unwrap();
+        let copies = vec![(start(), &pandemic); 4];
+        let dynamics = MacrosystemDynamics::new();
+        let base = MacrosystemContext::default();
+
+        let now = dynamics
+            .shift_at(copies.clone(), 4, start())
+            .apply_to(&base);
+        assert!((now.collective_trauma - 0.4).abs() < 1e-9);
+        assert!((now.cultural_stress - 0.5).abs() < 1e-9);
+
+        // Cultural stress has a two-year half-life
+        let later = dynamics
+            .shift_at(copies, 4, start() + Duration::years(2))
+            .apply_to(&base);
+        assert!((later.cultural_stress - 0.35).abs() < 0.01);
+        assert!(later.collective_trauma > later.cultural_stress - 0.2);
+    }
+
+    #[test]
+    fn policy_direction_sets_inequality_and_future_events_are_ignored() {
+        let policy = |favorability: f64| {
+            EventBuilder::new(EventType::PolicyChange)
+                .severity(1.0)
+                .payload(EventPayload::PolicyChange {
+                    policy_area: PolicyArea::Economic,
+                    favorability,
+                    magnitude: 0.0,
+                    jurisdiction: PolicyJurisdiction::Target,
+                })
+                .build()
+                .unwrap()
+        };
+        let (austerity, relief) = (policy(-1.0), policy(1.0));
+        let dynamics = MacrosystemDynamics::new();
+        let base = MacrosystemContext::default();
+
+        let cut = dynamics.shift_at([(start(), &austerity)], 1, start());
+        let evolved = cut.apply_to(&base);
+        assert!((evolved.economic_inequality - 0.6).abs() < 1e-9);
+        assert!((evolved.cultural_stress - 0.3).abs() < 1e-9);
+
+        let eased = dynamics.shift_at([(start(), &relief)], 1, start());
+        assert!((eased.apply_to(&base).economic_inequality - 0.2).abs() < 1e-9);
+
+        let before = dynamics.shift_at([(start(), &austerity)], 1, start() - Duration::days(1));
+        assert_eq!(before.apply_to(&base), base);
+    }
+
+    #[test]
+    fn members_pull_stress_and_raise_trauma() {
+        let mut strained = IndividualState::new();
+        strained.needs_mut().add_stress_delta(0.6);
+        strained
+            .mental_health_mut()
+            .add_acquired_capability_delta(0.8);
+        let shift = MacrosystemDynamics::new()
+            .with_member_weight(0.5)
+            .shift_at([], 1, start())
+            .with_member(&strained);
+        let stress = shift.member_stress().unwrap();
+
+        let base = MacrosystemContext::default();
+        let evolved = shift.apply_to(&base);
+        let expected = base.cultural_stress + (stress - base.cultural_stress) * 0.5;
+        assert!((evolved.cultural_stress - expected).abs() < 1e-6);
+        assert!(evolved.collective_trauma > base.collective_trauma);
+        assert!(MacrosystemShift::default().member_stress().is_none());
+    }
+
+    #[test]
+    fn member_stress_reaches_culture_over_the_half_life() {
+        let dynamics = MacrosystemDynamics::new();
+        let shock = start() + Duration::years(1);
+        let stress_at = |at: Timestamp| if at < shock { 0.2 } else { 0.8 };
+        let lagged = |timestamp: Timestamp| {
+            let times = dynamics.member_sample_times(start(), timestamp);
+            assert!(times.iter().all(|at| *at < timestamp));
+            let samples = times.into_iter().map(|at| (at, stress_at(at)));
+            dynamics.lagged_member_stress(samples, timestamp).unwrap()
+        };
+
+        assert!((lagged(shock) - 0.2).abs() < 1e-9);
+        let next_day = lagged(shock + Duration::days(1));
+        assert!(next_day > 0.2 && next_day < 0.21);
+        // One two-year half-life later, about half the change has arrived
+        let half = lagged(shock + Duration::years(2));
+        assert!(half > 0.4 && half < 0.6);
+        assert!((lagged(shock + Duration::years(20)) - 0.8).abs() < 0.01);
+
+        // Queries share grid samples, and nothing is sampled before the start
+        let early = dynamics.member_sample_times(start(), start() + Duration::days(200));
+        let later = dynamics.member_sample_times(start(), start() + Duration::days(400));
+        assert_eq!(early[..], later[..early.len()]);
+        let before = start() - Duration::days(1);
+        assert_eq!(dynamics.member_sample_times(start(), before), vec![before]);
+        assert!(dynamics.lagged_member_stress([], start()).is_none());
+    }
+}
@@ -729,16 +729,20 @@ mod tests {
         let mut microsystems = HashMap::new();
 
         // Create high-stress work
-        let mut work = WorkContext::default();
-        work.workload_stress = 0.8;
+        let mut work = WorkContext {
+            workload_stress: 0.8,
+            ..Default::default()
+        };
         work.interaction_profile.interaction_frequency = 0.7;
         let work_id = MicrosystemId::new("work").unwrap();
         microsystems.insert(work_id.clone(), Microsystem::new_work(work));
 
         // Create family with low boundary (low predictability/stability)
-        let mut family = FamilyContext::default();
-        family.predictability = 0.3;
-        family.stability = 0.3;
+        let family = FamilyContext {
+            predictability: 0.3,
+            stability: 0.3,
+            ..Default::default()
+        };
         let family_id = MicrosystemId::new("family").unwrap();
         microsystems.insert(family_id.clone(), Microsystem::new_family(family));
 
@@ -755,15 +759,19 @@ mod tests {
         let mut microsystems = HashMap::new();
 
         // Create high-demand work
-        let mut work = WorkContext::default();
-        work.workload_stress = 0.8;
+        let mut work = WorkContext {
+            workload_stress: 0.8,
+            ..Default::default()
+        };
         work.interaction_profile.interaction_frequency = 0.8;
         let work_id = MicrosystemId::new("work").unwrap();
         microsystems.insert(work_id.clone(), Microsystem::new_work(work));
 
         // Create high-demand family
-        let mut family = FamilyContext::default();
-        family.caregiving_burden = 0.8;
+        let mut family = FamilyContext {
+            caregiving_burden: 0.8,
+            ..Default::default()
+        };
         family.interaction_profile.interaction_frequency = 0.8;
         let family_id = MicrosystemId::new("family").unwrap();
         microsystems.insert(family_id.clone(), Microsystem::new_family(family));
@@ -869,8 +877,10 @@ mod tests {
         let work_id = MicrosystemId::new("work").unwrap();
         let family_id = MicrosystemId::new("family").unwrap();
 
-        let mut work = WorkContext::default();
-        work.workload_stress = 0.5;
+        let work = WorkContext {
+            workload_stress: 0.5,
+            ..Default::default()
+        };
         microsystems.insert(work_id.clone(), Microsystem::new_work(work));
         microsystems.insert(
             family_id.clone(),
@@ -894,8 +904,8 @@ mod tests {
         // Since cache doesn't store values, both calls compute from current state
         // Actually they may be the same if cache stores them, but the point is
         // we're computing from microsystem data, not storing mesosystem scalars
-        assert!(spillover1 >= 0.0 && spillover1 <= 1.0);
-        assert!(spillover2 >= 0.0 && spillover2 <= 1.0);
+        assert!((0.0..=1.0).contains(&spillover1));
+        assert!((0.0..=1.0).contains(&spillover2));
     }
 
     #[test]
@@ -906,13 +916,17 @@ mod tests {
         let work_id = MicrosystemId::new("work").unwrap();
         let family_id = MicrosystemId::new("family").unwrap();
 
-        let mut work = WorkContext::default();
-        work.workload_stress = 0.3;
+        let work = WorkContext {
+            workload_stress: 0.3,
+            ..Default::default()
+        };
         microsystems.insert(work_id.clone(), Microsystem::new_work(work));
 
-        let mut family = FamilyContext::default();
-        family.predictability = 0.5;
-        family.stability = 0.5;
+        let family = FamilyContext {
+            predictability: 0.5,
+            stability: 0.5,
+            ..Default::default()
+        };
         microsystems.insert(family_id.clone(), Microsystem::new_family(family));
 
         let spillover1 = cache.get_spillover(&work_id, &family_id, &microsystems);
@@ -936,15 +950,19 @@ mod tests {
         let mut microsystems = HashMap::new();
 
         // Create microsystems with similar value alignment
-        let mut work = WorkContext::default();
-        work.role_clarity = 0.7;
+        let work = WorkContext {
+            role_clarity: 0.7,
+            ..Default::default()
+        };
         microsystems.insert(
             MicrosystemId::new("work").unwrap(),
             Microsystem::new_work(work),
         );
 
-        let mut family = FamilyContext::default();
-        family.role_clarity = 0.7;
+        let family = FamilyContext {
+            role_clarity: 0.7,
+            ..Default::default()
+        };
         microsystems.insert(
             MicrosystemId::new("family").unwrap(),
             Microsystem::new_family(family),
@@ -961,15 +979,19 @@ mod tests {
         let mut microsystems = HashMap::new();
 
         // Create microsystems with maximally different value alignment
-        let mut work = WorkContext::default();
-        work.role_clarity = 1.0;
+        let work = WorkContext {
+            role_clarity: 1.0,
+            ..Default::default()
+        };
         microsystems.insert(
             MicrosystemId::new("work").unwrap(),
             Microsystem::new_work(work),
         );
 
-        let mut family = FamilyContext::default();
-        family.role_clarity = 0.0;
+        let family = FamilyContext {
+            role_clarity: 0.0,
+            ..Default::default()
+        };
         microsystems.insert(
             MicrosystemId::new("family").unwrap(),
             Microsystem::new_family(family),
@@ -1004,22 +1026,28 @@ mod tests {
     fn mesosystem_consistency_includes_social_and_ignores_other_types() {
         let mut microsystems = HashMap::new();
 
-        let mut work = WorkContext::default();
-        work.role_clarity = 0.6;
+        let work = WorkContext {
+            role_clarity: 0.6,
+            ..Default::default()
+        };
         microsystems.insert(
             MicrosystemId::new("work").unwrap(),
             Microsystem::new_work(work),
         );
 
-        let mut family = FamilyContext::default();
-        family.role_clarity = 0.4;
+        let family = FamilyContext {
+            role_clarity: 0.4,
+            ..Default::default()
+        };
         microsystems.insert(
             MicrosystemId::new("family").unwrap(),
             Microsystem::new_family(family),
         );
 
-        let mut social = SocialContext::default();
-        social.predictability = 0.7;
+        let social = SocialContext {
+            predictability: 0.7,
+            ..Default::default()
+        };
         microsystems.insert(
             MicrosystemId::new("social").unwrap(),
             Microsystem::new_social(social),
@@ -1045,21 +1073,25 @@ mod tests {
     fn mesosystem_shared_membership_no_overlap() {
         let mut microsystems = HashMap::new();
 
-        let mut work = WorkContext::default();
-        work.peer_ids = vec![
-            crate::types::EntityId::new("alice").unwrap(),
-            crate::types::EntityId::new("bob").unwrap(),
-        ];
+        let work = WorkContext {
+            peer_ids: vec![
+                crate::types::EntityId::new("alice").unwrap(),
+                crate::types::EntityId::new("bob").unwrap(),
+            ],
+            ..Default::default()
+        };
         microsystems.insert(
             MicrosystemId::new("work").unwrap(),
             Microsystem::new_work(work),
         );
 
-        let mut family = FamilyContext::default();
-        family.family_unit = vec![
-            crate::types::EntityId::new("charlie").unwrap(),
-            crate::types::EntityId::new("diana").unwrap(),
-        ];
+        let family = FamilyContext {
+            family_unit: vec![
+                crate::types::EntityId::new("charlie").unwrap(),
+                crate::types::EntityId::new("diana").unwrap(),
+            ],
+            ..Default::default()
+        };
         microsystems.insert(
             MicrosystemId::new("family").unwrap(),
             Microsystem::new_family(family),
@@ -1075,18 +1107,22 @@ mod tests {
 
         let shared_id = crate::types::EntityId::new("shared_person").unwrap();
 
-        let mut work = WorkContext::default();
-        work.peer_ids = vec![
-            shared_id.clone(),
-            crate::types::EntityId::new("bob").unwrap(),
-        ];
+        let work = WorkContext {
+            peer_ids: vec![
+                shared_id.clone(),
+                crate::types::EntityId::new("bob").unwrap(),
+            ],
+            ..Default::default()
+        };
         microsystems.insert(
             MicrosystemId::new("work").unwrap(),
             Microsystem::new_work(work),
         );
 
-        let mut social = SocialContext::default();
-        social.close_friends = vec![shared_id, crate::types::EntityId::new("charlie").unwrap()];
+        let social = SocialContext {
+            close_friends: vec![shared_id, crate::types::EntityId::new("charlie").unwrap()],
+            ..Default::default()
+        };
         microsystems.insert(
             MicrosystemId::new("social").unwrap(),
             Microsystem::new_social(social),
@@ -1114,36 +1150,42 @@ mod tests {
         let mut microsystems = HashMap::new();
         let shared_id = EntityId::new("shared_person").unwrap();
 
-        let mut work = WorkContext::default();
-        work.role_clarity = 0.2;
-        work.predictability = 0.3;
-        work.warmth = 0.2;
-        work.hostility = 0.1;
-        work.workload_stress = 0.8;
-        work.peer_ids = vec![shared_id.clone()];
+        let mut work = WorkContext {
+            role_clarity: 0.2,
+            predictability: 0.3,
+            warmth: 0.2,
+            hostility: 0.1,
+            workload_stress: 0.8,
+            peer_ids: vec![shared_id.clone()],
+            ..Default::default()
+        };
         work.interaction_profile.interaction_frequency = 0.8;
         microsystems.insert(
             MicrosystemId::new("work").unwrap(),
             Microsystem::new_work(work),
         );
 
-        let mut family = FamilyContext::default();
-        family.role_clarity = 0.8;
-        family.predictability = 0.9;
-        family.warmth = 0.7;
-        family.hostility = 0.1;
-        family.caregiving_burden = 0.8;
+        let mut family = FamilyContext {
+            role_clarity: 0.8,
+            predictability: 0.9,
+            warmth: 0.7,
+            hostility: 0.1,
+            caregiving_burden: 0.8,
+            ..Default::default()
+        };
         family.interaction_profile.interaction_frequency = 0.8;
         microsystems.insert(
             MicrosystemId::new("family").unwrap(),
             Microsystem::new_family(family),
         );
 
-        let mut social = SocialContext::default();
-        social.predictability = 0.9;
-        social.warmth = 0.8;
-        social.hostility = 0.1;
-        social.close_friends = vec![shared_id];
+        let mut social = SocialContext {
+            predictability: 0.9,
+            warmth: 0.8,
+            hostility: 0.1,
+            close_friends: vec![shared_id],
+            ..Default::default()
+        };
         social.interaction_profile.interaction_frequency = 0.6;
         microsystems.insert(
             MicrosystemId::new("social").unwrap(),
@@ -1235,8 +1277,10 @@ mod tests {
         let cache = MesosystemCache::new();
         let mut microsystems = HashMap::new();
 
-        let mut work = WorkContext::default();
-        work.workload_stress = 0.8;
+        let mut work = WorkContext {
+            workload_stress: 0.8,
+            ..Default::default()
+        };
         work.interaction_profile.interaction_frequency = 0.7;
         let work_id = MicrosystemId::new("work").unwrap();
         microsystems.insert(work_id.clone(), Microsystem::new_work(work));
@@ -1246,7 +1290,7 @@ mod tests {
         microsystems.insert(social_id.clone(), Microsystem::new_social(social));
 
         let spillover = cache.get_spillover(&work_id, &social_id, &microsystems);
-        assert!(spillover >= 0.0 && spillover <= 1.0);
+        assert!((0.0..=1.0).contains(&spillover));
     }
 
     #[test]
@@ -1256,8 +1300,10 @@ mod tests {
         let cache = MesosystemCache::new();
         let mut microsystems = HashMap::new();
 
-        let mut work = WorkContext::default();
-        work.workload_stress = 0.8;
+        let mut work = WorkContext {
+            workload_stress: 0.8,
+            ..Default::default()
+        };
         work.interaction_profile.interaction_frequency = 0.7;
         let work_id = MicrosystemId::new("work").unwrap();
         microsystems.insert(work_id.clone(), Microsystem::new_work(work));
@@ -1267,7 +1313,7 @@ mod tests {
         microsystems.insert(edu_id.clone(), Microsystem::new_education(edu));
 
         let spillover = cache.get_spillover(&work_id, &edu_id, &microsystems);
-        assert!(spillover >= 0.0 && spillover <= 1.0);
+        assert!((0.0..=1.0).contains(&spillover));
     }
 
     #[test]
@@ -1277,8 +1323,10 @@ mod tests {
         let cache = MesosystemCache::new();
         let mut microsystems = HashMap::new();
 
-        let mut work = WorkContext::default();
-        work.workload_stress = 0.8;
+        let mut work = WorkContext {
+            workload_stress: 0.8,
+            ..Default::default()
+        };
         work.interaction_profile.interaction_frequency = 0.7;
         let work_id = MicrosystemId::new("work").unwrap();
         microsystems.insert(work_id.clone(), Microsystem::new_work(work));
@@ -1288,7 +1336,7 @@ mod tests {
         microsystems.insert(hc_id.clone(), Microsystem::new_healthcare(hc));
 
         let spillover = cache.get_spillover(&work_id, &hc_id, &microsystems);
-        assert!(spillover >= 0.0 && spillover <= 1.0);
+        assert!((0.0..=1.0).contains(&spillover));
     }
 
     #[test]
@@ -1298,8 +1346,10 @@ mod tests {
         let cache = MesosystemCache::new();
         let mut microsystems = HashMap::new();
 
-        let mut work = WorkContext::default();
-        work.workload_stress = 0.8;
+        let mut work = WorkContext {
+            workload_stress: 0.8,
+            ..Default::default()
+        };
         work.interaction_profile.interaction_frequency = 0.7;
         let work_id = MicrosystemId::new("work").unwrap();
         microsystems.insert(work_id.clone(), Microsystem::new_work(work));
@@ -1309,7 +1359,7 @@ mod tests {
         microsystems.insert(rel_id.clone(), Microsystem::new_religious(rel));
 
         let spillover = cache.get_spillover(&work_id, &rel_id, &microsystems);
-        assert!(spillover >= 0.0 && spillover <= 1.0);
+        assert!((0.0..=1.0).contains(&spillover));
     }
 
     #[test]
@@ -1319,8 +1369,10 @@ mod tests {
         let cache = MesosystemCache::new();
         let mut microsystems = HashMap::new();
 
-        let mut work = WorkContext::default();
-        work.workload_stress = 0.8;
+        let mut work = WorkContext {
+            workload_stress: 0.8,
+            ..Default::default()
+        };
         work.interaction_profile.interaction_frequency = 0.7;
         let work_id = MicrosystemId::new("work").unwrap();
         microsystems.insert(work_id.clone(), Microsystem::new_work(work));
@@ -1330,7 +1382,7 @@ mod tests {
         microsystems.insert(nb_id.clone(), Microsystem::new_neighborhood(nb));
 
         let spillover = cache.get_spillover(&work_id, &nb_id, &microsystems);
-        assert!(spillover >= 0.0 && spillover <= 1.0);
+        assert!((0.0..=1.0).contains(&spillover));
     }
 
     #[test]
@@ -1342,16 +1394,20 @@ mod tests {
 
         let shared_id = EntityId::new("shared_person").unwrap();
 
-        let mut edu = EducationContext::default();
-        edu.peer_ids = vec![shared_id.clone()];
-        edu.instructors = vec![EntityId::new("instructor").unwrap()];
+        let edu = EducationContext {
+            peer_ids: vec![shared_id.clone()],
+            instructors: vec![EntityId::new("instructor").unwrap()],
+            ..Default::default()
+        };
         microsystems.insert(
             MicrosystemId::new("education").unwrap(),
             Microsystem::new_education(edu),
         );
 
-        let mut social = SocialContext::default();
-        social.close_friends = vec![shared_id.clone()];
+        let social = SocialContext {
+            close_friends: vec![shared_id.clone()],
+            ..Default::default()
+        };
         microsystems.insert(
             MicrosystemId::new("social").unwrap(),
             Microsystem::new_social(social),
@@ -1370,15 +1426,19 @@ mod tests {
 
         let shared_id = EntityId::new("shared_person").unwrap();
 
-        let mut hc = HealthcareContext::default();
-        hc.primary_provider_id = Some(shared_id.clone());
+        let hc = HealthcareContext {
+            primary_provider_id: Some(shared_id.clone()),
+            ..Default::default()
+        };
         microsystems.insert(
             MicrosystemId::new("healthcare").unwrap(),
             Microsystem::new_healthcare(hc),
         );
 
-        let mut social = SocialContext::default();
-        social.close_friends = vec![shared_id.clone()];
+        let social = SocialContext {
+            close_friends: vec![shared_id.clone()],
+            ..Default::default()
+        };
         microsystems.insert(
             MicrosystemId::new("social").unwrap(),
             Microsystem::new_social(social),
@@ -1397,15 +1457,19 @@ mod tests {
 
         let shared_id = EntityId::new("shared_person").unwrap();
 
-        let mut rel = ReligiousContext::default();
-        rel.leader_id = Some(shared_id.clone());
+        let rel = ReligiousContext {
+            leader_id: Some(shared_id.clone()),
+            ..Default::default()
+        };
         microsystems.insert(
             MicrosystemId::new("religious").unwrap(),
             Microsystem::new_religious(rel),
         );
 
-        let mut social = SocialContext::default();
-        social.close_friends = vec![shared_id.clone()];
+        let social = SocialContext {
+            close_friends: vec![shared_id.clone()],
+            ..Default::default()
+        };
         microsystems.insert(
             MicrosystemId::new("social").unwrap(),
             Microsystem::new_social(social),
@@ -1424,15 +1488,19 @@ mod tests {
 
         let shared_id = EntityId::new("shared_person").unwrap();
 
-        let mut nb = NeighborhoodContext::default();
-        nb.proximity_network = vec![shared_id.clone()];
+        let nb = NeighborhoodContext {
+            proximity_network: vec![shared_id.clone()],
+            ..Default::default()
+        };
         microsystems.insert(
             MicrosystemId::new("neighborhood").unwrap(),
             Microsystem::new_neighborhood(nb),
         );
 
-        let mut social = SocialContext::default();
-        social.close_friends = vec![shared_id.clone()];
+        let social = SocialContext {
+            close_friends: vec![shared_id.clone()],
+            ..Default::default()
+        };
         microsystems.insert(
             MicrosystemId::new("social").unwrap(),
             Microsystem::new_social(social),
@@ -1484,16 +1552,20 @@ mod tests {
 
         let shared_id = EntityId::new("supervisor_friend").unwrap();
 
-        let mut work = WorkContext::default();
-        work.peer_ids = vec![EntityId::new("coworker").unwrap()];
-        work.supervisor_id = Some(shared_id.clone());
+        let work = WorkContext {
+            peer_ids: vec![EntityId::new("coworker").unwrap()],
+            supervisor_id: Some(shared_id.clone()),
+            ..Default::default()
+        };
         microsystems.insert(
             MicrosystemId::new("work").unwrap(),
             Microsystem::new_work(work),
         );
 
-        let mut social = SocialContext::default();
-        social.close_friends = vec![shared_id.clone()];
+        let social = SocialContext {
+            close_friends: vec![shared_id.clone()],
+            ..Default::default()
+        };
         microsystems.insert(
             MicrosystemId::new("social").unwrap(),
             Microsystem::new_social(social),
@@ -1509,8 +1581,10 @@ mod tests {
         let cache = MesosystemCache::new();
         let mut microsystems = HashMap::new();
 
-        let mut work = WorkContext::default();
-        work.workload_stress = 0.8;
+        let work = WorkContext {
+            workload_stress: 0.8,
+            ..Default::default()
+        };
         let work_id = MicrosystemId::new("work").unwrap();
         microsystems.insert(work_id.clone(), Microsystem::new_work(work));
 
@@ -1526,14 +1600,18 @@ mod tests {
         let cache = MesosystemCache::new();
         let mut microsystems = HashMap::new();
 
-        let mut work = WorkContext::default();
-        work.workload_stress = 0.8;
+        let mut work = WorkContext {
+            workload_stress: 0.8,
+            ..Default::default()
+        };
         work.interaction_profile.interaction_frequency = 0.8;
         let work_id = MicrosystemId::new("work").unwrap();
         microsystems.insert(work_id.clone(), Microsystem::new_work(work));
 
-        let mut family = FamilyContext::default();
-        family.caregiving_burden = 0.8;
+        let mut family = FamilyContext {
+            caregiving_burden: 0.8,
+            ..Default::default()
+        };
         family.interaction_profile.interaction_frequency = 0.8;
         let family_id = MicrosystemId::new("family").unwrap();
         microsystems.insert(family_id.clone(), Microsystem::new_family(family));
@@ -1553,9 +1631,11 @@ mod tests {
         let cache = MesosystemCache::new();
         let mut microsystems = HashMap::new();
 
-        let mut family = FamilyContext::default();
-        family.caregiving_burden = 0.8;
-        family.hostility = 0.3;
+        let mut family = FamilyContext {
+            caregiving_burden: 0.8,
+            hostility: 0.3,
+            ..Default::default()
+        };
         family.interaction_profile.interaction_frequency = 0.7;
         let family_id = MicrosystemId::new("family").unwrap();
         microsystems.insert(family_id.clone(), Microsystem::new_family(family));
@@ -1566,7 +1646,7 @@ mod tests {
 
         // Spillover from family TO work
         let spillover = cache.get_spillover(&family_id, &work_id, &microsystems);
-        assert!(spillover >= 0.0 && spillover <= 1.0);
+        assert!((0.0..=1.0).contains(&spillover));
     }
 
     #[test]
@@ -1591,8 +1671,10 @@ mod tests {
         let mut cache = MesosystemCache::new();
         let mut microsystems = HashMap::new();
 
-        let mut work = WorkContext::default();
-        work.workload_stress = 0.8;
+        let mut work = WorkContext {
+            workload_stress: 0.8,
+            ..Default::default()
+        };
         work.interaction_profile.interaction_frequency = 0.7;
         let work_id = MicrosystemId::new("work").unwrap();
         microsystems.insert(work_id.clone(), Microsystem::new_work(work));
@@ -1620,14 +1702,18 @@ mod tests {
         let mut cache = MesosystemCache::new();
         let mut microsystems = HashMap::new();
 
-        let mut work = WorkContext::default();
-        work.workload_stress = 0.8;
+        let mut work = WorkContext {
+            workload_stress: 0.8,
+            ..Default::default()
+        };
         work.interaction_profile.interaction_frequency = 0.8;
         let work_id = MicrosystemId::new("work").unwrap();
         microsystems.insert(work_id.clone(), Microsystem::new_work(work));
 
-        let mut family = FamilyContext::default();
-        family.caregiving_burden = 0.8;
+        let mut family = FamilyContext {
+            caregiving_burden: 0.8,
+            ..Default::default()
+        };
         family.interaction_profile.interaction_frequency = 0.8;
         let family_id = MicrosystemId::new("family").unwrap();
         microsystems.insert(family_id.clone(), Microsystem::new_family(family));
@@ -1666,18 +1752,22 @@ mod tests {
         let shared_member = EntityId::new("shared_person").unwrap();
 
         // Work context with shared member as peer
-        let mut work = WorkContext::default();
-        work.peer_ids = vec![shared_member.clone(), EntityId::new("coworker1").unwrap()];
+        let mut work = WorkContext {
+            peer_ids: vec![shared_member.clone(), EntityId::new("coworker1").unwrap()],
+            ..Default::default()
+        };
         work.interaction_profile.interaction_frequency = 0.7;
         let work_id = MicrosystemId::new("work").unwrap();
         microsystems.insert(work_id.clone(), Microsystem::new_work(work));
 
         // Social context with shared member as friend
-        let mut social = SocialContext::default();
-        social.close_friends = vec![
-            shared_member.clone(),
-            EntityId::new("other_friend").unwrap(),
-        ];
+        let mut social = SocialContext {
+            close_friends: vec![
+                shared_member.clone(),
+                EntityId::new("other_friend").unwrap(),
+            ],
+            ..Default::default()
+        };
         social.interaction_profile.interaction_frequency = 0.6;
         let social_id = MicrosystemId::new("social").unwrap();
         microsystems.insert(social_id.clone(), Microsystem::new_social(social));
@@ -1700,16 +1790,20 @@ mod tests {
 
         // Without shared member, there would be no overlap
         let mut no_overlap_microsystems = HashMap::new();
-        let mut work2 = WorkContext::default();
-        work2.peer_ids = vec![EntityId::new("alice").unwrap()];
+        let mut work2 = WorkContext {
+            peer_ids: vec![EntityId::new("alice").unwrap()],
+            ..Default::default()
+        };
         work2.interaction_profile.interaction_frequency = 0.7;
         no_overlap_microsystems.insert(
             MicrosystemId::new("work").unwrap(),
             Microsystem::new_work(work2),
         );
 
-        let mut social2 = SocialContext::default();
-        social2.close_friends = vec![EntityId::new("bob").unwrap()];
+        let mut social2 = SocialContext {
+            close_friends: vec![EntityId::new("bob").unwrap()],
+            ..Default::default()
+        };
         social2.interaction_profile.interaction_frequency = 0.6;
         no_overlap_microsystems.insert(
             MicrosystemId::new("social").unwrap(),
@@ -1726,8 +1820,10 @@ mod tests {
         let cache = MesosystemCache::new();
         let mut microsystems = HashMap::new();
 
-        let mut work = WorkContext::default();
-        work.workload_stress = 0.8;
+        let work = WorkContext {
+            workload_stress: 0.8,
+            ..Default::default()
+        };
         let work_id = MicrosystemId::new("work").unwrap();
         microsystems.insert(work_id.clone(), Microsystem::new_work(work));
 
@@ -1742,13 +1838,17 @@ mod tests {
     fn mesosystem_work_family_conflict_with_non_zero_count() {
         let mut microsystems = HashMap::new();
 
-        let mut work = WorkContext::default();
-        work.workload_stress = 0.8;
+        let work = WorkContext {
+            workload_stress: 0.8,
+            ..Default::default()
+        };
         let work_id = MicrosystemId::new("work").unwrap();
         microsystems.insert(work_id.clone(), Microsystem::new_work(work));
 
-        let mut family = FamilyContext::default();
-        family.caregiving_burden = 0.7;
+        let family = FamilyContext {
+            caregiving_burden: 0.7,
+            ..Default::default()
+        };
         let family_id = MicrosystemId::new("family").unwrap();
         microsystems.insert(family_id.clone(), Microsystem::new_family(family));
 
@@ -1762,8 +1862,10 @@ mod tests {
         let mut microsystems = HashMap::new();
 
         // Only work, no family - count will be 0
-        let mut work = WorkContext::default();
-        work.workload_stress = 0.8;
+        let work = WorkContext {
+            workload_stress: 0.8,
+            ..Default::default()
+        };
         let work_id = MicrosystemId::new("work").unwrap();
         microsystems.insert(work_id.clone(), Microsystem::new_work(work));
 
@@ -1777,8 +1879,10 @@ mod tests {
         let cache = MesosystemCache::new();
         let mut microsystems = HashMap::new();
 
-        let mut work = WorkContext::default();
-        work.workload_stress = 0.3; // Below 0.5 threshold
+        let work = WorkContext {
+            workload_stress: 0.3, // Below 0.5 threshold
+            ..Default::default()
+        };
         let work_id = MicrosystemId::new("work").unwrap();
         microsystems.insert(work_id.clone(), Microsystem::new_work(work));
 
@@ -908,6 +908,34 @@ impl Microsystem {
         }
     }
 
+    /// Sets the warmth value for this microsystem (clamped to 0-1).
+    pub fn set_warmth(&mut self, warmth: f64) {
+        let warmth = warmth.clamp(0.0, 1.0);
+        match self {
+            Microsystem::Work(w) => w.warmth = warmth,
+            Microsystem::Family(f) => f.warmth = warmth,
+            Microsystem::Social(s) => s.warmth = warmth,
+            Microsystem::Education(e) => e.warmth = warmth,
+            Microsystem::Healthcare(h) => h.warmth = warmth,
+            Microsystem::Religious(r) => r.warmth = warmth,
+            Microsystem::Neighborhood(n) => n.warmth = warmth,
+        }
+    }
+
+    /// Sets the hostility value for this microsystem (clamped to 0-1).
+    pub fn set_hostility(&mut self, hostility: f64) {
+        let hostility = hostility.clamp(0.0, 1.0);
+        match self {
+            Microsystem::Work(w) => w.hostility = hostility,
+            Microsystem::Family(f) => f.hostility = hostility,
+            Microsystem::Social(s) => s.hostility = hostility,
+            Microsystem::Education(e) => e.hostility = hostility,
+            Microsystem::Healthcare(h) => h.hostility = hostility,
+            Microsystem::Religious(r) => r.hostility = hostility,
+            Microsystem::Neighborhood(n) => n.hostility = hostility,
+        }
+    }
+
     /// Returns the interaction frequency for this microsystem.
     #[must_use]
     pub fn interaction_frequency(&self) -> f64 {
@@ -922,6 +950,21 @@ impl Microsystem {
         }
     }
 
+    /// Sets the interaction frequency for this microsystem (clamped to 0-1).
+    pub fn set_interaction_frequency(&mut self, frequency: f64) {
+        let frequency = frequency.clamp(0.0, 1.0);
+        let profile = match self {
+            Microsystem::Work(w) => &mut w.interaction_profile,
+            Microsystem::Family(f) => &mut f.interaction_profile,
+            Microsystem::Social(s) => &mut s.interaction_profile,
+            Microsystem::Education(e) => &mut e.interaction_profile,
+            Microsystem::Healthcare(h) => &mut h.interaction_profile,
+            Microsystem::Religious(r) => &mut r.interaction_profile,
+            Microsystem::Neighborhood(n) => &mut n.interaction_profile,
+        };
+        profile.interaction_frequency = frequency;
+    }
+
     /// Returns the interaction complexity for this microsystem.
     #[must_use]
     pub fn interaction_complexity(&self) -> f64 {
@@ -951,6 +994,41 @@ impl Microsystem {
             Microsystem::Neighborhood(n) => (1.0 - n.safety) * 0.6 + n.hostility * 0.4,
         }
     }
+
+    /// Returns the entities named in this microsystem.
+    ///
+    /// These are the supervisor and peers at work, the family unit, close
+    /// friends, instructors and fellow students, the primary care provider,
+    /// the religious leader, or the neighborhood proximity network.
+    #[must_use]
+    pub fn listed_entities(&self) -> Vec<&EntityId> {
+        match self {
+            Microsystem::Work(w) => w.supervisor_id.iter().chain(&w.peer_ids).collect(),
+            Microsystem::Family(f) => f.family_unit.iter().collect(),
+            Microsystem::Social(s) => s.close_friends.iter().collect(),
+            Microsystem::Education(e) => e.instructors.iter().chain(&e.peer_ids).collect(),
+            Microsystem::Healthcare(h) => h.primary_provider_id.iter().collect(),
+            Microsystem::Religious(r) => r.leader_id.iter().collect(),
+            Microsystem::Neighborhood(n) => n.proximity_network.iter().collect(),
+        }
+    }
+
+    /// Returns the entities who hold authority in this microsystem.
+    ///
+    /// These are the supervisor at work, instructors, the primary care
+    /// provider and the religious leader.
+    #[must_use]
+    pub fn authority_figures(&self) -> Vec<&EntityId> {
+        match self {
+            Microsystem::Work(w) => w.supervisor_id.iter().collect(),
+            Microsystem::Education(e) => e.instructors.iter().collect(),
+            Microsystem::Healthcare(h) => h.primary_provider_id.iter().collect(),
+            Microsystem::Religious(r) => r.leader_id.iter().collect(),
+            Microsystem::Family(_) | Microsystem::Social(_) | Microsystem::Neighborhood(_) => {
+                Vec::new()
+            }
+        }
+    }
 }
 
 #[cfg(test)]
@@ -1046,7 +1124,7 @@ mod tests {
         let work = WorkContext::default();
         for path in WorkPath::all() {
             let value = work.get_value(path);
-            assert!(value >= 0.0 && value <= 1.0);
+            assert!((0.0..=1.0).contains(&value));
         }
     }
 
@@ -1077,7 +1155,7 @@ mod tests {
         let family = FamilyContext::default();
         for path in FamilyPath::all() {
             let value = family.get_value(path);
-            assert!(value >= 0.0 && value <= 1.0);
+            assert!((0.0..=1.0).contains(&value));
         }
     }
 
@@ -1107,7 +1185,7 @@ mod tests {
         let social = SocialContext::default();
         for path in SocialPath::all() {
             let value = social.get_value(path);
-            assert!(value >= 0.0 && value <= 1.0);
+            assert!((0.0..=1.0).contains(&value));
         }
     }
 
@@ -1124,7 +1202,7 @@ mod tests {
         let edu = EducationContext::default();
         for path in EducationPath::all() {
             let value = edu.get_value(path);
-            assert!(value >= 0.0 && value <= 1.0);
+            assert!((0.0..=1.0).contains(&value));
         }
     }
 
@@ -1148,7 +1226,7 @@ mod tests {
         let hc = HealthcareContext::default();
         for path in HealthcarePath::all() {
             let value = hc.get_value(path);
-            assert!(value >= 0.0 && value <= 1.0);
+            assert!((0.0..=1.0).contains(&value));
         }
     }
 
@@ -1172,7 +1250,7 @@ mod tests {
         let rel = ReligiousContext::default();
         for path in ReligiousPath::all() {
             let value = rel.get_value(path);
-            assert!(value >= 0.0 && value <= 1.0);
+            assert!((0.0..=1.0).contains(&value));
         }
     }
 
@@ -1196,7 +1274,7 @@ mod tests {
         let nb = NeighborhoodContext::default();
         for path in NeighborhoodPath::all() {
             let value = nb.get_value(path);
-            assert!(value >= 0.0 && value <= 1.0);
+            assert!((0.0..=1.0).contains(&value));
         }
     }
 
@@ -1255,7 +1333,7 @@ mod tests {
     fn microsystem_get_value_matching_type() {
         let m = Microsystem::new_work(WorkContext::default());
         let value = m.get_value(&MicrosystemPath::Work(WorkPath::Warmth));
-        assert!(value >= 0.0 && value <= 1.0);
+        assert!((0.0..=1.0).contains(&value));
     }
 
     #[test]
@@ -1336,35 +1414,35 @@ mod tests {
     fn microsystem_warmth() {
         let m = Microsystem::new_work(WorkContext::default());
         let warmth = m.warmth();
-        assert!(warmth >= 0.0 && warmth <= 1.0);
+        assert!((0.0..=1.0).contains(&warmth));
     }
 
     #[test]
     fn microsystem_hostility() {
         let m = Microsystem::new_family(FamilyContext::default());
         let hostility = m.hostility();
-        assert!(hostility >= 0.0 && hostility <= 1.0);
+        assert!((0.0..=1.0).contains(&hostility));
     }
 
     #[test]
     fn microsystem_interaction_frequency() {
         let m = Microsystem::new_work(WorkContext::default());
         let freq = m.interaction_frequency();
-        assert!(freq >= 0.0 && freq <= 1.0);
+        assert!((0.0..=1.0).contains(&freq));
     }
 
     #[test]
     fn microsystem_interaction_complexity() {
         let m = Microsystem::new_social(SocialContext::default());
         let complexity = m.interaction_complexity();
-        assert!(complexity >= 0.0 && complexity <= 1.0);
+        assert!((0.0..=1.0).contains(&complexity));
     }
 
     #[test]
     fn microsystem_stress_level() {
         let m = Microsystem::new_work(WorkContext::default());
         let stress = m.stress_level();
-        assert!(stress >= 0.0 && stress <= 1.0);
+        assert!((0.0..=1.0).contains(&stress));
     }
 
     #[test]
@@ -1381,7 +1459,7 @@ mod tests {
 
         for m in types {
             let stress = m.stress_level();
-            assert!(stress >= 0.0 && stress <= 1.0);
+            assert!((0.0..=1.0).contains(&stress));
         }
     }
 
@@ -1470,42 +1548,42 @@ mod tests {
     fn microsystem_get_value_family() {
         let m = Microsystem::new_family(FamilyContext::default());
         let value = m.get_value(&MicrosystemPath::Family(FamilyPath::Warmth));
-        assert!(value >= 0.0 && value <= 1.0);
+        assert!((0.0..=1.0).contains(&value));
     }
 
     #[test]
     fn microsystem_get_value_social() {
         let m = Microsystem::new_social(SocialContext::default());
         let value = m.get_value(&MicrosystemPath::Social(SocialPath::Warmth));
-        assert!(value >= 0.0 && value <= 1.0);
+        assert!((0.0..=1.0).contains(&value));
     }
 
     #[test]
     fn microsystem_get_value_education() {
         let m = Microsystem::new_education(EducationContext::default());
         let value = m.get_value(&MicrosystemPath::Education(EducationPath::Warmth));
-        assert!(value >= 0.0 && value <= 1.0);
+        assert!((0.0..=1.0).contains(&value));
     }
 
     #[test]
     fn microsystem_get_value_healthcare() {
         let m = Microsystem::new_healthcare(HealthcareContext::default());
         let value = m.get_value(&MicrosystemPath::Healthcare(HealthcarePath::Warmth));
-        assert!(value >= 0.0 && value <= 1.0);
+        assert!((0.0..=1.0).contains(&value));
     }
 
     #[test]
     fn microsystem_get_value_religious() {
         let m = Microsystem::new_religious(ReligiousContext::default());
         let value = m.get_value(&MicrosystemPath::Religious(ReligiousPath::Warmth));
-        assert!(value >= 0.0 && value <= 1.0);
+        assert!((0.0..=1.0).contains(&value));
     }
 
     #[test]
     fn microsystem_get_value_neighborhood() {
         let m = Microsystem::new_neighborhood(NeighborhoodContext::default());
         let value = m.get_value(&MicrosystemPath::Neighborhood(NeighborhoodPath::Warmth));
-        assert!(value >= 0.0 && value <= 1.0);
+        assert!((0.0..=1.0).contains(&value));
     }
 
     #[test]
@@ -1574,7 +1652,7 @@ mod tests {
         ];
         for m in types {
             let warmth = m.warmth();
-            assert!(warmth >= 0.0 && warmth <= 1.0);
+            assert!((0.0..=1.0).contains(&warmth));
         }
     }
 
@@ -1591,7 +1669,7 @@ mod tests {
         ];
         for m in types {
             let hostility = m.hostility();
-            assert!(hostility >= 0.0 && hostility <= 1.0);
+            assert!((0.0..=1.0).contains(&hostility));
         }
     }
 
@@ -1608,7 +1686,7 @@ mod tests {
         ];
         for m in types {
             let freq = m.interaction_frequency();
-            assert!(freq >= 0.0 && freq <= 1.0);
+            assert!((0.0..=1.0).contains(&freq));
         }
     }
 
@@ -1625,7 +1703,7 @@ mod tests {
         ];
         for m in types {
             let complexity = m.interaction_complexity();
-            assert!(complexity >= 0.0 && complexity <= 1.0);
+            assert!((0.0..=1.0).contains(&complexity));
         }
     }
 
@@ -1723,16 +1801,20 @@ mod tests {
         // that can influence entity state through microsystem effects
 
         // Create high-support family
-        let mut high_support = FamilyContext::default();
-        high_support.warmth = 0.9;
-        high_support.hostility = 0.1;
-        high_support.family_satisfaction = 0.8;
+        let high_support = FamilyContext {
+            warmth: 0.9,
+            hostility: 0.1,
+            family_satisfaction: 0.8,
+            ..Default::default()
+        };
 
         // Create low-support family
-        let mut low_support = FamilyContext::default();
-        low_support.warmth = 0.2;
-        low_support.hostility = 0.7;
-        low_support.family_satisfaction = 0.3;
+        let low_support = FamilyContext {
+            warmth: 0.2,
+            hostility: 0.7,
+            family_satisfaction: 0.3,
+            ..Default::default()
+        };
 
         let high_family = Microsystem::new_family(high_support);
         let low_family = Microsystem::new_family(low_support);
@@ -1754,18 +1836,22 @@ mod tests {
         // Tests that low social interaction and warmth correlate with isolation
 
         // Create socially connected context
-        let mut connected = SocialContext::default();
-        connected.warmth = 0.8;
-        connected.group_standing = 0.7;
-        connected.hostility = 0.1;
-        connected.interaction_profile = InteractionProfile::with_values(0.8, 0.7);
+        let connected = SocialContext {
+            warmth: 0.8,
+            group_standing: 0.7,
+            hostility: 0.1,
+            interaction_profile: InteractionProfile::with_values(0.8, 0.7),
+            ..Default::default()
+        };
 
         // Create isolated context
-        let mut isolated = SocialContext::default();
-        isolated.warmth = 0.2;
-        isolated.group_standing = 0.2;
-        isolated.hostility = 0.6;
-        isolated.interaction_profile = InteractionProfile::with_values(0.1, 0.1);
+        let isolated = SocialContext {
+            warmth: 0.2,
+            group_standing: 0.2,
+            hostility: 0.6,
+            interaction_profile: InteractionProfile::with_values(0.1, 0.1),
+            ..Default::default()
+        };
 
         let connected_micro = Microsystem::new_social(connected);
         let isolated_micro = Microsystem::new_social(isolated);
@@ -1817,4 +1903,21 @@ mod tests {
         let value = work_micro.get_value(&family_path);
         assert!((value - 0.0).abs() < f64::EPSILON);
     }
+
+    #[test]
+    fn listed_entities_per_type() {
+        let boss = EntityId::new("boss").unwrap();
+        let peer = EntityId::new("peer").unwrap();
+        let work = WorkContext {
+            supervisor_id: Some(boss.clone()),
+            peer_ids: vec![peer.clone()],
+            ..Default::default()
+        };
+        let work = Microsystem::new_work(work);
+        assert_eq!(work.listed_entities(), vec![&boss, &peer]);
+        assert_eq!(work.authority_figures(), vec![&boss]);
+
+        let family = Microsystem::new_family(FamilyContext::default());
+        assert!(family.listed_entities().is_empty());
+    }
 }
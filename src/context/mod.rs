@@ -16,21 +16,31 @@
 //! not gradual decay. Mesosystem values are always computed from microsystem data.
 
 mod chronosystem;
+mod climate;
 mod effects;
 mod exosystem;
+mod historical;
 mod macrosystem;
+mod macrosystem_dynamics;
 mod mesosystem;
 mod microsystem;
+mod normative_schedule;
+mod time_budget;
+mod timeline;
 
 pub use chronosystem::{
     ChronosystemContext, CohortEffects, CriticalPeriod, HistoricalPeriod, NonNormativeEvent,
     NormativeTransition, TurningPoint, TurningPointDomain,
 };
-pub(crate) use effects::apply_context_effects;
+pub use climate::MicrosystemClimate;
+pub(crate) use effects::{apply_context_effects, apply_context_timeline_effects};
 pub use exosystem::{ExosystemContext, ParentWorkQuality};
+pub use historical::{HistoricalReach, SharedHistoricalEvent, HISTORICAL_CRITICAL_PERIOD_DOMAIN};
 pub use macrosystem::{
     CulturalOrientation, InstitutionalStructure, MacrosystemConstraintSet, MacrosystemContext,
+    MacrosystemModifier,
 };
+pub use macrosystem_dynamics::{MacrosystemDynamics, MacrosystemShift};
 pub use mesosystem::{
     check_proximal_process_gate, MesosystemCache, MesosystemLinkage, MesosystemState,
     ProximalProcessGateError, INTERACTION_COMPLEXITY_THRESHOLD, INTERACTION_FREQUENCY_THRESHOLD,
@@ -40,8 +50,13 @@ pub use microsystem::{
     Microsystem, MicrosystemType, NeighborhoodContext, ReligiousContext, SocialContext,
     WorkContext,
 };
+pub use normative_schedule::{LifeTransition, NormativeSchedule};
+pub use time_budget::{
+    TimeBudget, AVAILABLE_HOURS_PER_WEEK, STANDARD_WORK_HOURS_PER_WEEK, SUSTAINABLE_HOURS_PER_WEEK,
+};
+pub use timeline::{ContextChange, ContextSegment, ContextTimeline};
 
-use crate::enums::ContextPath;
+use crate::enums::{ContextPath, PolicyArea};
 use crate::types::MicrosystemId;
 use std::collections::HashMap;
 
@@ -98,6 +113,12 @@ pub struct EcologicalContext {
 
     /// Stored mesosystem state computed from microsystems.
     mesosystem_state: MesosystemState,
+
+    /// Weekly time allocation, if interaction frequencies derive from it.
+    time_budget: Option<TimeBudget>,
+
+    /// Population-driven change to the macrosystem, if it evolves.
+    macrosystem_shift: Option<MacrosystemShift>,
 }
 
 impl EcologicalContext {
@@ -111,6 +132,8 @@ impl EcologicalContext {
             chronosystem: ChronosystemContext::default(),
             mesosystem_cache: MesosystemCache::new(),
             mesosystem_state: MesosystemState::default(),
+            time_budget: None,
+            macrosystem_shift: None,
         }
     }
 
@@ -213,6 +236,88 @@ impl EcologicalContext {
         &mut self.chronosystem
     }
 
+    // --- Time Budget ---
+
+    /// Returns the weekly time allocation, if one is set.
+    #[must_use]
+    pub fn time_budget(&self) -> Option<&TimeBudget> {
+        self.time_budget.as_ref()
+    }
+
+    /// Sets or clears the weekly time allocation.
+    ///
+    /// While a budget is set, context effects derive each microsystem's
+    /// interaction frequency from it (see `TimeBudget::apply_to`) instead of
+    /// using the authored values, and gate each microsystem on its own.
+    pub fn set_time_budget(&mut self, budget: Option<TimeBudget>) {
+        self.time_budget = budget;
+        self.mesosystem_cache.invalidate();
+    }
+
+    // --- Macrosystem Evolution ---
+
+    /// Returns the population-driven macrosystem shift, if one is set.
+    #[must_use]
+    pub fn macrosystem_shift(&self) -> Option<&MacrosystemShift> {
+        self.macrosystem_shift.as_ref()
+    }
+
+    /// Sets or clears the population-driven macrosystem shift.
+    ///
+    /// While a shift is set, context effects use the authored macrosystem
+    /// evolved by it (see `MacrosystemShift::apply_to`). The shift is kept
+    /// apart from the macrosystem, so it survives a
+    /// `ContextChange::ReplaceMacrosystem`.
+    pub fn set_macrosystem_shift(&mut self, shift: Option<MacrosystemShift>) {
+        self.macrosystem_shift = shift;
+    }
+
+    // --- Policy ---
+
+    /// Applies a policy change to the institutions of its area.
+    ///
+    /// `magnitude` is signed: positive values expand the affected
+    /// institutions and negative values cut them back. Each area maps to
+    /// concrete dimensions, clamped to 0.0-1.0:
+    ///
+    /// | Area | Change |
+    /// |------|--------|
+    /// | Healthcare | health system access |
+    /// | Social | community services |
+    /// | Education | educational system quality |
+    /// | Housing | resource availability |
+    /// | Economic | social mobility, and resource availability by half |
+    /// | Environmental | resource availability by half |
+    pub fn apply_policy(&mut self, area: PolicyArea, magnitude: f64) {
+        fn shift(value: &mut f64, delta: f64) {
+            *value = (*value + delta).clamp(0.0, 1.0);
+        }
+
+        let exosystem = &mut self.exosystem;
+        let macrosystem = &mut self.macrosystem;
+        match area {
+            PolicyArea::Healthcare => shift(&mut exosystem.health_system_access, magnitude),
+            PolicyArea::Social => {
+                shift(&mut exosystem.community_services_availability, magnitude);
+            }
+            PolicyArea::Education => {
+                shift(&mut exosystem.educational_system_quality, magnitude);
+            }
+            PolicyArea::Housing => shift(&mut exosystem.resource_availability, magnitude),
+            PolicyArea::Economic => {
+                shift(
+                    &mut macrosystem.institutional_structure.social_mobility,
+                    magnitude,
+                );
+                shift(&mut exosystem.resource_availability, magnitude * 0.5);
+            }
+            PolicyArea::Environmental => {
+                shift(&mut exosystem.resource_availability, magnitude * 0.5);
+            }
+        }
+        self.mesosystem_cache.invalidate();
+    }
+
     // --- Mesosystem Access ---
 
     /// Returns a reference to the mesosystem cache.
@@ -637,8 +742,10 @@ mod tests {
     fn get_microsystem_mut() {
         let mut context = EcologicalContext::default();
         let work_id = MicrosystemId::new("work_acme").unwrap();
-        let mut work = WorkContext::default();
-        work.workload_stress = 0.3;
+        let work = WorkContext {
+            workload_stress: 0.3,
+            ..Default::default()
+        };
 
         context.add_microsystem(work_id.clone(), Microsystem::new_work(work));
 
@@ -747,8 +854,10 @@ mod tests {
     fn context_path_microsystem_query() {
         let mut context = EcologicalContext::default();
         let work_id = MicrosystemId::new("work_acme").unwrap();
-        let mut work = WorkContext::default();
-        work.workload_stress = 0.7;
+        let work = WorkContext {
+            workload_stress: 0.7,
+            ..Default::default()
+        };
         context.add_microsystem(work_id.clone(), Microsystem::new_work(work));
 
         let path = ContextPath::Microsystem(
@@ -880,10 +989,14 @@ mod tests {
         let work1_id = MicrosystemId::new("work_job1").unwrap();
         let work2_id = MicrosystemId::new("work_job2").unwrap();
 
-        let mut work1 = WorkContext::default();
-        work1.workload_stress = 0.3;
-        let mut work2 = WorkContext::default();
-        work2.workload_stress = 0.8;
+        let work1 = WorkContext {
+            workload_stress: 0.3,
+            ..Default::default()
+        };
+        let work2 = WorkContext {
+            workload_stress: 0.8,
+            ..Default::default()
+        };
 
         context.add_microsystem(work1_id.clone(), Microsystem::new_work(work1));
         context.add_microsystem(work2_id.clone(), Microsystem::new_work(work2));
@@ -906,6 +1019,40 @@ mod tests {
         assert!((value2 - 0.8).abs() < f64::EPSILON);
     }
 
+    #[test]
+    fn policy_changes_move_the_institutions_of_their_area() {
+        let mut context = EcologicalContext::default();
+        context.apply_policy(PolicyArea::Healthcare, 0.3);
+        assert!((context.exosystem().health_system_access - 0.9).abs() < 1e-9);
+
+        context.apply_policy(PolicyArea::Healthcare, 0.5);
+        assert!((context.exosystem().health_system_access - 1.0).abs() < 1e-9);
+
+        context.apply_policy(PolicyArea::Housing, -0.4);
+        assert!((context.exosystem().resource_availability - 0.2).abs() < 1e-9);
+
+        context.apply_policy(PolicyArea::Economic, 0.2);
+        let institutions = &context.macrosystem().institutional_structure;
+        assert!((institutions.social_mobility - 0.7).abs() < 1e-9);
+        assert!((context.exosystem().resource_availability - 0.3).abs() < 1e-9);
+    }
+
+    #[test]
+    fn social_policy_expands_services_without_changing_culture() {
+        let mut context = EcologicalContext::default();
+        context
+            .macrosystem_mut()
+            .cultural_orientation
+            .power_distance = 0.7;
+        let constraints = context.macrosystem().constraint_set();
+
+        context.apply_policy(PolicyArea::Social, 0.4);
+
+        let services = context.exosystem().community_services_availability;
+        assert!((services - 0.9).abs() < 1e-9);
+        assert_eq!(context.macrosystem().constraint_set(), constraints);
+    }
+
     // --- Additional coverage tests for mesosystem methods ---
 
     #[test]
@@ -923,22 +1070,26 @@ mod tests {
             &MesosystemState::default()
         );
 
-        let mut work = WorkContext::default();
-        work.role_clarity = 0.2;
-        work.predictability = 0.3;
-        work.warmth = 0.2;
-        work.hostility = 0.1;
-        work.workload_stress = 0.8;
+        let mut work = WorkContext {
+            role_clarity: 0.2,
+            predictability: 0.3,
+            warmth: 0.2,
+            hostility: 0.1,
+            workload_stress: 0.8,
+            ..Default::default()
+        };
         work.interaction_profile.interaction_frequency = 0.8;
         context.add_microsystem(
             MicrosystemId::new("work").unwrap(),
             Microsystem::new_work(work),
         );
 
-        let mut social = SocialContext::default();
-        social.warmth = 0.8;
-        social.predictability = 0.9;
-        social.hostility = 0.1;
+        let mut social = SocialContext {
+            warmth: 0.8,
+            predictability: 0.9,
+            hostility: 0.1,
+            ..Default::default()
+        };
         social.interaction_profile.interaction_frequency = 0.6;
         context.add_microsystem(
             MicrosystemId::new("social").unwrap(),
@@ -958,18 +1109,22 @@ mod tests {
         let work_id = MicrosystemId::new("work").unwrap();
         let family_id = MicrosystemId::new("family").unwrap();
 
-        let mut work = WorkContext::default();
-        work.workload_stress = 0.8;
+        let mut work = WorkContext {
+            workload_stress: 0.8,
+            ..Default::default()
+        };
         work.interaction_profile.interaction_frequency = 0.7;
         context.add_microsystem(work_id.clone(), Microsystem::new_work(work));
 
-        let mut family = FamilyContext::default();
-        family.predictability = 0.3;
-        family.stability = 0.3;
+        let family = FamilyContext {
+            predictability: 0.3,
+            stability: 0.3,
+            ..Default::default()
+        };
         context.add_microsystem(family_id.clone(), Microsystem::new_family(family));
 
         let spillover = context.get_spillover(&work_id, &family_id);
-        assert!(spillover >= 0.0 && spillover <= 1.0);
+        assert!((0.0..=1.0).contains(&spillover));
     }
 
     #[test]
@@ -978,18 +1133,22 @@ mod tests {
         let work_id = MicrosystemId::new("work").unwrap();
         let family_id = MicrosystemId::new("family").unwrap();
 
-        let mut work = WorkContext::default();
-        work.workload_stress = 0.8;
+        let mut work = WorkContext {
+            workload_stress: 0.8,
+            ..Default::default()
+        };
         work.interaction_profile.interaction_frequency = 0.8;
         context.add_microsystem(work_id.clone(), Microsystem::new_work(work));
 
-        let mut family = FamilyContext::default();
-        family.caregiving_burden = 0.8;
+        let mut family = FamilyContext {
+            caregiving_burden: 0.8,
+            ..Default::default()
+        };
         family.interaction_profile.interaction_frequency = 0.8;
         context.add_microsystem(family_id.clone(), Microsystem::new_family(family));
 
         let conflict = context.get_role_conflict(&work_id, &family_id);
-        assert!(conflict >= 0.0 && conflict <= 1.0);
+        assert!((0.0..=1.0).contains(&conflict));
     }
 
     #[test]
@@ -1032,10 +1191,14 @@ mod tests {
         let social1_id = MicrosystemId::new("social1").unwrap();
         let social2_id = MicrosystemId::new("social2").unwrap();
 
-        let mut social1 = SocialContext::default();
-        social1.warmth = 0.8;
-        let mut social2 = SocialContext::default();
-        social2.warmth = 0.4;
+        let social1 = SocialContext {
+            warmth: 0.8,
+            ..Default::default()
+        };
+        let social2 = SocialContext {
+            warmth: 0.4,
+            ..Default::default()
+        };
 
         context.add_microsystem(social1_id, Microsystem::new_social(social1));
         context.add_microsystem(social2_id, Microsystem::new_social(social2));
@@ -1057,8 +1220,10 @@ mod tests {
         let mut context = EcologicalContext::default();
 
         let work_id = MicrosystemId::new("work").unwrap();
-        let mut work = WorkContext::default();
-        work.workload_stress = 0.8;
+        let work = WorkContext {
+            workload_stress: 0.8,
+            ..Default::default()
+        };
         context.add_microsystem(work_id, Microsystem::new_work(work));
 
         let stress = context.aggregate_stress();
@@ -1077,8 +1242,10 @@ mod tests {
     fn aggregate_hostility_with_microsystems() {
         let mut context = EcologicalContext::default();
         let work_id = MicrosystemId::new("work").unwrap();
-        let mut work = WorkContext::default();
-        work.hostility = 0.7;
+        let work = WorkContext {
+            hostility: 0.7,
+            ..Default::default()
+        };
         context.add_microsystem(work_id, Microsystem::new_work(work));
 
         let hostility = context.aggregate_hostility();
@@ -1090,8 +1257,10 @@ mod tests {
         let mut context = EcologicalContext::default();
 
         let social_id = MicrosystemId::new("social").unwrap();
-        let mut social = SocialContext::default();
-        social.warmth = 0.5;
+        let social = SocialContext {
+            warmth: 0.5,
+            ..Default::default()
+        };
         context.add_microsystem(social_id.clone(), Microsystem::new_social(social));
 
         // High extraversion should boost warmth
@@ -1110,8 +1279,10 @@ mod tests {
         let mut context = EcologicalContext::default();
 
         let social_id = MicrosystemId::new("social").unwrap();
-        let mut social = SocialContext::default();
-        social.warmth = 0.5;
+        let social = SocialContext {
+            warmth: 0.5,
+            ..Default::default()
+        };
         context.add_microsystem(social_id.clone(), Microsystem::new_social(social));
 
         // Low extraversion should not change warmth
@@ -1129,8 +1300,10 @@ mod tests {
     fn high_conscientiousness_increases_work_clarity() {
         let mut context = EcologicalContext::default();
         let work_id = MicrosystemId::new("work").unwrap();
-        let mut work = WorkContext::default();
-        work.role_clarity = 0.4;
+        let work = WorkContext {
+            role_clarity: 0.4,
+            ..Default::default()
+        };
         context.add_microsystem(work_id.clone(), Microsystem::new_work(work));
 
         context.apply_person_to_context_shaping(0.0, 0.7, 0.0, 0.0, 0.0);
@@ -1143,8 +1316,10 @@ mod tests {
     fn conscientiousness_ignores_non_work_microsystems() {
         let mut context = EcologicalContext::default();
         let social_id = MicrosystemId::new("social").unwrap();
-        let mut social = SocialContext::default();
-        social.warmth = 0.6;
+        let social = SocialContext {
+            warmth: 0.6,
+            ..Default::default()
+        };
         context.add_microsystem(social_id.clone(), Microsystem::new_social(social));
 
         context.apply_person_to_context_shaping(0.0, 0.7, 0.0, 0.0, 0.0);
@@ -1161,9 +1336,11 @@ mod tests {
     fn high_neuroticism_reduces_stability_tolerance() {
         let mut context = EcologicalContext::default();
         let work_id = MicrosystemId::new("work").unwrap();
-        let mut work = WorkContext::default();
-        work.stability = 0.8;
-        work.predictability = 0.8;
+        let work = WorkContext {
+            stability: 0.8,
+            predictability: 0.8,
+            ..Default::default()
+        };
         context.add_microsystem(work_id.clone(), Microsystem::new_work(work));
 
         context.apply_person_to_context_shaping(0.0, 0.0, 0.0, 0.7, 0.0);
@@ -1177,10 +1354,12 @@ mod tests {
     fn neuroticism_and_grievance_affect_family_context() {
         let mut context = EcologicalContext::default();
         let family_id = MicrosystemId::new("family").unwrap();
-        let mut family = FamilyContext::default();
-        family.stability = 0.8;
-        family.predictability = 0.8;
-        family.hostility = 0.2;
+        let family = FamilyContext {
+            stability: 0.8,
+            predictability: 0.8,
+            hostility: 0.2,
+            ..Default::default()
+        };
         context.add_microsystem(family_id.clone(), Microsystem::new_family(family));
 
         context.apply_person_to_context_shaping(0.0, 0.0, 0.0, 0.7, 0.8);
@@ -1199,8 +1378,10 @@ mod tests {
     fn high_agreeableness_increases_family_warmth() {
         let mut context = EcologicalContext::default();
         let family_id = MicrosystemId::new("family").unwrap();
-        let mut family = FamilyContext::default();
-        family.warmth = 0.4;
+        let family = FamilyContext {
+            warmth: 0.4,
+            ..Default::default()
+        };
         context.add_microsystem(family_id.clone(), Microsystem::new_family(family));
 
         context.apply_person_to_context_shaping(0.0, 0.0, 0.7, 0.0, 0.0);
@@ -1217,8 +1398,10 @@ mod tests {
     fn agreeableness_ignores_non_family_microsystems() {
         let mut context = EcologicalContext::default();
         let work_id = MicrosystemId::new("work").unwrap();
-        let mut work = WorkContext::default();
-        work.role_clarity = 0.4;
+        let work = WorkContext {
+            role_clarity: 0.4,
+            ..Default::default()
+        };
         context.add_microsystem(work_id.clone(), Microsystem::new_work(work));
 
         context.apply_person_to_context_shaping(0.0, 0.0, 0.7, 0.0, 0.0);
@@ -1233,10 +1416,14 @@ mod tests {
         let work_id = MicrosystemId::new("work").unwrap();
         let social_id = MicrosystemId::new("social").unwrap();
 
-        let mut work = WorkContext::default();
-        work.hostility = 0.2;
-        let mut social = SocialContext::default();
-        social.hostility = 0.2;
+        let work = WorkContext {
+            hostility: 0.2,
+            ..Default::default()
+        };
+        let social = SocialContext {
+            hostility: 0.2,
+            ..Default::default()
+        };
 
         context.add_microsystem(work_id.clone(), Microsystem::new_work(work));
         context.add_microsystem(social_id.clone(), Microsystem::new_social(social));
@@ -1259,8 +1446,10 @@ mod tests {
         let mut context = EcologicalContext::default();
 
         let work_id = MicrosystemId::new("work").unwrap();
-        let mut work = WorkContext::default();
-        work.workload_stress = 0.9;
+        let work = WorkContext {
+            workload_stress: 0.9,
+            ..Default::default()
+        };
         context.add_microsystem(work_id, Microsystem::new_work(work));
 
         let (stress_adj, loneliness_adj) = context.compute_context_to_person_effects(0.5);
@@ -1275,8 +1464,10 @@ mod tests {
     fn low_relationship_quality_increases_context_hostility() {
         let mut context = EcologicalContext::default();
         let work_id = MicrosystemId::new("work").unwrap();
-        let mut work = WorkContext::default();
-        work.hostility = 0.8;
+        let work = WorkContext {
+            hostility: 0.8,
+            ..Default::default()
+        };
         context.add_microsystem(work_id, Microsystem::new_work(work));
 
         let (high_quality_stress, _) = context.compute_context_to_person_effects(0.9);
@@ -0,0 +1,313 @@
+//! Culture-specific schedules of normative life transitions.
+//!
+//! A `NormativeSchedule` lists the transitions a culture expects of its
+//! members (school entry, first job, partnership, parenthood, retirement)
+//! with the ages at which it expects them:
+//!
+//! - **Collectivism** moves partnership and parenthood earlier and makes
+//!   them required; individualism moves them later.
+//! - **Uncertainty avoidance** narrows every timing window, so rigid
+//!   cultures tolerate less deviation from the expected age.
+//!
+//! Each `LifeTransition` is completed by the context transition that
+//! realizes it, such as the first move into a work microsystem.
+
+use crate::context::{
+    ChronosystemContext, EcologicalContext, FamilyRole, MacrosystemContext, Microsystem,
+    MicrosystemType, NormativeTransition,
+};
+use crate::types::MicrosystemId;
+
+/// Years partnership and parenthood shift per unit of individualism.
+const FAMILY_FORMATION_SHIFT: f64 = 3.0;
+
+/// A normative life transition that a context transition can complete.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LifeTransition {
+    /// Starting school: the first move into an education microsystem.
+    SchoolEntry,
+    /// Starting work: the first move into a work microsystem.
+    FirstJob,
+    /// Forming a partnership: the first move into a family as a spouse.
+    Partnership,
+    /// Becoming a parent: the first move into a family as a parent.
+    Parenthood,
+    /// Retiring: leaving the last work microsystem for one that is not
+    /// work, no earlier than two timing windows before the expected age.
+    Retirement,
+}
+
+impl LifeTransition {
+    /// All life transitions in the order they are usually expected.
+    pub const ALL: [LifeTransition; 5] = [
+        LifeTransition::SchoolEntry,
+        LifeTransition::FirstJob,
+        LifeTransition::Partnership,
+        LifeTransition::Parenthood,
+        LifeTransition::Retirement,
+    ];
+
+    /// Returns the name of the transition's `NormativeTransition`.
+    #[must_use]
+    pub const fn name(&self) -> &'static str {
+        match self {
+            LifeTransition::SchoolEntry => "school_entry",
+            LifeTransition::FirstJob => "first_job",
+            LifeTransition::Partnership => "partnership",
+            LifeTransition::Parenthood => "parenthood",
+            LifeTransition::Retirement => "retirement",
+        }
+    }
+
+    /// Returns the life transition with the given name, if any.
+    #[must_use]
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|t| t.name() == name)
+    }
+
+    /// Returns the life transition a move from `from` to `to` realizes.
+    ///
+    /// `before` and `after` are the contexts just before and just after the
+    /// move. Only the kind of move is checked: whether it is the first of
+    /// its kind, and the age bound on retirement, are left to the caller.
+    #[must_use]
+    pub fn realized_by(
+        from: &MicrosystemId,
+        to: &MicrosystemId,
+        before: &EcologicalContext,
+        after: &EcologicalContext,
+    ) -> Option<Self> {
+        let joined = after.get_microsystem(to);
+        if let Some(joined) = joined {
+            if let Some(transition) = Self::realized_by_joining(joined) {
+                return Some(transition);
+            }
+        }
+
+        let left_work = before
+            .get_microsystem(from)
+            .is_some_and(|m| m.microsystem_type() == MicrosystemType::Work);
+        let still_working = after
+            .microsystems_iter()
+            .any(|(_, m)| m.microsystem_type() == MicrosystemType::Work);
+        (left_work && !still_working).then_some(LifeTransition::Retirement)
+    }
+
+    fn realized_by_joining(microsystem: &Microsystem) -> Option<Self> {
+        match microsystem {
+            Microsystem::Education(_) => Some(LifeTransition::SchoolEntry),
+            Microsystem::Work(_) => Some(LifeTransition::FirstJob),
+            Microsystem::Family(family) => match family.family_role {
+                FamilyRole::Spouse => Some(LifeTransition::Partnership),
+                FamilyRole::Parent => Some(LifeTransition::Parenthood),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+}
+
+/// The normative transitions a culture expects, with their timing.
+///
+/// # Examples
+///
+/// ```
+/// use eventsim_rs::context::{LifeTransition, MacrosystemContext, NormativeSchedule};
+///
+/// let mut collectivist = MacrosystemContext::default();
+/// collectivist.cultural_orientation.individualism_collectivism = -0.8;
+///
+/// let neutral = NormativeSchedule::for_culture(&MacrosystemContext::default());
+/// let collectivist = NormativeSchedule::for_culture(&collectivist);
+///
+/// let partnership = |schedule: &NormativeSchedule| {
+///     schedule.get(LifeTransition::Partnership).unwrap().expected_age
+/// };
+/// assert!(partnership(&collectivist) < partnership(&neutral));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct NormativeSchedule {
+    transitions: Vec<NormativeTransition>,
+}
+
+impl NormativeSchedule {
+    /// Creates the default schedule for a culture.
+    #[must_use]
+    pub fn for_culture(macrosystem: &MacrosystemContext) -> Self {
+        let orientation = &macrosystem.cultural_orientation;
+        let individualism = orientation.individualism_collectivism.clamp(-1.0, 1.0);
+        let window_scale = 1.5 - orientation.uncertainty_avoidance.clamp(0.0, 1.0);
+        let family_shift = individualism * FAMILY_FORMATION_SHIFT;
+        let family_required = individualism < 0.0;
+
+        let transition = |transition: LifeTransition, age: f64, window: f64, required: bool| {
+            NormativeTransition::new(transition.name(), age, window * window_scale, required)
+        };
+        NormativeSchedule {
+            transitions: vec![
+                transition(LifeTransition::SchoolEntry, 6.0, 1.0, true),
+                transition(LifeTransition::FirstJob, 20.0, 4.0, true),
+                transition(
+                    LifeTransition::Partnership,
+                    28.0 + family_shift,
+                    5.0,
+                    family_required,
+                ),
+                transition(
+                    LifeTransition::Parenthood,
+                    30.0 + family_shift,
+                    5.0,
+                    family_required,
+                ),
+                transition(LifeTransition::Retirement, 65.0, 5.0, false),
+            ],
+        }
+    }
+
+    /// Returns the scheduled transitions.
+    #[must_use]
+    pub fn transitions(&self) -> &[NormativeTransition] {
+        &self.transitions
+    }
+
+    /// Returns the scheduled timing of a life transition.
+    #[must_use]
+    pub fn get(&self, transition: LifeTransition) -> Option<&NormativeTransition> {
+        self.transitions
+            .iter()
+            .find(|t| t.name == transition.name())
+    }
+
+    /// Adds the scheduled transitions still ahead or underway at
+    /// `age_years` to a chronosystem.
+    ///
+    /// Transitions whose window closed before that age are left out, as
+    /// are transitions the chronosystem already has by name, which are kept
+    /// as authored.
+    pub fn apply_to(&self, chronosystem: &mut ChronosystemContext, age_years: f64) {
+        for transition in &self.transitions {
+            let closed = transition.expected_age + transition.timing_window < age_years;
+            if !closed
+                && chronosystem
+                    .get_normative_transition(&transition.name)
+                    .is_none()
+            {
+                chronosystem.add_normative_transition(transition.clone());
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::context::{EducationContext, FamilyContext, WorkContext};
+
+    fn id(name: &str) -> MicrosystemId {
+        MicrosystemId::new(name).unwrap()
+    }
+
+    fn family(role: FamilyRole) -> Microsystem {
+        Microsystem::new_family(FamilyContext {
+            family_role: role,
+            ..FamilyContext::default()
+        })
+    }
+
+    #[test]
+    fn names_round_trip() {
+        for transition in LifeTransition::ALL {
+            assert_eq!(
+                LifeTransition::from_name(transition.name()),
+                Some(transition)
+            );
+        }
+        assert_eq!(LifeTransition::from_name("marriage"), None);
+    }
+
+    #[test]
+    fn culture_moves_family_formation_and_narrows_windows() {
+        let neutral = NormativeSchedule::for_culture(&MacrosystemContext::default());
+        let mut rigid_collectivist = MacrosystemContext::default();
+        rigid_collectivist
+            .cultural_orientation
+            .individualism_collectivism = -1.0;
+        rigid_collectivist
+            .cultural_orientation
+            .uncertainty_avoidance = 1.0;
+        let rigid_collectivist = NormativeSchedule::for_culture(&rigid_collectivist);
+
+        let parenthood = neutral.get(LifeTransition::Parenthood).unwrap();
+        assert!((parenthood.expected_age - 30.0).abs() < 1e-9);
+        assert!(!parenthood.is_required);
+
+        let parenthood = rigid_collectivist.get(LifeTransition::Parenthood).unwrap();
+        assert!((parenthood.expected_age - 27.0).abs() < 1e-9);
+        assert!((parenthood.timing_window - 2.5).abs() < 1e-9);
+        assert!(parenthood.is_required);
+    }
+
+    #[test]
+    fn apply_to_keeps_authored_transitions() {
+        let mut chronosystem = ChronosystemContext::default();
+        chronosystem.add_normative_transition(NormativeTransition::new(
+            LifeTransition::Retirement.name(),
+            60.0,
+            2.0,
+            true,
+        ));
+
+        NormativeSchedule::for_culture(&MacrosystemContext::default())
+            .apply_to(&mut chronosystem, 10.0);
+
+        // School entry closed before age 10
+        assert_eq!(chronosystem.normative_transitions().len(), 4);
+        assert!(chronosystem
+            .get_normative_transition("school_entry")
+            .is_none());
+        let retirement = chronosystem.get_normative_transition("retirement").unwrap();
+        assert!((retirement.expected_age - 60.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn moves_realize_the_matching_transition() {
+        let mut before = EcologicalContext::default();
+        before.add_microsystem(id("job"), Microsystem::new_work(WorkContext::default()));
+        let mut after = before.clone();
+        after.add_microsystem(
+            id("school"),
+            Microsystem::new_education(EducationContext::default()),
+        );
+        after.add_microsystem(id("spouse"), family(FamilyRole::Spouse));
+        after.add_microsystem(id("kids"), family(FamilyRole::Parent));
+
+        let realized =
+            |to: &str| LifeTransition::realized_by(&id("home"), &id(to), &before, &after);
+        assert_eq!(realized("school"), Some(LifeTransition::SchoolEntry));
+        assert_eq!(realized("job"), Some(LifeTransition::FirstJob));
+        assert_eq!(realized("spouse"), Some(LifeTransition::Partnership));
+        assert_eq!(realized("kids"), Some(LifeTransition::Parenthood));
+    }
+
+    #[test]
+    fn leaving_the_last_job_realizes_retirement() {
+        let mut before = EcologicalContext::default();
+        before.add_microsystem(id("job"), Microsystem::new_work(WorkContext::default()));
+        before.add_microsystem(
+            id("side_job"),
+            Microsystem::new_work(WorkContext::default()),
+        );
+        let mut after = before.clone();
+        after.remove_microsystem(&id("job"));
+        assert_eq!(
+            LifeTransition::realized_by(&id("job"), &id("garden"), &before, &after),
+            None
+        );
+
+        after.remove_microsystem(&id("side_job"));
+        assert_eq!(
+            LifeTransition::realized_by(&id("job"), &id("garden"), &before, &after),
+            Some(LifeTransition::Retirement)
+        );
+    }
+}
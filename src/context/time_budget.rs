@@ -0,0 +1,267 @@
+//! Weekly time allocation across microsystems.
+//!
+//! Without a budget, each microsystem's interaction frequency is an
+//! authored value. With one, it follows the hours the entity spends there,
+//! so time given to one setting is time taken from the others:
+//!
+//! - **Interaction frequency** is the hours allocated to a microsystem type
+//!   relative to the hours at which it is fully engaged, split evenly across
+//!   microsystems of that type.
+//! - **Squeeze**: work hours are fixed obligations. When requested hours
+//!   exceed the discretionary week, the other settings shrink to fit, so
+//!   overwork starves the family microsystem below the proximal process gate.
+//! - **Overtime** beyond a standard week raises workload stress, which spills
+//!   over into other settings.
+//! - **Overcommitment** beyond a sustainable week drives fatigue, and the
+//!   share of hours the budget cannot honor is role conflict.
+
+use std::collections::HashMap;
+
+use crate::context::{EcologicalContext, MicrosystemType};
+
+/// Discretionary hours per week, after sleep and self-maintenance.
+pub const AVAILABLE_HOURS_PER_WEEK: f64 = 90.0;
+
+/// Committed hours per week that can be sustained without fatigue.
+pub const SUSTAINABLE_HOURS_PER_WEEK: f64 = 80.0;
+
+/// Standard work hours per week; hours beyond this are overtime.
+pub const STANDARD_WORK_HOURS_PER_WEEK: f64 = 40.0;
+
+/// Hours allocated per week to each microsystem type.
+///
+/// # Examples
+///
+/// ```
+/// use eventsim_rs::context::{MicrosystemType, TimeBudget};
+///
+/// let balanced = TimeBudget::new()
+///     .with_hours(MicrosystemType::Work, 40.0)
+///     .with_hours(MicrosystemType::Family, 28.0);
+/// assert!((balanced.effective_hours(MicrosystemType::Family) - 28.0).abs() < 1e-9);
+///
+/// // Eighty hours at work leaves ten for everything else.
+/// let overworked = balanced.with_hours(MicrosystemType::Work, 80.0);
+/// assert!((overworked.effective_hours(MicrosystemType::Family) - 10.0).abs() < 1e-9);
+/// assert!(overworked.interaction_frequency(MicrosystemType::Family) < 0.4);
+/// ```
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct TimeBudget {
+    hours: HashMap<MicrosystemType, f64>,
+}
+
+impl TimeBudget {
+    /// Creates a budget with no hours allocated.
+    #[must_use]
+    pub fn new() -> Self {
+        TimeBudget::default()
+    }
+
+    /// Sets the hours per week for a microsystem type.
+    #[must_use]
+    pub fn with_hours(mut self, microsystem_type: MicrosystemType, hours: f64) -> Self {
+        self.set_hours(microsystem_type, hours);
+        self
+    }
+
+    /// Sets the hours per week for a microsystem type (clamped to 0-168).
+    pub fn set_hours(&mut self, microsystem_type: MicrosystemType, hours: f64) {
+        self.hours.insert(microsystem_type, hours.clamp(0.0, 168.0));
+    }
+
+    /// Returns the requested hours per week for a microsystem type.
+    #[must_use]
+    pub fn hours(&self, microsystem_type: MicrosystemType) -> f64 {
+        self.hours.get(&microsystem_type).copied().unwrap_or(0.0)
+    }
+
+    /// Returns the total requested hours per week.
+    #[must_use]
+    pub fn total_hours(&self) -> f64 {
+        self.hours.values().sum()
+    }
+
+    /// Returns the hours per week actually spent on a microsystem type.
+    ///
+    /// Work hours are honored up to the discretionary week. Other types
+    /// share what remains, scaled down proportionally when it is not enough.
+    #[must_use]
+    pub fn effective_hours(&self, microsystem_type: MicrosystemType) -> f64 {
+        let work = self
+            .hours(MicrosystemType::Work)
+            .min(AVAILABLE_HOURS_PER_WEEK);
+        if microsystem_type == MicrosystemType::Work {
+            return work;
+        }
+        self.hours(microsystem_type) * self.squeeze(work)
+    }
+
+    /// Returns the interaction frequency (0-1) a microsystem type receives.
+    #[must_use]
+    pub fn interaction_frequency(&self, microsystem_type: MicrosystemType) -> f64 {
+        let full = full_engagement_hours(microsystem_type);
+        (self.effective_hours(microsystem_type) / full).clamp(0.0, 1.0)
+    }
+
+    /// Returns the share (0-1) of requested non-work hours the budget
+    /// cannot honor.
+    #[must_use]
+    pub fn role_conflict(&self) -> f64 {
+        let work = self
+            .hours(MicrosystemType::Work)
+            .min(AVAILABLE_HOURS_PER_WEEK);
+        1.0 - self.squeeze(work)
+    }
+
+    /// Returns how far (0-1) requested hours exceed a sustainable week.
+    #[must_use]
+    pub fn overcommitment(&self) -> f64 {
+        ((self.total_hours() - SUSTAINABLE_HOURS_PER_WEEK) / SUSTAINABLE_HOURS_PER_WEEK)
+            .clamp(0.0, 1.0)
+    }
+
+    /// Returns the workload stress (0-1) implied by overtime, if any.
+    #[must_use]
+    pub fn overtime_pressure(&self) -> Option<f64> {
+        let overtime = self.effective_hours(MicrosystemType::Work) - STANDARD_WORK_HOURS_PER_WEEK;
+        (overtime > 0.0).then(|| 0.5 + 0.5 * (overtime / STANDARD_WORK_HOURS_PER_WEEK).min(1.0))
+    }
+
+    /// Sets each microsystem's interaction frequency from the budget.
+    ///
+    /// Work microsystems under overtime have their workload stress raised to
+    /// at least the overtime pressure. Applying a budget twice gives the
+    /// same result as applying it once.
+    pub fn apply_to(&self, context: &mut EcologicalContext) {
+        let mut counts: HashMap<MicrosystemType, usize> = HashMap::new();
+        for (_, microsystem) in context.microsystems_iter() {
+            *counts.entry(microsystem.microsystem_type()).or_default() += 1;
+        }
+
+        let overtime_pressure = self.overtime_pressure();
+        let ids: Vec<_> = context
+            .microsystems_iter()
+            .map(|(id, _)| id.clone())
+            .collect();
+        for id in ids {
+            let Some(microsystem) = context.get_microsystem_mut(&id) else {
+                continue;
+            };
+            let microsystem_type = microsystem.microsystem_type();
+            let share = counts.get(&microsystem_type).copied().unwrap_or(1) as f64;
+            microsystem
+                .set_interaction_frequency(self.interaction_frequency(microsystem_type) / share);
+            if let (Some(pressure), Some(work)) = (overtime_pressure, microsystem.work_mut()) {
+                work.workload_stress = work.workload_stress.max(pressure);
+            }
+        }
+        context.invalidate_mesosystem_cache();
+    }
+
+    /// Returns the factor (0-1) applied to non-work hours.
+    fn squeeze(&self, work: f64) -> f64 {
+        let requested: f64 = self
+            .hours
+            .iter()
+            .filter(|(microsystem_type, _)| **microsystem_type != MicrosystemType::Work)
+            .map(|(_, hours)| hours)
+            .sum();
+        let remaining = AVAILABLE_HOURS_PER_WEEK - work;
+        if requested <= remaining || requested <= 0.0 {
+            1.0
+        } else {
+            remaining / requested
+        }
+    }
+}
+
+/// Returns the hours per week at which a microsystem type is fully engaged.
+fn full_engagement_hours(microsystem_type: MicrosystemType) -> f64 {
+    match microsystem_type {
+        MicrosystemType::Work => 40.0,
+        MicrosystemType::Family => 28.0,
+        MicrosystemType::Education => 30.0,
+        MicrosystemType::Social => 10.0,
+        MicrosystemType::Healthcare => 2.0,
+        MicrosystemType::Religious => 4.0,
+        MicrosystemType::Neighborhood => 7.0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::context::{FamilyContext, Microsystem, SocialContext, WorkContext};
+    use crate::types::MicrosystemId;
+
+    fn balanced() -> TimeBudget {
+        TimeBudget::new()
+            .with_hours(MicrosystemType::Work, 40.0)
+            .with_hours(MicrosystemType::Family, 28.0)
+            .with_hours(MicrosystemType::Social, 10.0)
+    }
+
+    #[test]
+    fn balanced_week_has_no_squeeze_or_overtime() {
+        let budget = balanced();
+        assert!((budget.interaction_frequency(MicrosystemType::Family) - 1.0).abs() < 1e-9);
+        assert!(budget.role_conflict().abs() < 1e-9);
+        assert!(budget.overcommitment().abs() < 1e-9);
+        assert!(budget.overtime_pressure().is_none());
+        assert!(
+            budget
+                .interaction_frequency(MicrosystemType::Religious)
+                .abs()
+                < 1e-9
+        );
+    }
+
+    #[test]
+    fn overwork_squeezes_other_settings() {
+        let budget = balanced().with_hours(MicrosystemType::Work, 70.0);
+        // 20 hours remain for 38 requested
+        let family = budget.effective_hours(MicrosystemType::Family);
+        assert!((family - 28.0 * 20.0 / 38.0).abs() < 1e-9);
+        assert!((budget.role_conflict() - 18.0 / 38.0).abs() < 1e-9);
+        assert!((budget.overcommitment() - 28.0 / 80.0).abs() < 1e-9);
+        assert!((budget.overtime_pressure().unwrap() - 0.875).abs() < 1e-9);
+    }
+
+    #[test]
+    fn apply_to_splits_hours_and_raises_workload() {
+        let mut context = EcologicalContext::default();
+        context.add_microsystem(
+            MicrosystemId::new("work").unwrap(),
+            Microsystem::new_work(WorkContext::default()),
+        );
+        context.add_microsystem(
+            MicrosystemId::new("family").unwrap(),
+            Microsystem::new_family(FamilyContext::default()),
+        );
+        for id in ["club", "team"] {
+            context.add_microsystem(
+                MicrosystemId::new(id).unwrap(),
+                Microsystem::new_social(SocialContext::default()),
+            );
+        }
+
+        let budget = balanced().with_hours(MicrosystemType::Work, 60.0);
+        budget.apply_to(&mut context);
+        let once = context.clone();
+        budget.apply_to(&mut context);
+        assert_eq!(context, once);
+
+        let club = context
+            .get_microsystem(&MicrosystemId::new("club").unwrap())
+            .unwrap();
+        // 30 hours remain for 38 requested, social gets 10 * 30 / 38 split in two
+        let expected = 10.0 * 30.0 / 38.0 / 10.0 / 2.0;
+        assert!((club.interaction_frequency() - expected).abs() < 1e-9);
+
+        let work = context
+            .get_microsystem(&MicrosystemId::new("work").unwrap())
+            .unwrap();
+        assert!((work.interaction_frequency() - 1.0).abs() < 1e-9);
+        assert!((work.stress_level() - 0.75).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,486 @@
+//! Time-varying ecological context.
+//!
+//! An entity's `EcologicalContext` describes its environment before any
+//! scheduled change. A `ContextTimeline` records changes at timestamps
+//! (taking a job, moving away, leaving school, a new policy climate), so the
+//! context in force can differ across a queried interval. `state_at`
+//! integrates context effects piecewise over the segments between changes.
+//!
+//! Microsystems the entity leaves are kept, so a later transition back into
+//! them restores their last known values. Microsystems the entity has never
+//! belonged to can be registered in advance for transitions to move into,
+//! and microsystems that change on their own (such as shared ones) can be
+//! registered as of a timestamp, so a transition joins them as they were
+//! then.
+
+use std::collections::HashMap;
+
+use crate::context::{
+    EcologicalContext, ExosystemContext, MacrosystemContext, Microsystem, TimeBudget,
+};
+use crate::enums::{ContextPath, PolicyArea};
+use crate::types::{Duration, MicrosystemId, Timestamp};
+
+/// A change to an entity's ecological context.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ContextChange {
+    /// Joins a microsystem, replacing any with the same ID.
+    AddMicrosystem(MicrosystemId, Microsystem),
+    /// Leaves a microsystem.
+    RemoveMicrosystem(MicrosystemId),
+    /// Sets a single context value (microsystem, exosystem, macrosystem or
+    /// chronosystem).
+    SetValue(ContextPath, f64),
+    /// Replaces the exosystem.
+    ReplaceExosystem(ExosystemContext),
+    /// Replaces the macrosystem.
+    ReplaceMacrosystem(MacrosystemContext),
+    /// Sets or clears the weekly time allocation.
+    SetTimeBudget(Option<TimeBudget>),
+    /// Leaves one microsystem and joins another.
+    ///
+    /// The joined microsystem is the latest one registered with
+    /// `ContextTimeline::register_microsystem_at` by the transition, or else
+    /// one the entity left earlier or one registered with
+    /// `ContextTimeline::register_microsystem`. If none exists, only the
+    /// leaving half takes effect.
+    Transition {
+        /// Microsystem left.
+        from: MicrosystemId,
+        /// Microsystem joined.
+        to: MicrosystemId,
+    },
+    /// Completes a normative transition at the given age, unless it has
+    /// already occurred (see `ChronosystemContext::complete_normative_transition`).
+    CompleteTransition {
+        /// Name of the normative transition.
+        name: String,
+        /// Age at completion (years).
+        age: f64,
+    },
+    /// Applies a policy change (see `EcologicalContext::apply_policy`).
+    ApplyPolicy {
+        /// Area affected by the policy.
+        policy_area: PolicyArea,
+        /// Signed change to the institutions in the area.
+        magnitude: f64,
+    },
+}
+
+/// A stretch of time during which the context does not change.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ContextSegment {
+    start: Timestamp,
+    end: Timestamp,
+    context: EcologicalContext,
+}
+
+impl ContextSegment {
+    /// Returns when the segment starts.
+    #[must_use]
+    pub fn start(&self) -> Timestamp {
+        self.start
+    }
+
+    /// Returns when the segment ends.
+    #[must_use]
+    pub fn end(&self) -> Timestamp {
+        self.end
+    }
+
+    /// Returns the segment's length.
+    #[must_use]
+    pub fn duration(&self) -> Duration {
+        self.end - self.start
+    }
+
+    /// Returns the context in force during the segment.
+    #[must_use]
+    pub fn context(&self) -> &EcologicalContext {
+        &self.context
+    }
+}
+
+/// Context changes scheduled at timestamps.
+///
+/// # Examples
+///
+/// ```
+/// use eventsim_rs::context::{
+///     ContextChange, ContextTimeline, EcologicalContext, Microsystem, WorkContext,
+/// };
+/// use eventsim_rs::types::{MicrosystemId, Timestamp};
+///
+/// let job = MicrosystemId::new("work_acme").unwrap();
+/// let hired = Timestamp::from_ymd_hms(2024, 3, 1, 0, 0, 0);
+///
+/// let mut timeline = ContextTimeline::new();
+/// timeline.schedule(
+///     hired,
+///     ContextChange::AddMicrosystem(job.clone(), Microsystem::new_work(WorkContext::default())),
+/// );
+///
+/// let base = EcologicalContext::default();
+/// let before = Timestamp::from_ymd_hms(2024, 2, 1, 0, 0, 0);
+/// assert!(timeline.context_at(&base, before).get_microsystem(&job).is_none());
+/// assert!(timeline.context_at(&base, hired).get_microsystem(&job).is_some());
+/// ```
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ContextTimeline {
+    /// Changes in chronological order; same-timestamp changes keep insertion order.
+    changes: Vec<(Timestamp, ContextChange)>,
+    /// Microsystems available for transitions before the entity joins them.
+    registered: HashMap<MicrosystemId, Microsystem>,
+    /// Microsystems as of timestamps, in chronological order per ID.
+    snapshots: HashMap<MicrosystemId, Vec<(Timestamp, Microsystem)>>,
+}
+
+impl ContextTimeline {
+    /// Creates an empty timeline.
+    #[must_use]
+    pub fn new() -> Self {
+        ContextTimeline::default()
+    }
+
+    /// Schedules a change to take effect at the given timestamp.
+    ///
+    /// Changes at the same timestamp apply in the order they were scheduled.
+    pub fn schedule(&mut self, timestamp: Timestamp, change: ContextChange) {
+        let index = self.changes.partition_point(|(ts, _)| *ts <= timestamp);
+        self.changes.insert(index, (timestamp, change));
+    }
+
+    /// Registers a microsystem that transitions can move the entity into.
+    pub fn register_microsystem(&mut self, id: MicrosystemId, microsystem: Microsystem) {
+        self.registered.insert(id, microsystem);
+    }
+
+    /// Registers a microsystem as it stands at `timestamp`.
+    ///
+    /// Transitions into the microsystem at or after `timestamp` join the
+    /// latest values registered by then, rather than values the entity left
+    /// it with. A snapshot already registered at the same timestamp is
+    /// replaced.
+    pub fn register_microsystem_at(
+        &mut self,
+        id: MicrosystemId,
+        timestamp: Timestamp,
+        microsystem: Microsystem,
+    ) {
+        let snapshots = self.snapshots.entry(id).or_default();
+        match snapshots.binary_search_by_key(&timestamp, |(ts, _)| *ts) {
+            Ok(index) => snapshots[index].1 = microsystem,
+            Err(index) => snapshots.insert(index, (timestamp, microsystem)),
+        }
+    }
+
+    /// Returns the scheduled changes in chronological order.
+    pub fn changes(&self) -> impl Iterator<Item = (Timestamp, &ContextChange)> {
+        self.changes.iter().map(|(ts, change)| (*ts, change))
+    }
+
+    /// Returns the number of scheduled changes.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.changes.len()
+    }
+
+    /// Returns true if no changes are scheduled.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    /// Returns the context in force at the given timestamp.
+    ///
+    /// This is `base` with every change scheduled at or before `timestamp`
+    /// applied.
+    #[must_use]
+    pub fn context_at(&self, base: &EcologicalContext, timestamp: Timestamp) -> EcologicalContext {
+        let mut replay = Replay::new(base, self);
+        for (ts, change) in self.changes.iter().take_while(|(ts, _)| *ts <= timestamp) {
+            replay.apply(*ts, change);
+        }
+        replay.context
+    }
+
+    /// Splits the interval from `start` to `end` at each change.
+    ///
+    /// Segments are contiguous and cover the whole interval. Changes at
+    /// `end` belong to the following interval and do not create a segment.
+    #[must_use]
+    pub fn segments(
+        &self,
+        base: &EcologicalContext,
+        start: Timestamp,
+        end: Timestamp,
+    ) -> Vec<ContextSegment> {
+        let mut replay = Replay::new(base, self);
+        let mut changes = self.changes.iter().peekable();
+        while let Some((ts, change)) = changes.next_if(|(ts, _)| *ts <= start) {
+            replay.apply(*ts, change);
+        }
+
+        let mut segments = Vec::new();
+        let mut cursor = start;
+        for (ts, change) in changes.take_while(|(ts, _)| *ts < end) {
+            if *ts > cursor {
+                segments.push(ContextSegment {
+                    start: cursor,
+                    end: *ts,
+                    context: replay.context.clone(),
+                });
+                cursor = *ts;
+            }
+            replay.apply(*ts, change);
+        }
+        segments.push(ContextSegment {
+            start: cursor,
+            end,
+            context: replay.context,
+        });
+        segments
+    }
+}
+
+/// Context being rebuilt by applying changes in order.
+struct Replay<'t> {
+    context: EcologicalContext,
+    /// Microsystems the entity is not in but could transition into.
+    dormant: HashMap<MicrosystemId, Microsystem>,
+    /// Microsystems as of timestamps, from the timeline.
+    snapshots: &'t HashMap<MicrosystemId, Vec<(Timestamp, Microsystem)>>,
+}
+
+impl<'t> Replay<'t> {
+    fn new(base: &EcologicalContext, timeline: &'t ContextTimeline) -> Self {
+        Replay {
+            context: base.clone(),
+            dormant: timeline.registered.clone(),
+            snapshots: &timeline.snapshots,
+        }
+    }
+
+    fn apply(&mut self, timestamp: Timestamp, change: &ContextChange) {
+        match change {
+            ContextChange::AddMicrosystem(id, microsystem) => {
+                self.dormant.remove(id);
+                self.context
+                    .add_microsystem(id.clone(), microsystem.clone());
+            }
+            ContextChange::RemoveMicrosystem(id) => self.leave(id),
+            ContextChange::SetValue(path, value) => {
+                self.context.set(path, *value);
+            }
+            ContextChange::ReplaceExosystem(exosystem) => {
+                *self.context.exosystem_mut() = exosystem.clone();
+            }
+            ContextChange::ReplaceMacrosystem(macrosystem) => {
+                *self.context.macrosystem_mut() = macrosystem.clone();
+            }
+            ContextChange::SetTimeBudget(budget) => {
+                self.context.set_time_budget(budget.clone());
+            }
+            ContextChange::Transition { from, to } => {
+                self.leave(from);
+                if self.context.get_microsystem(to).is_none() {
+                    let snapshot = self.snapshots.get(to).and_then(|snapshots| {
+                        snapshots
+                            .iter()
+                            .rev()
+                            .find(|(ts, _)| *ts <= timestamp)
+                            .map(|(_, microsystem)| microsystem.clone())
+                    });
+                    let dormant = self.dormant.remove(to);
+                    if let Some(microsystem) = snapshot.or(dormant) {
+                        self.context.add_microsystem(to.clone(), microsystem);
+                    }
+                }
+            }
+            ContextChange::CompleteTransition { name, age } => {
+                self.context
+                    .chronosystem_mut()
+                    .complete_normative_transition(name, *age);
+            }
+            ContextChange::ApplyPolicy {
+                policy_area,
+                magnitude,
+            } => {
+                self.context.apply_policy(*policy_area, *magnitude);
+            }
+        }
+    }
+
+    fn leave(&mut self, id: &MicrosystemId) {
+        if let Some(microsystem) = self.context.remove_microsystem(id) {
+            self.dormant.insert(id.clone(), microsystem);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::context::{SocialContext, WorkContext};
+    use crate::enums::{MacrosystemPath, MicrosystemPath, WorkPath};
+
+    fn ts(month: u32) -> Timestamp {
+        Timestamp::from_ymd_hms(2024, month, 1, 0, 0, 0)
+    }
+
+    fn id(name: &str) -> MicrosystemId {
+        MicrosystemId::new(name).unwrap()
+    }
+
+    #[test]
+    fn changes_apply_from_their_timestamp() {
+        let mut timeline = ContextTimeline::new();
+        timeline.schedule(ts(6), ContextChange::RemoveMicrosystem(id("work")));
+        timeline.schedule(
+            ts(3),
+            ContextChange::AddMicrosystem(
+                id("work"),
+                Microsystem::new_work(WorkContext::default()),
+            ),
+        );
+
+        let base = EcologicalContext::default();
+        assert_eq!(timeline.context_at(&base, ts(2)).microsystem_count(), 0);
+        assert_eq!(timeline.context_at(&base, ts(3)).microsystem_count(), 1);
+        assert_eq!(timeline.context_at(&base, ts(6)).microsystem_count(), 0);
+        assert_eq!(timeline.len(), 2);
+    }
+
+    #[test]
+    fn segments_cover_interval_and_split_at_changes() {
+        let mut timeline = ContextTimeline::new();
+        let power_distance = ContextPath::Macrosystem(MacrosystemPath::PowerDistance);
+        timeline.schedule(ts(1), ContextChange::SetValue(power_distance.clone(), 0.2));
+        timeline.schedule(ts(4), ContextChange::SetValue(power_distance.clone(), 0.9));
+        timeline.schedule(ts(9), ContextChange::SetValue(power_distance.clone(), 0.1));
+
+        let base = EcologicalContext::default();
+        let segments = timeline.segments(&base, ts(2), ts(9));
+
+        assert_eq!(segments.len(), 2);
+        assert_eq!(segments[0].start(), ts(2));
+        assert_eq!(segments[0].end(), ts(4));
+        assert_eq!(segments[1].end(), ts(9));
+        assert!((segments[0].context().get(&power_distance).unwrap() - 0.2).abs() < 1e-9);
+        assert!((segments[1].context().get(&power_distance).unwrap() - 0.9).abs() < 1e-9);
+    }
+
+    #[test]
+    fn empty_timeline_is_single_segment() {
+        let base = EcologicalContext::default();
+        let segments = ContextTimeline::new().segments(&base, ts(1), ts(5));
+
+        assert_eq!(segments.len(), 1);
+        assert_eq!(segments[0].duration(), ts(5) - ts(1));
+        assert_eq!(segments[0].context(), &base);
+    }
+
+    #[test]
+    fn transition_swaps_microsystems_and_can_return() {
+        let mut base = EcologicalContext::default();
+        let office = WorkContext {
+            workload_stress: 0.9,
+            ..Default::default()
+        };
+        base.add_microsystem(id("office"), Microsystem::new_work(office));
+
+        let mut timeline = ContextTimeline::new();
+        timeline.register_microsystem(
+            id("club"),
+            Microsystem::new_social(SocialContext::default()),
+        );
+        timeline.schedule(
+            ts(2),
+            ContextChange::Transition {
+                from: id("office"),
+                to: id("club"),
+            },
+        );
+        timeline.schedule(
+            ts(5),
+            ContextChange::Transition {
+                from: id("club"),
+                to: id("office"),
+            },
+        );
+
+        let away = timeline.context_at(&base, ts(3));
+        assert!(away.get_microsystem(&id("office")).is_none());
+        assert!(away.get_microsystem(&id("club")).is_some());
+
+        let back = timeline.context_at(&base, ts(6));
+        let workload = ContextPath::Microsystem(
+            id("office"),
+            MicrosystemPath::Work(WorkPath::WorkloadStress),
+        );
+        assert!((back.get(&workload).unwrap() - 0.9).abs() < 1e-9);
+        assert!(back.get_microsystem(&id("club")).is_none());
+    }
+
+    #[test]
+    fn transition_to_unknown_microsystem_only_leaves() {
+        let mut base = EcologicalContext::default();
+        base.add_microsystem(
+            id("school"),
+            Microsystem::new_social(SocialContext::default()),
+        );
+
+        let mut timeline = ContextTimeline::new();
+        timeline.schedule(
+            ts(2),
+            ContextChange::Transition {
+                from: id("school"),
+                to: id("nowhere"),
+            },
+        );
+
+        assert!(timeline.context_at(&base, ts(3)).is_empty());
+    }
+
+    #[test]
+    fn transition_joins_the_snapshot_registered_by_then() {
+        let office = |workload_stress: f64| {
+            Microsystem::new_work(WorkContext {
+                workload_stress,
+                ..Default::default()
+            })
+        };
+        let mut base = EcologicalContext::default();
+        base.add_microsystem(
+            id("home"),
+            Microsystem::new_social(SocialContext::default()),
+        );
+
+        let mut timeline = ContextTimeline::new();
+        timeline.register_microsystem_at(id("office"), ts(6), office(0.9));
+        timeline.register_microsystem_at(id("office"), ts(1), office(0.2));
+        for (month, from, to) in [
+            (2, "home", "office"),
+            (4, "office", "home"),
+            (7, "home", "office"),
+        ] {
+            timeline.schedule(
+                ts(month),
+                ContextChange::Transition {
+                    from: id(from),
+                    to: id(to),
+                },
+            );
+        }
+
+        let workload = ContextPath::Microsystem(
+            id("office"),
+            MicrosystemPath::Work(WorkPath::WorkloadStress),
+        );
+        let first = timeline.context_at(&base, ts(3));
+        assert!((first.get(&workload).unwrap() - 0.2).abs() < 1e-9);
+        // Returning joins the office as registered later, not as it was left
+        let second = timeline.context_at(&base, ts(8));
+        assert!((second.get(&workload).unwrap() - 0.9).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,180 @@
+//! Candidate actions offered to the decision model.
+
+use crate::enums::TrustDomain;
+use crate::relationship::StakesLevel;
+use crate::types::EntityId;
+
+/// A candidate action described by what it offers and what it costs.
+///
+/// Attributes are consequences the entity anticipates, not outcomes. How
+/// much each one matters depends on the entity's state when deciding: a
+/// lonely entity values social contact more, a fatigued one minds effort
+/// more.
+///
+/// # Examples
+///
+/// ```
+/// use eventsim_rs::decision::ActionOption;
+/// use eventsim_rs::enums::TrustDomain;
+/// use eventsim_rs::relationship::StakesLevel;
+/// use eventsim_rs::types::EntityId;
+///
+/// let friend = EntityId::new("friend").unwrap();
+/// let confide = ActionOption::new("confide")
+///     .with_social(0.6)
+///     .with_counterpart(friend, TrustDomain::Disclosure, StakesLevel::Medium);
+///
+/// assert_eq!(confide.id(), "confide");
+/// assert!(confide.counterpart().is_some());
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct ActionOption {
+    id: String,
+    base_utility: f32,
+    valence: f32,
+    social: f32,
+    purpose: f32,
+    effort: f32,
+    risk: f32,
+    hostility: f32,
+    counterpart: Option<(EntityId, TrustDomain, StakesLevel)>,
+}
+
+impl ActionOption {
+    /// Creates an option with no attributes.
+    #[must_use]
+    pub fn new(id: impl Into<String>) -> Self {
+        ActionOption {
+            id: id.into(),
+            base_utility: 0.0,
+            valence: 0.0,
+            social: 0.0,
+            purpose: 0.0,
+            effort: 0.0,
+            risk: 0.0,
+            hostility: 0.0,
+            counterpart: None,
+        }
+    }
+
+    /// Sets a state-independent utility offset.
+    #[must_use]
+    pub fn with_base_utility(mut self, utility: f32) -> Self {
+        self.base_utility = utility;
+        self
+    }
+
+    /// Sets the expected change in mood (-1.0 to 1.0).
+    #[must_use]
+    pub fn with_valence(mut self, valence: f32) -> Self {
+        self.valence = valence.clamp(-1.0, 1.0);
+        self
+    }
+
+    /// Sets how much social connection the action offers (0.0 to 1.0).
+    #[must_use]
+    pub fn with_social(mut self, social: f32) -> Self {
+        self.social = social.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Sets how much sense of purpose the action offers (0.0 to 1.0).
+    #[must_use]
+    pub fn with_purpose(mut self, purpose: f32) -> Self {
+        self.purpose = purpose.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Sets the effort required (0.0 to 1.0).
+    #[must_use]
+    pub fn with_effort(mut self, effort: f32) -> Self {
+        self.effort = effort.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Sets the risk of harm to the entity (0.0 to 1.0).
+    #[must_use]
+    pub fn with_risk(mut self, risk: f32) -> Self {
+        self.risk = risk.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Sets how hostile or aggressive the action is (0.0 to 1.0).
+    #[must_use]
+    pub fn with_hostility(mut self, hostility: f32) -> Self {
+        self.hostility = hostility.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Sets the party the action makes the entity vulnerable to.
+    ///
+    /// The entity's trust decision toward the counterpart, in the given
+    /// domain and at the given stakes, makes the action more or less
+    /// attractive.
+    #[must_use]
+    pub fn with_counterpart(
+        mut self,
+        counterpart: EntityId,
+        domain: TrustDomain,
+        stakes: StakesLevel,
+    ) -> Self {
+        self.counterpart = Some((counterpart, domain, stakes));
+        self
+    }
+
+    /// Returns the option's identifier.
+    #[must_use]
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// Returns the state-independent utility offset.
+    #[must_use]
+    pub fn base_utility(&self) -> f32 {
+        self.base_utility
+    }
+
+    /// Returns the expected change in mood.
+    #[must_use]
+    pub fn valence(&self) -> f32 {
+        self.valence
+    }
+
+    /// Returns the social connection offered.
+    #[must_use]
+    pub fn social(&self) -> f32 {
+        self.social
+    }
+
+    /// Returns the sense of purpose offered.
+    #[must_use]
+    pub fn purpose(&self) -> f32 {
+        self.purpose
+    }
+
+    /// Returns the effort required.
+    #[must_use]
+    pub fn effort(&self) -> f32 {
+        self.effort
+    }
+
+    /// Returns the risk of harm.
+    #[must_use]
+    pub fn risk(&self) -> f32 {
+        self.risk
+    }
+
+    /// Returns the hostility of the action.
+    #[must_use]
+    pub fn hostility(&self) -> f32 {
+        self.hostility
+    }
+
+    /// Returns the counterpart, trust domain and stakes, if any.
+    #[must_use]
+    pub fn counterpart(&self) -> Option<(&EntityId, TrustDomain, StakesLevel)> {
+        self.counterpart
+            .as_ref()
+            .map(|(id, domain, stakes)| (id, *domain, *stakes))
+    }
+}
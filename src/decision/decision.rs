@@ -0,0 +1,421 @@
+//! Utility scoring, softmax choice probabilities and seeded sampling.
+
+use crate::decision::ActionOption;
+use crate::enums::TrustDomain;
+use crate::relationship::TrustDecision;
+use crate::state::IndividualState;
+use crate::types::EntityId;
+
+/// Default softmax temperature.
+///
+/// Utilities typically span about one unit, so 0.25 makes the best option
+/// clearly favoured without making choices deterministic.
+pub const DEFAULT_DECISION_TEMPERATURE: f32 = 0.25;
+
+/// Temperatures are clamped to at least this, which is effectively argmax.
+pub const MIN_DECISION_TEMPERATURE: f32 = 1e-3;
+
+/// Weight of expected mood change.
+const VALENCE_WEIGHT: f32 = 0.6;
+
+/// Weight of social connection, scaled by unmet belonging.
+const SOCIAL_WEIGHT: f32 = 0.8;
+
+/// Weight of purpose, scaled by how much purpose is lacking.
+const PURPOSE_WEIGHT: f32 = 0.5;
+
+/// Weight of effort cost, scaled by fatigue.
+const EFFORT_WEIGHT: f32 = 0.5;
+
+/// Weight of risk cost, scaled by risk aversion.
+const RISK_WEIGHT: f32 = 0.8;
+
+/// Weight of hostility, scaled by net hostile disposition.
+const HOSTILITY_WEIGHT: f32 = 0.6;
+
+/// Weight of trust toward the counterpart, relative to neutral willingness.
+const TRUST_WEIGHT: f32 = 1.0;
+
+/// Willingness at which trust neither attracts nor repels.
+const NEUTRAL_WILLINGNESS: f32 = 0.5;
+
+/// Error type for decisions requested through a simulation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DecisionError {
+    /// The deciding entity is not in the simulation.
+    UnknownEntity(EntityId),
+    /// The entity's model config does not enable behavioral decisions.
+    SubsystemInactive(EntityId),
+}
+
+impl std::fmt::Display for DecisionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DecisionError::UnknownEntity(id) => write!(f, "Unknown entity: {}", id),
+            DecisionError::SubsystemInactive(id) => {
+                write!(f, "Behavioral decisions are not enabled for {}", id)
+            }
+        }
+    }
+}
+
+impl std::error::Error for DecisionError {}
+
+/// Returns the utility of an option for an entity in the given state.
+///
+/// ```text
+/// utility = base
+///         + 0.6 * valence   * (1 + low mood)
+///         + 0.8 * social    * (loneliness + 1 - perceived caring) / 2
+///         + 0.5 * purpose   * (1 - purpose)
+///         - 0.5 * effort    * (0.5 + fatigue)
+///         - 0.8 * risk      * risk aversion
+///         + 0.6 * hostility * (aggression + grievance - empathy - impulse control) / 2
+///         + 1.0 * (willingness toward counterpart - 0.5)
+/// ```
+///
+/// Low mood is `max(0, -valence)`, so a sad entity values mood repair more.
+/// Risk aversion rises with stress and impulse control and falls with
+/// dominance. `trust` is the entity's trust decision toward the option's
+/// counterpart; it is ignored for options without one.
+#[must_use]
+pub fn utility(
+    option: &ActionOption,
+    state: &IndividualState,
+    trust: Option<&TrustDecision>,
+) -> f32 {
+    let mood = state.mood();
+    let needs = state.needs();
+    let social = state.social_cognition();
+    let disposition = state.disposition();
+
+    let low_mood = (-mood.valence_effective()).max(0.0);
+    let unmet_belonging = (social.loneliness_effective() + 1.0
+        - social.perceived_reciprocal_caring_effective())
+        / 2.0;
+    let lacking_purpose = 1.0 - needs.purpose_effective();
+    let tiredness = 0.5 + needs.fatigue_effective();
+    let risk_aversion =
+        (0.5 + 0.5 * needs.stress_effective() + 0.5 * disposition.impulse_control_effective()
+            - 0.25 * mood.dominance_effective())
+        .max(0.1);
+    let hostile_disposition = (disposition.aggression_effective()
+        + disposition.grievance_effective()
+        - disposition.empathy_effective()
+        - disposition.impulse_control_effective())
+        / 2.0;
+
+    let mut total = option.base_utility()
+        + VALENCE_WEIGHT * option.valence() * (1.0 + low_mood)
+        + SOCIAL_WEIGHT * option.social() * unmet_belonging
+        + PURPOSE_WEIGHT * option.purpose() * lacking_purpose
+        - EFFORT_WEIGHT * option.effort() * tiredness
+        - RISK_WEIGHT * option.risk() * risk_aversion
+        + HOSTILITY_WEIGHT * option.hostility() * hostile_disposition;
+
+    if let (Some((_, domain, _)), Some(trust)) = (option.counterpart(), trust) {
+        let willingness = match domain {
+            TrustDomain::Task => trust.task_willingness(),
+            TrustDomain::Support => trust.support_willingness(),
+            TrustDomain::Disclosure => trust.disclosure_willingness(),
+        };
+        total += TRUST_WEIGHT * (willingness - NEUTRAL_WILLINGNESS);
+    }
+    total
+}
+
+/// Ranks options by utility and converts utilities to choice probabilities.
+///
+/// Probabilities follow a softmax at `temperature`: low temperatures make
+/// the entity reliably pick its best option, high temperatures make choices
+/// closer to uniform. `trust` supplies the trust decision toward an option's
+/// counterpart.
+///
+/// # Examples
+///
+/// ```
+/// use eventsim_rs::decision::{decide, ActionOption, DEFAULT_DECISION_TEMPERATURE};
+/// use eventsim_rs::state::IndividualState;
+///
+/// let state = IndividualState::new();
+/// let options = [
+///     ActionOption::new("rest").with_valence(0.2),
+///     ActionOption::new("pick_fight").with_hostility(1.0).with_risk(0.6),
+/// ];
+///
+/// let decision = decide(&state, &options, |_| None, DEFAULT_DECISION_TEMPERATURE);
+/// assert_eq!(decision.best().unwrap().option_id(), "rest");
+/// ```
+#[must_use]
+pub fn decide<F>(
+    state: &IndividualState,
+    options: &[ActionOption],
+    mut trust: F,
+    temperature: f32,
+) -> Decision
+where
+    F: FnMut(&ActionOption) -> Option<TrustDecision>,
+{
+    let temperature = temperature.max(MIN_DECISION_TEMPERATURE);
+    let utilities: Vec<f32> = options
+        .iter()
+        .map(|option| utility(option, state, trust(option).as_ref()))
+        .collect();
+
+    let max_utility = utilities.iter().copied().fold(f32::NEG_INFINITY, f32::max);
+    let weights: Vec<f64> = utilities
+        .iter()
+        .map(|u| f64::from((u - max_utility) / temperature).exp())
+        .collect();
+    let total: f64 = weights.iter().sum();
+
+    let mut choices: Vec<RankedChoice> = options
+        .iter()
+        .zip(utilities)
+        .zip(weights)
+        .enumerate()
+        .map(|(index, ((option, utility), weight))| RankedChoice {
+            index,
+            option_id: option.id().to_string(),
+            utility,
+            probability: (weight / total) as f32,
+        })
+        .collect();
+    choices.sort_by(|a, b| b.utility.total_cmp(&a.utility).then(a.index.cmp(&b.index)));
+
+    Decision {
+        choices,
+        temperature,
+    }
+}
+
+/// One option's place in a decision.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RankedChoice {
+    index: usize,
+    option_id: String,
+    utility: f32,
+    probability: f32,
+}
+
+impl RankedChoice {
+    /// Returns the option's position in the slice passed to `decide`.
+    #[must_use]
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Returns the option's identifier.
+    #[must_use]
+    pub fn option_id(&self) -> &str {
+        &self.option_id
+    }
+
+    /// Returns the option's utility.
+    #[must_use]
+    pub fn utility(&self) -> f32 {
+        self.utility
+    }
+
+    /// Returns the probability of choosing this option.
+    #[must_use]
+    pub fn probability(&self) -> f32 {
+        self.probability
+    }
+}
+
+/// Options ranked by utility with their choice probabilities.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Decision {
+    choices: Vec<RankedChoice>,
+    temperature: f32,
+}
+
+impl Decision {
+    /// Returns all choices, best first.
+    #[must_use]
+    pub fn choices(&self) -> &[RankedChoice] {
+        &self.choices
+    }
+
+    /// Returns the highest-utility choice, or `None` if there were no options.
+    #[must_use]
+    pub fn best(&self) -> Option<&RankedChoice> {
+        self.choices.first()
+    }
+
+    /// Returns the softmax temperature used.
+    #[must_use]
+    pub fn temperature(&self) -> f32 {
+        self.temperature
+    }
+
+    /// Samples a choice according to the probabilities.
+    ///
+    /// The same seed always yields the same choice, so runs can be
+    /// reproduced. Returns `None` if there were no options.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use eventsim_rs::decision::{decide, ActionOption};
+    /// use eventsim_rs::state::IndividualState;
+    ///
+    /// let options = [ActionOption::new("a"), ActionOption::new("b")];
+    /// let decision = decide(&IndividualState::new(), &options, |_| None, 1.0);
+    ///
+    /// assert_eq!(decision.sample(7), decision.sample(7));
+    /// ```
+    #[must_use]
+    pub fn sample(&self, seed: u64) -> Option<&RankedChoice> {
+        self.sample_with(&mut DecisionRng::new(seed))
+    }
+
+    /// Samples a choice using the given generator.
+    ///
+    /// Use this to draw a sequence of decisions from one seeded stream.
+    pub fn sample_with(&self, rng: &mut DecisionRng) -> Option<&RankedChoice> {
+        let draw = rng.next_f32();
+        let mut cumulative = 0.0;
+        for choice in &self.choices {
+            cumulative += choice.probability;
+            if draw < cumulative {
+                return Some(choice);
+            }
+        }
+        // Rounding can leave the cumulative sum just under 1.0
+        self.choices.last()
+    }
+}
+
+/// Small deterministic random number generator for sampling decisions.
+///
+/// SplitMix64: fast, well distributed, and reproducible across platforms.
+/// Not suitable for cryptography.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DecisionRng {
+    state: u64,
+}
+
+impl DecisionRng {
+    /// Creates a generator from a seed.
+    #[must_use]
+    pub fn new(seed: u64) -> Self {
+        DecisionRng { state: seed }
+    }
+
+    /// Returns the next 64 random bits.
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Returns a uniform value in [0, 1).
+    pub fn next_f32(&mut self) -> f32 {
+        // Top 24 bits give every representable step in [0, 1)
+        (self.next_u64() >> 40) as f32 / (1u64 << 24) as f32
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::relationship::StakesLevel;
+
+    fn lonely_state() -> IndividualState {
+        let mut state = IndividualState::new();
+        state.social_cognition_mut().loneliness_mut().set_base(0.9);
+        state
+    }
+
+    #[test]
+    fn probabilities_sum_to_one_and_follow_utility() {
+        let options = [
+            ActionOption::new("low").with_base_utility(0.0),
+            ActionOption::new("high").with_base_utility(1.0),
+            ActionOption::new("mid").with_base_utility(0.5),
+        ];
+        let decision = decide(&IndividualState::new(), &options, |_| None, 0.5);
+
+        let total: f32 = decision
+            .choices()
+            .iter()
+            .map(RankedChoice::probability)
+            .sum();
+        assert!((total - 1.0).abs() < 1e-5);
+        let ids: Vec<_> = decision
+            .choices()
+            .iter()
+            .map(RankedChoice::option_id)
+            .collect();
+        assert_eq!(ids, ["high", "mid", "low"]);
+        assert_eq!(decision.best().unwrap().index(), 1);
+    }
+
+    #[test]
+    fn temperature_controls_determinism() {
+        let options = [
+            ActionOption::new("a").with_base_utility(0.0),
+            ActionOption::new("b").with_base_utility(0.2),
+        ];
+        let state = IndividualState::new();
+        let cold = decide(&state, &options, |_| None, 0.0);
+        let hot = decide(&state, &options, |_| None, 100.0);
+
+        assert!(cold.best().unwrap().probability() > 0.999);
+        assert!((hot.best().unwrap().probability() - 0.5).abs() < 0.01);
+        assert!((cold.temperature() - MIN_DECISION_TEMPERATURE).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn loneliness_raises_value_of_social_options() {
+        let socialize = ActionOption::new("socialize").with_social(1.0);
+        let content = utility(&socialize, &IndividualState::new(), None);
+        let lonely = utility(&socialize, &lonely_state(), None);
+        assert!(lonely > content);
+    }
+
+    #[test]
+    fn trust_toward_counterpart_shapes_utility() {
+        let friend = EntityId::new("friend").unwrap();
+        let confide = ActionOption::new("confide").with_counterpart(
+            friend,
+            TrustDomain::Disclosure,
+            StakesLevel::Medium,
+        );
+        let state = IndividualState::new();
+        let trusted = TrustDecision::new(0.5, 0.5, 0.9, 0.5, 0.5);
+        let distrusted = TrustDecision::new(0.5, 0.5, 0.1, 0.5, 0.5);
+
+        assert!(utility(&confide, &state, Some(&trusted)) > 0.0);
+        assert!(utility(&confide, &state, Some(&distrusted)) < 0.0);
+        assert!(utility(&confide, &state, None).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn seeded_sampling_matches_probabilities() {
+        let options = [
+            ActionOption::new("a").with_base_utility(0.0),
+            ActionOption::new("b").with_base_utility(0.5),
+        ];
+        let decision = decide(&IndividualState::new(), &options, |_| None, 0.5);
+        let expected_b = decision.best().unwrap().probability();
+
+        let mut rng = DecisionRng::new(42);
+        let draws = 10_000;
+        let picked_b = (0..draws)
+            .filter(|_| decision.sample_with(&mut rng).unwrap().option_id() == "b")
+            .count();
+        assert!((picked_b as f32 / draws as f32 - expected_b).abs() < 0.02);
+    }
+
+    #[test]
+    fn empty_options_yield_empty_decision() {
+        let decision = decide(&IndividualState::new(), &[], |_| None, 1.0);
+        assert!(decision.best().is_none());
+        assert!(decision.sample(1).is_none());
+    }
+}
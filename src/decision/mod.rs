@@ -0,0 +1,47 @@
+//! Behavioral decision subsystem.
+//!
+//! Given a set of candidate actions, the decision model scores each one
+//! against the entity's computed state and converts the scores into choice
+//! probabilities, so callers (such as NPC controllers) can ask what an
+//! entity is likely to do.
+//!
+//! # Utility
+//!
+//! Options describe what they offer (mood change, social connection,
+//! purpose) and what they cost (effort, risk), plus how hostile they are
+//! and who they make the entity vulnerable to. State decides how much each
+//! attribute matters: loneliness raises the value of connection, fatigue
+//! raises the cost of effort, stress raises risk aversion, and trust toward
+//! the counterpart makes trusting actions more or less attractive.
+//!
+//! # Choice
+//!
+//! Utilities become probabilities through a softmax with a temperature.
+//! Choices can be sampled from a seeded generator for reproducible runs.
+//!
+//! # Examples
+//!
+//! ```
+//! use eventsim_rs::decision::{decide, ActionOption, DEFAULT_DECISION_TEMPERATURE};
+//! use eventsim_rs::state::IndividualState;
+//!
+//! let state = IndividualState::new();
+//! let options = [
+//!     ActionOption::new("work_late").with_purpose(0.6).with_effort(0.8),
+//!     ActionOption::new("call_friend").with_social(0.8).with_valence(0.3),
+//! ];
+//!
+//! let decision = decide(&state, &options, |_| None, DEFAULT_DECISION_TEMPERATURE);
+//! let chosen = decision.sample(2024).unwrap();
+//! assert!(chosen.probability() > 0.0);
+//! ```
+
+mod action_option;
+#[allow(clippy::module_inception)]
+mod decision;
+
+pub use action_option::ActionOption;
+pub use decision::{
+    decide, utility, Decision, DecisionError, DecisionRng, RankedChoice,
+    DEFAULT_DECISION_TEMPERATURE, MIN_DECISION_TEMPERATURE,
+};
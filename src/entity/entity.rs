@@ -9,7 +9,7 @@
 //! - Relationship slots for connections to other entities
 //! - Memory storage for episodic memories
 
-use crate::context::EcologicalContext;
+use crate::context::{ContextTimeline, EcologicalContext};
 use crate::entity::{AffectiveState, PhysiologicalState};
 use crate::enums::{
     ContextPath, DispositionPath, HexacoPath, LifeStage, MentalHealthPath, MoodPath, NeedsPath,
@@ -104,6 +104,11 @@ pub struct Entity {
     /// context values that influence individual development.
     context: EcologicalContext,
 
+    /// Changes to the ecological context scheduled at timestamps.
+    ///
+    /// `context` is the environment before the first scheduled change.
+    context_timeline: ContextTimeline,
+
     /// Pending alerts generated by threshold checks and spiral detection.
     ///
     /// Alerts accumulate during processing and should be consumed/cleared
@@ -137,6 +142,7 @@ impl Entity {
             relationship_slots: Default::default(),
             memories: MemoryLayers::new(),
             context: EcologicalContext::default(),
+            context_timeline: ContextTimeline::new(),
             pending_alerts: Vec::new(),
             config,
         }
@@ -165,6 +171,7 @@ impl Entity {
             relationship_slots: Default::default(),
             memories: MemoryLayers::new(),
             context,
+            context_timeline: ContextTimeline::new(),
             pending_alerts: Vec::new(),
             config,
         }
@@ -664,6 +671,41 @@ impl Entity {
         &mut self.context
     }
 
+    /// Returns the entity's scheduled context changes.
+    ///
+    /// `context()` is the environment before the first scheduled change;
+    /// `state_at` applies context effects piecewise as changes take effect.
+    #[must_use]
+    pub fn context_timeline(&self) -> &ContextTimeline {
+        &self.context_timeline
+    }
+
+    /// Returns a mutable reference to the entity's scheduled context changes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use eventsim_rs::context::{ContextChange, Microsystem, WorkContext};
+    /// use eventsim_rs::entity::EntityBuilder;
+    /// use eventsim_rs::enums::Species;
+    /// use eventsim_rs::types::{MicrosystemId, Timestamp};
+    ///
+    /// let mut entity = EntityBuilder::new()
+    ///     .species(Species::Human)
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// let work_id = MicrosystemId::new("work_primary").unwrap();
+    /// entity.context_timeline_mut().schedule(
+    ///     Timestamp::from_ymd_hms(2024, 9, 1, 0, 0, 0),
+    ///     ContextChange::AddMicrosystem(work_id, Microsystem::new_work(WorkContext::default())),
+    /// );
+    /// assert_eq!(entity.context_timeline().len(), 1);
+    /// ```
+    pub fn context_timeline_mut(&mut self) -> &mut ContextTimeline {
+        &mut self.context_timeline
+    }
+
     // --- Configuration ---
 
     /// Returns a reference to the entity's model configuration.
@@ -1484,7 +1526,7 @@ mod tests {
         let stress = entity
             .get_effective(StatePath::Needs(NeedsPath::Stress))
             .unwrap();
-        assert!(stress >= 0.0 && stress <= 1.0);
+        assert!((0.0..=1.0).contains(&stress));
     }
 
     #[test]
@@ -1497,7 +1539,7 @@ mod tests {
         let fatigue = entity
             .get_effective(StatePath::Needs(NeedsPath::Fatigue))
             .unwrap();
-        assert!(fatigue >= 0.0 && fatigue <= 1.0);
+        assert!((0.0..=1.0).contains(&fatigue));
     }
 
     #[test]
@@ -1510,7 +1552,7 @@ mod tests {
         let depression = entity
             .get_effective(StatePath::MentalHealth(MentalHealthPath::Depression))
             .unwrap();
-        assert!(depression >= 0.0 && depression <= 1.0);
+        assert!((0.0..=1.0).contains(&depression));
     }
 
     #[test]
@@ -1523,7 +1565,7 @@ mod tests {
         let openness = entity
             .get_effective(StatePath::Hexaco(HexacoPath::Openness))
             .unwrap();
-        assert!(openness >= -1.0 && openness <= 1.0);
+        assert!((-1.0..=1.0).contains(&openness));
     }
 
     #[test]
@@ -1536,7 +1578,7 @@ mod tests {
         let empathy = entity
             .get_effective(StatePath::Disposition(DispositionPath::Empathy))
             .unwrap();
-        assert!(empathy >= 0.0 && empathy <= 1.0);
+        assert!((0.0..=1.0).contains(&empathy));
     }
 
     #[test]
@@ -1551,7 +1593,7 @@ mod tests {
                 PersonCharacteristicsPath::CognitiveAbility,
             ))
             .unwrap();
-        assert!(cognitive >= 0.0 && cognitive <= 1.0);
+        assert!((0.0..=1.0).contains(&cognitive));
     }
 
     #[test]
@@ -1609,7 +1651,7 @@ mod tests {
                 MentalHealthPath::ThwartedBelongingness,
             ))
             .unwrap();
-        assert!(tb >= 0.0 && tb <= 1.0);
+        assert!((0.0..=1.0).contains(&tb));
     }
 
     #[test]
@@ -1782,7 +1824,7 @@ mod tests {
 
         for path in NeedsPath::all() {
             let effective = entity.get_effective(StatePath::Needs(path)).unwrap();
-            assert!(effective >= 0.0 && effective <= 1.0);
+            assert!((0.0..=1.0).contains(&effective));
 
             let base = entity.get_base(StatePath::Needs(path));
             assert!(base.is_some());
@@ -1801,7 +1843,7 @@ mod tests {
 
         for path in DispositionPath::all() {
             let effective = entity.get_effective(StatePath::Disposition(path)).unwrap();
-            assert!(effective >= 0.0 && effective <= 1.0);
+            assert!((0.0..=1.0).contains(&effective));
 
             let base = entity.get_base(StatePath::Disposition(path));
             assert!(base.is_some());
@@ -1820,7 +1862,7 @@ mod tests {
 
         for path in MentalHealthPath::all() {
             let effective = entity.get_effective(StatePath::MentalHealth(path)).unwrap();
-            assert!(effective >= 0.0 && effective <= 1.0);
+            assert!((0.0..=1.0).contains(&effective));
 
             if path.is_computed() {
                 assert!(entity.get_base(StatePath::MentalHealth(path)).is_none());
@@ -1843,7 +1885,7 @@ mod tests {
             let effective = entity
                 .get_effective(StatePath::PersonCharacteristics(path))
                 .unwrap();
-            assert!(effective >= 0.0 && effective <= 1.0);
+            assert!((0.0..=1.0).contains(&effective));
 
             if path.is_composite() {
                 assert!(entity
@@ -1886,7 +1928,7 @@ mod tests {
 
         for path in HexacoPath::all() {
             let effective = entity.get_effective(StatePath::Hexaco(path)).unwrap();
-            assert!(effective >= -1.0 && effective <= 1.0);
+            assert!((-1.0..=1.0).contains(&effective));
 
             let base = entity.get_base(StatePath::Hexaco(path));
             assert!(base.is_some());
@@ -1906,7 +1948,7 @@ mod tests {
 
         for path in MoodPath::all() {
             let effective = entity.get_effective(StatePath::Mood(path)).unwrap();
-            assert!(effective >= -1.0 && effective <= 1.0);
+            assert!((-1.0..=1.0).contains(&effective));
 
             let base = entity.get_base(StatePath::Mood(path));
             assert!(base.is_some());
@@ -1952,7 +1994,7 @@ mod tests {
             neuroticism,
             honesty,
         ] {
-            assert!(v >= -1.0 && v <= 1.0);
+            assert!((-1.0..=1.0).contains(&v));
         }
 
         // Test base values
@@ -2028,10 +2070,10 @@ mod tests {
 
         for path in paths {
             let effective = entity.get_effective(StatePath::Needs(path)).unwrap();
-            assert!(effective >= 0.0 && effective <= 1.0);
+            assert!((0.0..=1.0).contains(&effective));
 
             let base = entity.get_base(StatePath::Needs(path)).unwrap();
-            assert!(base >= 0.0 && base <= 1.0);
+            assert!((0.0..=1.0).contains(&base));
 
             let delta = entity.get_delta(StatePath::Needs(path)).unwrap();
             // Use larger epsilon due to f32 -> f64 conversion precision
@@ -2058,10 +2100,10 @@ mod tests {
             let effective = entity
                 .get_effective(StatePath::SocialCognition(path))
                 .unwrap();
-            assert!(effective >= 0.0 && effective <= 1.0);
+            assert!((0.0..=1.0).contains(&effective));
 
             let base = entity.get_base(StatePath::SocialCognition(path)).unwrap();
-            assert!(base >= 0.0 && base <= 1.0);
+            assert!((0.0..=1.0).contains(&base));
 
             let delta = entity.get_delta(StatePath::SocialCognition(path)).unwrap();
             assert!(delta.abs() < 1e-6);
@@ -2235,7 +2277,7 @@ mod tests {
 
         for path in stored_paths {
             let effective = entity.get_effective(StatePath::MentalHealth(path)).unwrap();
-            assert!(effective >= 0.0 && effective <= 1.0);
+            assert!((0.0..=1.0).contains(&effective));
 
             let base = entity.get_base(StatePath::MentalHealth(path));
             assert!(base.is_some());
@@ -2262,7 +2304,7 @@ mod tests {
 
         for path in computed_paths {
             let effective = entity.get_effective(StatePath::MentalHealth(path)).unwrap();
-            assert!(effective >= 0.0 && effective <= 1.0);
+            assert!((0.0..=1.0).contains(&effective));
 
             let base = entity.get_base(StatePath::MentalHealth(path));
             assert!(base.is_none());
@@ -2291,10 +2333,10 @@ mod tests {
 
         for path in paths {
             let effective = entity.get_effective(StatePath::Disposition(path)).unwrap();
-            assert!(effective >= 0.0 && effective <= 1.0);
+            assert!((0.0..=1.0).contains(&effective));
 
             let base = entity.get_base(StatePath::Disposition(path)).unwrap();
-            assert!(base >= 0.0 && base <= 1.0);
+            assert!((0.0..=1.0).contains(&base));
 
             let delta = entity.get_delta(StatePath::Disposition(path)).unwrap();
             // Check delta is valid
@@ -2325,7 +2367,7 @@ mod tests {
             let effective = entity
                 .get_effective(StatePath::PersonCharacteristics(path))
                 .unwrap();
-            assert!(effective >= 0.0 && effective <= 1.0);
+            assert!((0.0..=1.0).contains(&effective));
 
             let base = entity.get_base(StatePath::PersonCharacteristics(path));
             assert!(base.is_some());
@@ -2352,7 +2394,7 @@ mod tests {
             let effective = entity
                 .get_effective(StatePath::PersonCharacteristics(path))
                 .unwrap();
-            assert!(effective >= 0.0 && effective <= 1.0);
+            assert!((0.0..=1.0).contains(&effective));
 
             let base = entity.get_base(StatePath::PersonCharacteristics(path));
             assert!(base.is_none());
@@ -2819,8 +2861,10 @@ mod tests {
 
         // Add high-stress work context
         let work_id = MicrosystemId::new("work").unwrap();
-        let mut work = WorkContext::default();
-        work.workload_stress = 0.9;
+        let work = WorkContext {
+            workload_stress: 0.9,
+            ..Default::default()
+        };
         entity
             .context_mut()
             .add_microsystem(work_id, Microsystem::new_work(work));
@@ -2852,8 +2896,10 @@ mod tests {
 
         // Add social context
         let social_id = MicrosystemId::new("social").unwrap();
-        let mut social = SocialContext::default();
-        social.warmth = 0.5;
+        let social = SocialContext {
+            warmth: 0.5,
+            ..Default::default()
+        };
         entity
             .context_mut()
             .add_microsystem(social_id.clone(), Microsystem::new_social(social));
@@ -2899,14 +2945,18 @@ mod tests {
         let work_id = MicrosystemId::new("work").unwrap();
         let social_id = MicrosystemId::new("social").unwrap();
 
-        let mut work = WorkContext::default();
-        work.workload_stress = 0.8;
+        let work = WorkContext {
+            workload_stress: 0.8,
+            ..Default::default()
+        };
         entity
             .context_mut()
             .add_microsystem(work_id, Microsystem::new_work(work));
 
-        let mut social = SocialContext::default();
-        social.warmth = 0.5;
+        let social = SocialContext {
+            warmth: 0.5,
+            ..Default::default()
+        };
         entity
             .context_mut()
             .add_microsystem(social_id.clone(), Microsystem::new_social(social));
@@ -3004,8 +3054,10 @@ mod tests {
 
         // Add low-interaction work context (below thresholds)
         let work_id = MicrosystemId::new("work").unwrap();
-        let mut work = WorkContext::default();
-        work.workload_stress = 0.9; // High stress
+        let mut work = WorkContext {
+            workload_stress: 0.9, // High stress
+            ..Default::default()
+        };
         work.interaction_profile.interaction_frequency = 0.2; // Low frequency (below threshold)
         work.interaction_profile.interaction_complexity = 0.2; // Low complexity (below threshold)
         entity
@@ -3037,8 +3089,10 @@ mod tests {
         // Use default thresholds (0.3)
         // Add high-interaction work context (above thresholds)
         let work_id = MicrosystemId::new("work").unwrap();
-        let mut work = WorkContext::default();
-        work.workload_stress = 0.9; // High stress
+        let mut work = WorkContext {
+            workload_stress: 0.9, // High stress
+            ..Default::default()
+        };
         work.interaction_profile.interaction_frequency = 0.8; // High frequency (above threshold)
         work.interaction_profile.interaction_complexity = 0.8; // High complexity (above threshold)
         entity
@@ -3072,8 +3126,10 @@ mod tests {
             .unwrap();
 
         let social_id = MicrosystemId::new("social").unwrap();
-        let mut social = SocialContext::default();
-        social.warmth = 0.1;
+        let mut social = SocialContext {
+            warmth: 0.1,
+            ..Default::default()
+        };
         social.interaction_profile.interaction_frequency = 0.8;
         social.interaction_profile.interaction_complexity = 0.8;
 
@@ -3124,10 +3180,12 @@ mod tests {
             .unwrap();
 
         let social_id = MicrosystemId::new("social").unwrap();
-        let mut social = SocialContext::default();
-        social.group_standing = 1.0;
-        social.warmth = 1.0;
-        social.hostility = 0.0;
+        let mut social = SocialContext {
+            group_standing: 1.0,
+            warmth: 1.0,
+            hostility: 0.0,
+            ..Default::default()
+        };
         social.interaction_profile.interaction_frequency = 0.7;
         social.interaction_profile.interaction_complexity = 0.7;
         entity
@@ -3180,8 +3238,10 @@ mod tests {
 
         // Set up identical stressful work contexts
         let work_id = MicrosystemId::new("work").unwrap();
-        let mut work = WorkContext::default();
-        work.workload_stress = 0.8;
+        let mut work = WorkContext {
+            workload_stress: 0.8,
+            ..Default::default()
+        };
         work.interaction_profile.interaction_frequency = 0.7;
         work.interaction_profile.interaction_complexity = 0.7;
 
@@ -3240,8 +3300,10 @@ mod tests {
 
         // Set up identical social contexts
         let social_id = MicrosystemId::new("social").unwrap();
-        let mut social = SocialContext::default();
-        social.warmth = 0.5;
+        let mut social = SocialContext {
+            warmth: 0.5,
+            ..Default::default()
+        };
         social.interaction_profile.interaction_frequency = 0.7;
         social.interaction_profile.interaction_complexity = 0.7;
 
@@ -116,6 +116,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::clone_on_copy)]
     fn clone_and_copy() {
         let s1 = AlertSeverity::Critical;
         let s2 = s1; // Copy
@@ -438,6 +438,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::clone_on_copy)]
     fn spiral_type_clone_copy() {
         let s1 = SpiralType::Stress;
         let s2 = s1; // Copy
@@ -640,6 +641,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::clone_on_copy)]
     fn its_alert_copy_clone() {
         let a1 = ItsAlert::SingleFactorTb;
         let a2 = a1; // Copy
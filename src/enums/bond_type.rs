@@ -104,6 +104,29 @@ impl BondType {
         )
     }
 
+    /// Returns the relative rate at which this bond fades without contact.
+    ///
+    /// 1.0 is a typical friendship. Family bonds are the most durable and
+    /// role-based bonds such as colleagues fade fastest once contact stops.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use eventsim_rs::enums::BondType;
+    ///
+    /// assert!(BondType::Sibling.neglect_rate() < BondType::Colleague.neglect_rate());
+    /// ```
+    #[must_use]
+    pub const fn neglect_rate(&self) -> f32 {
+        match self {
+            BondType::Family | BondType::Parent | BondType::Child | BondType::Sibling => 0.3,
+            BondType::Romantic => 0.8,
+            BondType::Friend | BondType::Rival => 1.0,
+            BondType::Peer | BondType::Mentor | BondType::Mentee => 1.2,
+            BondType::Colleague | BondType::Authority | BondType::Subordinate => 1.5,
+        }
+    }
+
     /// Returns the reciprocal bond type, if any.
     ///
     /// For asymmetric bonds, returns what the other entity would have.
@@ -247,6 +270,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::clone_on_copy)]
     fn copy_and_clone() {
         let original = BondType::Parent;
         let copied = original;
@@ -109,6 +109,10 @@ impl From<&EventType> for DevelopmentalCategory {
             EventType::Rejection => DevelopmentalCategory::Attachment,
             EventType::SocialIsolation => DevelopmentalCategory::Attachment,
             EventType::GroupExclusion => DevelopmentalCategory::Attachment,
+            // Trust repair rebuilds broken attachment
+            EventType::Apology => DevelopmentalCategory::Attachment,
+            EventType::Restitution => DevelopmentalCategory::Attachment,
+            EventType::Forgiveness => DevelopmentalCategory::Attachment,
 
             // Industry category - competence
             EventType::Achievement => DevelopmentalCategory::Industry,
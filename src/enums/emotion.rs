@@ -277,6 +277,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::clone_on_copy)]
     fn clone_and_copy() {
         let e1 = Emotion::Relaxed;
         let e2 = e1; // Copy
@@ -122,11 +122,25 @@ pub enum EventPayload {
 
     // Environmental events
     /// Institutional/policy change.
+    ///
+    /// `magnitude` and `jurisdiction` default when deserializing, but code
+    /// that builds this variant directly must set them; `0.0` and
+    /// `PolicyJurisdiction::Target` keep the event from changing anyone's
+    /// institutions.
     PolicyChange {
         /// Area affected by policy.
         policy_area: PolicyArea,
         /// How favorable the change is (-1.0 to 1.0).
         favorability: f64,
+        /// Signed change to the institutions in the policy area (-1.0 to 1.0).
+        ///
+        /// Positive values expand them, negative values cut them back, and
+        /// zero leaves them unchanged.
+        #[serde(default)]
+        magnitude: f64,
+        /// Entities whose institutions the policy changes.
+        #[serde(default)]
+        jurisdiction: PolicyJurisdiction,
     },
     /// Movement between microsystems.
     ContextTransition {
@@ -301,6 +315,30 @@ impl PolicyArea {
     }
 }
 
+/// Entities whose institutions a policy change reaches.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum PolicyJurisdiction {
+    /// Only the event's target.
+    #[default]
+    Target,
+    /// The listed entities, whether or not the target is among them.
+    Entities(Vec<EntityId>),
+    /// Every entity in the simulation.
+    Global,
+}
+
+impl PolicyJurisdiction {
+    /// Returns true if a policy targeting `target` reaches `entity`.
+    #[must_use]
+    pub fn covers(&self, target: Option<&EntityId>, entity: &EntityId) -> bool {
+        match self {
+            PolicyJurisdiction::Target => target == Some(entity),
+            PolicyJurisdiction::Entities(entities) => entities.contains(entity),
+            PolicyJurisdiction::Global => true,
+        }
+    }
+}
+
 /// Type of historical/chronosystem event.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum HistoricalEventType {
@@ -747,6 +785,8 @@ mod tests {
         let _ = EventPayload::PolicyChange {
             policy_area: PolicyArea::Healthcare,
             favorability: 0.5,
+            magnitude: 0.2,
+            jurisdiction: PolicyJurisdiction::Target,
         };
         let from = MicrosystemId::new("home").unwrap();
         let to = MicrosystemId::new("work").unwrap();
@@ -899,4 +939,24 @@ mod tests {
         let cloned = payload.clone();
         assert_eq!(payload, cloned);
     }
+
+    #[test]
+    fn policy_change_without_jurisdiction_reaches_only_its_target() {
+        let json = r#"{"PolicyChange":{"policy_area":"Healthcare","favorability":0.5}}"#;
+        let payload: EventPayload = serde_json::from_str(json).unwrap();
+        let EventPayload::PolicyChange { jurisdiction, .. } = payload else {
+            panic!("expected a policy change");
+        };
+        assert_eq!(jurisdiction, PolicyJurisdiction::Target);
+
+        let target = EntityId::new("target").unwrap();
+        let other = EntityId::new("other").unwrap();
+        assert!(jurisdiction.covers(Some(&target), &target));
+        assert!(!jurisdiction.covers(Some(&target), &other));
+        assert!(!jurisdiction.covers(None, &target));
+        assert!(PolicyJurisdiction::Global.covers(None, &other));
+        let listed = PolicyJurisdiction::Entities(vec![other.clone()]);
+        assert!(listed.covers(Some(&target), &other));
+        assert!(!listed.covers(Some(&target), &target));
+    }
 }
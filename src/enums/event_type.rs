@@ -39,6 +39,14 @@ pub enum EventType {
     /// Physical aggression.
     Violence,
 
+    // Trust repair (source is the transgressor, target the wronged party)
+    /// Acknowledgement of a wrong and expression of remorse.
+    Apology,
+    /// Concrete amends for a wrong.
+    Restitution,
+    /// The target lets go of a grievance against the source.
+    Forgiveness,
+
     // Control/Power (Dominance dimension)
     /// Loss of control/status (reduces dominance).
     Humiliation,
@@ -164,6 +172,9 @@ impl EventType {
             EventType::Betrayal => EventCategory::Social,
             EventType::Support => EventCategory::Social,
             EventType::Conflict => EventCategory::Social,
+            EventType::Apology => EventCategory::Social,
+            EventType::Restitution => EventCategory::Social,
+            EventType::Forgiveness => EventCategory::Social,
 
             // Contextual events
             EventType::PolicyChange => EventCategory::Contextual,
@@ -173,6 +184,15 @@ impl EventType {
         }
     }
 
+    /// Returns true if this event repairs trust after a violation.
+    #[must_use]
+    pub const fn is_trust_repair(&self) -> bool {
+        matches!(
+            self,
+            EventType::Apology | EventType::Restitution | EventType::Forgiveness
+        )
+    }
+
     /// Returns all ITS pathways affected by this event type.
     ///
     /// Multi-pathway events (like job loss, bereavement) affect multiple
@@ -255,6 +275,9 @@ impl EventType {
             EventType::Support => "Support",
             EventType::Conflict => "Conflict",
             EventType::Violence => "Violence",
+            EventType::Apology => "Apology",
+            EventType::Restitution => "Restitution",
+            EventType::Forgiveness => "Forgiveness",
             EventType::Humiliation => "Humiliation",
             EventType::Empowerment => "Empowerment",
             EventType::Achievement => "Achievement",
@@ -290,7 +313,7 @@ impl EventType {
 
     /// Returns all event type variants.
     #[must_use]
-    pub const fn all() -> [EventType; 35] {
+    pub const fn all() -> [EventType; 38] {
         [
             EventType::Interaction,
             EventType::SocialExclusion,
@@ -300,6 +323,9 @@ impl EventType {
             EventType::Support,
             EventType::Conflict,
             EventType::Violence,
+            EventType::Apology,
+            EventType::Restitution,
+            EventType::Forgiveness,
             EventType::Humiliation,
             EventType::Empowerment,
             EventType::Achievement,
@@ -502,7 +528,7 @@ mod tests {
     #[test]
     fn event_type_all_returns_all_variants() {
         let all = EventType::all();
-        assert_eq!(all.len(), 35);
+        assert_eq!(all.len(), 38);
     }
 
     #[test]
@@ -0,0 +1,78 @@
+//! Formative shift policy for deriving personality base shifts.
+//!
+//! Base shifts normally come only from explicit
+//! `EventBuilder::with_base_shift` calls. The automatic policy derives
+//! default shifts from severe events so scenarios get consistent
+//! magnitudes without hand-tuning each event.
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// Controls where formative HEXACO base shifts come from.
+///
+/// # Examples
+///
+/// ```
+/// use eventsim_rs::enums::FormativeShiftPolicy;
+///
+/// let policy = FormativeShiftPolicy::default();
+/// assert!(!policy.is_automatic());
+/// assert!(FormativeShiftPolicy::Automatic.is_automatic());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub enum FormativeShiftPolicy {
+    /// Only shifts declared on the event with `with_base_shift` apply.
+    #[default]
+    ExplicitOnly,
+
+    /// Severe events without explicit shifts get derived default shifts.
+    ///
+    /// Derived shifts depend on event type, severity, developmental
+    /// category and age. Explicit shifts on an event override them.
+    Automatic,
+}
+
+impl FormativeShiftPolicy {
+    /// Returns true if shifts are derived automatically.
+    #[must_use]
+    pub const fn is_automatic(&self) -> bool {
+        matches!(self, FormativeShiftPolicy::Automatic)
+    }
+
+    /// Returns a human-readable name for this policy.
+    #[must_use]
+    pub const fn name(&self) -> &'static str {
+        match self {
+            FormativeShiftPolicy::ExplicitOnly => "ExplicitOnly",
+            FormativeShiftPolicy::Automatic => "Automatic",
+        }
+    }
+}
+
+impl fmt::Display for FormativeShiftPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_is_explicit_only() {
+        assert_eq!(
+            FormativeShiftPolicy::default(),
+            FormativeShiftPolicy::ExplicitOnly
+        );
+    }
+
+    #[test]
+    fn display_matches_name() {
+        assert_eq!(format!("{}", FormativeShiftPolicy::Automatic), "Automatic");
+        assert_eq!(
+            FormativeShiftPolicy::ExplicitOnly.to_string(),
+            "ExplicitOnly"
+        );
+    }
+}
@@ -345,6 +345,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::clone_on_copy)]
     fn copy_and_clone() {
         let original = LifeStage::Adult;
         let copied = original;
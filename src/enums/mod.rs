@@ -15,6 +15,7 @@ mod emotion;
 mod event_payload;
 mod event_scope;
 mod event_type;
+mod formative_shift_policy;
 mod life_stage;
 mod personality_profile;
 mod rel_path;
@@ -38,10 +39,11 @@ pub(crate) use developmental_category::DevelopmentalCategory;
 pub use emotion::Emotion;
 pub use event_payload::{
     EventPayload, HistoricalEventType, HistoricalScope, InteractionTopic, LifeDomain, LossType,
-    PolicyArea, RealizationType, SupportType, TraumaType, WeaponType,
+    PolicyArea, PolicyJurisdiction, RealizationType, SupportType, TraumaType, WeaponType,
 };
 pub use event_scope::EventScope;
 pub use event_type::{EventCategory, EventTag, EventType};
+pub use formative_shift_policy::FormativeShiftPolicy;
 pub use life_stage::LifeStage;
 pub use personality_profile::PersonalityProfile;
 pub use rel_path::{Direction, DirectionalPath, RelPath, SharedPath, TrustPath};
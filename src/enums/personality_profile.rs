@@ -332,6 +332,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::clone_on_copy)]
     fn copy_and_clone() {
         let original = PersonalityProfile::Anxious;
         let copied = original;
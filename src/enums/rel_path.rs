@@ -340,6 +340,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::clone_on_copy)]
     fn direction_clone_copy() {
         let d1 = Direction::AToB;
         let d2 = d1;
@@ -416,6 +417,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::clone_on_copy)]
     fn trust_path_clone_copy() {
         let t1 = TrustPath::Integrity;
         let t2 = t1;
@@ -486,6 +488,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::clone_on_copy)]
     fn directional_path_clone_copy() {
         let d1 = DirectionalPath::Attachment;
         let d2 = d1;
@@ -545,6 +548,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::clone_on_copy)]
     fn shared_path_clone_copy() {
         let s1 = SharedPath::Intimacy;
         let s2 = s1;
@@ -619,6 +623,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::clone_on_copy)]
     fn rel_path_clone_copy() {
         let r1 = RelPath::Stage;
         let r2 = r1;
@@ -78,6 +78,23 @@ impl RelationshipSchema {
         )
     }
 
+    /// Returns the relative rate at which this schema fades without contact.
+    ///
+    /// Combined with the bond-type rate to scale relationship neglect.
+    /// Core family ties are the most resistant to drifting apart.
+    #[must_use]
+    pub const fn neglect_rate(&self) -> f32 {
+        match self {
+            RelationshipSchema::Nuclear => 0.5,
+            RelationshipSchema::Family => 0.6,
+            RelationshipSchema::Extended => 0.8,
+            RelationshipSchema::Romantic => 0.9,
+            RelationshipSchema::Peer | RelationshipSchema::Rival => 1.0,
+            RelationshipSchema::Mentor => 1.1,
+            RelationshipSchema::Subordinate => 1.2,
+        }
+    }
+
     /// Returns a description of this schema.
     #[must_use]
     pub const fn description(&self) -> &'static str {
@@ -129,6 +146,16 @@ mod tests {
         assert!(set.contains(&RelationshipSchema::Extended));
     }
 
+    #[test]
+    fn family_schemas_fade_slowest() {
+        for schema in RelationshipSchema::all() {
+            assert!(RelationshipSchema::Nuclear.neglect_rate() <= schema.neglect_rate());
+        }
+        assert!(
+            RelationshipSchema::Extended.neglect_rate() < RelationshipSchema::Peer.neglect_rate()
+        );
+    }
+
     #[test]
     fn all_schemas() {
         let all = RelationshipSchema::all();
@@ -188,6 +215,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::clone_on_copy)]
     fn copy_and_clone() {
         let original = RelationshipSchema::Nuclear;
         let copied = original;
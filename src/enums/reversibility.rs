@@ -157,6 +157,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::clone_on_copy)]
     fn reversibility_result_clone_copy() {
         let r1 = ReversibilityResult::Reversible;
         let r2 = r1; // Copy
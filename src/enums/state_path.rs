@@ -20,7 +20,7 @@
 ///
 /// This is the root enum for accessing any state dimension. Use this
 /// when you need to specify a path to any part of an entity's state.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
 pub enum StatePath {
     /// Path to a HEXACO personality dimension.
     Hexaco(HexacoPath),
@@ -70,7 +70,7 @@ pub enum HexacoPath {
 ///
 /// Note: Mood contains ONLY PAD dimensions (valence, arousal, dominance).
 /// Fatigue and stress are physiological states in [`NeedsPath`].
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
 pub enum MoodPath {
     /// Valence: pleasantness (-1 to +1).
     Valence,
@@ -85,7 +85,7 @@ pub enum MoodPath {
 /// Path to needs dimensions.
 ///
 /// These include physiological states (fatigue, stress) and purpose.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
 pub enum NeedsPath {
     /// Physical and mental tiredness.
     Fatigue,
@@ -100,7 +100,7 @@ pub enum NeedsPath {
 /// Path to social cognition dimensions.
 ///
 /// These include beliefs that feed into ITS computations.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
 pub enum SocialCognitionPath {
     /// Social isolation - feeling disconnected.
     Loneliness,
@@ -122,7 +122,7 @@ pub enum SocialCognitionPath {
 ///
 /// These include ITS (Interpersonal Theory of Suicide) factors
 /// and other mental health indicators.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
 pub enum MentalHealthPath {
     /// Depression severity.
     Depression,
@@ -159,7 +159,7 @@ pub enum MentalHealthPath {
 }
 
 /// Path to disposition dimensions.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
 pub enum DispositionPath {
     /// Self-regulation capacity.
     ImpulseControl,
@@ -181,7 +181,7 @@ pub enum DispositionPath {
 }
 
 /// Path to person characteristics (PPCT model).
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
 pub enum PersonCharacteristicsPath {
     // Resource characteristics
     /// Reasoning and problem-solving ability.
@@ -217,6 +217,126 @@ pub enum PersonCharacteristicsPath {
     Force,
 }
 
+impl StatePath {
+    /// Returns every state path, including computed and composite paths.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use eventsim_rs::enums::{MoodPath, StatePath};
+    ///
+    /// let paths = StatePath::all();
+    /// assert_eq!(paths.len(), 42);
+    /// assert!(paths.contains(&StatePath::Mood(MoodPath::Valence)));
+    /// ```
+    #[must_use]
+    pub fn all() -> Vec<StatePath> {
+        let mut paths = Vec::with_capacity(42);
+        paths.extend(HexacoPath::all().into_iter().map(StatePath::Hexaco));
+        paths.extend(MoodPath::all().into_iter().map(StatePath::Mood));
+        paths.extend(NeedsPath::all().into_iter().map(StatePath::Needs));
+        paths.extend(
+            SocialCognitionPath::all()
+                .into_iter()
+                .map(StatePath::SocialCognition),
+        );
+        paths.extend(
+            MentalHealthPath::all()
+                .into_iter()
+                .map(StatePath::MentalHealth),
+        );
+        paths.extend(
+            DispositionPath::all()
+                .into_iter()
+                .map(StatePath::Disposition),
+        );
+        paths.extend(
+            PersonCharacteristicsPath::all()
+                .into_iter()
+                .map(StatePath::PersonCharacteristics),
+        );
+        paths
+    }
+
+    /// Returns true if this path is computed from other dimensions.
+    ///
+    /// Computed paths (ITS factors, composite person characteristics) have
+    /// no stored base or delta of their own.
+    #[must_use]
+    pub const fn is_computed(&self) -> bool {
+        match self {
+            StatePath::MentalHealth(p) => p.is_computed(),
+            StatePath::PersonCharacteristics(p) => p.is_composite(),
+            _ => false,
+        }
+    }
+
+    /// Returns true if this path has a stable baseline that formative
+    /// events can shift.
+    ///
+    /// HEXACO traits, social cognition, stored mental health dimensions,
+    /// dispositions and stored person characteristics qualify. Mood and
+    /// needs are transient states, and computed paths have no base.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use eventsim_rs::enums::{DispositionPath, MoodPath, StatePath};
+    ///
+    /// assert!(StatePath::Disposition(DispositionPath::TrustPropensity).supports_base_shift());
+    /// assert!(!StatePath::Mood(MoodPath::Valence).supports_base_shift());
+    /// ```
+    #[must_use]
+    pub const fn supports_base_shift(&self) -> bool {
+        match self {
+            StatePath::Mood(_) | StatePath::Needs(_) => false,
+            _ => !self.is_computed(),
+        }
+    }
+}
+
+impl From<HexacoPath> for StatePath {
+    fn from(path: HexacoPath) -> Self {
+        StatePath::Hexaco(path)
+    }
+}
+
+impl From<MoodPath> for StatePath {
+    fn from(path: MoodPath) -> Self {
+        StatePath::Mood(path)
+    }
+}
+
+impl From<NeedsPath> for StatePath {
+    fn from(path: NeedsPath) -> Self {
+        StatePath::Needs(path)
+    }
+}
+
+impl From<SocialCognitionPath> for StatePath {
+    fn from(path: SocialCognitionPath) -> Self {
+        StatePath::SocialCognition(path)
+    }
+}
+
+impl From<MentalHealthPath> for StatePath {
+    fn from(path: MentalHealthPath) -> Self {
+        StatePath::MentalHealth(path)
+    }
+}
+
+impl From<DispositionPath> for StatePath {
+    fn from(path: DispositionPath) -> Self {
+        StatePath::Disposition(path)
+    }
+}
+
+impl From<PersonCharacteristicsPath> for StatePath {
+    fn from(path: PersonCharacteristicsPath) -> Self {
+        StatePath::PersonCharacteristics(path)
+    }
+}
+
 // Implement name methods for each path enum
 
 impl HexacoPath {
@@ -446,6 +566,43 @@ impl std::fmt::Display for StatePath {
 mod tests {
     use super::*;
 
+    #[test]
+    fn state_path_all_covers_every_variant() {
+        let paths = StatePath::all();
+        assert_eq!(paths.len(), 42);
+
+        let computed = paths.iter().filter(|p| p.is_computed()).count();
+        assert_eq!(computed, 6);
+        assert!(StatePath::MentalHealth(MentalHealthPath::AttemptRisk).is_computed());
+        assert!(!StatePath::Mood(MoodPath::Valence).is_computed());
+    }
+
+    #[test]
+    fn supports_base_shift_excludes_transient_and_computed() {
+        let shiftable = StatePath::all()
+            .into_iter()
+            .filter(StatePath::supports_base_shift)
+            .count();
+        // 42 paths minus 3 mood, 3 needs and 6 computed
+        assert_eq!(shiftable, 30);
+        assert!(!StatePath::Needs(NeedsPath::Stress).supports_base_shift());
+        assert!(!StatePath::MentalHealth(MentalHealthPath::SuicidalDesire).supports_base_shift());
+        assert!(StatePath::MentalHealth(MentalHealthPath::SelfWorth).supports_base_shift());
+    }
+
+    #[test]
+    fn from_sub_paths() {
+        assert_eq!(
+            StatePath::from(HexacoPath::Openness),
+            StatePath::Hexaco(HexacoPath::Openness)
+        );
+        let path: StatePath = SocialCognitionPath::Loneliness.into();
+        assert_eq!(
+            path,
+            StatePath::SocialCognition(SocialCognitionPath::Loneliness)
+        );
+    }
+
     #[test]
     fn state_path_mood_variants_exist() {
         // Verify MoodPath variants compile
@@ -194,6 +194,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::clone_on_copy)]
     fn copy_and_clone() {
         let original = SubsystemId::State;
         let copied = original; // Copy
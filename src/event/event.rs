@@ -3,7 +3,7 @@
 //! Events are occurrences that affect entity state. Each event has a type,
 //! optional source and target, severity, tags, and type-specific payload.
 
-use crate::enums::{EventCategory, EventPayload, EventTag, EventType, HexacoPath};
+use crate::enums::{EventCategory, EventPayload, EventTag, EventType, StatePath};
 use crate::types::{Duration, EntityId, EventId, MicrosystemId};
 use uuid::Uuid;
 
@@ -64,9 +64,9 @@ pub struct Event {
     timestamp: Duration,
     /// Microsystem context where event occurred.
     microsystem_context: Option<MicrosystemId>,
-    /// Personality base shifts triggered by this event.
-    /// Each entry is (trait, shift_amount) to be processed during simulation.
-    base_shifts: Vec<(HexacoPath, f32)>,
+    /// Baseline shifts triggered by this event.
+    /// Each entry is (path, shift_amount) to be processed during simulation.
+    base_shifts: Vec<(StatePath, f32)>,
 }
 
 impl Event {
@@ -176,16 +176,16 @@ impl Event {
         self.microsystem_context.as_ref()
     }
 
-    /// Returns the personality base shifts for this event.
+    /// Returns the base shifts for this event.
     ///
-    /// Each entry is a (trait, shift_amount) pair representing a permanent
-    /// personality change to apply when this event is processed.
+    /// Each entry is a (path, shift_amount) pair representing a permanent
+    /// change to a trait or baseline when this event is processed.
     #[must_use]
-    pub fn base_shifts(&self) -> &[(HexacoPath, f32)] {
+    pub fn base_shifts(&self) -> &[(StatePath, f32)] {
         &self.base_shifts
     }
 
-    /// Returns true if this event has any personality base shifts.
+    /// Returns true if this event has any base shifts.
     #[must_use]
     pub fn has_base_shifts(&self) -> bool {
         !self.base_shifts.is_empty()
@@ -262,7 +262,7 @@ impl Event {
         self.microsystem_context = context;
     }
 
-    pub(crate) fn set_base_shifts(&mut self, shifts: Vec<(HexacoPath, f32)>) {
+    pub(crate) fn set_base_shifts(&mut self, shifts: Vec<(StatePath, f32)>) {
         self.base_shifts = shifts;
     }
 }
@@ -270,6 +270,7 @@ impl Event {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::enums::HexacoPath;
 
     #[test]
     fn event_creation_with_type() {
@@ -481,13 +482,19 @@ mod tests {
     fn event_with_base_shifts() {
         let mut event = Event::new(EventType::Violence);
         event.set_base_shifts(vec![
-            (HexacoPath::Neuroticism, 0.25),
-            (HexacoPath::Agreeableness, -0.15),
+            (HexacoPath::Neuroticism.into(), 0.25),
+            (HexacoPath::Agreeableness.into(), -0.15),
         ]);
 
         assert!(event.has_base_shifts());
         assert_eq!(event.base_shifts().len(), 2);
-        assert_eq!(event.base_shifts()[0], (HexacoPath::Neuroticism, 0.25));
-        assert_eq!(event.base_shifts()[1], (HexacoPath::Agreeableness, -0.15));
+        assert_eq!(
+            event.base_shifts()[0],
+            (StatePath::Hexaco(HexacoPath::Neuroticism), 0.25)
+        );
+        assert_eq!(
+            event.base_shifts()[1],
+            (StatePath::Hexaco(HexacoPath::Agreeableness), -0.15)
+        );
     }
 }
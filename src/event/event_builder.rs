@@ -3,7 +3,7 @@
 //! The builder pattern allows clean construction of events with many
 //! optional fields. Category is auto-derived from EventType.
 
-use crate::enums::{EventPayload, EventTag, EventType, HexacoPath};
+use crate::enums::{EventPayload, EventTag, EventType, StatePath};
 use crate::event::Event;
 use crate::types::{Duration, EntityId, EventId, MicrosystemId};
 use std::fmt;
@@ -66,7 +66,7 @@ pub struct EventBuilder {
     payload: Option<EventPayload>,
     timestamp: Duration,
     microsystem_context: Option<MicrosystemId>,
-    base_shifts: Vec<(HexacoPath, f32)>,
+    base_shifts: Vec<(StatePath, f32)>,
 }
 
 impl EventBuilder {
@@ -162,26 +162,29 @@ impl EventBuilder {
         self
     }
 
-    /// Adds a personality base shift to this event.
+    /// Adds a base shift to this event.
     ///
-    /// Base shifts represent permanent personality changes triggered by
-    /// formative events. Multiple shifts can be added to the same event.
+    /// Base shifts represent permanent changes triggered by formative
+    /// events. They can target HEXACO traits or any other dimension with a
+    /// stable baseline (see [`StatePath::supports_base_shift`]), such as
+    /// trust propensity or loneliness. Multiple shifts can be added to the
+    /// same event.
     ///
     /// # Arguments
     ///
-    /// * `trait_path` - Which HEXACO trait to shift
+    /// * `path` - Which trait or baseline to shift
     /// * `amount` - Shift amount (clamped to -1.0 to 1.0)
     ///
     /// # Examples
     ///
     /// ```
     /// use eventsim_rs::event::EventBuilder;
-    /// use eventsim_rs::enums::{EventType, HexacoPath};
+    /// use eventsim_rs::enums::{DispositionPath, EventType, HexacoPath};
     ///
-    /// let event = EventBuilder::new(EventType::Violence)
+    /// let event = EventBuilder::new(EventType::Betrayal)
     ///     .severity(0.9)
     ///     .with_base_shift(HexacoPath::Neuroticism, 0.25)
-    ///     .with_base_shift(HexacoPath::Agreeableness, -0.15)
+    ///     .with_base_shift(DispositionPath::TrustPropensity, -0.15)
     ///     .build()
     ///     .unwrap();
     ///
@@ -189,9 +192,9 @@ impl EventBuilder {
     /// assert_eq!(event.base_shifts().len(), 2);
     /// ```
     #[must_use]
-    pub fn with_base_shift(mut self, trait_path: HexacoPath, amount: f32) -> Self {
+    pub fn with_base_shift(mut self, path: impl Into<StatePath>, amount: f32) -> Self {
         let clamped = amount.clamp(-1.0, 1.0);
-        self.base_shifts.push((trait_path, clamped));
+        self.base_shifts.push((path.into(), clamped));
         self
     }
 
@@ -201,10 +204,25 @@ impl EventBuilder {
     ///
     /// The constructed event, or an error if validation fails.
     ///
+    /// # Errors
+    ///
+    /// Returns an error if a base shift targets a path without a stable
+    /// baseline (mood, needs or a computed dimension).
+    ///
     /// # Notes
     ///
     /// If no payload was set, defaults to `EventPayload::Empty`.
     pub fn build(self) -> Result<Event, EventBuildError> {
+        if let Some((path, _)) = self
+            .base_shifts
+            .iter()
+            .find(|(path, _)| !path.supports_base_shift())
+        {
+            return Err(EventBuildError {
+                reason: format!("{} has no stable baseline to shift", path),
+            });
+        }
+
         let mut event = if let Some(id) = self.id {
             Event::with_id(id, self.event_type)
         } else {
@@ -228,7 +246,9 @@ impl EventBuilder {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::enums::{EventCategory, SupportType};
+    use crate::enums::{
+        DispositionPath, EventCategory, HexacoPath, MentalHealthPath, MoodPath, SupportType,
+    };
 
     #[test]
     fn event_builder_minimal() {
@@ -400,8 +420,14 @@ mod tests {
 
         assert!(event.has_base_shifts());
         assert_eq!(event.base_shifts().len(), 2);
-        assert_eq!(event.base_shifts()[0], (HexacoPath::Neuroticism, 0.25));
-        assert_eq!(event.base_shifts()[1], (HexacoPath::Agreeableness, -0.15));
+        assert_eq!(
+            event.base_shifts()[0],
+            (StatePath::Hexaco(HexacoPath::Neuroticism), 0.25)
+        );
+        assert_eq!(
+            event.base_shifts()[1],
+            (StatePath::Hexaco(HexacoPath::Agreeableness), -0.15)
+        );
     }
 
     #[test]
@@ -421,4 +447,35 @@ mod tests {
         assert!((event.base_shifts()[0].1 - 1.0).abs() < f32::EPSILON);
         assert!((event.base_shifts()[1].1 - (-1.0)).abs() < f32::EPSILON);
     }
+
+    #[test]
+    fn event_builder_base_shift_on_baseline_path() {
+        let event = EventBuilder::new(EventType::Betrayal)
+            .with_base_shift(DispositionPath::TrustPropensity, -0.2)
+            .with_base_shift(MentalHealthPath::SelfWorth, -0.1)
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            event.base_shifts()[0].0,
+            StatePath::Disposition(DispositionPath::TrustPropensity)
+        );
+        assert_eq!(
+            event.base_shifts()[1].0,
+            StatePath::MentalHealth(MentalHealthPath::SelfWorth)
+        );
+    }
+
+    #[test]
+    fn event_builder_rejects_base_shift_without_baseline() {
+        let result = EventBuilder::new(EventType::Violence)
+            .with_base_shift(MoodPath::Valence, -0.2)
+            .build();
+        assert!(result.is_err());
+
+        let result = EventBuilder::new(EventType::Violence)
+            .with_base_shift(MentalHealthPath::AttemptRisk, 0.2)
+            .build();
+        assert!(result.unwrap_err().reason.contains("Attempt Risk"));
+    }
 }
@@ -52,6 +52,7 @@
 //! should not be called directly by consumers.
 
 pub mod context;
+pub mod decision;
 pub mod entity;
 pub mod enums;
 pub mod event;
@@ -73,37 +74,47 @@ pub use enums::{
     FamilyPath, HealthcarePath, HexacoPath, HistoricalEventType, HistoricalScope, InteractionTopic,
     LifeDomain, LifeStage, LossType, MacrosystemPath, MentalHealthPath, MicrosystemPath, MoodPath,
     NeedsPath, NeighborhoodPath, PersonCharacteristicsPath, PersonalityProfile, PolicyArea,
-    RealizationType, RelPath, RelationshipSchema, ReligiousPath, ReversibilityError,
-    ReversibilityResult, SharedPath, SocialCognitionPath, SocialPath, Species, SpiralType,
-    StatePath, SubsystemId, SupportType, TraumaType, TrustPath, WeaponType, WorkPath,
+    PolicyJurisdiction, RealizationType, RelPath, RelationshipSchema, ReligiousPath,
+    ReversibilityError, ReversibilityResult, SharedPath, SocialCognitionPath, SocialPath, Species,
+    SpiralType, StatePath, SubsystemId, SupportType, TraumaType, TrustPath, WeaponType, WorkPath,
 };
 
 // Re-export context types at crate root
 pub use context::{
-    check_proximal_process_gate, ChronosystemContext, CohortEffects, CriticalPeriod,
-    CulturalOrientation, EcologicalContext, EducationContext, ExosystemContext, FamilyContext,
-    FamilyRole, HealthcareContext, HistoricalPeriod, InstitutionalStructure, InteractionProfile,
-    MacrosystemConstraintSet, MacrosystemContext, MesosystemCache, MesosystemLinkage, Microsystem,
-    MicrosystemType, NeighborhoodContext, NonNormativeEvent, NormativeTransition,
-    ParentWorkQuality, ProximalProcessGateError, ReligiousContext, SocialContext, TurningPoint,
-    TurningPointDomain, WorkContext, INTERACTION_COMPLEXITY_THRESHOLD,
-    INTERACTION_FREQUENCY_THRESHOLD,
+    check_proximal_process_gate, ChronosystemContext, CohortEffects, ContextChange, ContextSegment,
+    ContextTimeline, CriticalPeriod, CulturalOrientation, EcologicalContext, EducationContext,
+    ExosystemContext, FamilyContext, FamilyRole, HealthcareContext, HistoricalPeriod,
+    HistoricalReach, InstitutionalStructure, InteractionProfile, LifeTransition,
+    MacrosystemConstraintSet, MacrosystemContext, MacrosystemDynamics, MacrosystemModifier,
+    MacrosystemShift, MesosystemCache, MesosystemLinkage, Microsystem, MicrosystemClimate,
+    MicrosystemType, NeighborhoodContext, NonNormativeEvent, NormativeSchedule,
+    NormativeTransition, ParentWorkQuality, ProximalProcessGateError, ReligiousContext,
+    SharedHistoricalEvent, SocialContext, TimeBudget, TurningPoint, TurningPointDomain,
+    WorkContext, AVAILABLE_HOURS_PER_WEEK, HISTORICAL_CRITICAL_PERIOD_DOMAIN,
+    INTERACTION_COMPLEXITY_THRESHOLD, INTERACTION_FREQUENCY_THRESHOLD,
+    STANDARD_WORK_HOURS_PER_WEEK, SUSTAINABLE_HOURS_PER_WEEK,
 };
 
+// Re-export decision types at crate root
+pub use decision::{ActionOption, Decision, DecisionError, RankedChoice};
+
 // NOTE: Processor module contains internal implementation details.
 // Consumers should use the Simulation API (state_at) instead of calling
 // processor functions directly.
 
 // Re-export simulation types at crate root
 pub use simulation::{
-    AnchoredEntity, ComputedState, EntityQueryHandle, RegressionQuality, Simulation,
-    SimulationBuildError, SimulationBuilder, TimestampedEvent, TimestampedRelationship,
+    AnchoredEntity, ComputedState, DimensionRegression, EntityQueryHandle, RegressionMethod,
+    RegressionQuality, RegressionReport, Simulation, SimulationBuildError, SimulationBuilder,
+    TimestampedEvent, TimestampedRelationship,
 };
 
 // Re-export commonly used state types at crate root
 pub use state::{
-    age_plasticity, apply_formative_modifiers, combined_plasticity, cumulative_in_direction,
-    effective_base_at, saturation_factor, sensitive_period_modifier, species_plasticity_modifier,
+    age_plasticity, apply_baseline_modifiers, apply_formative_modifiers,
+    apply_normative_maturation, baseline_modifier, baseline_stability_coefficient,
+    combined_plasticity, cumulative_in_direction, effective_base_at, formative_shift_profile,
+    normative_drift, saturation_factor, sensitive_period_modifier, species_plasticity_modifier,
     stability_coefficient, trait_modifier, BaseShiftRecord, DemandCharacteristics, Demographical,
     Disposition, EntityModelConfig, Hexaco, IndividualState, MentalHealth, Mood, Needs,
     PersonCharacteristics, SocialCognition, StateValue, CUMULATIVE_CAP, HOPELESSNESS_THRESHOLD,
@@ -113,10 +124,12 @@ pub use state::{
 
 // Re-export relationship types at crate root
 pub use relationship::{
-    AntecedentDirection, AntecedentMapping, AntecedentType, DirectionalDimensions,
-    InteractionPattern, PerceivedRisk, Relationship, RelationshipError, RelationshipStage,
-    SharedDimensions, StakesLevel, TrustAntecedent, TrustContext, TrustDecision,
-    TrustworthinessFactors, Vulnerability, VulnerabilityType, TRUST_ANTECEDENT_TABLE,
+    ActualTrustworthiness, AntecedentDirection, AntecedentMapping, AntecedentType,
+    DirectionalDimensions, InteractionPattern, InteractionRecord, PerceivedRisk, Relationship,
+    RelationshipError, RelationshipStage, Reputation, ReputationStore, SharedDimensions,
+    StageTransition, StageTransitionCause, StakesLevel, TrustAntecedent, TrustCalibration,
+    TrustContext, TrustDecision, TrustworthinessFactors, Vulnerability, VulnerabilityType,
+    TRUST_ANTECEDENT_TABLE,
 };
 
 // Re-export event types at crate root
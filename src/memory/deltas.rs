@@ -410,6 +410,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::clone_on_copy)]
     fn reputation_delta_clone_and_copy() {
         let delta = ReputationDelta::new().with_feared(0.1);
         let cloned = delta.clone();
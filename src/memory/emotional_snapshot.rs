@@ -348,6 +348,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::clone_on_copy)]
     fn clone_and_copy() {
         let snapshot = EmotionalSnapshot::new(0.5, 0.3, -0.2);
         let cloned = snapshot.clone();
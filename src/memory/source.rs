@@ -154,6 +154,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::clone_on_copy)]
     fn clone_and_copy() {
         let source = MemorySource::Witness;
         let cloned = source.clone();
@@ -197,13 +197,10 @@ mod tests {
     fn has_threshold_alert(alerts: &[Alert], expected_path: StatePath) -> bool {
         let mut found = false;
         for alert in alerts {
-            match alert.trigger() {
-                AlertTrigger::ThresholdExceeded(path, _) => {
-                    if *path == expected_path {
-                        found = true;
-                    }
+            if let AlertTrigger::ThresholdExceeded(path, _) = alert.trigger() {
+                if *path == expected_path {
+                    found = true;
                 }
-                _ => {}
             }
         }
         found
@@ -212,13 +209,10 @@ mod tests {
     fn has_spiral_alert(alerts: &[Alert], expected_spiral: SpiralType) -> bool {
         let mut found = false;
         for alert in alerts {
-            match alert.trigger() {
-                AlertTrigger::SpiralDetected(spiral_type) => {
-                    if *spiral_type == expected_spiral {
-                        found = true;
-                    }
+            if let AlertTrigger::SpiralDetected(spiral_type) = alert.trigger() {
+                if *spiral_type == expected_spiral {
+                    found = true;
                 }
-                _ => {}
             }
         }
         found
@@ -183,6 +183,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::default_constructed_unit_structs)]
     fn no_op_processor_default() {
         let processor = NoOpDecayProcessor::default();
         let mut state = IndividualState::new();
@@ -194,6 +195,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::clone_on_copy)]
     fn no_op_processor_clone() {
         let p1 = NoOpDecayProcessor::new();
         let p2 = p1.clone();
@@ -344,6 +346,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::default_constructed_unit_structs)]
     fn state_decay_processor_default() {
         let processor = StateDecayProcessor::default();
         let mut state = IndividualState::new();
@@ -356,6 +359,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::clone_on_copy)]
     fn state_decay_processor_clone() {
         let p1 = StateDecayProcessor::new();
         let p2 = p1.clone();
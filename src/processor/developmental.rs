@@ -22,8 +22,9 @@
 
 use crate::context::TurningPoint;
 use crate::entity::Entity;
-use crate::enums::{DevelopmentalCategory, LifeStage};
+use crate::enums::{DevelopmentalCategory, LifeStage, StatePath};
 use crate::event::Event;
+use crate::state::{formative_shift_profile, AUTOMATIC_SHIFT_SEVERITY_THRESHOLD};
 use crate::types::Timestamp;
 
 /// Constants for plasticity computation.
@@ -100,6 +101,38 @@ pub(crate) fn apply_developmental_effects(
     event_impact * effective_plasticity * sensitive_multiplier
 }
 
+/// Derives default formative base shifts for a severe event.
+///
+/// Used under `FormativeShiftPolicy::Automatic` for events that carry no
+/// explicit base shifts. The event type selects a trait and baseline profile, scaled by
+/// severity and by the sensitive period multiplier for the event's
+/// developmental category at the entity's life stage (e.g., attachment
+/// events in childhood). The returned amounts are raw shift requests that
+/// still pass through `apply_baseline_modifiers`.
+///
+/// Returns an empty vector for events below
+/// `AUTOMATIC_SHIFT_SEVERITY_THRESHOLD` or without a profile.
+#[must_use]
+pub(crate) fn derive_formative_shifts(
+    event: &Event,
+    life_stage: &LifeStage,
+) -> Vec<(StatePath, f32)> {
+    let severity = event.severity();
+    if severity < AUTOMATIC_SHIFT_SEVERITY_THRESHOLD {
+        return Vec::new();
+    }
+
+    let event_type = event.event_type();
+    let category = DevelopmentalCategory::from(&event_type);
+    let sensitive_multiplier = get_sensitive_period_multiplier(life_stage, &category);
+    let scale = (severity * sensitive_multiplier) as f32;
+
+    formative_shift_profile(event_type)
+        .iter()
+        .map(|(path, amount)| (*path, amount * scale))
+        .collect()
+}
+
 /// Returns the plasticity modifier based on age.
 ///
 /// Plasticity follows a continuous decreasing curve from 2.0 at birth
@@ -244,7 +277,7 @@ mod tests {
     use super::*;
     use crate::context::{TurningPoint, TurningPointDomain};
     use crate::entity::EntityBuilder;
-    use crate::enums::{EventType, Species};
+    use crate::enums::{EventType, HexacoPath, Species};
     use crate::event::EventBuilder;
     use crate::types::{Duration, EventId, Timestamp};
 
@@ -252,6 +285,49 @@ mod tests {
         Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0) + Duration::days(days)
     }
 
+    // === Automatic Formative Shift Tests ===
+
+    #[test]
+    fn derive_formative_shifts_ignores_mild_events() {
+        let event = EventBuilder::new(EventType::ChildhoodAbuse)
+            .severity(0.5)
+            .build()
+            .unwrap();
+        assert!(derive_formative_shifts(&event, &LifeStage::Child).is_empty());
+    }
+
+    #[test]
+    fn derive_formative_shifts_scales_with_severity() {
+        let event = EventBuilder::new(EventType::ChildhoodAbuse)
+            .severity(0.9)
+            .build()
+            .unwrap();
+        let shifts = derive_formative_shifts(&event, &LifeStage::Child);
+
+        let neuroticism = shifts
+            .iter()
+            .find(|(t, _)| *t == StatePath::Hexaco(HexacoPath::Neuroticism))
+            .map(|(_, a)| *a)
+            .unwrap();
+        assert!((neuroticism - 0.40 * 0.9).abs() < 1e-6);
+        assert!(shifts
+            .iter()
+            .any(|(t, a)| *t == StatePath::Hexaco(HexacoPath::Agreeableness) && *a < 0.0));
+    }
+
+    #[test]
+    fn derive_formative_shifts_amplified_in_sensitive_period() {
+        // Support is an attachment event (2.0x in childhood)
+        let event = EventBuilder::new(EventType::Support)
+            .severity(0.8)
+            .build()
+            .unwrap();
+        let child = derive_formative_shifts(&event, &LifeStage::Child);
+        let adult = derive_formative_shifts(&event, &LifeStage::Adult);
+
+        assert!((child[0].1 - adult[0].1 * 2.0).abs() < 1e-6);
+    }
+
     // === Plasticity Tests ===
 
     #[test]
@@ -283,7 +359,7 @@ mod tests {
         let test_ages = [0.0, 5.0, 12.0, 18.0, 25.0, 40.0, 65.0, 80.0, 100.0];
         for age in test_ages {
             let plasticity = get_plasticity_modifier(&LifeStage::Adult, age);
-            assert!(plasticity >= 0.5 && plasticity <= 2.0);
+            assert!((0.5..=2.0).contains(&plasticity));
         }
     }
 
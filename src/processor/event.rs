@@ -3,6 +3,7 @@
 //! This module provides functions for interpreting events based on
 //! entity personality, applying state changes, and computing salience.
 
+use crate::context::EcologicalContext;
 use crate::entity::Entity;
 use crate::enums::{
     Attribution, AttributionStability, Direction, DispositionPath, EventCategory, EventPayload,
@@ -12,7 +13,10 @@ use crate::enums::{
 use crate::event::{compute_arousal_modulated_salience, Event};
 #[cfg(test)]
 use crate::memory::MemoryTag;
-use crate::relationship::{get_antecedent_for_event, Relationship, TrustAntecedent};
+use crate::relationship::{
+    count_violations, get_antecedent_for_event, repair_effectiveness, Relationship,
+    TrustAntecedent, FORGIVENESS_RESENTMENT_RELIEF, REPAIR_BETRAYAL_RISK_RELIEF,
+};
 use crate::types::{EventId, Timestamp};
 
 /// Interpretation of an event based on entity state and personality.
@@ -138,6 +142,9 @@ pub mod impact {
 /// - Agreeableness affects social event interpretation
 /// - Honesty-Humility affects attribution patterns
 ///
+/// Culture is read from the entity's own context; see
+/// [`interpret_event_in_context`].
+///
 /// # Arguments
 ///
 /// * `event` - The event to interpret
@@ -170,8 +177,28 @@ pub mod impact {
 /// assert!(interpreted.valence_delta < 0.0); // Exclusion is negative
 /// assert!(interpreted.loneliness_delta > 0.0); // Increases loneliness
 /// ```
+#[cfg(test)]
 #[must_use]
 pub(crate) fn interpret_event(event: &Event, entity: &Entity) -> InterpretedEvent {
+    interpret_event_in_context(event, entity, entity.context())
+}
+
+/// Interprets an event within an ecological context.
+///
+/// The context's macrosystem, resolved for the entity's subculture,
+/// moderates appraisal:
+///
+/// - Shaming and family discord weigh more heavily on perceived
+///   burdensomeness in collectivist cultures.
+/// - Humiliation by an authority figure in one of the context's
+///   microsystems is moderated by power distance.
+/// - Social exclusion is scaled by the belonging need weight.
+#[must_use]
+pub(crate) fn interpret_event_in_context(
+    event: &Event,
+    entity: &Entity,
+    context: &EcologicalContext,
+) -> InterpretedEvent {
     // Get personality for modulation
     let hexaco = entity.individual_state().hexaco();
     let emotionality = hexaco.emotionality(); // HEXACO Emotionality
@@ -352,6 +379,29 @@ pub(crate) fn interpret_event(event: &Event, entity: &Entity) -> InterpretedEven
         }
     }
 
+    // Culture moderates appraisal of shame, hierarchy and exclusion
+    match event_type {
+        EventType::ShamingEvent | EventType::FamilyDiscord => {
+            let weight = context.macrosystem().resolved().family_shame_weight() as f32;
+            perceived_liability_delta *= weight;
+            self_hate_delta *= weight;
+        }
+        EventType::Humiliation if is_humiliated_by_authority(event, context) => {
+            let weight = context
+                .macrosystem()
+                .resolved()
+                .authority_humiliation_weight() as f32;
+            valence_delta *= weight;
+            dominance_delta *= weight;
+        }
+        EventType::SocialExclusion => {
+            let weight = context.macrosystem().resolved().belonging_need_weight() as f32;
+            loneliness_delta *= weight;
+            prc_delta *= weight;
+        }
+        _ => {}
+    }
+
     // Modulate by Emotionality (higher = stronger emotional response)
     let emotionality_factor = 1.0 + (emotionality * 0.3);
     valence_delta *= emotionality_factor;
@@ -486,6 +536,14 @@ pub(crate) fn interpret_event(event: &Event, entity: &Entity) -> InterpretedEven
         ));
     }
 
+    // Forgiveness releases the forgiver's grievance
+    if event_type == EventType::Forgiveness {
+        state_deltas.push((
+            StatePath::Disposition(DispositionPath::Grievance),
+            (-0.10 * severity) as f64,
+        ));
+    }
+
     InterpretedEvent {
         event: event.clone(),
         original_event: event.id().clone(),
@@ -506,6 +564,21 @@ pub(crate) fn interpret_event(event: &Event, entity: &Entity) -> InterpretedEven
     }
 }
 
+/// Returns true if the humiliation's perpetrator holds authority in one of
+/// the context's microsystems.
+fn is_humiliated_by_authority(event: &Event, context: &EcologicalContext) -> bool {
+    let EventPayload::Humiliation {
+        perpetrator: Some(perpetrator),
+        ..
+    } = event.payload()
+    else {
+        return false;
+    };
+    context
+        .microsystems_iter()
+        .any(|(_, microsystem)| microsystem.authority_figures().contains(&perpetrator))
+}
+
 /// Computes base salience from event properties.
 fn compute_base_salience(event: &Event) -> f32 {
     let severity = event.severity() as f32;
@@ -710,52 +783,143 @@ pub(crate) fn apply_interpreted_event(interpreted: &InterpretedEvent, entity: &m
 /// Processes an event into trust antecedents for related relationships.
 ///
 /// For events with a source and target, this updates the target's
-/// trustworthiness perceptions of the source.
+/// trustworthiness perceptions of the source, records dyadic interactions
+/// in the interaction pattern and then re-evaluates the relationship stage
+/// (see `apply_event_effects`). Each relationship keeps the events it was
+/// given in timestamp order and replays them when an earlier event arrives
+/// after a later one.
 pub(crate) fn process_event_to_relationships(
     event: &Event,
     timestamp: Timestamp,
     relationships: &mut [Relationship],
+    trustor_agreeableness: Option<f32>,
 ) {
     let (Some(source), Some(target)) = (event.source(), event.target()) else {
         return;
     };
 
-    let mappings = get_antecedent_for_event(event);
-    if mappings.is_empty() {
-        return;
+    for relationship in relationships.iter_mut() {
+        if direction_for_relationship(relationship, target, source).is_some() {
+            relationship.apply_event(event, timestamp, trustor_agreeableness);
+        }
     }
+}
+
+/// Applies one event's effects to a relationship between its source and
+/// target.
+///
+/// Betrayals mark the target's perceived risk. Repair events (apology,
+/// restitution, forgiveness) are weighted by `repair_effectiveness`, using
+/// the target's agreeableness (`None` is treated as neutral) and the
+/// violations up to `timestamp`, and release part of that risk;
+/// forgiveness also releases resentment.
+pub(crate) fn apply_event_effects(
+    relationship: &mut Relationship,
+    event: &Event,
+    timestamp: Timestamp,
+    trustor_agreeableness: Option<f32>,
+) {
+    let (Some(source), Some(target)) = (event.source(), event.target()) else {
+        return;
+    };
+    let Some(direction) = direction_for_relationship(relationship, target, source) else {
+        return;
+    };
 
+    let mappings = get_antecedent_for_event(event);
     let severity = event.severity() as f32;
+    let event_type = event.event_type();
 
-    for relationship in relationships.iter_mut() {
-        let Some(direction) = direction_for_relationship(relationship, target, source) else {
+    let consistency = relationship
+        .pattern()
+        .consistency_at(timestamp)
+        .clamp(0.0, 1.0);
+    let consistency_weight = 0.5 + (consistency * 0.5);
+
+    let repair = event_type.is_trust_repair().then(|| {
+        let since_violation = relationship
+            .last_negative_antecedent(direction)
+            .filter(|last| *last <= timestamp)
+            .map(|last| timestamp - last);
+        let earlier: Vec<TrustAntecedent> = relationship
+            .antecedent_history(direction)
+            .iter()
+            .filter(|antecedent| antecedent.timestamp() <= timestamp)
+            .cloned()
+            .collect();
+        repair_effectiveness(
+            since_violation,
+            trustor_agreeableness.unwrap_or(0.0),
+            count_violations(&earlier),
+        )
+    });
+    let repair_weight = repair.unwrap_or(1.0);
+
+    for mapping in &mappings {
+        let raw_magnitude = (mapping.base_magnitude * severity).clamp(0.0, 1.0);
+        let magnitude = (raw_magnitude * consistency_weight * repair_weight).clamp(0.0, 1.0);
+        if magnitude <= 0.0 {
             continue;
-        };
-
-        let consistency = relationship.pattern().consistency.clamp(0.0, 1.0);
-        let consistency_weight = 0.5 + (consistency * 0.5);
-
-        for mapping in &mappings {
-            let raw_magnitude = (mapping.base_magnitude * severity).clamp(0.0, 1.0);
-            let magnitude = raw_magnitude * consistency_weight;
-            if magnitude <= 0.0 {
-                continue;
-            }
-            let antecedent = TrustAntecedent::new(
-                timestamp,
-                mapping.antecedent_type,
-                mapping.direction,
-                magnitude,
-                mapping.context,
-            );
-            relationship.append_antecedent(direction, antecedent);
         }
+        let antecedent = TrustAntecedent::new(
+            timestamp,
+            mapping.antecedent_type,
+            mapping.direction,
+            magnitude,
+            mapping.context,
+        );
+        relationship.append_event_antecedent(direction, antecedent);
+    }
 
+    if !mappings.is_empty() {
         let history = relationship.antecedent_history(direction).to_vec();
         relationship
             .trustworthiness_mut(direction)
             .recompute_from_antecedents(&history);
     }
+    if event_type == EventType::Betrayal {
+        relationship.perceived_risk_mut(direction).mark_betrayal();
+    }
+    if let Some(effectiveness) = repair {
+        let strength = effectiveness * severity;
+        relationship
+            .perceived_risk_mut(direction)
+            .repair_betrayal(REPAIR_BETRAYAL_RISK_RELIEF * strength);
+        if event_type == EventType::Forgiveness {
+            let resentment = relationship.directional(direction).resentment();
+            let relief = (FORGIVENESS_RESENTMENT_RELIEF * strength).min(resentment.effective());
+            relationship.release_resentment(direction, relief);
+        }
+    }
+    if let Some(valence) = interaction_valence(event) {
+        relationship
+            .pattern_mut()
+            .record_interaction(timestamp, valence);
+    }
+    relationship.update_stage(timestamp, Some(event));
+}
+
+/// Returns the valence (-1.0 to 1.0) of a dyadic interaction event.
+///
+/// Returns `None` for event types that are not direct exchanges between
+/// the pair and so should not count toward the interaction pattern.
+fn interaction_valence(event: &Event) -> Option<f32> {
+    let base = match event.event_type() {
+        EventType::Interaction => 0.3,
+        EventType::Support => 0.8,
+        EventType::SocialInclusion => 0.6,
+        EventType::Conflict => match event.payload() {
+            EventPayload::Conflict { resolved: true, .. } => -0.2,
+            _ => -0.6,
+        },
+        EventType::BurdenFeedback => -0.5,
+        EventType::SocialExclusion | EventType::FamilyDiscord => -0.6,
+        EventType::Rejection | EventType::ShamingEvent => -0.7,
+        EventType::Humiliation => -0.8,
+        EventType::Betrayal | EventType::Violence => -1.0,
+        _ => return None,
+    };
+    Some(base * event.severity() as f32)
 }
 
 fn direction_for_relationship(
@@ -900,6 +1064,105 @@ mod tests {
         assert!(interpreted.loneliness_delta < 0.0);
     }
 
+    fn collectivist_context() -> EcologicalContext {
+        let mut context = EcologicalContext::default();
+        context
+            .macrosystem_mut()
+            .cultural_orientation
+            .individualism_collectivism = -0.8;
+        context
+    }
+
+    #[test]
+    fn collectivism_amplifies_shame_on_burdensomeness() {
+        let entity = create_human();
+        let neutral = EcologicalContext::default();
+        let collectivist = collectivist_context();
+        for event_type in [EventType::ShamingEvent, EventType::FamilyDiscord] {
+            let event = EventBuilder::new(event_type).severity(0.6).build().unwrap();
+            let base = interpret_event_in_context(&event, &entity, &neutral);
+            let moderated = interpret_event_in_context(&event, &entity, &collectivist);
+            // 1 + 0.8 * 0.4
+            let ratio = moderated.perceived_liability_delta / base.perceived_liability_delta;
+            assert!((ratio - 1.32).abs() < 1e-5);
+            assert!(moderated.self_hate_delta > base.self_hate_delta);
+            assert!((moderated.valence_delta - base.valence_delta).abs() < f32::EPSILON);
+        }
+    }
+
+    #[test]
+    fn power_distance_moderates_only_authority_humiliation() {
+        use crate::context::{Microsystem, WorkContext};
+        use crate::types::{EntityId, MicrosystemId};
+
+        let entity = create_human();
+        let boss = EntityId::new("boss").unwrap();
+        let mut context = EcologicalContext::default();
+        context.add_microsystem(
+            MicrosystemId::new("office").unwrap(),
+            Microsystem::new_work(WorkContext {
+                supervisor_id: Some(boss.clone()),
+                ..WorkContext::default()
+            }),
+        );
+        let mut hierarchical = context.clone();
+        hierarchical
+            .macrosystem_mut()
+            .cultural_orientation
+            .power_distance = 0.9;
+
+        let humiliation = |perpetrator: &str| {
+            EventBuilder::new(EventType::Humiliation)
+                .severity(0.5)
+                .payload(EventPayload::Humiliation {
+                    public: true,
+                    perpetrator: Some(EntityId::new(perpetrator).unwrap()),
+                })
+                .build()
+                .unwrap()
+        };
+
+        let by_boss = humiliation("boss");
+        let base = interpret_event_in_context(&by_boss, &entity, &context);
+        let moderated = interpret_event_in_context(&by_boss, &entity, &hierarchical);
+        // 1 + (0.9 - 0.5) * 0.8
+        assert!((moderated.dominance_delta / base.dominance_delta - 1.32).abs() < 1e-5);
+
+        let by_peer = humiliation("peer");
+        let base = interpret_event_in_context(&by_peer, &entity, &context);
+        let moderated = interpret_event_in_context(&by_peer, &entity, &hierarchical);
+        assert!((moderated.dominance_delta - base.dominance_delta).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn subculture_belonging_weight_scales_exclusion() {
+        use crate::context::MacrosystemModifier;
+
+        let mut entity = create_human();
+        let event = EventBuilder::new(EventType::SocialExclusion)
+            .severity(0.7)
+            .build()
+            .unwrap();
+        let base = interpret_event(&event, &entity);
+
+        let macrosystem = entity.context_mut().macrosystem_mut();
+        macrosystem.subculture_overrides.insert(
+            "enclave".to_string(),
+            MacrosystemModifier {
+                individualism_collectivism: Some(-1.0),
+                ..MacrosystemModifier::default()
+            },
+        );
+        let unaffiliated = interpret_event(&event, &entity);
+        assert!((unaffiliated.loneliness_delta - base.loneliness_delta).abs() < f32::EPSILON);
+
+        entity.context_mut().macrosystem_mut().subculture = Some("enclave".to_string());
+        let member = interpret_event(&event, &entity);
+        // belonging need weight 1 + 1.0 * 0.3
+        assert!((member.loneliness_delta / base.loneliness_delta - 1.3).abs() < 1e-5);
+        assert!(member.prc_delta < base.prc_delta);
+    }
+
     #[test]
     fn interpret_event_ignores_unhandled_event_type_for_category() {
         let entity = create_human();
@@ -2073,7 +2336,7 @@ mod tests {
         let timestamp = Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0);
 
         let mut relationships = vec![Relationship::try_between(alice, bob).unwrap()];
-        process_event_to_relationships(&event, timestamp, &mut relationships);
+        process_event_to_relationships(&event, timestamp, &mut relationships, None);
 
         let history = relationships[0].antecedent_history(Direction::BToA);
         assert!(!history.is_empty());
@@ -2121,7 +2384,7 @@ mod tests {
         let high_expected = raw_magnitude * (0.5 + high_consistency_value * 0.5);
 
         let mut relationships = vec![low_consistency, high_consistency];
-        process_event_to_relationships(&event, timestamp, &mut relationships);
+        process_event_to_relationships(&event, timestamp, &mut relationships, None);
 
         let low_mag = relationships[0]
             .antecedent_history(Direction::BToA)
@@ -2157,7 +2420,7 @@ mod tests {
         let timestamp = Timestamp::from_ymd_hms(2024, 1, 2, 0, 0, 0);
 
         let mut relationships = vec![Relationship::try_between(alice, bob).unwrap()];
-        process_event_to_relationships(&event, timestamp, &mut relationships);
+        process_event_to_relationships(&event, timestamp, &mut relationships, None);
 
         let history = relationships[0].antecedent_history(Direction::BToA);
         assert!(history
@@ -2191,7 +2454,7 @@ mod tests {
         high_consistency.pattern_mut().consistency = 1.0;
 
         let mut relationships = vec![low_consistency, high_consistency];
-        process_event_to_relationships(&event, timestamp, &mut relationships);
+        process_event_to_relationships(&event, timestamp, &mut relationships, None);
 
         let low_mag = relationships[0].antecedent_history(Direction::BToA)[0].magnitude();
         let high_mag = relationships[1].antecedent_history(Direction::BToA)[0].magnitude();
@@ -2220,7 +2483,7 @@ mod tests {
         let timestamp = Timestamp::from_ymd_hms(2024, 1, 4, 0, 0, 0);
 
         let mut relationships = vec![Relationship::try_between(alice, bob).unwrap()];
-        process_event_to_relationships(&event, timestamp, &mut relationships);
+        process_event_to_relationships(&event, timestamp, &mut relationships, None);
 
         assert!(!relationships[0]
             .antecedent_history(Direction::AToB)
@@ -2241,7 +2504,7 @@ mod tests {
         let timestamp = Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0);
 
         let mut relationships = vec![Relationship::try_between(alice, bob).unwrap()];
-        process_event_to_relationships(&event, timestamp, &mut relationships);
+        process_event_to_relationships(&event, timestamp, &mut relationships, None);
 
         assert!(relationships[0]
             .antecedent_history(Direction::AToB)
@@ -2251,6 +2514,278 @@ mod tests {
             .is_empty());
     }
 
+    #[test]
+    fn process_event_to_relationships_estranges_after_betrayal() {
+        use crate::relationship::{Relationship, RelationshipStage, StageTransitionCause};
+        use crate::types::Timestamp;
+
+        let alice = EntityId::new("alice").unwrap();
+        let bob = EntityId::new("bob").unwrap();
+        let event = EventBuilder::new(EventType::Betrayal)
+            .source(bob.clone())
+            .target(alice.clone())
+            .severity(0.9)
+            .build()
+            .unwrap();
+        let timestamp = Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+
+        let mut relationships = vec![Relationship::try_between(alice, bob)
+            .unwrap()
+            .with_stage(RelationshipStage::Established)];
+        process_event_to_relationships(&event, timestamp, &mut relationships, None);
+
+        assert_eq!(relationships[0].stage(), RelationshipStage::Estranged);
+        let log = relationships[0].stage_transitions();
+        assert_eq!(log.len(), 1);
+        assert_eq!(log[0].from(), RelationshipStage::Established);
+        assert_eq!(log[0].cause(), StageTransitionCause::Betrayal);
+        assert_eq!(log[0].timestamp(), timestamp);
+    }
+
+    #[test]
+    fn process_event_to_relationships_replays_out_of_order_events() {
+        use crate::relationship::{Relationship, RelationshipStage};
+        use crate::types::{Duration, Timestamp};
+
+        let alice = EntityId::new("alice").unwrap();
+        let bob = EntityId::new("bob").unwrap();
+        let t0 = Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+        let pair_event = |event_type: EventType| {
+            EventBuilder::new(event_type)
+                .source(bob.clone())
+                .target(alice.clone())
+                .severity(0.8)
+                .build()
+                .unwrap()
+        };
+        let events = [
+            (pair_event(EventType::Support), t0),
+            (pair_event(EventType::Betrayal), t0 + Duration::days(10)),
+            (pair_event(EventType::Forgiveness), t0 + Duration::days(40)),
+        ];
+        let fresh = || {
+            let mut relationship = Relationship::try_between(alice.clone(), bob.clone())
+                .unwrap()
+                .with_stage(RelationshipStage::Established);
+            relationship
+                .directional_mut(Direction::AToB)
+                .resentment_mut()
+                .set_base(0.6);
+            vec![relationship]
+        };
+
+        let mut chronological = fresh();
+        for (event, ts) in &events {
+            process_event_to_relationships(event, *ts, &mut chronological, Some(0.5));
+        }
+        let mut reversed = fresh();
+        for (event, ts) in events.iter().rev() {
+            process_event_to_relationships(event, *ts, &mut reversed, Some(0.5));
+        }
+
+        assert_eq!(reversed, chronological);
+        assert_eq!(
+            reversed[0].stage_transitions(),
+            chronological[0].stage_transitions()
+        );
+        assert!(reversed[0]
+            .stage_transitions()
+            .windows(2)
+            .all(|pair| pair[0].timestamp() <= pair[1].timestamp()));
+        assert_eq!(
+            reversed[0].stage_at(t0 + Duration::days(10)),
+            RelationshipStage::Estranged
+        );
+    }
+
+    #[test]
+    fn process_event_to_relationships_records_dyadic_interactions() {
+        use crate::relationship::Relationship;
+        use crate::types::{Duration, Timestamp};
+
+        let alice = EntityId::new("alice").unwrap();
+        let bob = EntityId::new("bob").unwrap();
+        let start = Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+        let mut relationships =
+            vec![Relationship::try_between(alice.clone(), bob.clone()).unwrap()];
+
+        for day in 0..14 {
+            let event = EventBuilder::new(EventType::Support)
+                .source(alice.clone())
+                .target(bob.clone())
+                .severity(0.8)
+                .build()
+                .unwrap();
+            process_event_to_relationships(
+                &event,
+                start + Duration::days(day),
+                &mut relationships,
+                None,
+            );
+        }
+        let achievement = EventBuilder::new(EventType::Achievement)
+            .source(alice)
+            .target(bob)
+            .build()
+            .unwrap();
+        process_event_to_relationships(
+            &achievement,
+            start + Duration::days(20),
+            &mut relationships,
+            None,
+        );
+
+        let pattern = relationships[0].pattern();
+        assert_eq!(pattern.interactions().len(), 14);
+        assert_eq!(pattern.last_interaction, Some(start + Duration::days(13)));
+        assert!(pattern.frequency > 0.4);
+        assert!(pattern.consistency > 0.8);
+    }
+
+    fn dyadic(event_type: EventType, source: &EntityId, target: &EntityId) -> Event {
+        EventBuilder::new(event_type)
+            .source(source.clone())
+            .target(target.clone())
+            .severity(0.8)
+            .build()
+            .unwrap()
+    }
+
+    /// Betrays alice (as bob), then applies the given repairs a week later.
+    /// Returns alice's perceived integrity of bob before the betrayal, after
+    /// it and after the repairs.
+    fn betray_then_repair(
+        betrayals: usize,
+        repairs: &[EventType],
+        agreeableness: Option<f32>,
+    ) -> (f32, f32, f32, crate::relationship::Relationship) {
+        use crate::relationship::Relationship;
+        use crate::types::{Duration, Timestamp};
+
+        let alice = EntityId::new("alice").unwrap();
+        let bob = EntityId::new("bob").unwrap();
+        let start = Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+        let mut relationships =
+            vec![Relationship::try_between(alice.clone(), bob.clone()).unwrap()];
+        let integrity = |rels: &[Relationship]| {
+            rels[0]
+                .trustworthiness(Direction::AToB)
+                .integrity_effective()
+        };
+
+        let before = integrity(&relationships);
+        for i in 0..betrayals {
+            let event = dyadic(EventType::Betrayal, &bob, &alice);
+            let ts = start + Duration::days(i as u64);
+            process_event_to_relationships(&event, ts, &mut relationships, agreeableness);
+        }
+        let betrayed = integrity(&relationships);
+        for (i, repair) in repairs.iter().enumerate() {
+            let event = dyadic(*repair, &bob, &alice);
+            let ts = start + Duration::days(7 + i as u64);
+            process_event_to_relationships(&event, ts, &mut relationships, agreeableness);
+        }
+        let repaired = integrity(&relationships);
+        (before, betrayed, repaired, relationships.remove(0))
+    }
+
+    #[test]
+    fn repair_recovers_trust_more_slowly_than_it_fell() {
+        let (before, betrayed, repaired, rel) =
+            betray_then_repair(1, &[EventType::Apology, EventType::Restitution], None);
+
+        assert!(betrayed < before);
+        assert!(repaired > betrayed);
+        assert!(repaired - betrayed < before - betrayed);
+        assert!(rel.perceived_risk(Direction::AToB).has_betrayal_history());
+        assert!(rel.perceived_risk(Direction::AToB).betrayal_repair() > 0.0);
+    }
+
+    #[test]
+    fn agreeable_trustors_recover_more() {
+        let (_, _, agreeable, _) = betray_then_repair(1, &[EventType::Apology], Some(0.9));
+        let (_, _, antagonistic, _) = betray_then_repair(1, &[EventType::Apology], Some(-0.9));
+        assert!(agreeable > antagonistic);
+    }
+
+    #[test]
+    fn repeat_offenders_recover_less() {
+        let apology_magnitude = |betrayals| {
+            let (_, _, _, rel) = betray_then_repair(betrayals, &[EventType::Apology], None);
+            rel.antecedent_history(Direction::AToB)
+                .last()
+                .map(|antecedent| antecedent.magnitude())
+                .unwrap()
+        };
+        assert!(apology_magnitude(3) < apology_magnitude(1));
+    }
+
+    #[test]
+    fn repair_without_prior_violation_has_no_effect() {
+        let (before, _, repaired, rel) = betray_then_repair(0, &[EventType::Apology], None);
+        assert!((repaired - before).abs() < f32::EPSILON);
+        assert!(rel.antecedent_history(Direction::AToB).is_empty());
+    }
+
+    #[test]
+    fn forgiveness_releases_resentment_and_grievance() {
+        let (_, _, _, mut rel) = betray_then_repair(1, &[], None);
+        rel.directional_mut(Direction::AToB)
+            .resentment_mut()
+            .add_delta(0.5);
+
+        let alice = EntityId::new("alice").unwrap();
+        let bob = EntityId::new("bob").unwrap();
+        let forgiveness = dyadic(EventType::Forgiveness, &bob, &alice);
+        let ts = crate::types::Timestamp::from_ymd_hms(2024, 1, 8, 0, 0, 0);
+        let mut relationships = vec![rel];
+        process_event_to_relationships(&forgiveness, ts, &mut relationships, None);
+
+        let resentment = relationships[0]
+            .directional(Direction::AToB)
+            .resentment_effective();
+        assert!(resentment < 0.5);
+
+        let interpreted = interpret_event(&forgiveness, &create_human());
+        assert!(interpreted.state_deltas.iter().any(|(path, delta)| {
+            *path == StatePath::Disposition(DispositionPath::Grievance) && *delta < 0.0
+        }));
+    }
+
+    #[test]
+    fn as_of_derives_betrayal_risk_and_resentment_from_earlier_events() {
+        use crate::types::{Duration, Timestamp};
+
+        let (_, _, _, mut rel) = betray_then_repair(1, &[], None);
+        rel.directional_mut(Direction::AToB)
+            .resentment_mut()
+            .add_delta(0.5);
+        let alice = EntityId::new("alice").unwrap();
+        let bob = EntityId::new("bob").unwrap();
+        let betrayed = Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+        let forgiven = betrayed + Duration::days(7);
+        let forgiveness = dyadic(EventType::Forgiveness, &bob, &alice);
+        let mut relationships = vec![rel];
+        process_event_to_relationships(&forgiveness, forgiven, &mut relationships, None);
+        let rel = relationships.remove(0);
+
+        let before = rel.as_of(betrayed - Duration::days(1));
+        assert!(!before
+            .perceived_risk(Direction::AToB)
+            .has_betrayal_history());
+
+        let unforgiven = rel.as_of(forgiven - Duration::days(1));
+        let risk = unforgiven.perceived_risk(Direction::AToB);
+        assert!(risk.has_betrayal_history());
+        assert!(risk.betrayal_repair().abs() < f32::EPSILON);
+        let resentment =
+            |rel: &Relationship| rel.directional(Direction::AToB).resentment_effective();
+        assert!(resentment(&unforgiven) > resentment(&rel));
+
+        assert!(rel.perceived_risk(Direction::AToB).betrayal_repair() > 0.0);
+        assert_eq!(rel.as_of(forgiven), rel);
+    }
+
     #[test]
     fn process_event_to_relationships_skips_unmapped_events() {
         use crate::relationship::Relationship;
@@ -2267,7 +2802,7 @@ mod tests {
         let timestamp = Timestamp::from_ymd_hms(2024, 1, 2, 0, 0, 0);
 
         let mut relationships = vec![Relationship::try_between(alice, bob).unwrap()];
-        process_event_to_relationships(&event, timestamp, &mut relationships);
+        process_event_to_relationships(&event, timestamp, &mut relationships, None);
 
         assert!(relationships[0]
             .antecedent_history(Direction::AToB)
@@ -2295,7 +2830,7 @@ mod tests {
         let timestamp = Timestamp::from_ymd_hms(2024, 1, 3, 0, 0, 0);
 
         let mut relationships = vec![Relationship::try_between(alice, bob).unwrap()];
-        process_event_to_relationships(&event, timestamp, &mut relationships);
+        process_event_to_relationships(&event, timestamp, &mut relationships, None);
 
         assert!(relationships[0]
             .antecedent_history(Direction::AToB)
@@ -2442,7 +2977,7 @@ mod tests {
 
         // Relationship between alice and bob (unrelated to event)
         let mut relationships = vec![Relationship::try_between(alice, bob).unwrap()];
-        process_event_to_relationships(&event, timestamp, &mut relationships);
+        process_event_to_relationships(&event, timestamp, &mut relationships, None);
 
         // No antecedents should be added since relationship doesn't match event participants
         assert!(relationships[0]
@@ -2472,7 +3007,7 @@ mod tests {
         let timestamp = Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0);
 
         let mut relationships = vec![Relationship::try_between(alice, bob).unwrap()];
-        process_event_to_relationships(&event, timestamp, &mut relationships);
+        process_event_to_relationships(&event, timestamp, &mut relationships, None);
 
         // With severity 0.0, magnitude is 0.0, so no antecedents added
         assert!(relationships[0]
@@ -98,7 +98,7 @@ impl std::fmt::Display for ItsProximalFactor {
 /// | X  |    | X  | Moderate - belongingness + capability |
 /// |    | X  | X  | Moderate - burdensomeness + capability |
 /// | X  | X  | X  | HIGH - three-factor convergence |
-#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, Default)]
 pub struct ConvergenceStatus {
     /// True if all three factors (TB, PB, AC) are elevated.
     pub is_three_factor_convergent: bool,
@@ -203,19 +203,6 @@ impl ConvergenceStatus {
     }
 }
 
-impl Default for ConvergenceStatus {
-    fn default() -> Self {
-        ConvergenceStatus {
-            is_three_factor_convergent: false,
-            highest_factor: None,
-            elevated_factor_count: 0,
-            tb_elevated: false,
-            pb_elevated: false,
-            ac_elevated: false,
-        }
-    }
-}
-
 /// Computed ITS factors from entity state.
 ///
 /// This struct holds the computed values for all ITS components.
@@ -627,8 +614,10 @@ mod tests {
 
     #[test]
     fn its_factors_has_significant_risk_at_threshold() {
-        let mut factors = ItsFactors::default();
-        factors.attempt_risk = 0.31;
+        let factors = ItsFactors {
+            attempt_risk: 0.31,
+            ..Default::default()
+        };
         assert!(factors.has_significant_risk());
     }
 
@@ -688,6 +677,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::clone_on_copy)]
     fn proximal_factor_copy_clone() {
         let f1 = ItsProximalFactor::ThwartedBelongingness;
         let f2 = f1; // Copy
@@ -841,6 +831,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::clone_on_copy)]
     fn convergence_status_copy_clone() {
         let s1 = ConvergenceStatus::from_factors(0.6, 0.6, 0.6);
         let s2 = s1; // Copy
@@ -1339,6 +1339,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::clone_on_copy)]
     fn its_contributor_debug_clone_hash() {
         use std::collections::HashSet;
 
@@ -15,18 +15,22 @@
 //! - [`NoOpDecayProcessor`] - No-op implementation for testing/robotic entities
 //! - [`InterpretedEvent`] - Interpreted event with computed deltas
 //! - [`ItsFactors`] - Computed ITS risk factors
+//! - [`RegressionReport`] - Per-dimension method and error bounds for backward regression
 //!
 //! # Internal Functions (crate visibility)
 //!
 //! The following functions are internal to the crate and used by the Simulation API:
-//! - State evolution: `advance_state`, `regress_state`, `apply_interpreted_event_to_state`, `reverse_interpreted_event_from_state`
+//! - State evolution: `advance_state`, `apply_interpreted_event_to_state`, `reverse_interpreted_event_from_state`
+//! - Reversibility: `regress_state_with_report`, `reverse_interpreted_event_with_report`
 //! - Event processing: `interpret_event`
-//! - Developmental: `apply_developmental_effects`
+//! - Developmental: `apply_developmental_effects`, `derive_formative_shifts`
+//! - Social support: `relationship_support`, `network_support`, `apply_social_support`,
+//!   `relationship_coupling`, `apply_relationship_coupling`
 //!
 //! The following functions are internal to their modules and used only in tests:
 //! - Alerts: `check_its_thresholds`, `check_spiral_alerts`
 //! - Event: `apply_interpreted_event`, `process_event`
-//! - State evolution: `apply_event_to_state`
+//! - State evolution: `apply_event_to_state`, `regress_state`
 
 mod alerts;
 mod decay;
@@ -37,16 +41,19 @@ mod feedback;
 mod its;
 mod its_contributors;
 mod reversibility;
+mod social_support;
 mod state_evolution;
 
 // Alert functions are internal to the alerts module and its tests
 pub use decay::DecayProcessor;
 #[allow(unused_imports)]
 pub use decay::{NoOpDecayProcessor, StateDecayProcessor};
-pub(crate) use developmental::apply_developmental_effects;
+pub(crate) use developmental::{apply_developmental_effects, derive_formative_shifts};
+#[cfg(test)]
 pub(crate) use event::interpret_event;
-pub(crate) use event::process_event_to_relationships;
+pub(crate) use event::interpret_event_in_context;
 pub use event::InterpretedEvent;
+pub(crate) use event::{apply_event_effects, process_event_to_relationships};
 // apply_interpreted_event and process_event are internal to the event module and its tests
 #[allow(unused_imports)]
 pub use its::{
@@ -57,8 +64,21 @@ pub use its_contributors::{
     AcContributor, ContributorActivation, ItsContributor, ItsContributors, PbContributor,
     TbContributor, ACUTE_CONTRIBUTOR_DECAY_HALF_LIFE, CONTRIBUTOR_ACTIVATION_THRESHOLD,
 };
+pub(crate) use reversibility::{
+    dimension_confidence, regress_state_with_report, reverse_interpreted_event_with_report,
+};
+pub use reversibility::{
+    DimensionRegression, RegressionMethod, RegressionReport, ANCHOR_CONFIDENCE_HALF_LIFE,
+    APPROXIMATED_EVENT_CONFIDENCE_FACTOR, OBSERVATION_RESOLUTION, SPIRAL_CONFIDENCE_FACTOR,
+};
+pub(crate) use social_support::{
+    apply_relationship_coupling, apply_social_support, network_support, relationship_coupling,
+    relationship_quality_from_support, relationship_support,
+};
+pub use social_support::{
+    NO_SUPPORT_RELATIONSHIP_QUALITY, RELATIONSHIP_COUPLING_WEIGHT, SUPPORT_RECENCY_HALF_LIFE_DAYS,
+};
 pub(crate) use state_evolution::{
-    advance_state, apply_interpreted_event_to_state, regress_state,
-    reverse_interpreted_event_from_state,
+    advance_state, apply_interpreted_event_to_state, reverse_interpreted_event_from_state,
 };
 // apply_event_to_state is internal to the state_evolution module and its tests
@@ -1,4 +1,1032 @@
 //! Delta reversibility logic for backward regression.
 //!
-//! This module is reserved for future implementation of decay reversal
-//! for backward time regression.
+//! Forward evolution decays every delta toward zero (acute and chronic
+//! components at different rates) and adds event deltas on top. Backward
+//! regression inverts that model one dimension at a time:
+//!
+//! - Decay is inverted in closed form from each value's own half-lives.
+//! - When the closed form cannot be evaluated or does not reproduce the
+//!   forward model (overflow, or deltas flushed to zero in f32), the forward
+//!   model is solved numerically by bisection.
+//! - When the inverse would push the effective value outside the
+//!   dimension's bounds, it is clamped and the clamped amount becomes error.
+//! - Increases to non-decaying dimensions (Acquired Capability) and
+//!   feedback-loop contributions cannot be walked back; the unrecoverable
+//!   amount becomes error.
+//! - Uncertainty at the anchor grows with each decay step. That growth
+//!   widens the error bound but leaves the method alone: a dimension no
+//!   event touched is still recovered by the exact inverse.
+//!
+//! Every step records its outcome in a [`RegressionReport`], which carries
+//! a per-`StatePath` method and error bound for the regressed state.
+
+use crate::enums::{MentalHealthPath, PersonCharacteristicsPath, SocialCognitionPath, StatePath};
+use crate::processor::InterpretedEvent;
+use crate::state::{IndividualState, StateValue};
+use crate::types::Duration;
+use std::collections::HashMap;
+
+/// Resolution at which an observed delta is known.
+///
+/// Deltas closer together than this at the anchor are treated as
+/// indistinguishable. Regressing decay amplifies this uncertainty by the
+/// decay growth factor, so error bounds widen with distance from the anchor.
+pub const OBSERVATION_RESOLUTION: f32 = 0.001;
+
+/// Relative tolerance when checking a closed-form inverse against the
+/// forward model.
+const FORWARD_CHECK_TOLERANCE: f32 = 1e-4;
+
+/// Number of bisection iterations for numerical inversion.
+const BISECTION_ITERATIONS: u32 = 64;
+
+/// Distance from the anchor at which confidence halves.
+///
+/// Events that were never recorded accumulate with distance in either
+/// direction, so even an exactly recovered value is an extrapolation far
+/// from the observation.
+pub const ANCHOR_CONFIDENCE_HALF_LIFE: Duration = Duration::years(2);
+
+/// Confidence multiplier for dimensions affected by a feedback loop (spiral).
+pub const SPIRAL_CONFIDENCE_FACTOR: f32 = 0.75;
+
+/// Confidence multiplier applied per event whose reversal was approximated.
+pub const APPROXIMATED_EVENT_CONFIDENCE_FACTOR: f32 = 0.9;
+
+/// How a dimension's earlier value was recovered.
+///
+/// Variants are ordered from most to least reliable; combining two methods
+/// keeps the less reliable one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, PartialOrd, Ord)]
+pub enum RegressionMethod {
+    /// Closed-form inverse of the forward model.
+    #[default]
+    Exact,
+    /// The forward model was solved numerically.
+    Numerical,
+    /// The inverse left the dimension's bounds and was clamped.
+    Bounded,
+    /// The forward model discarded information (e.g., Acquired Capability).
+    Irreversible,
+}
+
+impl RegressionMethod {
+    /// Returns true if the value was recovered exactly.
+    #[must_use]
+    pub const fn is_exact(&self) -> bool {
+        matches!(self, RegressionMethod::Exact)
+    }
+
+    /// Returns the less reliable of two methods.
+    #[must_use]
+    pub fn combine(self, other: RegressionMethod) -> RegressionMethod {
+        self.max(other)
+    }
+
+    /// Returns the confidence multiplier for values recovered this way.
+    #[must_use]
+    pub const fn confidence_weight(&self) -> f32 {
+        match self {
+            RegressionMethod::Exact => 1.0,
+            RegressionMethod::Numerical => 0.95,
+            RegressionMethod::Bounded => 0.75,
+            RegressionMethod::Irreversible => 0.6,
+        }
+    }
+}
+
+impl std::fmt::Display for RegressionMethod {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RegressionMethod::Exact => write!(f, "Exact"),
+            RegressionMethod::Numerical => write!(f, "Numerical"),
+            RegressionMethod::Bounded => write!(f, "Bounded"),
+            RegressionMethod::Irreversible => write!(f, "Irreversible"),
+        }
+    }
+}
+
+/// Regression outcome for a single stored dimension.
+///
+/// The error bound is a half-width on the effective value: the true value
+/// lies within `effective ± error_bound`, capped at the dimension's range.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct DimensionRegression {
+    method: RegressionMethod,
+    error_bound: f32,
+    clamp_count: u32,
+    approximated_events: u32,
+}
+
+impl DimensionRegression {
+    /// Returns the least reliable method used for this dimension.
+    #[must_use]
+    pub fn method(&self) -> RegressionMethod {
+        self.method
+    }
+
+    /// Returns the error bound on the regressed effective value.
+    #[must_use]
+    pub fn error_bound(&self) -> f32 {
+        self.error_bound
+    }
+
+    /// Returns how many regression steps clamped this dimension to a bound.
+    #[must_use]
+    pub fn clamp_count(&self) -> u32 {
+        self.clamp_count
+    }
+
+    /// Returns true if the dimension was clamped at least once.
+    #[must_use]
+    pub fn was_clamped(&self) -> bool {
+        self.clamp_count > 0
+    }
+
+    /// Returns how many reversed events could not be undone exactly here.
+    #[must_use]
+    pub fn approximated_events(&self) -> u32 {
+        self.approximated_events
+    }
+}
+
+/// Per-dimension diagnostics for a backward regression.
+///
+/// Stored dimensions are tracked directly. Computed paths (TB, PB, desire,
+/// attempt risk) and composites (Resource, Force) derive their method and
+/// error bound from their inputs. HEXACO traits are not regressed: they
+/// drift with age through normative maturation and formative shifts, so
+/// they report an exact method but lose confidence with distance from the
+/// anchor.
+///
+/// # Examples
+///
+/// ```
+/// use eventsim_rs::simulation::{RegressionMethod, RegressionReport};
+/// use eventsim_rs::enums::{MoodPath, StatePath};
+///
+/// let report = RegressionReport::new();
+/// let path = StatePath::Mood(MoodPath::Valence);
+///
+/// assert!(report.is_exact());
+/// assert_eq!(report.method(path), RegressionMethod::Exact);
+/// assert_eq!(report.error_bound(path), 0.0);
+/// ```
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct RegressionReport {
+    dimensions: HashMap<StatePath, DimensionRegression>,
+}
+
+impl RegressionReport {
+    /// Creates an empty report (everything exact).
+    #[must_use]
+    pub fn new() -> Self {
+        RegressionReport::default()
+    }
+
+    /// Returns the tracked outcome for a stored dimension, if any.
+    #[must_use]
+    pub fn get(&self, path: StatePath) -> Option<&DimensionRegression> {
+        self.dimensions.get(&path)
+    }
+
+    /// Returns the method for a path, deriving computed paths from inputs.
+    #[must_use]
+    pub fn method(&self, path: StatePath) -> RegressionMethod {
+        match derived_inputs(path) {
+            Some(inputs) => inputs
+                .iter()
+                .map(|(input, _)| self.method(*input))
+                .fold(RegressionMethod::Exact, RegressionMethod::combine),
+            None => self
+                .dimensions
+                .get(&path)
+                .map_or(RegressionMethod::Exact, DimensionRegression::method),
+        }
+    }
+
+    /// Returns the error bound for a path, propagating through computed paths.
+    ///
+    /// Computed paths use first-order propagation: each input's bound is
+    /// weighted by the sensitivity of the formula to that input.
+    #[must_use]
+    pub fn error_bound(&self, path: StatePath) -> f32 {
+        match derived_inputs(path) {
+            Some(inputs) => {
+                let propagated: f32 = inputs
+                    .iter()
+                    .map(|(input, weight)| self.error_bound(*input) * weight)
+                    .sum();
+                propagated.min(1.0)
+            }
+            None => self
+                .dimensions
+                .get(&path)
+                .map_or(0.0, DimensionRegression::error_bound),
+        }
+    }
+
+    /// Returns how many reversed events were approximated for a path.
+    ///
+    /// Computed paths sum the counts of their inputs.
+    #[must_use]
+    pub fn approximated_events(&self, path: StatePath) -> u32 {
+        match derived_inputs(path) {
+            Some(inputs) => inputs
+                .iter()
+                .map(|(input, _)| self.approximated_events(*input))
+                .sum(),
+            None => self
+                .dimensions
+                .get(&path)
+                .map_or(0, DimensionRegression::approximated_events),
+        }
+    }
+
+    /// Returns true if every tracked dimension was recovered exactly.
+    #[must_use]
+    pub fn is_exact(&self) -> bool {
+        self.dimensions.values().all(|d| d.method.is_exact())
+    }
+
+    /// Returns the least reliable method across all tracked dimensions.
+    #[must_use]
+    pub fn worst_method(&self) -> RegressionMethod {
+        self.dimensions
+            .values()
+            .map(DimensionRegression::method)
+            .fold(RegressionMethod::Exact, RegressionMethod::combine)
+    }
+
+    /// Returns an iterator over tracked stored dimensions.
+    pub fn iter(&self) -> impl Iterator<Item = (&StatePath, &DimensionRegression)> {
+        self.dimensions.iter()
+    }
+
+    /// Returns the number of tracked dimensions.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.dimensions.len()
+    }
+
+    /// Returns true if no dimensions have been tracked.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.dimensions.is_empty()
+    }
+
+    fn entry(&mut self, path: StatePath) -> &mut DimensionRegression {
+        self.dimensions.entry(path).or_default()
+    }
+}
+
+/// Returns the inputs and sensitivities of a computed path.
+///
+/// Sensitivities are upper bounds of the partial derivatives over the unit
+/// range (e.g., PB = liability * self_hate has partials of at most 1).
+fn derived_inputs(path: StatePath) -> Option<&'static [(StatePath, f32)]> {
+    const LONELINESS: StatePath = StatePath::SocialCognition(SocialCognitionPath::Loneliness);
+    const CARING: StatePath =
+        StatePath::SocialCognition(SocialCognitionPath::PerceivedReciprocalCaring);
+    const LIABILITY: StatePath =
+        StatePath::SocialCognition(SocialCognitionPath::PerceivedLiability);
+    const SELF_HATE: StatePath = StatePath::SocialCognition(SocialCognitionPath::SelfHate);
+    const HOPELESSNESS: StatePath =
+        StatePath::MentalHealth(MentalHealthPath::InterpersonalHopelessness);
+    const CAPABILITY: StatePath = StatePath::MentalHealth(MentalHealthPath::AcquiredCapability);
+    const TB: StatePath = StatePath::MentalHealth(MentalHealthPath::ThwartedBelongingness);
+    const PB: StatePath = StatePath::MentalHealth(MentalHealthPath::PerceivedBurdensomeness);
+    const DESIRE: StatePath = StatePath::MentalHealth(MentalHealthPath::SuicidalDesire);
+
+    match path {
+        StatePath::MentalHealth(MentalHealthPath::ThwartedBelongingness) => {
+            Some(&[(LONELINESS, 0.5), (CARING, 0.5)])
+        }
+        StatePath::MentalHealth(MentalHealthPath::PerceivedBurdensomeness) => {
+            Some(&[(LIABILITY, 1.0), (SELF_HATE, 1.0)])
+        }
+        StatePath::MentalHealth(MentalHealthPath::SuicidalDesire) => {
+            Some(&[(TB, 1.0), (PB, 1.0), (HOPELESSNESS, 1.0)])
+        }
+        StatePath::MentalHealth(MentalHealthPath::AttemptRisk) => {
+            Some(&[(DESIRE, 1.0), (CAPABILITY, 1.0)])
+        }
+        StatePath::PersonCharacteristics(PersonCharacteristicsPath::Resource) => Some(&[
+            (
+                StatePath::PersonCharacteristics(PersonCharacteristicsPath::CognitiveAbility),
+                0.2,
+            ),
+            (
+                StatePath::PersonCharacteristics(
+                    PersonCharacteristicsPath::EmotionalRegulationAssets,
+                ),
+                0.2,
+            ),
+            (
+                StatePath::PersonCharacteristics(PersonCharacteristicsPath::SocialCapital),
+                0.2,
+            ),
+            (
+                StatePath::PersonCharacteristics(PersonCharacteristicsPath::MaterialSecurity),
+                0.2,
+            ),
+            (
+                StatePath::PersonCharacteristics(PersonCharacteristicsPath::ExperienceDiversity),
+                0.2,
+            ),
+        ]),
+        StatePath::PersonCharacteristics(PersonCharacteristicsPath::Force) => Some(&[
+            (
+                StatePath::PersonCharacteristics(PersonCharacteristicsPath::BaselineMotivation),
+                1.0 / 3.0,
+            ),
+            (
+                StatePath::PersonCharacteristics(PersonCharacteristicsPath::PersistenceTendency),
+                1.0 / 3.0,
+            ),
+            (
+                StatePath::PersonCharacteristics(PersonCharacteristicsPath::CuriosityTendency),
+                1.0 / 3.0,
+            ),
+        ]),
+        _ => None,
+    }
+}
+
+/// Computes a reliability score in [0, 1] for one dimension of a state.
+///
+/// The score multiplies together:
+/// - the unexplained fraction of the dimension's range (`1 - error / span`)
+/// - the weight of the least reliable recovery method
+/// - a penalty per event whose reversal was approximated
+/// - a spiral penalty if the value (or any input) is feedback-loop affected
+/// - a halving per [`ANCHOR_CONFIDENCE_HALF_LIFE`] of distance from the anchor
+///
+/// HEXACO traits are not regressed and only lose confidence with distance.
+#[must_use]
+pub(crate) fn dimension_confidence(
+    report: &RegressionReport,
+    state: &IndividualState,
+    path: StatePath,
+    anchor_distance: Duration,
+) -> f32 {
+    let distance_factor = 0.5_f64
+        .powf(anchor_distance.as_seconds() as f64 / ANCHOR_CONFIDENCE_HALF_LIFE.as_seconds() as f64)
+        as f32;
+
+    let stored = state.get(path);
+    let range = stored.map_or(1.0, span);
+    let relative_error = if range > 0.0 {
+        (report.error_bound(path) / range).min(1.0)
+    } else {
+        0.0
+    };
+
+    let spiral_factor = if is_feedback_affected(state, path) {
+        SPIRAL_CONFIDENCE_FACTOR
+    } else {
+        1.0
+    };
+
+    let event_factor =
+        APPROXIMATED_EVENT_CONFIDENCE_FACTOR.powi(report.approximated_events(path) as i32);
+
+    let confidence = (1.0 - relative_error)
+        * report.method(path).confidence_weight()
+        * event_factor
+        * spiral_factor
+        * distance_factor;
+    confidence.clamp(0.0, 1.0)
+}
+
+/// Returns true if a path (or any input of a computed path) is in a spiral.
+fn is_feedback_affected(state: &IndividualState, path: StatePath) -> bool {
+    match derived_inputs(path) {
+        Some(inputs) => inputs
+            .iter()
+            .any(|(input, _)| is_feedback_affected(state, *input)),
+        None => state
+            .get(path)
+            .is_some_and(StateValue::is_feedback_loop_affected),
+    }
+}
+
+/// Regresses every stored dimension backward by `duration`, recording
+/// per-dimension outcomes in `report`.
+#[must_use]
+pub(crate) fn regress_state_with_report(
+    state: IndividualState,
+    duration: Duration,
+    report: &mut RegressionReport,
+) -> IndividualState {
+    let mut new_state = state;
+
+    if duration.is_zero() {
+        return new_state;
+    }
+
+    for path in StatePath::all() {
+        let Some(value) = new_state.get_mut(path) else {
+            continue;
+        };
+        if !value.decays() {
+            continue;
+        }
+        reverse_value_decay(value, duration, report.entry(path));
+    }
+
+    new_state
+}
+
+/// Reverses an interpreted event, recording per-dimension outcomes.
+///
+/// Acquired Capability increases are left in place (permanent per ITS
+/// theory); the amount that may not have existed before the event is
+/// recorded as error instead.
+#[must_use]
+pub(crate) fn reverse_interpreted_event_with_report(
+    state: IndividualState,
+    interpreted: &InterpretedEvent,
+    report: &mut RegressionReport,
+) -> IndividualState {
+    let mut new_state = crate::processor::reverse_interpreted_event_from_state(state, interpreted);
+
+    for (path, delta) in &interpreted.state_deltas {
+        if delta.abs() < f64::from(f32::EPSILON) {
+            continue;
+        }
+        let Some(value) = new_state.get_mut(*path) else {
+            continue;
+        };
+        let outcome = report.entry(*path);
+
+        if matches!(
+            path,
+            StatePath::MentalHealth(MentalHealthPath::AcquiredCapability)
+        ) {
+            outcome.method = outcome.method.combine(RegressionMethod::Irreversible);
+            outcome.error_bound = (outcome.error_bound + delta.abs() as f32).min(span(value));
+            outcome.approximated_events += 1;
+            continue;
+        }
+
+        if clamp_to_bounds(value, outcome) {
+            outcome.approximated_events += 1;
+        }
+    }
+
+    new_state
+}
+
+/// Inverts decay on a single value over `elapsed`, updating `outcome`.
+fn reverse_value_decay(
+    value: &mut StateValue,
+    elapsed: Duration,
+    outcome: &mut DimensionRegression,
+) {
+    let (Some(half_life), Some(chronic_half_life)) =
+        (value.decay_half_life(), value.chronic_decay_half_life())
+    else {
+        return;
+    };
+    if half_life.is_zero() {
+        return;
+    }
+
+    let acute_growth = growth_factor(elapsed, half_life);
+    let chronic_growth = growth_factor(elapsed, chronic_half_life);
+
+    // Uncertainty carried from the anchor (or earlier steps) grows with the
+    // faster-decaying component, plus the anchor's own resolution.
+    outcome.error_bound = (outcome.error_bound as f64 * acute_growth
+        + OBSERVATION_RESOLUTION as f64 * (acute_growth - 1.0))
+        .min(f64::from(span(value))) as f32;
+
+    if value.is_feedback_loop_affected() {
+        outcome.method = outcome.method.combine(RegressionMethod::Irreversible);
+        outcome.error_bound = (outcome.error_bound + value.delta().abs()).min(span(value));
+    }
+
+    let acute = value.acute_delta();
+    let chronic = value.chronic_delta();
+    if acute == 0.0 && chronic == 0.0 {
+        return;
+    }
+
+    let (min_delta, max_delta) = admissible_delta_range(value);
+    let mut method = RegressionMethod::Exact;
+
+    let acute_inverse = invert_component(
+        value,
+        acute,
+        acute_growth,
+        false,
+        elapsed,
+        min_delta,
+        max_delta,
+    );
+    let chronic_inverse = invert_component(
+        value,
+        chronic,
+        chronic_growth,
+        true,
+        elapsed,
+        min_delta,
+        max_delta,
+    );
+
+    let mut components = [0.0_f32; 2];
+    for (slot, inverse) in components.iter_mut().zip([acute_inverse, chronic_inverse]) {
+        match inverse {
+            Inversion::ClosedForm(x) => *slot = x,
+            Inversion::Numerical(x) => {
+                method = method.combine(RegressionMethod::Numerical);
+                *slot = x;
+            }
+            Inversion::Unreachable(x) => {
+                method = method.combine(RegressionMethod::Bounded);
+                outcome.clamp_count += 1;
+                outcome.error_bound = span(value);
+                *slot = x;
+            }
+        }
+    }
+
+    value.set_delta_components(components[0], components[1]);
+    outcome.method = outcome.method.combine(method);
+    let _ = clamp_to_bounds(value, outcome);
+}
+
+/// Result of inverting one delta component.
+enum Inversion {
+    /// Closed-form inverse that reproduces the forward model.
+    ClosedForm(f32),
+    /// Inverse found by bisection on the forward model.
+    Numerical(f32),
+    /// No admissible value reproduces the observation; nearest bound returned.
+    Unreachable(f32),
+}
+
+/// Inverts decay for one component, preferring the closed form.
+fn invert_component(
+    value: &StateValue,
+    observed: f32,
+    growth: f64,
+    chronic: bool,
+    elapsed: Duration,
+    min_delta: f32,
+    max_delta: f32,
+) -> Inversion {
+    if observed == 0.0 {
+        return Inversion::ClosedForm(0.0);
+    }
+
+    let closed = observed as f64 * growth;
+    if closed.is_finite() && closed.abs() <= f64::from(f32::MAX) {
+        let candidate = closed as f32;
+        let forward = forward_component(value, candidate, chronic, elapsed);
+        if (forward - observed).abs() <= FORWARD_CHECK_TOLERANCE * observed.abs().max(1.0) {
+            return Inversion::ClosedForm(candidate);
+        }
+    }
+
+    solve_numerically(value, observed, chronic, elapsed, min_delta, max_delta)
+}
+
+/// Finds a component value whose forward decay reproduces `observed`.
+///
+/// The forward model is monotonic in the component, so bisection over the
+/// admissible range converges. If the observation lies outside the forward
+/// image of that range, the nearest endpoint is returned as unreachable.
+fn solve_numerically(
+    value: &StateValue,
+    observed: f32,
+    chronic: bool,
+    elapsed: Duration,
+    min_delta: f32,
+    max_delta: f32,
+) -> Inversion {
+    let forward_min = forward_component(value, min_delta, chronic, elapsed);
+    let forward_max = forward_component(value, max_delta, chronic, elapsed);
+
+    if observed < forward_min {
+        return Inversion::Unreachable(min_delta);
+    }
+    if observed > forward_max {
+        return Inversion::Unreachable(max_delta);
+    }
+
+    let mut low = min_delta;
+    let mut high = max_delta;
+    for _ in 0..BISECTION_ITERATIONS {
+        let mid = low + (high - low) / 2.0;
+        if forward_component(value, mid, chronic, elapsed) < observed {
+            low = mid;
+        } else {
+            high = mid;
+        }
+    }
+
+    Inversion::Numerical(low + (high - low) / 2.0)
+}
+
+/// Runs the forward decay model on a single component.
+fn forward_component(value: &StateValue, component: f32, chronic: bool, elapsed: Duration) -> f32 {
+    let mut probe = value.clone();
+    if chronic {
+        probe.set_delta_components(0.0, component);
+    } else {
+        probe.set_delta_components(component, 0.0);
+    }
+    probe.apply_decay(elapsed);
+    if chronic {
+        probe.chronic_delta()
+    } else {
+        probe.acute_delta()
+    }
+}
+
+/// Clamps a value's delta so its raw effective value stays within bounds.
+///
+/// Both components are scaled proportionally; the removed amount is added
+/// to the error bound. Returns true if the value was clamped.
+fn clamp_to_bounds(value: &mut StateValue, outcome: &mut DimensionRegression) -> bool {
+    let (min_delta, max_delta) = admissible_delta_range(value);
+    let total = value.delta();
+    if total >= min_delta && total <= max_delta {
+        return false;
+    }
+
+    let target = total.clamp(min_delta, max_delta);
+    let scale = if total.abs() > f32::EPSILON {
+        target / total
+    } else {
+        0.0
+    };
+    value.set_delta_components(value.acute_delta() * scale, value.chronic_delta() * scale);
+
+    outcome.method = outcome.method.combine(RegressionMethod::Bounded);
+    outcome.clamp_count += 1;
+    outcome.error_bound = (outcome.error_bound + (total - target).abs()).min(span(value));
+    true
+}
+
+/// Returns the delta range that keeps the raw effective value in bounds.
+fn admissible_delta_range(value: &StateValue) -> (f32, f32) {
+    (
+        value.min_bound() - value.base(),
+        value.max_bound() - value.base(),
+    )
+}
+
+/// Returns the width of a value's bounded range.
+fn span(value: &StateValue) -> f32 {
+    (value.max_bound() - value.min_bound()).max(0.0)
+}
+
+/// Returns 2^(elapsed / half_life), the inverse of the decay factor.
+fn growth_factor(elapsed: Duration, half_life: Duration) -> f64 {
+    let elapsed_seconds = elapsed.as_seconds() as f64;
+    let half_life_seconds = half_life.as_seconds() as f64;
+    if half_life_seconds <= 0.0 {
+        return 1.0;
+    }
+    2.0_f64.powf(elapsed_seconds / half_life_seconds)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entity::EntityBuilder;
+    use crate::enums::{EventType, MoodPath, NeedsPath, Species};
+    use crate::event::EventBuilder;
+    use crate::processor::interpret_event;
+
+    const VALENCE: StatePath = StatePath::Mood(MoodPath::Valence);
+    const CAPABILITY: StatePath = StatePath::MentalHealth(MentalHealthPath::AcquiredCapability);
+
+    #[test]
+    fn regression_method_combine_keeps_least_reliable() {
+        assert_eq!(
+            RegressionMethod::Exact.combine(RegressionMethod::Numerical),
+            RegressionMethod::Numerical
+        );
+        assert_eq!(
+            RegressionMethod::Irreversible.combine(RegressionMethod::Bounded),
+            RegressionMethod::Irreversible
+        );
+        assert!(RegressionMethod::default().is_exact());
+        assert_eq!(format!("{}", RegressionMethod::Bounded), "Bounded");
+    }
+
+    #[test]
+    fn closed_form_inverse_round_trips_forward_decay() {
+        let mut state = IndividualState::new();
+        state.mood_mut().add_valence_delta(0.4);
+        state.needs_mut().stress_mut().add_chronic_delta(0.2);
+        let original = state.clone();
+
+        let mut forward = state;
+        forward.apply_decay(Duration::hours(6));
+
+        let mut report = RegressionReport::new();
+        let regressed = regress_state_with_report(forward, Duration::hours(6), &mut report);
+
+        assert!((regressed.mood().valence_delta() - 0.4).abs() < 1e-5);
+        let stress = regressed.needs().stress();
+        assert!((stress.chronic_delta() - original.needs().stress().chronic_delta()).abs() < 1e-5);
+        assert!(stress.acute_delta().abs() < 1e-6);
+        assert!(report.is_exact());
+    }
+
+    #[test]
+    fn regression_uses_each_dimension_half_life() {
+        // Dominance has a 12-hour half-life, valence 6 hours.
+        let mut state = IndividualState::new();
+        state.mood_mut().add_valence_delta(0.1);
+        state.mood_mut().add_dominance_delta(0.1);
+
+        let mut report = RegressionReport::new();
+        let regressed = regress_state_with_report(state, Duration::hours(12), &mut report);
+
+        assert!((regressed.mood().valence_delta() - 0.4).abs() < 1e-5);
+        assert!((regressed.mood().dominance_delta() - 0.2).abs() < 1e-5);
+    }
+
+    #[test]
+    fn error_bound_widens_with_distance_from_anchor() {
+        let state = IndividualState::new();
+
+        let mut near = RegressionReport::new();
+        let _ = regress_state_with_report(state.clone(), Duration::hours(6), &mut near);
+        let mut far = RegressionReport::new();
+        let _ = regress_state_with_report(state, Duration::days(2), &mut far);
+
+        assert!(near.error_bound(VALENCE) > 0.0);
+        assert!(far.error_bound(VALENCE) > near.error_bound(VALENCE));
+        // Capped at the valence range width.
+        assert!(far.error_bound(VALENCE) <= 2.0);
+        assert!(near.is_exact());
+    }
+
+    #[test]
+    fn decay_saturated_error_bound_keeps_the_method_exact() {
+        let mut report = RegressionReport::new();
+        let _ = regress_state_with_report(IndividualState::new(), Duration::days(30), &mut report);
+
+        let outcome = report.get(VALENCE).unwrap();
+        assert_eq!(outcome.method(), RegressionMethod::Exact);
+        assert!((outcome.error_bound() - 2.0).abs() < 1e-6);
+        assert!(report.is_exact());
+    }
+
+    #[test]
+    fn inverse_leaving_bounds_is_clamped() {
+        let mut state = IndividualState::new();
+        state.needs_mut().add_stress_delta(0.3);
+
+        let mut report = RegressionReport::new();
+        let regressed = regress_state_with_report(state, Duration::days(2), &mut report);
+
+        let stress = regressed.needs().stress();
+        assert!(stress.effective_raw() <= stress.max_bound() + 1e-6);
+        let outcome = report.get(StatePath::Needs(NeedsPath::Stress)).unwrap();
+        assert_eq!(outcome.method(), RegressionMethod::Bounded);
+        assert!(outcome.was_clamped());
+        assert!(outcome.error_bound() > 0.0);
+        assert!(!report.is_exact());
+    }
+
+    #[test]
+    fn flushed_forward_model_is_solved_or_bounded() {
+        // Over 100 years a 6-hour half-life flushes any delta to zero in f32,
+        // so a nonzero observation is unreachable from any admissible value.
+        let mut value = StateValue::new(0.0)
+            .with_bounds(-1.0, 1.0)
+            .with_decay_half_life(Duration::hours(6))
+            .with_delta(0.2);
+        let mut outcome = DimensionRegression::default();
+        reverse_value_decay(&mut value, Duration::years(100), &mut outcome);
+
+        assert_eq!(outcome.method(), RegressionMethod::Bounded);
+        assert!((value.delta() - 1.0).abs() < 1e-6);
+        assert!((outcome.error_bound() - 2.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn zero_half_life_leaves_value_unchanged() {
+        let mut value = StateValue::new(0.5)
+            .with_decay_half_life(Duration::zero())
+            .with_delta(0.3);
+        let mut outcome = DimensionRegression::default();
+        reverse_value_decay(&mut value, Duration::days(1), &mut outcome);
+
+        assert!((value.delta() - 0.3).abs() < f32::EPSILON);
+        assert!(outcome.method().is_exact());
+    }
+
+    #[test]
+    fn negative_delta_grows_in_magnitude() {
+        let mut value = StateValue::new(0.0)
+            .with_bounds(-1.0, 1.0)
+            .with_decay_half_life(Duration::hours(6))
+            .with_delta(-0.3);
+        let mut outcome = DimensionRegression::default();
+        reverse_value_decay(&mut value, Duration::hours(6), &mut outcome);
+
+        assert!((value.delta() + 0.6).abs() < 1e-5);
+        assert!(outcome.method().is_exact());
+    }
+
+    #[test]
+    fn extreme_durations_stay_finite_and_bounded() {
+        for delta in [1e-10_f32, 0.1, 10.0] {
+            let mut value = StateValue::new(0.5)
+                .with_bounds(0.0, 1.0)
+                .with_decay_half_life(Duration::hours(1))
+                .with_delta(delta);
+            let mut outcome = DimensionRegression::default();
+            reverse_value_decay(&mut value, Duration::days(365 * 1000), &mut outcome);
+
+            assert!(value.delta().is_finite());
+            assert!(value.effective_raw() <= 1.0 + 1e-6);
+            assert!(!outcome.method().is_exact());
+            assert!(outcome.error_bound() <= 1.0);
+        }
+    }
+
+    #[test]
+    fn very_long_half_life_barely_changes_delta() {
+        let mut value = StateValue::new(0.5)
+            .with_decay_half_life(Duration::days(1000))
+            .with_delta(0.3);
+        let mut outcome = DimensionRegression::default();
+        reverse_value_decay(&mut value, Duration::seconds(1), &mut outcome);
+
+        assert!((value.delta() - 0.3).abs() < 1e-5);
+    }
+
+    #[test]
+    fn numerical_inversion_matches_forward_model() {
+        let value = StateValue::new(0.0)
+            .with_bounds(-1.0, 1.0)
+            .with_decay_half_life(Duration::days(1));
+        let elapsed = Duration::days(1);
+
+        let Inversion::Numerical(x) = solve_numerically(&value, 0.25, false, elapsed, -1.0, 1.0)
+        else {
+            panic!("expected numerical inversion");
+        };
+        assert!((x - 0.5).abs() < 1e-4);
+        assert!((forward_component(&value, x, false, elapsed) - 0.25).abs() < 1e-5);
+
+        assert!(matches!(
+            solve_numerically(&value, 0.9, false, elapsed, -1.0, 1.0),
+            Inversion::Unreachable(_)
+        ));
+    }
+
+    #[test]
+    fn non_decaying_dimensions_are_left_unchanged() {
+        let mut state = IndividualState::new();
+        state.mental_health_mut().add_acquired_capability_delta(0.3);
+
+        let mut report = RegressionReport::new();
+        let regressed = regress_state_with_report(state, Duration::years(5), &mut report);
+
+        assert!((regressed.mental_health().acquired_capability().delta() - 0.3).abs() < 1e-6);
+        assert!(report.get(CAPABILITY).is_none());
+    }
+
+    #[test]
+    fn feedback_loop_affected_values_are_irreversible() {
+        let mut state = IndividualState::new();
+        state.needs_mut().add_stress_delta(0.01);
+        state.needs_mut().stress_mut().mark_feedback_loop_affected();
+
+        let mut report = RegressionReport::new();
+        let _ = regress_state_with_report(state, Duration::hours(1), &mut report);
+
+        assert_eq!(
+            report.method(StatePath::Needs(NeedsPath::Stress)),
+            RegressionMethod::Irreversible
+        );
+    }
+
+    #[test]
+    fn reversing_trauma_records_irreversible_capability() {
+        let entity = EntityBuilder::new()
+            .species(Species::Human)
+            .build()
+            .unwrap();
+        let event = EventBuilder::new(EventType::Violence)
+            .severity(0.8)
+            .build()
+            .unwrap();
+        let interpreted = interpret_event(&event, &entity);
+        let ac_delta = interpreted
+            .state_deltas
+            .iter()
+            .find(|(path, _)| *path == CAPABILITY)
+            .map(|(_, delta)| *delta as f32)
+            .unwrap();
+
+        let state = crate::processor::apply_interpreted_event_to_state(
+            IndividualState::new(),
+            &interpreted,
+        );
+        let mut report = RegressionReport::new();
+        let reversed = reverse_interpreted_event_with_report(state, &interpreted, &mut report);
+
+        assert!((reversed.mental_health().acquired_capability().delta() - ac_delta).abs() < 1e-6);
+        assert_eq!(report.method(CAPABILITY), RegressionMethod::Irreversible);
+        assert!((report.error_bound(CAPABILITY) - ac_delta).abs() < 1e-6);
+        assert_eq!(report.method(VALENCE), RegressionMethod::Exact);
+    }
+
+    #[test]
+    fn computed_paths_propagate_input_bounds() {
+        let mut report = RegressionReport::new();
+        report
+            .entry(StatePath::SocialCognition(SocialCognitionPath::Loneliness))
+            .error_bound = 0.2;
+        report
+            .entry(StatePath::SocialCognition(
+                SocialCognitionPath::PerceivedReciprocalCaring,
+            ))
+            .error_bound = 0.1;
+        report.entry(CAPABILITY).method = RegressionMethod::Irreversible;
+
+        let tb = StatePath::MentalHealth(MentalHealthPath::ThwartedBelongingness);
+        assert!((report.error_bound(tb) - 0.15).abs() < 1e-6);
+
+        let risk = StatePath::MentalHealth(MentalHealthPath::AttemptRisk);
+        assert_eq!(report.method(risk), RegressionMethod::Irreversible);
+        assert_eq!(report.worst_method(), RegressionMethod::Irreversible);
+
+        let resource = StatePath::PersonCharacteristics(PersonCharacteristicsPath::Resource);
+        assert_eq!(report.error_bound(resource), 0.0);
+        assert_eq!(report.len(), 3);
+        assert!(!report.is_empty());
+        assert_eq!(report.iter().count(), 3);
+    }
+
+    #[test]
+    fn confidence_is_full_at_anchor_and_decays_with_distance() {
+        let report = RegressionReport::new();
+        let state = IndividualState::new();
+
+        let at_anchor = dimension_confidence(&report, &state, VALENCE, Duration::zero());
+        let one_half_life =
+            dimension_confidence(&report, &state, VALENCE, ANCHOR_CONFIDENCE_HALF_LIFE);
+
+        assert!((at_anchor - 1.0).abs() < f32::EPSILON);
+        assert!((one_half_life - 0.5).abs() < 1e-4);
+    }
+
+    #[test]
+    fn confidence_penalizes_irreversible_and_approximated_events() {
+        let mut report = RegressionReport::new();
+        let outcome = report.entry(CAPABILITY);
+        outcome.method = RegressionMethod::Irreversible;
+        outcome.error_bound = 0.2;
+        outcome.approximated_events = 1;
+        let state = IndividualState::new();
+
+        let confidence = dimension_confidence(&report, &state, CAPABILITY, Duration::zero());
+        let expected = 0.8 * RegressionMethod::Irreversible.confidence_weight() * 0.9;
+        assert!((confidence - expected).abs() < 1e-5);
+
+        // Attempt risk inherits the capability penalty
+        let risk = StatePath::MentalHealth(MentalHealthPath::AttemptRisk);
+        assert_eq!(report.approximated_events(risk), 1);
+        assert!(dimension_confidence(&report, &state, risk, Duration::zero()) < 1.0);
+        assert!(
+            (dimension_confidence(&report, &state, VALENCE, Duration::zero()) - 1.0).abs() < 1e-6
+        );
+    }
+
+    #[test]
+    fn confidence_penalizes_spirals_through_computed_paths() {
+        let report = RegressionReport::new();
+        let mut state = IndividualState::new();
+        state
+            .social_cognition_mut()
+            .loneliness_mut()
+            .mark_feedback_loop_affected();
+
+        let loneliness = StatePath::SocialCognition(SocialCognitionPath::Loneliness);
+        let tb = StatePath::MentalHealth(MentalHealthPath::ThwartedBelongingness);
+        let pb = StatePath::MentalHealth(MentalHealthPath::PerceivedBurdensomeness);
+
+        let expected = SPIRAL_CONFIDENCE_FACTOR;
+        assert!(
+            (dimension_confidence(&report, &state, loneliness, Duration::zero()) - expected).abs()
+                < 1e-6
+        );
+        assert!(
+            (dimension_confidence(&report, &state, tb, Duration::zero()) - expected).abs() < 1e-6
+        );
+        assert!((dimension_confidence(&report, &state, pb, Duration::zero()) - 1.0).abs() < 1e-6);
+    }
+}
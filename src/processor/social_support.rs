@@ -0,0 +1,503 @@
+//! Social support from an entity's relationship network.
+//!
+//! Relationships feed back into individual state: warm, intimate and
+//! trusted partners buffer stress, reduce loneliness and lift perceived
+//! reciprocal caring. Contact recency matters, so a friend who has not
+//! been seen for a year supports less than one seen last week.
+//!
+//! # Theory Background
+//!
+//! - Cohen & Wills (1985): Stress-buffering hypothesis
+//! - Holt-Lunstad et al. (2010): Social relationships and health
+//! - Joiner (2005): Reciprocal caring as the antidote to thwarted belongingness
+//!
+//! # Key Concepts
+//!
+//! - **Partner Quality**: The partner's warmth toward the entity, shared
+//!   intimacy and the entity's trust in the partner
+//! - **Recency**: Support decays with time since the last known contact
+//! - **Network Support**: Partners combine with diminishing returns, so
+//!   each additional supportive partner helps less than the previous one
+
+use crate::enums::Direction;
+use crate::relationship::{Relationship, RelationshipStage};
+use crate::state::{IndividualState, StateValue};
+use crate::types::{Duration, EntityId, Timestamp};
+
+/// Relationship quality used for context effects when an entity has no support.
+pub const NO_SUPPORT_RELATIONSHIP_QUALITY: f64 = 0.3;
+
+/// Half-life in days for the support of a partner since last contact.
+pub const SUPPORT_RECENCY_HALF_LIFE_DAYS: f64 = 90.0;
+
+/// Fraction of the way TB/PB input bases move toward relationship targets.
+pub const RELATIONSHIP_COUPLING_WEIGHT: f32 = 0.5;
+
+/// Days over which support effects approach their full strength.
+const SUPPORT_EFFECT_TIME_CONSTANT_DAYS: f64 = 30.0;
+
+/// Maximum stress reduction from full social support.
+const MAX_STRESS_BUFFER: f32 = 0.15;
+
+/// Maximum loneliness reduction from full social support.
+const MAX_LONELINESS_REDUCTION: f32 = 0.15;
+
+/// Maximum perceived reciprocal caring increase from full social support.
+const MAX_CARING_BOOST: f32 = 0.10;
+
+/// Computes the support one relationship provides to an entity at a timestamp.
+///
+/// Quality is `0.4 * partner warmth + 0.3 * intimacy + 0.3 * trust`, scaled
+/// by recency since the latest known contact (formation, last interaction,
+/// or trust antecedent at or before `timestamp`). Returns 0.0 if the entity
+/// is not part of the relationship or it formed after `timestamp`.
+///
+/// # Arguments
+///
+/// * `relationship` - The relationship to evaluate
+/// * `entity_id` - The entity receiving support
+/// * `formed` - When the relationship was formed
+/// * `timestamp` - The timestamp to evaluate support at
+#[must_use]
+pub(crate) fn relationship_support(
+    relationship: &Relationship,
+    entity_id: &EntityId,
+    formed: Timestamp,
+    timestamp: Timestamp,
+) -> f64 {
+    if formed > timestamp {
+        return 0.0;
+    }
+    let Some(perspective) = perspective_of(relationship, entity_id) else {
+        return 0.0;
+    };
+
+    let partner_warmth = relationship
+        .directional(perspective.opposite())
+        .warmth_effective();
+    let intimacy = relationship.shared().intimacy_effective();
+    let trust = relationship.trustworthiness(perspective).overall();
+    let quality = f64::from(0.4 * partner_warmth + 0.3 * intimacy + 0.3 * trust).clamp(0.0, 1.0);
+
+    quality * contact_recency(relationship, formed, timestamp)
+}
+
+/// Returns the direction in which the entity perceives its partner.
+fn perspective_of(relationship: &Relationship, entity_id: &EntityId) -> Option<Direction> {
+    if relationship.entity_a() == entity_id {
+        Some(Direction::AToB)
+    } else if relationship.entity_b() == entity_id {
+        Some(Direction::BToA)
+    } else {
+        None
+    }
+}
+
+/// Returns the recency weight (1.0 at contact, halving every half-life).
+fn contact_recency(relationship: &Relationship, formed: Timestamp, timestamp: Timestamp) -> f64 {
+    let last_contact = last_contact_at(relationship, formed, timestamp);
+    let days_since = (timestamp - last_contact).as_days_f64();
+    0.5_f64.powf(days_since / SUPPORT_RECENCY_HALF_LIFE_DAYS)
+}
+
+/// Returns the latest known contact at or before the timestamp.
+fn last_contact_at(
+    relationship: &Relationship,
+    formed: Timestamp,
+    timestamp: Timestamp,
+) -> Timestamp {
+    let antecedents = [Direction::AToB, Direction::BToA]
+        .into_iter()
+        .flat_map(|direction| relationship.antecedent_history(direction))
+        .map(|antecedent| antecedent.timestamp());
+
+    relationship
+        .pattern()
+        .last_interaction_at(timestamp)
+        .into_iter()
+        .chain(antecedents)
+        .filter(|ts| *ts <= timestamp)
+        .fold(formed, |latest, ts| latest.max(ts))
+}
+
+/// Combines per-relationship support into network support (0.0 to 1.0).
+///
+/// Uses `1 - product(1 - support_i)`, so support grows with each partner
+/// but saturates below 1.0. Supports are sorted first so the result does
+/// not depend on iteration order.
+#[must_use]
+pub(crate) fn network_support(supports: impl IntoIterator<Item = f64>) -> f64 {
+    let mut supports: Vec<f64> = supports
+        .into_iter()
+        .map(|support| support.clamp(0.0, 1.0))
+        .collect();
+    supports.sort_by(f64::total_cmp);
+
+    let unsupported: f64 = supports.iter().map(|support| 1.0 - support).product();
+    1.0 - unsupported
+}
+
+/// Converts network support to the relationship quality used by context effects.
+///
+/// No support maps to [`NO_SUPPORT_RELATIONSHIP_QUALITY`] and full support
+/// maps to 1.0.
+#[must_use]
+pub(crate) fn relationship_quality_from_support(support: f64) -> f64 {
+    NO_SUPPORT_RELATIONSHIP_QUALITY + (1.0 - NO_SUPPORT_RELATIONSHIP_QUALITY) * support
+}
+
+/// Relationship-derived inputs to thwarted belongingness and perceived
+/// burdensomeness.
+///
+/// All values are on a 0.0 to 1.0 scale.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub(crate) struct RelationshipCoupling {
+    /// Combined warmth partners feel toward the entity, weighted by recency.
+    pub incoming_warmth: f64,
+    /// Combined closeness of Established and Intimate ties.
+    pub close_ties: f64,
+    /// Mean strain from the entity's dependence on partners and the
+    /// obligation they feel toward it.
+    pub burden_strain: f64,
+}
+
+/// Derives coupling inputs from the relationships an entity is part of.
+///
+/// Relationships formed after `timestamp` or not involving the entity are
+/// ignored, and close ties are judged by the stage at `timestamp`. Warmth
+/// and closeness combine with diminishing returns like
+/// [`network_support`]; strain is averaged over partners.
+///
+/// # Arguments
+///
+/// * `relationships` - (relationship, formation timestamp) pairs
+/// * `entity_id` - The entity whose inputs are derived
+/// * `timestamp` - The timestamp to evaluate at
+#[must_use]
+pub(crate) fn relationship_coupling<'a>(
+    relationships: impl IntoIterator<Item = (&'a Relationship, Timestamp)>,
+    entity_id: &EntityId,
+    timestamp: Timestamp,
+) -> RelationshipCoupling {
+    let mut warmths = Vec::new();
+    let mut closeness = Vec::new();
+    let mut strains = Vec::new();
+
+    for (relationship, formed) in relationships {
+        if formed > timestamp {
+            continue;
+        }
+        let Some(perspective) = perspective_of(relationship, entity_id) else {
+            continue;
+        };
+
+        let recency = contact_recency(relationship, formed, timestamp);
+        let own = relationship.directional(perspective);
+        let partner = relationship.directional(perspective.opposite());
+        let partner_warmth = f64::from(partner.warmth_effective());
+
+        warmths.push(partner_warmth * recency);
+
+        if matches!(
+            relationship.stage_at(timestamp),
+            RelationshipStage::Established | RelationshipStage::Intimate
+        ) {
+            let intimacy = f64::from(relationship.shared().intimacy_effective());
+            closeness.push((partner_warmth + intimacy) / 2.0 * recency);
+        }
+
+        let strain = (own.dependence_effective() + partner.obligation_effective()) / 2.0;
+        strains.push(f64::from(strain));
+    }
+
+    let burden_strain = if strains.is_empty() {
+        0.0
+    } else {
+        strains.iter().sum::<f64>() / strains.len() as f64
+    };
+
+    RelationshipCoupling {
+        incoming_warmth: network_support(warmths),
+        close_ties: network_support(closeness),
+        burden_strain,
+    }
+}
+
+/// Pulls TB/PB input bases toward their relationship-derived targets.
+///
+/// Each base moves [`RELATIONSHIP_COUPLING_WEIGHT`] of the way toward its
+/// target: perceived reciprocal caring toward incoming warmth, perceived
+/// liability toward burden strain, and loneliness toward the absence of
+/// close ties. Deltas from events are untouched.
+#[must_use]
+pub(crate) fn apply_relationship_coupling(
+    mut state: IndividualState,
+    coupling: &RelationshipCoupling,
+) -> IndividualState {
+    let social = state.social_cognition_mut();
+    pull_base_toward(
+        social.perceived_reciprocal_caring_mut(),
+        coupling.incoming_warmth,
+    );
+    pull_base_toward(social.perceived_liability_mut(), coupling.burden_strain);
+    pull_base_toward(social.loneliness_mut(), 1.0 - coupling.close_ties);
+    state
+}
+
+/// Moves a base by the coupling weight toward a target, within bounds.
+fn pull_base_toward(value: &mut StateValue, target: f64) {
+    let base = value.base();
+    let pulled = base + RELATIONSHIP_COUPLING_WEIGHT * (target as f32 - base);
+    value.set_base(pulled.clamp(value.min_bound(), value.max_bound()));
+}
+
+/// Applies social support buffering to state over a duration.
+///
+/// Support lowers stress and loneliness and raises perceived reciprocal
+/// caring. The effect approaches full strength over about a month.
+#[must_use]
+pub(crate) fn apply_social_support(
+    mut state: IndividualState,
+    support: f64,
+    duration: Duration,
+) -> IndividualState {
+    let days = duration.as_days_f64();
+    if support <= 0.0 || days <= 0.0 {
+        return state;
+    }
+
+    let strength = (support * (1.0 - (-days / SUPPORT_EFFECT_TIME_CONSTANT_DAYS).exp())) as f32;
+
+    state
+        .needs_mut()
+        .add_stress_delta(-MAX_STRESS_BUFFER * strength);
+    state
+        .social_cognition_mut()
+        .add_loneliness_delta(-MAX_LONELINESS_REDUCTION * strength);
+    state
+        .social_cognition_mut()
+        .add_perceived_reciprocal_caring_delta(MAX_CARING_BOOST * strength);
+
+    state
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ids() -> (EntityId, EntityId) {
+        (
+            EntityId::new("alice").unwrap(),
+            EntityId::new("bob").unwrap(),
+        )
+    }
+
+    fn supportive(alice: &EntityId, bob: &EntityId) -> Relationship {
+        let mut rel = Relationship::try_between(alice.clone(), bob.clone()).unwrap();
+        rel.directional_mut(Direction::BToA)
+            .warmth_mut()
+            .set_base(0.9);
+        rel.shared_mut().intimacy_mut().set_base(0.8);
+        *rel.trustworthiness_mut(Direction::AToB) =
+            crate::relationship::TrustworthinessFactors::with_bases(0.8, 0.8, 0.8);
+        rel
+    }
+
+    #[test]
+    fn supportive_partner_gives_more_support_than_default() {
+        let (alice, bob) = ids();
+        let ts = Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+        let default = Relationship::try_between(alice.clone(), bob.clone()).unwrap();
+
+        let low = relationship_support(&default, &alice, ts, ts);
+        let high = relationship_support(&supportive(&alice, &bob), &alice, ts, ts);
+
+        assert!(high > low);
+        assert!(high > 0.7);
+    }
+
+    #[test]
+    fn support_uses_partner_warmth_from_perspective() {
+        let (alice, bob) = ids();
+        let ts = Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+        let rel = supportive(&alice, &bob);
+
+        // Bob is warm toward Alice, not the other way around
+        let for_alice = relationship_support(&rel, &alice, ts, ts);
+        let for_bob = relationship_support(&rel, &bob, ts, ts);
+        assert!(for_alice > for_bob);
+    }
+
+    #[test]
+    fn support_decays_with_time_since_contact() {
+        let (alice, bob) = ids();
+        let formed = Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+        let rel = supportive(&alice, &bob);
+
+        let fresh = relationship_support(&rel, &alice, formed, formed);
+        let later = relationship_support(&rel, &alice, formed, formed + Duration::days(90));
+        assert!((later - fresh * 0.5).abs() < 1e-6);
+
+        let mut contacted = rel.clone();
+        contacted.pattern_mut().last_interaction = Some(formed + Duration::days(80));
+        let recent = relationship_support(&contacted, &alice, formed, formed + Duration::days(90));
+        assert!(recent > later);
+    }
+
+    #[test]
+    fn no_support_before_formation_or_for_strangers() {
+        let (alice, bob) = ids();
+        let formed = Timestamp::from_ymd_hms(2024, 6, 1, 0, 0, 0);
+        let rel = supportive(&alice, &bob);
+
+        let before = Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+        assert!(relationship_support(&rel, &alice, formed, before).abs() < f64::EPSILON);
+
+        let carol = EntityId::new("carol").unwrap();
+        assert!(relationship_support(&rel, &carol, formed, formed).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn network_support_saturates() {
+        assert!(network_support(std::iter::empty()).abs() < f64::EPSILON);
+        let one = network_support([0.5]);
+        let two = network_support([0.5, 0.5]);
+        assert!((one - 0.5).abs() < f64::EPSILON);
+        assert!((two - 0.75).abs() < f64::EPSILON);
+        assert!(network_support([1.5, 0.2]) <= 1.0);
+    }
+
+    #[test]
+    fn relationship_quality_maps_support_range() {
+        assert!(
+            (relationship_quality_from_support(0.0) - NO_SUPPORT_RELATIONSHIP_QUALITY).abs()
+                < f64::EPSILON
+        );
+        assert!((relationship_quality_from_support(1.0) - 1.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn social_support_buffers_stress_and_loneliness() {
+        let state = IndividualState::new();
+        let supported = apply_social_support(state.clone(), 0.8, Duration::days(60));
+
+        assert!(supported.needs().stress_effective() < state.needs().stress_effective());
+        assert!(
+            supported.social_cognition().loneliness_effective()
+                < state.social_cognition().loneliness_effective()
+        );
+        assert!(
+            supported
+                .social_cognition()
+                .perceived_reciprocal_caring_effective()
+                > state
+                    .social_cognition()
+                    .perceived_reciprocal_caring_effective()
+        );
+    }
+
+    #[test]
+    fn no_support_or_no_time_leaves_state_unchanged() {
+        let state = IndividualState::new();
+        assert_eq!(
+            apply_social_support(state.clone(), 0.0, Duration::days(60)),
+            state
+        );
+        assert_eq!(
+            apply_social_support(state.clone(), 0.8, Duration::zero()),
+            state
+        );
+    }
+
+    #[test]
+    fn coupling_ignores_unrelated_and_future_relationships() {
+        let (alice, bob) = ids();
+        let ts = Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+        let rel = supportive(&alice, &bob);
+        let carol = EntityId::new("carol").unwrap();
+
+        let none = relationship_coupling([(&rel, ts)], &carol, ts);
+        assert_eq!(none, RelationshipCoupling::default());
+
+        let future = relationship_coupling([(&rel, ts + Duration::days(1))], &alice, ts);
+        assert_eq!(future, RelationshipCoupling::default());
+    }
+
+    #[test]
+    fn coupling_counts_only_close_ties_for_loneliness() {
+        let (alice, bob) = ids();
+        let ts = Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+        let mut rel = supportive(&alice, &bob);
+
+        let acquaintance = relationship_coupling([(&rel, ts)], &alice, ts);
+        assert!(acquaintance.incoming_warmth > 0.8);
+        assert!(acquaintance.close_ties.abs() < f64::EPSILON);
+
+        rel.set_stage(RelationshipStage::Intimate).unwrap();
+        let intimate = relationship_coupling([(&rel, ts)], &alice, ts);
+        assert!(intimate.close_ties > 0.8);
+    }
+
+    #[test]
+    fn coupling_uses_the_stage_at_the_timestamp() {
+        use crate::enums::EventType;
+        use crate::event::EventBuilder;
+
+        let (alice, bob) = ids();
+        let ts = Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+        let mut rel = supportive(&alice, &bob).with_stage(RelationshipStage::Intimate);
+        let betrayal = EventBuilder::new(EventType::Betrayal)
+            .source(bob)
+            .target(alice.clone())
+            .severity(0.9)
+            .build()
+            .unwrap();
+        let betrayed_at = ts + Duration::days(30);
+        rel.update_stage(betrayed_at, Some(&betrayal));
+        assert_eq!(rel.stage(), RelationshipStage::Estranged);
+
+        let before = relationship_coupling([(&rel, ts)], &alice, ts);
+        assert!(before.close_ties > 0.8);
+        let after = relationship_coupling([(&rel, ts)], &alice, betrayed_at);
+        assert!(after.close_ties.abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn coupling_strain_from_dependence_and_obligation() {
+        let (alice, bob) = ids();
+        let ts = Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+        let mut rel = Relationship::try_between(alice.clone(), bob.clone()).unwrap();
+        rel.directional_mut(Direction::AToB)
+            .dependence_mut()
+            .set_base(0.8);
+        rel.directional_mut(Direction::BToA)
+            .obligation_mut()
+            .set_base(0.6);
+
+        let coupling = relationship_coupling([(&rel, ts)], &alice, ts);
+        assert!((coupling.burden_strain - 0.7).abs() < 1e-6);
+
+        // Bob's own dependence is on Alice, so he feels no strain
+        let coupling = relationship_coupling([(&rel, ts)], &bob, ts);
+        assert!(coupling.burden_strain.abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn apply_coupling_pulls_bases_toward_targets() {
+        let state = IndividualState::new();
+        let coupling = RelationshipCoupling {
+            incoming_warmth: 1.0,
+            close_ties: 1.0,
+            burden_strain: 1.0,
+        };
+        let coupled = apply_relationship_coupling(state.clone(), &coupling);
+        let social = coupled.social_cognition();
+        let before = state.social_cognition();
+
+        let expected_caring = before.perceived_reciprocal_caring().base()
+            + RELATIONSHIP_COUPLING_WEIGHT * (1.0 - before.perceived_reciprocal_caring().base());
+        assert!((social.perceived_reciprocal_caring().base() - expected_caring).abs() < 1e-6);
+        assert!(social.perceived_liability().base() > before.perceived_liability().base());
+        assert!(social.loneliness().base() < before.loneliness().base());
+    }
+}
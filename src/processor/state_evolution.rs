@@ -8,6 +8,8 @@
 use crate::enums::SocialCognitionPath;
 #[cfg(test)]
 use crate::event::Event;
+#[cfg(test)]
+use crate::processor::reversibility::{regress_state_with_report, RegressionReport};
 use crate::state::{IndividualState, SocialCognition};
 use crate::types::Duration;
 
@@ -74,15 +76,11 @@ pub(crate) fn advance_state(state: IndividualState, duration: Duration) -> Indiv
 /// let initial = IndividualState::new();
 /// let regressed = regress_state(initial, Duration::hours(6));
 /// ```
+#[cfg(test)]
 #[must_use]
 pub(crate) fn regress_state(state: IndividualState, duration: Duration) -> IndividualState {
-    let mut new_state = state;
-
-    // Reverse decay on all decayable dimensions
-    // Note: This is an approximation - we reverse by applying the inverse of decay
-    reverse_decay_on_state(&mut new_state, duration);
-
-    new_state
+    let mut report = RegressionReport::new();
+    regress_state_with_report(state, duration, &mut report)
 }
 
 fn apply_social_cognition_delta(
@@ -132,127 +130,6 @@ fn apply_social_cognition_delta(
     }
 }
 
-/// Applies decay reversal to all reversible dimensions of a state.
-fn reverse_decay_on_state(state: &mut IndividualState, duration: Duration) {
-    // Mood dimensions have 6-hour half-life
-    reverse_dimension_decay(state.mood_mut().valence_mut(), duration, Duration::hours(6));
-    reverse_dimension_decay(state.mood_mut().arousal_mut(), duration, Duration::hours(6));
-    reverse_dimension_decay(
-        state.mood_mut().dominance_mut(),
-        duration,
-        Duration::hours(6),
-    );
-
-    // Social cognition dimensions have various half-lives
-    reverse_dimension_decay(
-        state.social_cognition_mut().loneliness_mut(),
-        duration,
-        Duration::days(1),
-    );
-    reverse_dimension_decay(
-        state
-            .social_cognition_mut()
-            .perceived_reciprocal_caring_mut(),
-        duration,
-        Duration::days(2),
-    );
-    reverse_dimension_decay(
-        state.social_cognition_mut().perceived_liability_mut(),
-        duration,
-        Duration::days(3),
-    );
-    reverse_dimension_decay(
-        state.social_cognition_mut().self_hate_mut(),
-        duration,
-        Duration::days(3),
-    );
-    reverse_dimension_decay(
-        state.needs_mut().stress_mut(),
-        duration,
-        Duration::hours(12),
-    );
-    reverse_dimension_decay(
-        state.needs_mut().fatigue_mut(),
-        duration,
-        Duration::hours(8),
-    );
-    reverse_dimension_decay(state.needs_mut().purpose_mut(), duration, Duration::days(3));
-
-    // Mental health dimensions (except AC which has no decay)
-    reverse_dimension_decay(
-        state.mental_health_mut().depression_mut(),
-        duration,
-        Duration::weeks(2),
-    );
-    reverse_dimension_decay(
-        state.mental_health_mut().interpersonal_hopelessness_mut(),
-        duration,
-        Duration::weeks(2),
-    );
-    // Acquired Capability has no decay - cannot be reversed
-
-    // Disposition dimensions
-    reverse_dimension_decay(
-        state.disposition_mut().empathy_mut(),
-        duration,
-        Duration::weeks(4),
-    );
-    reverse_dimension_decay(
-        state.disposition_mut().aggression_mut(),
-        duration,
-        Duration::weeks(1),
-    );
-    reverse_dimension_decay(
-        state.disposition_mut().grievance_mut(),
-        duration,
-        Duration::weeks(1),
-    );
-
-    // Person characteristics - social capital
-    reverse_dimension_decay(
-        state.person_characteristics_mut().social_capital_mut(),
-        duration,
-        Duration::weeks(4),
-    );
-}
-
-/// Reverses decay on a single state value.
-fn reverse_dimension_decay(
-    state_value: &mut crate::state::StateValue,
-    duration: Duration,
-    half_life: Duration,
-) {
-    let current_delta = state_value.delta();
-
-    // Skip if delta is effectively zero
-    if current_delta.abs() < f32::EPSILON {
-        return;
-    }
-
-    // Compute reversal factor: exp(ln(2) * t / half_life)
-    let elapsed_ms = duration.as_millis() as f64;
-    let half_life_ms = half_life.as_millis() as f64;
-
-    if half_life_ms <= 0.0 {
-        return;
-    }
-
-    let ln2 = std::f64::consts::LN_2;
-    let exponent = ln2 * elapsed_ms / half_life_ms;
-
-    // Guard against overflow
-    if exponent > 700.0 {
-        return;
-    }
-
-    let reversal_factor = exponent.exp();
-    let original_delta = (current_delta as f64) * reversal_factor;
-
-    // Clamp to reasonable range to avoid numerical issues
-    let clamped = original_delta.clamp(-100.0, 100.0) as f32;
-    state_value.set_delta(clamped);
-}
-
 /// Applies an event's effects to state, returning a new state.
 ///
 /// This is a pure function that interprets the event and applies
@@ -1818,45 +1695,6 @@ mod tests {
         );
     }
 
-    #[test]
-    fn reverse_dimension_decay_handles_zero_half_life() {
-        // Test the half_life <= 0 early return (line 198)
-        // When half_life is zero, the function should return early without modification.
-        use crate::state::StateValue;
-
-        let mut state_value = StateValue::new(0.5).with_delta(0.3);
-        let original_delta = state_value.delta();
-        let duration = Duration::days(1);
-        let half_life = Duration::zero(); // Zero half-life triggers early return
-
-        reverse_dimension_decay(&mut state_value, duration, half_life);
-
-        // Delta should be unchanged because function returned early
-        assert!((state_value.delta() - original_delta).abs() < 0.001);
-    }
-
-    #[test]
-    fn reverse_dimension_decay_handles_extremely_long_duration() {
-        // Test the exponent > 700 early return (line 206)
-        // This guards against exp() overflow with very long durations.
-        // exponent = ln(2) * elapsed_ms / half_life_ms
-        // For exponent > 700, we need elapsed_ms / half_life_ms > 1010
-        // With 1 day half-life, 1010+ days elapsed triggers this.
-        use crate::state::StateValue;
-
-        let mut state_value = StateValue::new(0.5).with_delta(0.3);
-        let original_delta = state_value.delta();
-        let duration = Duration::years(10); // Very long duration
-        let half_life = Duration::days(1); // 1 day half-life
-
-        // exponent = ln(2) * (10*365*24*60*60*1000) / (24*60*60*1000)
-        // exponent = ln(2) * 3650 = 2530 which is > 700
-        reverse_dimension_decay(&mut state_value, duration, half_life);
-
-        // Delta should be unchanged because function returned early due to overflow guard
-        assert!((state_value.delta() - original_delta).abs() < 0.001);
-    }
-
     #[test]
     fn apply_social_cognition_delta_loneliness_acute() {
         let mut state = IndividualState::new();
@@ -2270,37 +2108,6 @@ mod tests {
         assert!(reversed.needs().purpose().delta().abs() < 0.001);
     }
 
-    #[test]
-    fn reverse_dimension_decay_with_negative_delta() {
-        use crate::state::StateValue;
-
-        let mut state_value = StateValue::new(0.5).with_delta(-0.3);
-        let original_delta = state_value.delta();
-        let duration = Duration::hours(6);
-        let half_life = Duration::hours(6);
-
-        reverse_dimension_decay(&mut state_value, duration, half_life);
-
-        // Negative delta should double in magnitude (reversal)
-        assert!(state_value.delta() < original_delta);
-        assert!(state_value.delta().abs() > original_delta.abs() * 0.9);
-    }
-
-    #[test]
-    fn reverse_dimension_decay_with_extremely_small_delta() {
-        use crate::state::StateValue;
-
-        let mut state_value = StateValue::new(0.5).with_delta(1e-10);
-        let original_delta = state_value.delta();
-        let duration = Duration::hours(6);
-        let half_life = Duration::hours(6);
-
-        reverse_dimension_decay(&mut state_value, duration, half_life);
-
-        // Should skip due to epsilon check
-        assert!((state_value.delta() - original_delta).abs() < 1e-15);
-    }
-
     #[test]
     fn apply_event_chronic_pattern_affects_pb_slower_decay() {
         let state = IndividualState::new();
@@ -2465,21 +2272,6 @@ mod tests {
         assert!(loneliness_delta < -0.2);
     }
 
-    #[test]
-    fn reverse_dimension_decay_clamping_large_reversal() {
-        use crate::state::StateValue;
-
-        let mut state_value = StateValue::new(0.5).with_delta(10.0);
-        let duration = Duration::years(1);
-        let half_life = Duration::hours(6);
-
-        reverse_dimension_decay(&mut state_value, duration, half_life);
-
-        // Should be clamped to 100.0 due to clamp(-100.0, 100.0)
-        assert!(state_value.delta() <= 100.0);
-        assert!(state_value.delta() >= -100.0);
-    }
-
     #[test]
     fn apply_interpreted_event_moral_violation_flag_set() {
         let state = IndividualState::new();
@@ -2559,54 +2351,10 @@ mod tests {
         assert!(reversed.social_cognition().perceived_liability().delta() > -0.01);
     }
 
-    #[test]
-    fn reverse_dimension_decay_exponent_overflow_guard() {
-        use crate::state::StateValue;
-
-        let mut state_value = StateValue::new(0.5).with_delta(10.0);
-        let duration = Duration::years(100);
-        let half_life = Duration::hours(1);
-
-        reverse_dimension_decay(&mut state_value, duration, half_life);
-
-        assert!(state_value.delta().is_finite());
-    }
-
     // ========================================================================
     // Coverage tests for state_evolution.rs missed regions
     // ========================================================================
 
-    #[test]
-    fn reverse_dimension_decay_very_long_half_life() {
-        use crate::state::StateValue;
-
-        // Test edge case: very long half_life with short duration should barely change delta
-        let mut state_value = StateValue::new(0.5).with_delta(0.3);
-        let duration = Duration::seconds(1);
-        let half_life = Duration::days(1000); // Very long half-life relative to duration
-
-        // This test primarily verifies the function handles extreme values gracefully
-        reverse_dimension_decay(&mut state_value, duration, half_life);
-        assert!(state_value.delta().is_finite());
-        // Delta should be nearly the same since only 1 second elapsed vs 1000 day half-life
-        assert!(state_value.delta() > 0.29);
-    }
-
-    #[test]
-    fn reverse_dimension_decay_very_small_delta() {
-        use crate::state::StateValue;
-
-        // Test that very small deltas are handled correctly
-        let mut state_value = StateValue::new(0.5).with_delta(1e-10);
-        let duration = Duration::hours(1);
-        let half_life = Duration::hours(6);
-
-        reverse_dimension_decay(&mut state_value, duration, half_life);
-
-        // Should not cause underflow or NaN
-        assert!(state_value.delta().is_finite());
-    }
-
     #[test]
     fn apply_decay_chronically_affected_dimension() {
         use crate::state::IndividualState;
@@ -2630,22 +2378,6 @@ mod tests {
         assert!(final_delta < initial_delta);
     }
 
-    #[test]
-    fn reverse_dimension_decay_overflow_guard() {
-        use crate::state::StateValue;
-
-        // Test that extremely large duration doesn't cause overflow
-        // This tests the guard at line 245: if exponent > 700.0
-        let mut state_value = StateValue::new(0.5).with_delta(0.1);
-        let duration = Duration::days(365 * 1000); // 1000 years - extreme case
-        let half_life = Duration::hours(6);
-
-        reverse_dimension_decay(&mut state_value, duration, half_life);
-
-        // Should not cause overflow - delta should remain unchanged due to guard
-        assert!((state_value.delta() - 0.1).abs() < f32::EPSILON);
-    }
-
     #[test]
     fn support_event_with_instrumental_type_does_not_affect_social_cognition() {
         // This test covers the case where Support event has a non-Emotional/non-Companionship
@@ -205,6 +205,53 @@ const TRAUMATIC_EXPOSURE_ANTECEDENTS: [AntecedentMapping; 2] = [
     ),
 ];
 
+// Repair magnitudes are deliberately smaller than the violations they
+// answer: trust lost to a betrayal is only partially restored by one repair.
+const APOLOGY_ANTECEDENTS: [AntecedentMapping; 2] = [
+    AntecedentMapping::new(
+        AntecedentType::Integrity,
+        AntecedentDirection::Positive,
+        0.15,
+        "apology",
+    ),
+    AntecedentMapping::new(
+        AntecedentType::Benevolence,
+        AntecedentDirection::Positive,
+        0.1,
+        "apology_remorse",
+    ),
+];
+
+const RESTITUTION_ANTECEDENTS: [AntecedentMapping; 2] = [
+    AntecedentMapping::new(
+        AntecedentType::Integrity,
+        AntecedentDirection::Positive,
+        0.2,
+        "restitution",
+    ),
+    AntecedentMapping::new(
+        AntecedentType::Benevolence,
+        AntecedentDirection::Positive,
+        0.15,
+        "restitution",
+    ),
+];
+
+const FORGIVENESS_ANTECEDENTS: [AntecedentMapping; 2] = [
+    AntecedentMapping::new(
+        AntecedentType::Benevolence,
+        AntecedentDirection::Positive,
+        0.1,
+        "forgiveness",
+    ),
+    AntecedentMapping::new(
+        AntecedentType::Integrity,
+        AntecedentDirection::Positive,
+        0.05,
+        "forgiveness",
+    ),
+];
+
 /// Trust antecedent lookup table.
 pub const TRUST_ANTECEDENT_TABLE: &[(EventType, &[AntecedentMapping])] = &[
     (EventType::Achievement, &ACHIEVEMENT_ANTECEDENTS),
@@ -225,6 +272,9 @@ pub const TRUST_ANTECEDENT_TABLE: &[(EventType, &[AntecedentMapping])] = &[
         EventType::TraumaticExposure,
         &TRAUMATIC_EXPOSURE_ANTECEDENTS,
     ),
+    (EventType::Apology, &APOLOGY_ANTECEDENTS),
+    (EventType::Restitution, &RESTITUTION_ANTECEDENTS),
+    (EventType::Forgiveness, &FORGIVENESS_ANTECEDENTS),
 ];
 
 fn clamp01(value: f32) -> f32 {
@@ -609,6 +659,24 @@ pub fn get_antecedent_for_event(event: &Event) -> Vec<AntecedentMapping> {
                 ),
             ]
         }
+        EventType::Apology | EventType::Restitution | EventType::Forgiveness => {
+            let mappings: &[AntecedentMapping] = match event.event_type() {
+                EventType::Apology => &APOLOGY_ANTECEDENTS,
+                EventType::Restitution => &RESTITUTION_ANTECEDENTS,
+                _ => &FORGIVENESS_ANTECEDENTS,
+            };
+            mappings
+                .iter()
+                .map(|mapping| {
+                    AntecedentMapping::new(
+                        mapping.antecedent_type,
+                        mapping.direction,
+                        clamp01(mapping.base_magnitude * stakes * witness),
+                        mapping.context,
+                    )
+                })
+                .collect()
+        }
         _ => Vec::new(),
     }
 }
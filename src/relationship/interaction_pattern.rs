@@ -1,8 +1,102 @@
 //! Interaction pattern metadata for relationships.
 //!
 //! Captures how frequently and consistently entities interact.
+//!
+//! Patterns can be authored directly through the builder methods, or
+//! derived from recorded interactions. Once interactions are recorded,
+//! frequency is the rolling rate of interactions per week and consistency
+//! reflects how stable the valence of those interactions has been. Both
+//! can be queried at any timestamp from the recorded history. Authored
+//! values act as a prior that the recorded interactions are blended with,
+//! so the observed pattern takes over as interactions accumulate.
+//!
+//! Only the most recent interactions are kept individually; older ones are
+//! folded into a summary that keeps their valence statistics, the earliest
+//! and latest contact, and the time without contact between them.
+
+use crate::relationship::neglect::NEGLECT_GRACE_DAYS;
+use crate::types::{Duration, Timestamp};
+
+/// Rolling window used to measure interaction frequency, in days.
+pub const INTERACTION_WINDOW_DAYS: f64 = 28.0;
+
+/// Interactions per week that count as maximal frequency (daily contact).
+const MAX_INTERACTIONS_PER_WEEK: f64 = 7.0;
+
+/// Pseudo-count that shrinks consistency toward the authored consistency
+/// (zero if none) for short histories.
+const CONSISTENCY_PRIOR_COUNT: f32 = 2.0;
+
+/// Pseudo-count of interactions an authored frequency is worth.
+const FREQUENCY_PRIOR_COUNT: f32 = 4.0;
+
+/// Maximum number of interactions retained individually.
+const MAX_INTERACTION_HISTORY: usize = 200;
+
+/// A single recorded interaction between the pair.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct InteractionRecord {
+    /// When the interaction happened.
+    pub timestamp: Timestamp,
+
+    /// How positive the interaction was (-1 = hostile, 1 = warm).
+    pub valence: f32,
+}
+
+/// Interactions folded out of the retained history.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct FoldedInteractions {
+    /// Number of folded interactions.
+    count: usize,
+
+    /// Sum of their valences.
+    valence_sum: f32,
 
-use crate::types::Timestamp;
+    /// Sum of their squared valences.
+    valence_square_sum: f32,
+
+    /// The earliest folded interaction.
+    pub(crate) earliest: Timestamp,
+
+    /// The latest folded interaction.
+    pub(crate) latest: Timestamp,
+
+    /// Days without contact beyond `NEGLECT_GRACE_DAYS`, summed over the
+    /// gaps between folded interactions.
+    pub(crate) neglected_days: f64,
+}
+
+impl FoldedInteractions {
+    fn fold(folded: Option<Self>, record: InteractionRecord) -> Self {
+        let valence = record.valence;
+        match folded {
+            None => FoldedInteractions {
+                count: 1,
+                valence_sum: valence,
+                valence_square_sum: valence * valence,
+                earliest: record.timestamp,
+                latest: record.timestamp,
+                neglected_days: 0.0,
+            },
+            Some(folded) => {
+                let grace_end = folded.latest + Duration::days(NEGLECT_GRACE_DAYS);
+                let neglected = if record.timestamp > grace_end {
+                    (record.timestamp - grace_end).as_days_f64()
+                } else {
+                    0.0
+                };
+                FoldedInteractions {
+                    count: folded.count + 1,
+                    valence_sum: folded.valence_sum + valence,
+                    valence_square_sum: folded.valence_square_sum + valence * valence,
+                    earliest: folded.earliest,
+                    latest: folded.latest.max(record.timestamp),
+                    neglected_days: folded.neglected_days + neglected,
+                }
+            }
+        }
+    }
+}
 
 /// Interaction pattern for a relationship.
 #[derive(Debug, Clone, PartialEq)]
@@ -15,6 +109,18 @@ pub struct InteractionPattern {
 
     /// Timestamp of the last interaction, if known.
     pub last_interaction: Option<Timestamp>,
+
+    /// Recorded interactions, ordered by timestamp.
+    interactions: Vec<InteractionRecord>,
+
+    /// Interactions older than the retained ones, folded into a summary.
+    folded: Option<FoldedInteractions>,
+
+    /// Frequency set through `with_frequency`, kept as a prior.
+    authored_frequency: Option<f32>,
+
+    /// Consistency set through `with_consistency`, kept as a prior.
+    authored_consistency: Option<f32>,
 }
 
 impl Default for InteractionPattern {
@@ -23,6 +129,10 @@ impl Default for InteractionPattern {
             frequency: 0.0,
             consistency: 0.0,
             last_interaction: None,
+            interactions: Vec::new(),
+            folded: None,
+            authored_frequency: None,
+            authored_consistency: None,
         }
     }
 }
@@ -35,16 +145,24 @@ impl InteractionPattern {
     }
 
     /// Sets the interaction frequency.
+    ///
+    /// The authored frequency is kept as a prior once interactions are
+    /// recorded (see `frequency_at`).
     #[must_use]
     pub fn with_frequency(mut self, frequency: f32) -> Self {
         self.frequency = frequency.clamp(0.0, 1.0);
+        self.authored_frequency = Some(self.frequency);
         self
     }
 
     /// Sets the interaction consistency.
+    ///
+    /// The authored consistency is kept as a prior once interactions are
+    /// recorded (see `consistency_at`).
     #[must_use]
     pub fn with_consistency(mut self, consistency: f32) -> Self {
         self.consistency = consistency.clamp(0.0, 1.0);
+        self.authored_consistency = Some(self.consistency);
         self
     }
 
@@ -54,11 +172,203 @@ impl InteractionPattern {
         self.last_interaction = Some(last_interaction);
         self
     }
+
+    /// Records an interaction and refreshes the pattern fields.
+    ///
+    /// `valence` is clamped to -1.0..=1.0. Interactions may be recorded out
+    /// of order; the fields always describe the pattern as of the latest
+    /// recorded interaction. Beyond the most recent interactions, the oldest
+    /// are folded into a summary rather than dropped.
+    pub fn record_interaction(&mut self, timestamp: Timestamp, valence: f32) {
+        let record = InteractionRecord {
+            timestamp,
+            valence: valence.clamp(-1.0, 1.0),
+        };
+        let index = self
+            .interactions
+            .partition_point(|existing| existing.timestamp <= timestamp);
+        self.interactions.insert(index, record);
+        if self.interactions.len() > MAX_INTERACTION_HISTORY {
+            let overflow = self.interactions.len() - MAX_INTERACTION_HISTORY;
+            for dropped in self.interactions.drain(..overflow) {
+                self.folded = Some(FoldedInteractions::fold(self.folded, dropped));
+            }
+        }
+
+        let latest = self.interactions[self.interactions.len() - 1].timestamp;
+        self.frequency = self.frequency_at(latest);
+        self.consistency = self.consistency_at(latest);
+        self.last_interaction = Some(latest);
+    }
+
+    /// Returns the retained interactions, oldest first.
+    ///
+    /// Interactions folded out of the history are not included.
+    #[must_use]
+    pub fn interactions(&self) -> &[InteractionRecord] {
+        &self.interactions
+    }
+
+    /// Returns true once any interaction has been recorded, including
+    /// interactions folded out of the history.
+    #[must_use]
+    pub fn has_recorded_interactions(&self) -> bool {
+        !self.interactions.is_empty() || self.folded.is_some()
+    }
+
+    /// Returns the summary of interactions folded out of the history.
+    pub(crate) fn folded(&self) -> Option<&FoldedInteractions> {
+        self.folded.as_ref()
+    }
+
+    /// Returns the interaction frequency as of the given timestamp.
+    ///
+    /// Computed as interactions per week over the trailing
+    /// `INTERACTION_WINDOW_DAYS`, scaled so daily contact is 1.0. A frequency
+    /// set through `with_frequency` is blended in as if it had been observed
+    /// over `FREQUENCY_PRIOR_COUNT` interactions. Falls back to the
+    /// `frequency` field when no interactions are recorded.
+    #[must_use]
+    pub fn frequency_at(&self, timestamp: Timestamp) -> f32 {
+        if !self.has_recorded_interactions() {
+            return self.frequency;
+        }
+        let count = self
+            .recorded_until(timestamp)
+            .iter()
+            .filter(|record| (timestamp - record.timestamp).as_days_f64() < INTERACTION_WINDOW_DAYS)
+            .count();
+        let per_week = count as f64 * 7.0 / INTERACTION_WINDOW_DAYS;
+        let observed = (per_week / MAX_INTERACTIONS_PER_WEEK).min(1.0) as f32;
+        match self.authored_frequency {
+            Some(authored) => {
+                let n = self.observed_count(timestamp) as f32;
+                (observed * n + authored * FREQUENCY_PRIOR_COUNT) / (n + FREQUENCY_PRIOR_COUNT)
+            }
+            None => observed,
+        }
+    }
+
+    /// Returns the interaction consistency as of the given timestamp.
+    ///
+    /// Consistency is one minus the standard deviation of interaction
+    /// valence, shrunk toward the consistency set through `with_consistency`
+    /// (zero if none) while only a few interactions have been observed.
+    /// Falls back to the `consistency` field when no interactions are
+    /// recorded.
+    #[must_use]
+    pub fn consistency_at(&self, timestamp: Timestamp) -> f32 {
+        if !self.has_recorded_interactions() {
+            return self.consistency;
+        }
+        let records = self.recorded_until(timestamp);
+        let folded = self.folded.filter(|folded| folded.latest <= timestamp);
+        let folded_count = folded.map_or(0, |folded| folded.count);
+        if records.is_empty() && folded_count == 0 {
+            return self.authored_consistency.unwrap_or(0.0);
+        }
+        let n = (records.len() + folded_count) as f32;
+        let folded_sum = folded.map_or(0.0, |folded| folded.valence_sum);
+        let mean = (records.iter().map(|r| r.valence).sum::<f32>() + folded_sum) / n;
+        let folded_squares = folded.map_or(0.0, |folded| {
+            folded.valence_square_sum - 2.0 * mean * folded.valence_sum
+                + folded.count as f32 * mean * mean
+        });
+        let variance = (records
+            .iter()
+            .map(|r| (r.valence - mean).powi(2))
+            .sum::<f32>()
+            + folded_squares)
+            .max(0.0)
+            / n;
+        let stability = (1.0 - variance.sqrt()).clamp(0.0, 1.0);
+        let prior = self.authored_consistency.unwrap_or(0.0);
+        (stability * n + prior * CONSISTENCY_PRIOR_COUNT) / (n + CONSISTENCY_PRIOR_COUNT)
+    }
+
+    /// Returns the most recent interaction at or before the timestamp.
+    ///
+    /// Falls back to the authored `last_interaction` when no interactions
+    /// are recorded.
+    #[must_use]
+    pub fn last_interaction_at(&self, timestamp: Timestamp) -> Option<Timestamp> {
+        if !self.has_recorded_interactions() {
+            return self.last_interaction.filter(|last| *last <= timestamp);
+        }
+        self.recorded_until(timestamp)
+            .last()
+            .map(|record| record.timestamp)
+            .or_else(|| {
+                self.folded
+                    .filter(|folded| folded.earliest <= timestamp)
+                    .map(|folded| folded.latest.min(timestamp))
+            })
+    }
+
+    /// Drops interactions after the timestamp and refreshes the pattern
+    /// fields as of the latest remaining interaction.
+    ///
+    /// Authored patterns without recorded interactions only lose a
+    /// `last_interaction` after the timestamp. A timestamp inside the folded
+    /// stretch keeps the summary, ending it at the timestamp.
+    pub(crate) fn retain_until(&mut self, timestamp: Timestamp) {
+        if !self.has_recorded_interactions() {
+            self.last_interaction = self.last_interaction.filter(|last| *last <= timestamp);
+            return;
+        }
+        let end = self.recorded_until(timestamp).len();
+        let folded_kept = self.folded.is_none_or(|folded| folded.latest <= timestamp);
+        if end == self.interactions.len() && folded_kept {
+            return;
+        }
+        self.interactions.truncate(end);
+        let latest = match self.interactions.last() {
+            Some(record) => record.timestamp,
+            None => match self.folded.filter(|folded| folded.earliest <= timestamp) {
+                Some(mut folded) => {
+                    folded.latest = folded.latest.min(timestamp);
+                    self.folded = Some(folded);
+                    folded.latest
+                }
+                None => {
+                    *self = InteractionPattern {
+                        frequency: self.authored_frequency.unwrap_or(0.0),
+                        consistency: self.authored_consistency.unwrap_or(0.0),
+                        authored_frequency: self.authored_frequency,
+                        authored_consistency: self.authored_consistency,
+                        ..InteractionPattern::default()
+                    };
+                    return;
+                }
+            },
+        };
+        self.frequency = self.frequency_at(latest);
+        self.consistency = self.consistency_at(latest);
+        self.last_interaction = Some(latest);
+    }
+
+    /// Returns how many interactions were recorded up to the timestamp,
+    /// including folded ones.
+    fn observed_count(&self, timestamp: Timestamp) -> usize {
+        let folded = self
+            .folded
+            .filter(|folded| folded.latest <= timestamp)
+            .map_or(0, |folded| folded.count);
+        self.recorded_until(timestamp).len() + folded
+    }
+
+    fn recorded_until(&self, timestamp: Timestamp) -> &[InteractionRecord] {
+        let end = self
+            .interactions
+            .partition_point(|record| record.timestamp <= timestamp);
+        &self.interactions[..end]
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::types::Duration;
 
     #[test]
     fn interaction_pattern_defaults_empty() {
@@ -80,4 +390,120 @@ mod tests {
         assert!((pattern.consistency - 0.0).abs() < f32::EPSILON);
         assert_eq!(pattern.last_interaction, Some(ts));
     }
+
+    #[test]
+    fn record_interaction_sets_rolling_frequency() {
+        let start = Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+        let mut pattern = InteractionPattern::new();
+        for day in 0..28 {
+            pattern.record_interaction(start + Duration::days(day), 0.5);
+        }
+
+        let last = start + Duration::days(27);
+        assert!((pattern.frequency - 1.0).abs() < 1e-6);
+        assert_eq!(pattern.last_interaction, Some(last));
+
+        // Weekly contact reads as one seventh of daily contact.
+        let mut weekly = InteractionPattern::new();
+        for week in 0..4 {
+            weekly.record_interaction(start + Duration::days(week * 7), 0.5);
+        }
+        assert!((weekly.frequency - 1.0 / 7.0).abs() < 1e-6);
+
+        // Frequency falls off once the window passes the last interaction.
+        assert!(pattern.frequency_at(last + Duration::days(60)).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn consistency_tracks_valence_variance() {
+        let start = Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+        let mut steady = InteractionPattern::new();
+        let mut volatile = InteractionPattern::new();
+        for day in 0..10 {
+            steady.record_interaction(start + Duration::days(day), 0.6);
+            let swing = if day % 2 == 0 { 0.9 } else { -0.9 };
+            volatile.record_interaction(start + Duration::days(day), swing);
+        }
+
+        assert!(steady.consistency > 0.8);
+        assert!(volatile.consistency < 0.2);
+    }
+
+    #[test]
+    fn consistency_grows_with_observations() {
+        let start = Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+        let mut pattern = InteractionPattern::new();
+        pattern.record_interaction(start, 0.5);
+        let after_one = pattern.consistency;
+        pattern.record_interaction(start + Duration::days(1), 0.5);
+        assert!(pattern.consistency > after_one);
+    }
+
+    #[test]
+    fn pattern_is_queryable_at_earlier_timestamps() {
+        let start = Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+        let mut pattern = InteractionPattern::new();
+        pattern.record_interaction(start + Duration::days(10), 0.5);
+        // Recorded out of order.
+        pattern.record_interaction(start, 0.5);
+
+        assert_eq!(pattern.interactions()[0].timestamp, start);
+        assert_eq!(pattern.last_interaction, Some(start + Duration::days(10)));
+        assert_eq!(
+            pattern.last_interaction_at(start + Duration::days(5)),
+            Some(start)
+        );
+        assert_eq!(pattern.last_interaction_at(start - Duration::days(1)), None);
+        assert!(pattern.consistency_at(start - Duration::days(1)).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn folded_interactions_still_count_toward_consistency() {
+        let start = Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+        let mut pattern = InteractionPattern::new();
+        for day in 0..250 {
+            pattern.record_interaction(start + Duration::days(day), 0.5);
+        }
+
+        assert_eq!(pattern.interactions().len(), MAX_INTERACTION_HISTORY);
+        assert!((pattern.consistency - 250.0 / 252.0).abs() < 1e-6);
+        assert_eq!(
+            pattern.last_interaction_at(start + Duration::days(10)),
+            Some(start + Duration::days(10))
+        );
+    }
+
+    #[test]
+    fn authored_values_are_blended_with_recorded_interactions() {
+        let start = Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+        let mut pattern = InteractionPattern::new()
+            .with_frequency(1.0)
+            .with_consistency(0.9);
+
+        // One interaction barely moves an authored daily, steady pattern.
+        pattern.record_interaction(start, -0.5);
+        assert!(pattern.frequency > 0.75);
+        assert!(pattern.consistency > 0.6);
+
+        // With weekly contact the observed pattern takes over.
+        for week in 1..100 {
+            pattern.record_interaction(start + Duration::days(week * 7), 0.5);
+        }
+        assert!((pattern.frequency - 1.0 / 7.0).abs() < 0.05);
+        assert!(pattern.consistency > 0.85);
+    }
+
+    #[test]
+    fn authored_values_apply_without_recorded_interactions() {
+        let ts = Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+        let pattern = InteractionPattern::new()
+            .with_frequency(0.4)
+            .with_consistency(0.7)
+            .with_last_interaction(ts);
+
+        assert!((pattern.frequency_at(ts) - 0.4).abs() < f32::EPSILON);
+        assert!((pattern.consistency_at(ts) - 0.7).abs() < f32::EPSILON);
+        assert_eq!(pattern.last_interaction_at(ts), Some(ts));
+        assert_eq!(pattern.last_interaction_at(ts - Duration::days(1)), None);
+    }
 }
@@ -60,13 +60,19 @@ mod antecedent;
 mod antecedent_mapping;
 mod directional_dimensions;
 mod interaction_pattern;
+mod neglect;
 mod perceived_risk;
 mod predictions;
 #[allow(clippy::module_inception)]
 mod relationship;
+mod repair;
+mod reputation;
 mod shared_dimensions;
 mod stage;
+mod stage_transition;
+mod transitive_trust;
 mod trust;
+mod trust_calibration;
 mod trust_context;
 mod trust_decision;
 mod trustworthiness;
@@ -74,13 +80,39 @@ mod trustworthiness;
 pub use antecedent::{AntecedentDirection, AntecedentType, TrustAntecedent};
 pub use antecedent_mapping::{get_antecedent_for_event, AntecedentMapping, TRUST_ANTECEDENT_TABLE};
 pub use directional_dimensions::DirectionalDimensions;
-pub use interaction_pattern::InteractionPattern;
+pub use interaction_pattern::{InteractionPattern, InteractionRecord, INTERACTION_WINDOW_DAYS};
+pub use neglect::{
+    neglect_rate, neglect_retention, ATTACHMENT_NEGLECT_HALF_LIFE_MULTIPLIER, NEGLECT_GRACE_DAYS,
+    NEGLECT_HALF_LIFE_DAYS,
+};
 pub use perceived_risk::{PerceivedRisk, StakesLevel, Vulnerability, VulnerabilityType};
 pub use predictions::{would_confide, would_help};
 pub use relationship::{Relationship, RelationshipError, StageTransitionError};
+pub use repair::{
+    count_violations, repair_effectiveness, FORGIVENESS_RESENTMENT_RELIEF,
+    REPAIR_BETRAYAL_RISK_RELIEF, REPAIR_TIMING_HALF_LIFE_DAYS,
+};
+pub use reputation::{
+    reputation_delta_for_event, Reputation, ReputationStore, GOSSIP_MIN_CONFIDENCE,
+    REPUTATION_PRIOR_WEIGHT,
+};
 pub use shared_dimensions::SharedDimensions;
 pub use stage::RelationshipStage;
+pub use stage_transition::{
+    closeness_score, entry_threshold, evaluate_stage_transition, StageTransition,
+    StageTransitionCause, ACQUAINTANCE_THRESHOLD, BETRAYAL_ESTRANGEMENT_SEVERITY,
+    ELEVATED_TENSION_THRESHOLD, ESTABLISHED_THRESHOLD, INTIMATE_THRESHOLD, STAGE_HYSTERESIS,
+    SUSTAINED_TENSION_DAYS,
+};
+pub use transitive_trust::{
+    TransitiveTrustPrior, TrustReferral, MAX_TRANSITIVE_PATH_LENGTH, MAX_VOUCHING_BONUS,
+    TRANSITIVE_PATH_DISCOUNT, TRANSITIVE_TRUST_CONTEXT, VOUCHING_BONUS, VOUCHING_CONTEXT,
+    VOUCHING_TRUST_THRESHOLD,
+};
 pub use trust::Trust;
+pub use trust_calibration::{
+    ActualTrustworthiness, CalibrationAssessment, TrustCalibration, CALIBRATION_TOLERANCE,
+};
 pub use trust_context::TrustContext;
 pub use trust_decision::TrustDecision;
 pub use trustworthiness::TrustworthinessFactors;
@@ -0,0 +1,114 @@
+//! Relationship neglect: drifting apart without contact.
+//!
+//! Once a pair has gone `NEGLECT_GRACE_DAYS` without interacting, intimacy,
+//! affinity and warmth fade exponentially toward the levels of a fresh
+//! relationship. Attachment fades more slowly, so an old bond can still be
+//! felt after the warmth has gone. The pace depends on the bond types and
+//! schema: family ties are far more durable than workplace ones.
+//!
+//! Fading is applied to the base and both delta components of each
+//! dimension, so it persists rather than being undone by delta decay.
+
+use crate::enums::{BondType, RelationshipSchema};
+use crate::state::StateValue;
+
+/// Days without contact before neglect begins.
+pub const NEGLECT_GRACE_DAYS: u64 = 90;
+
+/// Half-life of intimacy, affinity and warmth under neglect at rate 1.0.
+pub const NEGLECT_HALF_LIFE_DAYS: f64 = 730.0;
+
+/// How much longer attachment persists under neglect than warmth.
+pub const ATTACHMENT_NEGLECT_HALF_LIFE_MULTIPLIER: f64 = 3.0;
+
+/// Levels that neglected dimensions fade toward (fresh relationship values).
+pub(crate) const INTIMACY_FLOOR: f32 = 0.0;
+pub(crate) const AFFINITY_FLOOR: f32 = 0.1;
+pub(crate) const WARMTH_FLOOR: f32 = 0.2;
+pub(crate) const ATTACHMENT_FLOOR: f32 = 0.0;
+
+/// Returns the combined neglect rate for a relationship's bonds and schema.
+///
+/// The most durable bond governs (a sibling who is also a colleague fades
+/// like a sibling). Relationships without bonds use the friendship rate.
+#[must_use]
+pub fn neglect_rate(bonds: &[BondType], schema: RelationshipSchema) -> f32 {
+    let bond_rate = bonds
+        .iter()
+        .map(BondType::neglect_rate)
+        .fold(None, |lowest: Option<f32>, rate| {
+            Some(lowest.map_or(rate, |l| l.min(rate)))
+        })
+        .unwrap_or(1.0);
+    bond_rate * schema.neglect_rate()
+}
+
+/// Returns the fraction of closeness retained after a span of neglect.
+///
+/// `neglected_days` is time beyond the grace period. `half_life_days` is
+/// the half-life at rate 1.0 and is divided by `rate`.
+#[must_use]
+pub fn neglect_retention(neglected_days: f64, half_life_days: f64, rate: f32) -> f32 {
+    if neglected_days <= 0.0 || rate <= 0.0 {
+        return 1.0;
+    }
+    let half_life = half_life_days / f64::from(rate);
+    0.5_f64.powf(neglected_days / half_life) as f32
+}
+
+/// Fades a value toward `floor`, keeping `retention` of its distance.
+pub(crate) fn fade_toward(value: &mut StateValue, floor: f32, retention: f32) {
+    let base = value.base();
+    value.set_base(floor + (base - floor) * retention);
+    value.set_delta_components(
+        value.acute_delta() * retention,
+        value.chronic_delta() * retention,
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn family_bonds_fade_slower_than_colleagues() {
+        let family = neglect_rate(&[BondType::Family], RelationshipSchema::Family);
+        let colleague = neglect_rate(&[BondType::Colleague], RelationshipSchema::Peer);
+        assert!(family < colleague);
+
+        let days = 365.0;
+        assert!(
+            neglect_retention(days, NEGLECT_HALF_LIFE_DAYS, family)
+                > neglect_retention(days, NEGLECT_HALF_LIFE_DAYS, colleague)
+        );
+    }
+
+    #[test]
+    fn most_durable_bond_governs() {
+        let mixed = neglect_rate(
+            &[BondType::Colleague, BondType::Sibling],
+            RelationshipSchema::Peer,
+        );
+        let sibling = neglect_rate(&[BondType::Sibling], RelationshipSchema::Peer);
+        assert!((mixed - sibling).abs() < f32::EPSILON);
+        assert!((neglect_rate(&[], RelationshipSchema::Peer) - 1.0).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn retention_halves_each_half_life() {
+        let r = neglect_retention(NEGLECT_HALF_LIFE_DAYS, NEGLECT_HALF_LIFE_DAYS, 1.0);
+        assert!((r - 0.5).abs() < 1e-6);
+        assert!((neglect_retention(0.0, NEGLECT_HALF_LIFE_DAYS, 1.0) - 1.0).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn fade_scales_base_and_deltas_toward_floor() {
+        let mut value = StateValue::new(0.6).with_delta(0.2);
+        value.add_chronic_delta(0.1);
+        fade_toward(&mut value, 0.2, 0.5);
+
+        assert!((value.base() - 0.4).abs() < 1e-6);
+        assert!((value.acute_delta() - 0.1).abs() < 1e-6);
+        assert!((value.chronic_delta() - 0.05).abs() < 1e-6);
+    }
+}
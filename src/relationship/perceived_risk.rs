@@ -168,7 +168,7 @@ impl std::fmt::Display for StakesLevel {
 /// assert_eq!(vuln.vulnerability_type(), VulnerabilityType::Resources);
 /// assert_eq!(vuln.stakes(), StakesLevel::High);
 /// ```
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
 pub struct Vulnerability {
     /// What type of thing is at risk.
     vulnerability_type: VulnerabilityType,
@@ -220,15 +220,6 @@ impl Vulnerability {
     }
 }
 
-impl Default for Vulnerability {
-    fn default() -> Self {
-        Vulnerability {
-            vulnerability_type: VulnerabilityType::default(),
-            stakes: StakesLevel::default(),
-        }
-    }
-}
-
 impl std::fmt::Display for Vulnerability {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{} ({})", self.vulnerability_type, self.stakes)
@@ -266,6 +257,9 @@ pub struct PerceivedRisk {
 
     /// Whether this relationship has a history of betrayal.
     betrayal_history: bool,
+
+    /// Fraction of the betrayal risk released through repair (0-1).
+    betrayal_repair: f32,
 }
 
 impl PerceivedRisk {
@@ -289,6 +283,7 @@ impl PerceivedRisk {
                 .with_bounds(0.0, 1.0)
                 .with_decay_half_life(PERCEIVED_RISK_DECAY_HALF_LIFE),
             betrayal_history: false,
+            betrayal_repair: 0.0,
         }
     }
 
@@ -313,6 +308,7 @@ impl PerceivedRisk {
                 .with_bounds(0.0, 1.0)
                 .with_decay_half_life(PERCEIVED_RISK_DECAY_HALF_LIFE),
             betrayal_history: false,
+            betrayal_repair: 0.0,
         }
     }
 
@@ -353,16 +349,47 @@ impl PerceivedRisk {
 
     /// Marks a betrayal in the relationship history.
     ///
-    /// This permanently increases the risk baseline for this relationship.
+    /// This increases the risk baseline for this relationship until it is
+    /// repaired. A new betrayal undoes any repair made so far.
     pub fn mark_betrayal(&mut self) {
         self.betrayal_history = true;
+        self.betrayal_repair = 0.0;
     }
 
     /// Clears the betrayal history.
     ///
-    /// This is typically only used during testing or relationship repair.
+    /// This is typically only used during testing. Use `repair_betrayal`
+    /// for gradual repair.
     pub fn clear_betrayal_history(&mut self) {
         self.betrayal_history = false;
+        self.betrayal_repair = 0.0;
+    }
+
+    /// Releases part of the betrayal risk through repair.
+    ///
+    /// `amount` is the fraction of the betrayal risk released (0-1). Once
+    /// the accumulated repair reaches 1.0, the betrayal history is cleared.
+    pub fn repair_betrayal(&mut self, amount: f32) {
+        if !self.betrayal_history {
+            return;
+        }
+        self.betrayal_repair = (self.betrayal_repair + amount.max(0.0)).min(1.0);
+        if self.betrayal_repair >= 1.0 {
+            self.clear_betrayal_history();
+        }
+    }
+
+    /// Returns the fraction of the betrayal risk released through repair.
+    #[must_use]
+    pub fn betrayal_repair(&self) -> f32 {
+        self.betrayal_repair
+    }
+
+    /// Copies the betrayal history and repair from `other`, leaving the
+    /// risk level itself unchanged.
+    pub(crate) fn restore_betrayal_from(&mut self, other: &PerceivedRisk) {
+        self.betrayal_history = other.betrayal_history;
+        self.betrayal_repair = other.betrayal_repair;
     }
 
     /// Computes the total risk for a specific stakes level.
@@ -395,7 +422,7 @@ impl PerceivedRisk {
         let mut total = self.effective() + stakes.risk_contribution();
 
         if self.betrayal_history {
-            total += Self::BETRAYAL_RISK_INCREASE;
+            total += Self::BETRAYAL_RISK_INCREASE * (1.0 - self.betrayal_repair);
         }
 
         total.clamp(0.0, 1.0)
@@ -614,6 +641,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::clone_on_copy)]
     fn stakes_level_clone_copy() {
         let s1 = StakesLevel::Medium;
         let s2 = s1;
@@ -674,6 +702,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::clone_on_copy)]
     fn vulnerability_type_clone_copy() {
         let v1 = VulnerabilityType::Reputation;
         let v2 = v1;
@@ -738,6 +767,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::clone_on_copy)]
     fn vulnerability_clone_copy() {
         let v1 = Vulnerability::new(VulnerabilityType::Emotional, StakesLevel::Low);
         let v2 = v1;
@@ -964,6 +994,26 @@ mod tests {
         assert!(!risk.has_betrayal_history());
     }
 
+    #[test]
+    fn repair_betrayal_releases_risk_gradually() {
+        let mut risk = PerceivedRisk::new();
+        risk.mark_betrayal();
+        let betrayed = risk.compute_for_stakes(StakesLevel::Medium);
+
+        risk.repair_betrayal(0.5);
+        let repaired = risk.compute_for_stakes(StakesLevel::Medium);
+        assert!(repaired < betrayed);
+        assert!(risk.has_betrayal_history());
+
+        risk.repair_betrayal(0.6);
+        assert!(!risk.has_betrayal_history());
+        assert!(risk.betrayal_repair().abs() < f32::EPSILON);
+
+        // Repair without a betrayal has nothing to release.
+        risk.repair_betrayal(0.5);
+        assert!(risk.betrayal_repair().abs() < f32::EPSILON);
+    }
+
     #[test]
     fn decay_over_7_days() {
         let mut risk = PerceivedRisk::new();
@@ -5,10 +5,20 @@
 //! and perceived risk.
 
 use crate::enums::{BondType, Direction, DirectionalPath, RelPath, RelationshipSchema};
+use crate::event::Event;
+use crate::processor::apply_event_effects;
+use crate::relationship::neglect::{
+    fade_toward, neglect_rate, neglect_retention, AFFINITY_FLOOR, ATTACHMENT_FLOOR,
+    ATTACHMENT_NEGLECT_HALF_LIFE_MULTIPLIER, INTIMACY_FLOOR, NEGLECT_GRACE_DAYS,
+    NEGLECT_HALF_LIFE_DAYS, WARMTH_FLOOR,
+};
+use crate::relationship::stage_transition::{
+    evaluate_stage_transition, StageTransitionCause, ELEVATED_TENSION_THRESHOLD,
+};
 use crate::relationship::{
     AntecedentDirection, DirectionalDimensions, InteractionPattern, PerceivedRisk,
-    RelationshipStage, SharedDimensions, StakesLevel, TrustAntecedent, TrustDecision,
-    TrustworthinessFactors,
+    RelationshipStage, Reputation, SharedDimensions, StageTransition, StakesLevel, TrustAntecedent,
+    TrustDecision, TrustworthinessFactors, REPUTATION_PRIOR_WEIGHT,
 };
 use crate::state::StateValue;
 use crate::types::{Duration, EntityId, RelationshipId, Timestamp};
@@ -79,6 +89,30 @@ impl std::error::Error for StageTransitionError {}
 /// have minimal impact anyway, so this cap is primarily for memory safety.
 const MAX_ANTECEDENT_HISTORY: usize = 100;
 
+/// A pair event applied to a relationship, kept so that its effects can be
+/// replayed in timestamp order.
+#[derive(Debug, Clone, PartialEq)]
+struct AppliedEvent {
+    timestamp: Timestamp,
+    event: Event,
+    trustor_agreeableness: Option<f32>,
+}
+
+/// The event-derived state of a relationship before any event was applied.
+#[derive(Debug, Clone)]
+struct EventBaseline {
+    stage: RelationshipStage,
+    stage_transitions: Vec<StageTransition>,
+    tension_elevated_since: Option<Timestamp>,
+    pattern: InteractionPattern,
+    trustworthiness_a_to_b: TrustworthinessFactors,
+    trustworthiness_b_to_a: TrustworthinessFactors,
+    antecedent_history_a_to_b: Vec<TrustAntecedent>,
+    antecedent_history_b_to_a: Vec<TrustAntecedent>,
+    perceived_risk_a_to_b: PerceivedRisk,
+    perceived_risk_b_to_a: PerceivedRisk,
+}
+
 /// A relationship between two entities.
 ///
 /// Relationships contain:
@@ -175,6 +209,24 @@ pub struct Relationship {
 
     /// Most recent negative antecedent timestamp (B's perspective).
     last_negative_antecedent_b_to_a: Option<Timestamp>,
+
+    /// Automatic stage transitions, in the order they were applied.
+    stage_transitions: Vec<StageTransition>,
+
+    /// When shared tension last rose above the estrangement threshold.
+    tension_elevated_since: Option<Timestamp>,
+
+    /// Pair events applied to the relationship, in timestamp order.
+    applied_events: Vec<AppliedEvent>,
+
+    /// Event-derived state before the first applied event, for replays.
+    event_baseline: Option<Box<EventBaseline>>,
+
+    /// Resentment released by forgiveness (A's perspective).
+    forgiveness_relief_a_to_b: f32,
+
+    /// Resentment released by forgiveness (B's perspective).
+    forgiveness_relief_b_to_a: f32,
 }
 
 impl Relationship {
@@ -232,6 +284,12 @@ impl Relationship {
             antecedent_history_b_to_a: Vec::new(),
             last_negative_antecedent_a_to_b: None,
             last_negative_antecedent_b_to_a: None,
+            stage_transitions: Vec::new(),
+            tension_elevated_since: None,
+            applied_events: Vec::new(),
+            event_baseline: None,
+            forgiveness_relief_a_to_b: 0.0,
+            forgiveness_relief_b_to_a: 0.0,
         })
     }
 
@@ -370,8 +428,9 @@ impl Relationship {
 
     /// Sets the relationship stage.
     ///
-    /// This is primarily for testing. In production, stages should
-    /// transition based on events.
+    /// This is primarily for testing. In production, stages transition
+    /// automatically through `update_stage`. Manual changes are not
+    /// recorded in the stage transition log.
     ///
     /// # Errors
     ///
@@ -383,6 +442,342 @@ impl Relationship {
         Ok(())
     }
 
+    /// Re-evaluates the stage from the current relationship state.
+    ///
+    /// `trigger` is the event being processed, if any; a severe betrayal
+    /// between the pair estranges the relationship immediately. Also tracks
+    /// how long tension has been elevated so sustained conflict can estrange
+    /// it. Any transition is recorded in the stage transition log; the log
+    /// is rebuilt in timestamp order when applied events are replayed.
+    ///
+    /// Returns the transition that was applied, if any.
+    pub fn update_stage(
+        &mut self,
+        timestamp: Timestamp,
+        trigger: Option<&Event>,
+    ) -> Option<StageTransition> {
+        if self.shared.tension_effective() >= ELEVATED_TENSION_THRESHOLD {
+            self.tension_elevated_since.get_or_insert(timestamp);
+        } else {
+            self.tension_elevated_since = None;
+        }
+
+        let (to, cause) = evaluate_stage_transition(self, timestamp, trigger)?;
+        let transition = StageTransition::new(timestamp, self.stage, to, cause);
+        self.stage = to;
+        self.stage_transitions.push(transition);
+        Some(transition)
+    }
+
+    /// Applies neglect for the time without contact before `timestamp`.
+    ///
+    /// Contacts are `known_since` (for example when the relationship
+    /// formed) and the recorded interactions after it, up to `timestamp`.
+    /// Every gap between contacts, and the time since the last one, counts
+    /// beyond `NEGLECT_GRACE_DAYS`, so the result depends only on the
+    /// interactions before `timestamp`. Over that time intimacy, affinity
+    /// and warmth fade toward fresh-relationship levels at a pace set by the
+    /// bonds and schema, while attachment fades more slowly. The stage then
+    /// regresses as far as the faded closeness warrants.
+    ///
+    /// Neglect is computed from scratch, so apply it once to a relationship
+    /// that has not been neglected yet (see
+    /// `TimestampedRelationship::relationship_at`). Returns the stage
+    /// transitions caused by the neglect.
+    pub fn apply_neglect(
+        &mut self,
+        timestamp: Timestamp,
+        known_since: Timestamp,
+    ) -> Vec<StageTransition> {
+        let days = self.neglected_days(timestamp, known_since);
+        if days <= 0.0 {
+            return Vec::new();
+        }
+
+        let rate = neglect_rate(&self.bonds, self.schema);
+        let retention = neglect_retention(days, NEGLECT_HALF_LIFE_DAYS, rate);
+        let attachment_retention = neglect_retention(
+            days,
+            NEGLECT_HALF_LIFE_DAYS * ATTACHMENT_NEGLECT_HALF_LIFE_MULTIPLIER,
+            rate,
+        );
+
+        fade_toward(self.shared.intimacy_mut(), INTIMACY_FLOOR, retention);
+        fade_toward(self.shared.affinity_mut(), AFFINITY_FLOOR, retention);
+        for directional in [&mut self.directional_a_to_b, &mut self.directional_b_to_a] {
+            fade_toward(directional.warmth_mut(), WARMTH_FLOOR, retention);
+            fade_toward(
+                directional.attachment_mut(),
+                ATTACHMENT_FLOOR,
+                attachment_retention,
+            );
+        }
+        self.pattern.frequency = if !self.pattern.has_recorded_interactions() {
+            self.pattern.frequency * retention
+        } else {
+            self.pattern.frequency_at(timestamp)
+        };
+
+        let mut transitions = Vec::new();
+        while let Some((to, StageTransitionCause::Regression)) =
+            evaluate_stage_transition(self, timestamp, None)
+        {
+            let transition =
+                StageTransition::new(timestamp, self.stage, to, StageTransitionCause::Neglect);
+            self.stage = to;
+            self.stage_transitions.push(transition);
+            transitions.push(transition);
+        }
+        transitions
+    }
+
+    /// Returns the days without contact beyond the grace period, summed
+    /// over every gap between contacts up to `timestamp`.
+    fn neglected_days(&self, timestamp: Timestamp, known_since: Timestamp) -> f64 {
+        let grace = Duration::days(NEGLECT_GRACE_DAYS);
+        let neglected = |from: Timestamp, to: Timestamp| {
+            let grace_end = from + grace;
+            if to > grace_end {
+                (to - grace_end).as_days_f64()
+            } else {
+                0.0
+            }
+        };
+
+        let contacts: Vec<Timestamp> = if self.pattern.has_recorded_interactions() {
+            self.pattern
+                .interactions()
+                .iter()
+                .map(|record| record.timestamp)
+                .take_while(|contact| *contact <= timestamp)
+                .collect()
+        } else {
+            self.pattern
+                .last_interaction_at(timestamp)
+                .into_iter()
+                .collect()
+        };
+
+        let mut last_contact = known_since;
+        let mut days = 0.0;
+        // Interactions folded out of the history keep the neglect between
+        // them; a timestamp inside the folded stretch counts it as contact.
+        if let Some(folded) = self
+            .pattern
+            .folded()
+            .filter(|folded| folded.earliest <= timestamp)
+        {
+            if folded.earliest > last_contact {
+                days += neglected(last_contact, folded.earliest);
+            }
+            if folded.latest <= timestamp {
+                days += folded.neglected_days;
+            }
+            last_contact = last_contact.max(folded.latest.min(timestamp));
+        }
+        for contact in contacts {
+            if contact > last_contact {
+                days += neglected(last_contact, contact);
+                last_contact = contact;
+            }
+        }
+        days + neglected(last_contact, timestamp)
+    }
+
+    /// Returns the automatic stage transitions, oldest first.
+    #[must_use]
+    pub fn stage_transitions(&self) -> &[StageTransition] {
+        &self.stage_transitions
+    }
+
+    /// Returns the stage the relationship was in at the given timestamp,
+    /// according to the stage transition log.
+    #[must_use]
+    pub fn stage_at(&self, timestamp: Timestamp) -> RelationshipStage {
+        match self
+            .stage_transitions
+            .iter()
+            .rev()
+            .find(|transition| transition.timestamp() <= timestamp)
+        {
+            Some(transition) => transition.to(),
+            None => self
+                .stage_transitions
+                .first()
+                .map_or(self.stage, StageTransition::from),
+        }
+    }
+
+    /// Returns the relationship as it was at the given timestamp.
+    ///
+    /// Applied events after `timestamp` are left out and the rest are
+    /// replayed, so trust, the betrayal risk, resentment released by
+    /// forgiveness, recorded interactions and the stage log all describe the
+    /// relationship at `timestamp`. Antecedents and interactions recorded
+    /// directly after `timestamp` are dropped as well. Dimensions that events
+    /// do not record (warmth, intimacy and the like) are kept as they are.
+    /// Neglect is not applied.
+    #[must_use]
+    pub fn as_of(&self, timestamp: Timestamp) -> Relationship {
+        let mut past = self.clone();
+        if past
+            .applied_events
+            .last()
+            .is_some_and(|latest| latest.timestamp > timestamp)
+        {
+            past.applied_events
+                .retain(|applied| applied.timestamp <= timestamp);
+            past.replay_events();
+        }
+        past.stage = past.stage_at(timestamp);
+        past.stage_transitions
+            .retain(|transition| transition.timestamp() <= timestamp);
+        past.tension_elevated_since = past
+            .tension_elevated_since
+            .filter(|since| *since <= timestamp);
+        past.pattern.retain_until(timestamp);
+
+        for direction in [Direction::AToB, Direction::BToA] {
+            let history = past.antecedent_history(direction);
+            let kept: Vec<TrustAntecedent> = history
+                .iter()
+                .filter(|antecedent| antecedent.timestamp() <= timestamp)
+                .cloned()
+                .collect();
+            if kept.len() == history.len() {
+                continue;
+            }
+            let last_negative = Self::latest_negative(&kept);
+            past.trustworthiness_mut(direction)
+                .recompute_from_antecedents(&kept);
+            match direction {
+                Direction::AToB => {
+                    past.antecedent_history_a_to_b = kept;
+                    past.last_negative_antecedent_a_to_b = last_negative;
+                }
+                Direction::BToA => {
+                    past.antecedent_history_b_to_a = kept;
+                    past.last_negative_antecedent_b_to_a = last_negative;
+                }
+            }
+        }
+        past
+    }
+
+    /// Returns when tension last rose above the estrangement threshold,
+    /// if it is still elevated.
+    #[must_use]
+    pub fn tension_elevated_since(&self) -> Option<Timestamp> {
+        self.tension_elevated_since
+    }
+
+    // Event replay
+
+    /// Applies the effects of a pair event to the relationship.
+    ///
+    /// Applied events are kept in timestamp order. An event earlier than
+    /// one already applied resets the event-derived state (trust
+    /// antecedents and trustworthiness, betrayal risk, resentment released
+    /// by forgiveness, recorded interactions and the stage log) to how it
+    /// stood before the first event, then replays every event in timestamp
+    /// order, so the result does not depend on the order events arrive in.
+    pub(crate) fn apply_event(
+        &mut self,
+        event: &Event,
+        timestamp: Timestamp,
+        trustor_agreeableness: Option<f32>,
+    ) {
+        if self.event_baseline.is_none() {
+            self.event_baseline = Some(Box::new(EventBaseline {
+                stage: self.stage,
+                stage_transitions: self.stage_transitions.clone(),
+                tension_elevated_since: self.tension_elevated_since,
+                pattern: self.pattern.clone(),
+                trustworthiness_a_to_b: self.trustworthiness_a_to_b.clone(),
+                trustworthiness_b_to_a: self.trustworthiness_b_to_a.clone(),
+                antecedent_history_a_to_b: self.antecedent_history_a_to_b.clone(),
+                antecedent_history_b_to_a: self.antecedent_history_b_to_a.clone(),
+                perceived_risk_a_to_b: self.perceived_risk_a_to_b.clone(),
+                perceived_risk_b_to_a: self.perceived_risk_b_to_a.clone(),
+            }));
+        }
+
+        let index = self
+            .applied_events
+            .partition_point(|applied| applied.timestamp <= timestamp);
+        let in_order = index == self.applied_events.len();
+        self.applied_events.insert(
+            index,
+            AppliedEvent {
+                timestamp,
+                event: event.clone(),
+                trustor_agreeableness,
+            },
+        );
+        if in_order {
+            apply_event_effects(self, event, timestamp, trustor_agreeableness);
+        } else {
+            self.replay_events();
+        }
+    }
+
+    /// Resets the event-derived state to the baseline and re-applies every
+    /// applied event in timestamp order.
+    fn replay_events(&mut self) {
+        let Some(baseline) = self.event_baseline.as_deref().cloned() else {
+            return;
+        };
+        self.stage = baseline.stage;
+        self.stage_transitions = baseline.stage_transitions;
+        self.tension_elevated_since = baseline.tension_elevated_since;
+        self.pattern = baseline.pattern;
+        self.trustworthiness_a_to_b = baseline.trustworthiness_a_to_b;
+        self.trustworthiness_b_to_a = baseline.trustworthiness_b_to_a;
+        self.last_negative_antecedent_a_to_b =
+            Self::latest_negative(&baseline.antecedent_history_a_to_b);
+        self.last_negative_antecedent_b_to_a =
+            Self::latest_negative(&baseline.antecedent_history_b_to_a);
+        self.antecedent_history_a_to_b = baseline.antecedent_history_a_to_b;
+        self.antecedent_history_b_to_a = baseline.antecedent_history_b_to_a;
+        self.perceived_risk_a_to_b
+            .restore_betrayal_from(&baseline.perceived_risk_a_to_b);
+        self.perceived_risk_b_to_a
+            .restore_betrayal_from(&baseline.perceived_risk_b_to_a);
+        for direction in [Direction::AToB, Direction::BToA] {
+            let relief = std::mem::take(self.forgiveness_relief_mut(direction));
+            self.directional_mut(direction)
+                .resentment_mut()
+                .add_delta(relief);
+        }
+
+        let events = std::mem::take(&mut self.applied_events);
+        for applied in &events {
+            apply_event_effects(
+                self,
+                &applied.event,
+                applied.timestamp,
+                applied.trustor_agreeableness,
+            );
+        }
+        self.applied_events = events;
+    }
+
+    /// Releases resentment through forgiveness, remembering the amount so
+    /// that a replay can restore it.
+    pub(crate) fn release_resentment(&mut self, direction: Direction, relief: f32) {
+        self.directional_mut(direction)
+            .resentment_mut()
+            .add_delta(-relief);
+        *self.forgiveness_relief_mut(direction) += relief;
+    }
+
+    fn forgiveness_relief_mut(&mut self, direction: Direction) -> &mut f32 {
+        match direction {
+            Direction::AToB => &mut self.forgiveness_relief_a_to_b,
+            Direction::BToA => &mut self.forgiveness_relief_b_to_a,
+        }
+    }
+
     // Trustworthiness access
 
     /// Returns a reference to the trustworthiness factors for a direction.
@@ -402,6 +797,37 @@ impl Relationship {
         }
     }
 
+    /// Swaps the reputation prior on a direction's trustworthiness bases.
+    ///
+    /// `previous` is undone and `prior` applied, both on the current
+    /// factors and on those restored when applied events are replayed.
+    pub(crate) fn replace_reputation_prior(
+        &mut self,
+        direction: Direction,
+        previous: Option<&Reputation>,
+        prior: Option<&Reputation>,
+    ) {
+        let baseline = self
+            .event_baseline
+            .as_deref_mut()
+            .map(|baseline| match direction {
+                Direction::AToB => &mut baseline.trustworthiness_a_to_b,
+                Direction::BToA => &mut baseline.trustworthiness_b_to_a,
+            });
+        let current = match direction {
+            Direction::AToB => &mut self.trustworthiness_a_to_b,
+            Direction::BToA => &mut self.trustworthiness_b_to_a,
+        };
+        for factors in std::iter::once(current).chain(baseline) {
+            if let Some(previous) = previous {
+                factors.remove_reputation_prior(previous);
+            }
+            if let Some(prior) = prior {
+                factors.apply_reputation_prior(prior);
+            }
+        }
+    }
+
     // Perceived risk access
 
     /// Returns a reference to the perceived risk for a direction.
@@ -443,7 +869,28 @@ impl Relationship {
     // Trust antecedent history
 
     /// Appends a trust antecedent to the history for the given direction.
+    ///
+    /// Appended antecedents are kept when applied events are replayed.
     pub fn append_antecedent(&mut self, direction: Direction, antecedent: TrustAntecedent) {
+        if let Some(baseline) = self.event_baseline.as_deref_mut() {
+            let history = match direction {
+                Direction::AToB => &mut baseline.antecedent_history_a_to_b,
+                Direction::BToA => &mut baseline.antecedent_history_b_to_a,
+            };
+            Self::push_antecedent(history, antecedent.clone(), &mut None);
+        }
+        self.append_event_antecedent(direction, antecedent);
+    }
+
+    /// Appends an antecedent produced by an applied event.
+    ///
+    /// Unlike `append_antecedent`, the antecedent is not kept across
+    /// replays; replaying the event produces it again.
+    pub(crate) fn append_event_antecedent(
+        &mut self,
+        direction: Direction,
+        antecedent: TrustAntecedent,
+    ) {
         match direction {
             Direction::AToB => {
                 Self::push_antecedent(
@@ -486,7 +933,7 @@ impl Relationship {
         last_negative: &mut Option<Timestamp>,
     ) {
         if antecedent.direction() == AntecedentDirection::Negative {
-            *last_negative = Some(antecedent.timestamp());
+            *last_negative = (*last_negative).max(Some(antecedent.timestamp()));
         }
 
         history.push(antecedent);
@@ -497,6 +944,14 @@ impl Relationship {
         }
     }
 
+    fn latest_negative(history: &[TrustAntecedent]) -> Option<Timestamp> {
+        history
+            .iter()
+            .filter(|antecedent| antecedent.direction() == AntecedentDirection::Negative)
+            .map(TrustAntecedent::timestamp)
+            .max()
+    }
+
     // Path-based access
 
     /// Gets a StateValue reference by path.
@@ -714,6 +1169,41 @@ impl Relationship {
         )
     }
 
+    /// Computes a trust decision that accounts for the trustee's reputation.
+    ///
+    /// Reputation only matters while the pair are strangers: the trustor's
+    /// propensity is shifted by the reputation (see
+    /// `Reputation::shift_trust`), and a reputation for being feared lowers
+    /// every willingness. Past the Stranger stage, first-hand experience governs
+    /// and this is the same as `compute_trust_decision`.
+    #[must_use]
+    pub fn compute_trust_decision_with_reputation(
+        &self,
+        direction: Direction,
+        trustor_propensity: f32,
+        stakes: StakesLevel,
+        reputation: Option<&Reputation>,
+    ) -> TrustDecision {
+        let Some(reputation) = reputation.filter(|_| self.stage == RelationshipStage::Stranger)
+        else {
+            return self.compute_trust_decision(direction, trustor_propensity, stakes);
+        };
+
+        let propensity = reputation.shift_trust(trustor_propensity, 1.0);
+        let decision = self.compute_trust_decision(direction, propensity, stakes);
+        let fear = reputation.fear_penalty();
+
+        TrustDecision::new(
+            decision.task_willingness() - fear,
+            decision.support_willingness() - fear,
+            decision.disclosure_willingness() - fear,
+            decision.decision_certainty(),
+            decision
+                .trustee_confidence()
+                .max(reputation.confidence() * REPUTATION_PRIOR_WEIGHT),
+        )
+    }
+
     // Decay
 
     /// Applies decay to all relationship dimensions over the specified duration.
@@ -749,6 +1239,8 @@ impl PartialEq for Relationship {
             && self.antecedent_history_b_to_a == other.antecedent_history_b_to_a
             && self.last_negative_antecedent_a_to_b == other.last_negative_antecedent_a_to_b
             && self.last_negative_antecedent_b_to_a == other.last_negative_antecedent_b_to_a
+            && self.stage_transitions == other.stage_transitions
+            && self.tension_elevated_since == other.tension_elevated_since
     }
 }
 
@@ -756,7 +1248,7 @@ impl PartialEq for Relationship {
 mod tests {
     use super::*;
     use crate::enums::{DirectionalPath, SharedPath, TrustPath};
-    use crate::relationship::{AntecedentDirection, AntecedentType};
+    use crate::relationship::{AntecedentDirection, AntecedentType, StageTransitionCause};
     use crate::types::Duration;
 
     fn alice() -> EntityId {
@@ -1117,6 +1609,41 @@ mod tests {
         assert!(constrained.task_willingness() < baseline.task_willingness());
     }
 
+    #[test]
+    fn reputation_shapes_stranger_trust_only() {
+        use crate::memory::ReputationDelta;
+
+        let mut notorious = Reputation::new();
+        notorious.apply_delta(
+            &ReputationDelta::new().with_trusted(-0.8).with_feared(0.6),
+            1.0,
+        );
+
+        let stranger = Relationship::try_between(alice(), bob()).unwrap();
+        let plain = stranger.compute_trust_decision(Direction::AToB, 0.6, StakesLevel::Low);
+        let warned = stranger.compute_trust_decision_with_reputation(
+            Direction::AToB,
+            0.6,
+            StakesLevel::Low,
+            Some(&notorious),
+        );
+        assert!(warned.support_willingness() < plain.support_willingness());
+        assert!(warned.trustee_confidence() > plain.trustee_confidence());
+
+        let established = Relationship::try_between(alice(), bob())
+            .unwrap()
+            .with_stage(RelationshipStage::Established);
+        assert_eq!(
+            established.compute_trust_decision_with_reputation(
+                Direction::AToB,
+                0.6,
+                StakesLevel::Low,
+                Some(&notorious),
+            ),
+            established.compute_trust_decision(Direction::AToB, 0.6, StakesLevel::Low)
+        );
+    }
+
     #[test]
     fn propensity_weight_diminishes_with_stage() {
         let stranger = Relationship::try_between(alice(), bob()).unwrap();
@@ -1607,4 +2134,136 @@ mod tests {
         assert!(decision.task_willingness() >= 0.0);
         assert!(decision.task_willingness() <= 1.0);
     }
+
+    #[test]
+    fn update_stage_logs_transitions_and_answers_stage_at() {
+        let mut rel = Relationship::try_between(alice(), bob()).unwrap();
+        let t0 = Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+        let t1 = Timestamp::from_ymd_hms(2024, 3, 1, 0, 0, 0);
+
+        assert!(rel.update_stage(t0, None).is_none());
+        assert!(rel.stage_transitions().is_empty());
+
+        rel.shared_mut().affinity_mut().set_base(0.8);
+        rel.pattern_mut().frequency = 0.6;
+        let transition = rel.update_stage(t1, None).unwrap();
+
+        assert_eq!(transition.from(), RelationshipStage::Stranger);
+        assert_eq!(transition.to(), RelationshipStage::Acquaintance);
+        assert_eq!(transition.cause(), StageTransitionCause::Progression);
+        assert_eq!(rel.stage(), RelationshipStage::Acquaintance);
+        assert_eq!(rel.stage_transitions(), &[transition]);
+        assert_eq!(rel.stage_at(t0), RelationshipStage::Stranger);
+        assert_eq!(rel.stage_at(t1), RelationshipStage::Acquaintance);
+    }
+
+    #[test]
+    fn tension_tracking_resets_when_tension_subsides() {
+        let mut rel = Relationship::try_between(alice(), bob()).unwrap();
+        let t0 = Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+        let t1 = Timestamp::from_ymd_hms(2024, 1, 10, 0, 0, 0);
+
+        rel.shared_mut().tension_mut().set_base(0.9);
+        rel.update_stage(t0, None);
+        rel.update_stage(t1, None);
+        assert_eq!(rel.tension_elevated_since(), Some(t0));
+
+        rel.shared_mut().tension_mut().set_base(0.1);
+        rel.update_stage(t1, None);
+        assert_eq!(rel.tension_elevated_since(), None);
+    }
+
+    #[test]
+    fn as_of_leaves_out_later_antecedents_and_interactions() {
+        let ts_positive = Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+        let ts_negative = Timestamp::from_ymd_hms(2024, 6, 1, 0, 0, 0);
+        let history = [
+            (ts_positive, AntecedentDirection::Positive, "support"),
+            (ts_negative, AntecedentDirection::Negative, "betrayal"),
+        ];
+        let with_history = |count: usize| {
+            let mut rel = Relationship::try_between(alice(), bob()).unwrap();
+            for (ts, direction, context) in &history[..count] {
+                rel.append_antecedent(
+                    Direction::AToB,
+                    TrustAntecedent::new(*ts, AntecedentType::Integrity, *direction, 0.5, *context),
+                );
+                rel.pattern_mut().record_interaction(*ts, 0.5);
+            }
+            let antecedents = rel.antecedent_history(Direction::AToB).to_vec();
+            rel.trustworthiness_mut(Direction::AToB)
+                .recompute_from_antecedents(&antecedents);
+            rel
+        };
+        let rel = with_history(2);
+
+        let past = rel.as_of(ts_positive + Duration::days(30));
+        assert_eq!(past.last_negative_antecedent(Direction::AToB), None);
+        assert_eq!(past, with_history(1));
+        assert_eq!(rel.as_of(ts_negative), rel);
+    }
+
+    #[test]
+    fn apply_neglect_counts_every_gap_between_contacts() {
+        let t0 = Timestamp::from_ymd_hms(2020, 1, 1, 0, 0, 0);
+        let mut base = Relationship::try_between(alice(), bob()).unwrap();
+        base.shared_mut().intimacy_mut().set_base(0.8);
+
+        // Two gaps of 200 days, each neglected for 110 days past the grace
+        // period, fade as much as one 310-day gap neglected for 220 days.
+        let mut resumed = base.clone();
+        resumed
+            .pattern_mut()
+            .record_interaction(t0 + Duration::days(200), 0.5);
+        resumed.apply_neglect(t0 + Duration::days(400), t0);
+        let mut silent = base;
+        silent.apply_neglect(t0 + Duration::days(310), t0);
+
+        let a = resumed.shared().intimacy_effective();
+        let b = silent.shared().intimacy_effective();
+        assert!(a < 0.8);
+        assert!((a - b).abs() < 1e-4);
+    }
+
+    #[test]
+    fn apply_neglect_keeps_contacts_folded_out_of_the_history() {
+        let t0 = Timestamp::from_ymd_hms(2000, 1, 1, 0, 0, 0);
+        let mut base = Relationship::try_between(alice(), bob()).unwrap();
+        base.shared_mut().intimacy_mut().set_base(0.8);
+
+        // Monthly contact for 25 years is never neglected, even though only
+        // the latest interactions are kept individually.
+        let mut regular = base.clone();
+        for month in 0..300 {
+            regular
+                .pattern_mut()
+                .record_interaction(t0 + Duration::days(month * 30), 0.5);
+        }
+        assert!(regular.pattern().interactions().len() < 300);
+        let last = t0 + Duration::days(299 * 30);
+        regular.apply_neglect(last + Duration::days(10), t0);
+        assert!((regular.shared().intimacy_effective() - 0.8).abs() < f32::EPSILON);
+
+        // A 300-day silence among the folded interactions still counts.
+        let mut lapsed = base.clone();
+        for week in 0..10 {
+            lapsed
+                .pattern_mut()
+                .record_interaction(t0 + Duration::days(week * 7), 0.5);
+        }
+        let resumed = t0 + Duration::days(63 + 300);
+        for week in 0..250 {
+            lapsed
+                .pattern_mut()
+                .record_interaction(resumed + Duration::days(week * 7), 0.5);
+        }
+        lapsed.apply_neglect(resumed + Duration::days(249 * 7 + 10), t0);
+        let mut silent = base;
+        silent.apply_neglect(t0 + Duration::days(300), t0);
+
+        let a = lapsed.shared().intimacy_effective();
+        let b = silent.shared().intimacy_effective();
+        assert!(a < 0.8);
+        assert!((a - b).abs() < 1e-4);
+    }
 }
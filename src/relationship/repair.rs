@@ -0,0 +1,147 @@
+//! Trust repair after violations.
+//!
+//! Apology, restitution and forgiveness events produce positive integrity
+//! and benevolence antecedents, but how much they count depends on the
+//! circumstances of the repair:
+//!
+//! - **Timing**: repair is most credible soon after the violation and
+//!   loses force as the wrong goes unaddressed.
+//! - **Agreeableness**: agreeable trustors are more willing to accept
+//!   repair attempts.
+//! - **Prior violations**: each repeated violation makes the next repair
+//!   less believable.
+//!
+//! Repair magnitudes are smaller than the violations they answer, and
+//! positive antecedents already count for less during the rebuilding
+//! window, so trust recovers more slowly than it fell.
+
+use crate::relationship::{AntecedentDirection, AntecedentType, TrustAntecedent};
+use crate::types::Duration;
+
+/// Half-life of repair credibility as time passes since the violation.
+pub const REPAIR_TIMING_HALF_LIFE_DAYS: f64 = 90.0;
+
+/// Floor on the timing factor so late repair still counts for something.
+const MIN_TIMING_FACTOR: f32 = 0.3;
+
+/// Agreeableness sensitivity: agreeableness 1.0 scales repair by 1.5.
+const AGREEABLENESS_SENSITIVITY: f32 = 0.5;
+
+/// Penalty per prior violation beyond the first.
+const REPEAT_VIOLATION_PENALTY: f32 = 0.5;
+
+/// Maximum resentment released by a full-strength forgiveness.
+pub const FORGIVENESS_RESENTMENT_RELIEF: f32 = 0.3;
+
+/// Share of betrayal risk released by a full-strength repair.
+pub const REPAIR_BETRAYAL_RISK_RELIEF: f32 = 0.25;
+
+/// Returns how effective a repair attempt is (0.0 to 1.5).
+///
+/// # Arguments
+///
+/// * `since_violation` - Time since the most recent violation, or `None`
+///   if there is nothing to repair (which makes the repair ineffective)
+/// * `agreeableness` - The trustor's HEXACO agreeableness (-1.0 to 1.0)
+/// * `prior_violations` - Number of violations in the trustor's history
+///
+/// # Examples
+///
+/// ```
+/// use eventsim_rs::relationship::repair_effectiveness;
+/// use eventsim_rs::types::Duration;
+///
+/// let prompt = repair_effectiveness(Some(Duration::days(1)), 0.0, 1);
+/// let late = repair_effectiveness(Some(Duration::days(365)), 0.0, 1);
+/// assert!(prompt > late);
+/// assert_eq!(repair_effectiveness(None, 0.0, 0), 0.0);
+/// ```
+#[must_use]
+pub fn repair_effectiveness(
+    since_violation: Option<Duration>,
+    agreeableness: f32,
+    prior_violations: usize,
+) -> f32 {
+    let Some(elapsed) = since_violation else {
+        return 0.0;
+    };
+
+    let timing = (0.5_f64.powf(elapsed.as_days_f64() / REPAIR_TIMING_HALF_LIFE_DAYS) as f32)
+        .max(MIN_TIMING_FACTOR);
+    let disposition = 1.0 + AGREEABLENESS_SENSITIVITY * agreeableness.clamp(-1.0, 1.0);
+    let repeats = prior_violations.saturating_sub(1) as f32;
+    let credibility = 1.0 / (1.0 + REPEAT_VIOLATION_PENALTY * repeats);
+
+    (timing * disposition * credibility).clamp(0.0, 1.5)
+}
+
+/// Counts distinct violations in an antecedent history.
+///
+/// A violation is a negative integrity or benevolence antecedent. Several
+/// antecedents from the same event share a timestamp and count once.
+#[must_use]
+pub fn count_violations(history: &[TrustAntecedent]) -> usize {
+    let mut timestamps: Vec<_> = history
+        .iter()
+        .filter(|antecedent| {
+            antecedent.direction() == AntecedentDirection::Negative
+                && matches!(
+                    antecedent.antecedent_type(),
+                    AntecedentType::Integrity | AntecedentType::Benevolence
+                )
+        })
+        .map(TrustAntecedent::timestamp)
+        .collect();
+    timestamps.sort();
+    timestamps.dedup();
+    timestamps.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::Timestamp;
+
+    #[test]
+    fn agreeable_trustors_accept_repair_more_readily() {
+        let since = Some(Duration::days(7));
+        assert!(repair_effectiveness(since, 0.8, 1) > repair_effectiveness(since, -0.8, 1));
+    }
+
+    #[test]
+    fn repeated_violations_undermine_repair() {
+        let since = Some(Duration::days(7));
+        let first = repair_effectiveness(since, 0.0, 1);
+        let third = repair_effectiveness(since, 0.0, 3);
+        assert!((third - first / 2.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn late_repair_keeps_a_floor() {
+        let decade = repair_effectiveness(Some(Duration::days(3650)), 0.0, 1);
+        assert!((decade - MIN_TIMING_FACTOR).abs() < 1e-6);
+    }
+
+    #[test]
+    fn count_violations_groups_by_event() {
+        let t1 = Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+        let t2 = Timestamp::from_ymd_hms(2024, 2, 1, 0, 0, 0);
+        let negative = |ts, kind| {
+            TrustAntecedent::new(ts, kind, AntecedentDirection::Negative, 0.3, "betrayal")
+        };
+        let history = vec![
+            negative(t1, AntecedentType::Integrity),
+            negative(t1, AntecedentType::Benevolence),
+            negative(t2, AntecedentType::Integrity),
+            negative(t2, AntecedentType::Ability),
+            TrustAntecedent::new(
+                t2,
+                AntecedentType::Integrity,
+                AntecedentDirection::Positive,
+                0.2,
+                "apology",
+            ),
+        ];
+        assert_eq!(count_violations(&history), 2);
+    }
+}
@@ -0,0 +1,897 @@
+//! Reputation: what observers believe about an entity they may never have met.
+//!
+//! Each observer holds their own view of a subject: how trusted, feared and
+//! hated the subject is, plus how confident the observer is in that view.
+//! Views form three ways:
+//!
+//! - **Direct experience**: the target of an event judges its source at full
+//!   confidence.
+//! - **Witnessing**: bystanders present at an event, and targets of events
+//!   tagged `Witnessed`, judge its source at `MemorySource::Witness`
+//!   confidence.
+//! - **Gossip**: a teller passes their view to a listener. The listener
+//!   discounts it by `MemorySource::Rumor` confidence, by the teller's own
+//!   confidence and by how much integrity the listener sees in the teller.
+//!
+//! Every observation and conversation is recorded at a timestamp, and views
+//! are computed as of a timestamp from what the observer knew by then.
+//!
+//! Group members' views can be aggregated into a group-level reputation.
+//! Reputation seeds trustworthiness priors when strangers first meet and
+//! shifts trust decisions while the relationship is still at the Stranger
+//! stage.
+
+use std::collections::{HashMap, HashSet};
+
+use crate::enums::{EventTag, EventType};
+use crate::event::Event;
+use crate::memory::{MemorySource, ReputationDelta};
+use crate::types::{EntityId, GroupId, Timestamp};
+
+/// Weight of a reputation's pull on a stranger's trustworthiness bases
+/// (see `Reputation::shift_trust`).
+pub const REPUTATION_PRIOR_WEIGHT: f32 = 0.5;
+
+/// Views held with less confidence than this are not passed on as gossip.
+pub const GOSSIP_MIN_CONFIDENCE: f32 = 0.05;
+
+/// How much a fully confident reputation for being feared lowers a
+/// stranger's willingness to be vulnerable.
+const FEAR_WILLINGNESS_PENALTY: f32 = 0.3;
+
+/// How much being hated counts against a subject's standing.
+const HATRED_STANDING_PENALTY: f32 = 0.5;
+
+/// An observer's view of a subject's reputation.
+///
+/// - `trusted`: -1.0 (known to be untrustworthy) to 1.0 (known to be trustworthy)
+/// - `feared`: 0.0 to 1.0
+/// - `hated`: 0.0 to 1.0
+/// - `confidence`: 0.0 (no information) to 1.0 (certain)
+///
+/// # Examples
+///
+/// ```
+/// use eventsim_rs::memory::{MemorySource, ReputationDelta};
+/// use eventsim_rs::relationship::Reputation;
+///
+/// let mut view = Reputation::new();
+/// view.apply_delta(&ReputationDelta::new().with_trusted(-0.4), MemorySource::Witness.confidence());
+///
+/// assert!(view.trusted() < 0.0);
+/// assert!((view.confidence() - 0.7).abs() < f32::EPSILON);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Reputation {
+    trusted: f32,
+    feared: f32,
+    hated: f32,
+    confidence: f32,
+}
+
+impl Reputation {
+    /// Creates a neutral view held with no confidence.
+    #[must_use]
+    pub fn new() -> Self {
+        Reputation {
+            trusted: 0.0,
+            feared: 0.0,
+            hated: 0.0,
+            confidence: 0.0,
+        }
+    }
+
+    /// Returns how trusted the subject is believed to be (-1.0 to 1.0).
+    #[must_use]
+    pub fn trusted(&self) -> f32 {
+        self.trusted
+    }
+
+    /// Returns how feared the subject is believed to be (0.0 to 1.0).
+    #[must_use]
+    pub fn feared(&self) -> f32 {
+        self.feared
+    }
+
+    /// Returns how hated the subject is believed to be (0.0 to 1.0).
+    #[must_use]
+    pub fn hated(&self) -> f32 {
+        self.hated
+    }
+
+    /// Returns the confidence in this view (0.0 to 1.0).
+    #[must_use]
+    pub fn confidence(&self) -> f32 {
+        self.confidence
+    }
+
+    /// Applies an observed change, scaled by the observation's confidence.
+    ///
+    /// Confidence accumulates across observations: each one removes a share
+    /// of the remaining uncertainty equal to its own confidence.
+    pub fn apply_delta(&mut self, delta: &ReputationDelta, confidence: f32) {
+        let confidence = confidence.clamp(0.0, 1.0);
+        if !delta.has_changes() || confidence <= 0.0 {
+            return;
+        }
+        if let Some(d) = delta.trusted() {
+            self.trusted = (self.trusted + d * confidence).clamp(-1.0, 1.0);
+        }
+        if let Some(d) = delta.feared() {
+            self.feared = (self.feared + d * confidence).clamp(0.0, 1.0);
+        }
+        if let Some(d) = delta.hated() {
+            self.hated = (self.hated + d * confidence).clamp(0.0, 1.0);
+        }
+        self.confidence = 1.0 - (1.0 - self.confidence) * (1.0 - confidence);
+    }
+
+    /// Blends another view into this one with the given weight.
+    ///
+    /// Values move to the confidence-weighted mean of the two views, so a
+    /// listener with no view adopts what they hear while a well-informed
+    /// listener barely moves. Confidence rises to at most `weight`: hearing
+    /// the same rumor twice does not make it more certain.
+    pub fn blend(&mut self, other: &Reputation, weight: f32) {
+        let weight = weight.clamp(0.0, 1.0);
+        if weight <= 0.0 {
+            return;
+        }
+        let share = weight / (self.confidence + weight);
+        self.trusted += (other.trusted - self.trusted) * share;
+        self.feared += (other.feared - self.feared) * share;
+        self.hated += (other.hated - self.hated) * share;
+        self.confidence = self.confidence.max(weight);
+    }
+
+    /// Returns the net standing this reputation implies (-1.0 to 1.0).
+    ///
+    /// Being hated counts against the subject alongside being distrusted.
+    #[must_use]
+    pub fn standing(&self) -> f32 {
+        (self.trusted - HATRED_STANDING_PENALTY * self.hated).clamp(-1.0, 1.0)
+    }
+
+    /// Moves a trust value (0.0 to 1.0) toward what this reputation suggests.
+    ///
+    /// A positive standing pulls the value toward 1.0 and a negative one
+    /// toward 0.0, by the standing's share of the distance. The move is
+    /// scaled by `weight` and by confidence, so a neutral or unconfident
+    /// reputation leaves the value where it was.
+    #[must_use]
+    pub fn shift_trust(&self, value: f32, weight: f32) -> f32 {
+        let standing = self.standing();
+        let target = if standing >= 0.0 {
+            value + (1.0 - value) * standing
+        } else {
+            value * (1.0 + standing)
+        };
+        (value + (target - value) * weight.clamp(0.0, 1.0) * self.confidence).clamp(0.0, 1.0)
+    }
+
+    /// Undoes `shift_trust`, returning the value it was applied to.
+    #[must_use]
+    pub(crate) fn unshift_trust(&self, shifted: f32, weight: f32) -> f32 {
+        let standing = self.standing();
+        let pull = standing * weight.clamp(0.0, 1.0) * self.confidence;
+        let value = if standing >= 0.0 {
+            if pull >= 1.0 {
+                return shifted;
+            }
+            (shifted - pull) / (1.0 - pull)
+        } else {
+            shifted / (1.0 + pull)
+        };
+        value.clamp(0.0, 1.0)
+    }
+
+    /// Returns the willingness penalty from the subject being feared.
+    #[must_use]
+    pub fn fear_penalty(&self) -> f32 {
+        FEAR_WILLINGNESS_PENALTY * self.feared * self.confidence
+    }
+}
+
+impl Default for Reputation {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Returns the reputation change an event implies for its source.
+///
+/// Changes are scaled by severity. Returns `None` for event types that say
+/// nothing about the source's character.
+///
+/// # Examples
+///
+/// ```
+/// use eventsim_rs::enums::EventType;
+/// use eventsim_rs::event::EventBuilder;
+/// use eventsim_rs::relationship::reputation_delta_for_event;
+///
+/// let betrayal = EventBuilder::new(EventType::Betrayal).severity(1.0).build().unwrap();
+/// let delta = reputation_delta_for_event(&betrayal).unwrap();
+/// assert!(delta.trusted().unwrap() < 0.0);
+///
+/// let loss = EventBuilder::new(EventType::Loss).build().unwrap();
+/// assert!(reputation_delta_for_event(&loss).is_none());
+/// ```
+#[must_use]
+pub fn reputation_delta_for_event(event: &Event) -> Option<ReputationDelta> {
+    let (trusted, feared, hated) = match event.event_type() {
+        EventType::Betrayal => (-0.4, 0.0, 0.2),
+        EventType::Violence => (-0.3, 0.4, 0.3),
+        EventType::Humiliation | EventType::ShamingEvent => (-0.1, 0.1, 0.2),
+        EventType::SocialExclusion | EventType::Rejection | EventType::GroupExclusion => {
+            (-0.05, 0.0, 0.1)
+        }
+        EventType::Conflict => (0.0, 0.0, 0.05),
+        EventType::Support => (0.2, 0.0, 0.0),
+        EventType::SocialInclusion => (0.1, 0.0, 0.0),
+        EventType::Apology => (0.05, 0.0, 0.0),
+        EventType::Restitution => (0.1, 0.0, 0.0),
+        _ => return None,
+    };
+
+    let severity = event.severity() as f32;
+    let mut delta = ReputationDelta::new();
+    if trusted != 0.0 {
+        delta = delta.with_trusted(trusted * severity);
+    }
+    if feared != 0.0 {
+        delta = delta.with_feared(feared * severity);
+    }
+    if hated != 0.0 {
+        delta = delta.with_hated(hated * severity);
+    }
+    Some(delta)
+}
+
+/// A piece of evidence an observer holds about a subject.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Evidence {
+    /// A first-hand or witnessed observation, with its confidence.
+    Observed {
+        delta: ReputationDelta,
+        confidence: f32,
+    },
+    /// A view heard from someone else, with the weight it carries.
+    Heard { told: Reputation, weight: f32 },
+}
+
+impl Evidence {
+    /// Orders evidence recorded at the same timestamp: observations first.
+    fn rank(&self) -> u8 {
+        match self {
+            Evidence::Observed { .. } => 0,
+            Evidence::Heard { .. } => 1,
+        }
+    }
+
+    fn apply_to(&self, view: &mut Reputation) {
+        match self {
+            Evidence::Observed { delta, confidence } => view.apply_delta(delta, *confidence),
+            Evidence::Heard { told, weight } => view.blend(told, *weight),
+        }
+    }
+}
+
+/// A conversation in which a listener heard every view a teller held.
+#[derive(Debug, Clone, PartialEq)]
+struct Conversation {
+    timestamp: Timestamp,
+    teller: EntityId,
+    teller_integrity: f32,
+}
+
+/// Returns the weight a rumor carries: rumor confidence scaled by the
+/// teller's own confidence and by the teller's integrity (0.0 to 1.0).
+fn rumor_weight(told: &Reputation, teller_integrity: f32) -> f32 {
+    MemorySource::Rumor.confidence() * told.confidence() * teller_integrity.clamp(0.0, 1.0)
+}
+
+/// Per-observer reputation evidence plus group membership for aggregates.
+///
+/// Every observation, rumor and conversation is recorded at a timestamp,
+/// and views are computed as of a timestamp from what was recorded up to
+/// it, in timestamp order. Views therefore do not depend on the order in
+/// which evidence is recorded.
+///
+/// # Examples
+///
+/// ```
+/// use eventsim_rs::memory::{MemorySource, ReputationDelta};
+/// use eventsim_rs::relationship::ReputationStore;
+/// use eventsim_rs::types::{EntityId, Timestamp};
+///
+/// let alice = EntityId::new("alice").unwrap();
+/// let bob = EntityId::new("bob").unwrap();
+/// let carol = EntityId::new("carol").unwrap();
+/// let seen = Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+/// let told = Timestamp::from_ymd_hms(2024, 2, 1, 0, 0, 0);
+///
+/// let mut store = ReputationStore::new();
+/// let delta = ReputationDelta::new().with_trusted(-0.5);
+/// store.observe(&alice, &carol, &delta, MemorySource::Self_, seen);
+///
+/// // Alice tells Bob, who sees her as fairly honest.
+/// store.hear_from(&bob, &alice, 0.8, told);
+///
+/// let own = store.view(&alice, &carol, told).unwrap();
+/// let heard = store.view(&bob, &carol, told).unwrap();
+/// assert!(heard.trusted() < 0.0);
+/// assert!(heard.confidence() < own.confidence());
+/// assert!(store.view(&bob, &carol, seen).is_none());
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ReputationStore {
+    /// Evidence keyed by (observer, subject), in timestamp order.
+    evidence: HashMap<(EntityId, EntityId), Vec<(Timestamp, Evidence)>>,
+    /// Conversations keyed by listener, in timestamp order.
+    conversations: HashMap<EntityId, Vec<Conversation>>,
+    /// Members of each group.
+    groups: HashMap<GroupId, HashSet<EntityId>>,
+}
+
+impl ReputationStore {
+    /// Creates an empty store.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the observer's own view of the subject as of `timestamp`, if
+    /// they had one.
+    #[must_use]
+    pub fn view(
+        &self,
+        observer: &EntityId,
+        subject: &EntityId,
+        timestamp: Timestamp,
+    ) -> Option<Reputation> {
+        self.view_until(observer, subject, timestamp, true, &mut HashMap::new())
+    }
+
+    /// Returns every view the observer held as of `timestamp`, as
+    /// (subject, view) pairs sorted by subject.
+    #[must_use]
+    pub fn views_held_by(
+        &self,
+        observer: &EntityId,
+        timestamp: Timestamp,
+    ) -> Vec<(EntityId, Reputation)> {
+        let mut subjects: Vec<&EntityId> = self
+            .evidence
+            .keys()
+            .map(|(_, subject)| subject)
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .collect();
+        subjects.sort_by(|a, b| a.as_str().cmp(b.as_str()));
+        subjects
+            .into_iter()
+            .filter_map(|subject| {
+                let view = self.view(observer, subject, timestamp)?;
+                Some((subject.clone(), view))
+            })
+            .collect()
+    }
+
+    /// Records a first-hand or witnessed observation of the subject at
+    /// `timestamp`.
+    ///
+    /// The observation counts with the source's confidence. Observations
+    /// of oneself are ignored.
+    pub fn observe(
+        &mut self,
+        observer: &EntityId,
+        subject: &EntityId,
+        delta: &ReputationDelta,
+        source: MemorySource,
+        timestamp: Timestamp,
+    ) {
+        let confidence = source.confidence().clamp(0.0, 1.0);
+        if observer == subject || !delta.has_changes() || confidence <= 0.0 {
+            return;
+        }
+        self.record(
+            observer,
+            subject,
+            timestamp,
+            Evidence::Observed {
+                delta: *delta,
+                confidence,
+            },
+        );
+    }
+
+    /// Records what the event at `timestamp` shows of its source to its
+    /// target and bystanders.
+    ///
+    /// The target experiences the event directly, unless it is tagged
+    /// `Witnessed`. Each bystander witnesses it. Events without both a
+    /// source and a target, or that say nothing about character, are
+    /// ignored.
+    pub fn observe_event(&mut self, event: &Event, timestamp: Timestamp, bystanders: &[EntityId]) {
+        let (Some(subject), Some(observer)) = (event.source(), event.target()) else {
+            return;
+        };
+        let Some(delta) = reputation_delta_for_event(event) else {
+            return;
+        };
+        let source = if event.has_tag(EventTag::Witnessed) {
+            MemorySource::Witness
+        } else {
+            MemorySource::Self_
+        };
+        self.observe(observer, subject, &delta, source, timestamp);
+        for bystander in bystanders.iter().filter(|b| *b != observer) {
+            self.observe(bystander, subject, &delta, MemorySource::Witness, timestamp);
+        }
+    }
+
+    /// Records a view of the subject that the listener heard at
+    /// `timestamp`.
+    ///
+    /// The view counts with rumor confidence, scaled by its own confidence
+    /// and by `teller_integrity` (the listener's perception of the teller's
+    /// integrity, 0.0 to 1.0). Rumors about the listener themselves are
+    /// ignored.
+    pub fn hear(
+        &mut self,
+        listener: &EntityId,
+        subject: &EntityId,
+        told: &Reputation,
+        teller_integrity: f32,
+        timestamp: Timestamp,
+    ) {
+        let weight = rumor_weight(told, teller_integrity);
+        if listener == subject || weight <= 0.0 {
+            return;
+        }
+        self.record(
+            listener,
+            subject,
+            timestamp,
+            Evidence::Heard {
+                told: *told,
+                weight,
+            },
+        );
+    }
+
+    /// Records a conversation at `timestamp` in which the listener hears
+    /// the teller's views.
+    ///
+    /// Every view the teller held at `timestamp` with at least
+    /// `GOSSIP_MIN_CONFIDENCE`, other than of the listener, is passed on as
+    /// by `hear`. The teller's views include their observations at
+    /// `timestamp` but not what they heard in conversations at the same
+    /// timestamp, so a rumor travels one conversation per timestamp.
+    pub fn hear_from(
+        &mut self,
+        listener: &EntityId,
+        teller: &EntityId,
+        teller_integrity: f32,
+        timestamp: Timestamp,
+    ) {
+        if listener == teller {
+            return;
+        }
+        let conversations = self.conversations.entry(listener.clone()).or_default();
+        let index = conversations.partition_point(|existing| existing.timestamp <= timestamp);
+        conversations.insert(
+            index,
+            Conversation {
+                timestamp,
+                teller: teller.clone(),
+                teller_integrity,
+            },
+        );
+    }
+
+    /// Adds an entity to a group.
+    pub fn add_group_member(&mut self, group: GroupId, member: EntityId) {
+        self.groups.entry(group).or_default().insert(member);
+    }
+
+    /// Removes an entity from a group.
+    pub fn remove_group_member(&mut self, group: &GroupId, member: &EntityId) {
+        if let Some(members) = self.groups.get_mut(group) {
+            members.remove(member);
+        }
+    }
+
+    /// Returns the members of a group.
+    pub fn group_members(&self, group: &GroupId) -> impl Iterator<Item = &EntityId> {
+        self.groups.get(group).into_iter().flatten()
+    }
+
+    /// Returns the group's aggregate view of the subject as of `timestamp`.
+    ///
+    /// Values are the confidence-weighted mean of members' views. Confidence
+    /// is the mean confidence across all members, so a reputation known to
+    /// only one member is held weakly by the group. Returns `None` if no
+    /// member has a view.
+    #[must_use]
+    pub fn group_view(
+        &self,
+        group: &GroupId,
+        subject: &EntityId,
+        timestamp: Timestamp,
+    ) -> Option<Reputation> {
+        let members = self.groups.get(group)?;
+        let views: Vec<Reputation> = members
+            .iter()
+            .filter(|member| *member != subject)
+            .filter_map(|member| self.view(member, subject, timestamp))
+            .collect();
+        let total_confidence: f32 = views.iter().map(|view| view.confidence).sum();
+        if total_confidence <= 0.0 {
+            return None;
+        }
+
+        let weighted = |value: fn(&Reputation) -> f32| {
+            views
+                .iter()
+                .map(|view| value(view) * view.confidence)
+                .sum::<f32>()
+                / total_confidence
+        };
+        Some(Reputation {
+            trusted: weighted(|view| view.trusted),
+            feared: weighted(|view| view.feared),
+            hated: weighted(|view| view.hated),
+            confidence: total_confidence / members.len() as f32,
+        })
+    }
+
+    /// Returns the view the observer acts on for the subject as of
+    /// `timestamp`.
+    ///
+    /// The observer's own view wins. Without one, the most confident
+    /// aggregate among the observer's groups stands in.
+    #[must_use]
+    pub fn effective_view(
+        &self,
+        observer: &EntityId,
+        subject: &EntityId,
+        timestamp: Timestamp,
+    ) -> Option<Reputation> {
+        if let Some(view) = self.view(observer, subject, timestamp) {
+            return Some(view);
+        }
+        self.groups
+            .iter()
+            .filter(|(_, members)| members.contains(observer))
+            .filter_map(|(group, _)| self.group_view(group, subject, timestamp))
+            .max_by(|a, b| a.confidence.total_cmp(&b.confidence))
+    }
+
+    fn record(
+        &mut self,
+        observer: &EntityId,
+        subject: &EntityId,
+        timestamp: Timestamp,
+        evidence: Evidence,
+    ) {
+        let held = self
+            .evidence
+            .entry((observer.clone(), subject.clone()))
+            .or_default();
+        let key = (timestamp, evidence.rank());
+        let index = held.partition_point(|(ts, existing)| (*ts, existing.rank()) <= key);
+        held.insert(index, (timestamp, evidence));
+    }
+
+    /// Computes the observer's view of the subject from the evidence up to
+    /// `timestamp` and the conversations before it, or up to it as well if
+    /// `with_conversations_at` is set. `told` memoizes tellers' views by
+    /// (teller, conversation timestamp).
+    fn view_until(
+        &self,
+        observer: &EntityId,
+        subject: &EntityId,
+        timestamp: Timestamp,
+        with_conversations_at: bool,
+        told: &mut HashMap<(EntityId, Timestamp), Option<Reputation>>,
+    ) -> Option<Reputation> {
+        if observer == subject {
+            return None;
+        }
+        let evidence = self
+            .evidence
+            .get(&(observer.clone(), subject.clone()))
+            .map_or(&[][..], Vec::as_slice);
+        let evidence = &evidence[..evidence.partition_point(|(ts, _)| *ts <= timestamp)];
+        let conversations = self
+            .conversations
+            .get(observer)
+            .map_or(&[][..], Vec::as_slice);
+        let conversations = &conversations[..conversations.partition_point(|conversation| {
+            conversation.timestamp < timestamp
+                || (with_conversations_at && conversation.timestamp == timestamp)
+        })];
+
+        let mut view: Option<Reputation> = None;
+        let (mut next_evidence, mut next_conversation) = (0, 0);
+        loop {
+            let evidence_first = match (
+                evidence.get(next_evidence),
+                conversations.get(next_conversation),
+            ) {
+                (None, None) => break,
+                (Some(_), None) => true,
+                (None, Some(_)) => false,
+                (Some((ts, _)), Some(conversation)) => *ts <= conversation.timestamp,
+            };
+            if evidence_first {
+                evidence[next_evidence]
+                    .1
+                    .apply_to(view.get_or_insert_with(Reputation::new));
+                next_evidence += 1;
+                continue;
+            }
+
+            let conversation = &conversations[next_conversation];
+            next_conversation += 1;
+            let key = (conversation.teller.clone(), conversation.timestamp);
+            let teller_view = match told.get(&key) {
+                Some(teller_view) => *teller_view,
+                None => {
+                    let teller_view = self.view_until(
+                        &conversation.teller,
+                        subject,
+                        conversation.timestamp,
+                        false,
+                        told,
+                    );
+                    told.insert(key, teller_view);
+                    teller_view
+                }
+            };
+            let Some(teller_view) =
+                teller_view.filter(|teller_view| teller_view.confidence >= GOSSIP_MIN_CONFIDENCE)
+            else {
+                continue;
+            };
+            let weight = rumor_weight(&teller_view, conversation.teller_integrity);
+            if weight > 0.0 {
+                view.get_or_insert_with(Reputation::new)
+                    .blend(&teller_view, weight);
+            }
+        }
+        view
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::event::EventBuilder;
+
+    fn id(name: &str) -> EntityId {
+        EntityId::new(name).unwrap()
+    }
+
+    fn day(day: u32) -> Timestamp {
+        Timestamp::from_ymd_hms(2024, 1, day, 0, 0, 0)
+    }
+
+    #[test]
+    fn witnessing_counts_less_than_experience() {
+        let (victim, bystander, culprit) = (id("victim"), id("bystander"), id("culprit"));
+        let mut store = ReputationStore::new();
+
+        let direct = EventBuilder::new(EventType::Betrayal)
+            .source(culprit.clone())
+            .target(victim.clone())
+            .severity(1.0)
+            .build()
+            .unwrap();
+        let witnessed = EventBuilder::new(EventType::Betrayal)
+            .source(culprit.clone())
+            .target(bystander.clone())
+            .severity(1.0)
+            .tag(EventTag::Witnessed)
+            .build()
+            .unwrap();
+        store.observe_event(&direct, day(1), &[]);
+        store.observe_event(&witnessed, day(1), &[]);
+
+        let victim_view = store.view(&victim, &culprit, day(1)).unwrap();
+        let bystander_view = store.view(&bystander, &culprit, day(1)).unwrap();
+        assert!(victim_view.trusted() < bystander_view.trusted());
+        assert!(victim_view.confidence() > bystander_view.confidence());
+    }
+
+    #[test]
+    fn bystanders_witness_what_they_see() {
+        let (victim, bystander, culprit) = (id("victim"), id("bystander"), id("culprit"));
+        let mut store = ReputationStore::new();
+
+        let betrayal = EventBuilder::new(EventType::Betrayal)
+            .source(culprit.clone())
+            .target(victim.clone())
+            .severity(1.0)
+            .build()
+            .unwrap();
+        store.observe_event(&betrayal, day(1), &[bystander.clone(), culprit.clone()]);
+
+        let victim_view = store.view(&victim, &culprit, day(1)).unwrap();
+        let bystander_view = store.view(&bystander, &culprit, day(1)).unwrap();
+        assert!(bystander_view.trusted() < 0.0);
+        assert!(
+            (bystander_view.confidence() - MemorySource::Witness.confidence()).abs() < f32::EPSILON
+        );
+        assert!(victim_view.confidence() > bystander_view.confidence());
+        assert!(store.view(&culprit, &culprit, day(1)).is_none());
+    }
+
+    #[test]
+    fn gossip_is_discounted_by_teller_integrity() {
+        let (teller, subject) = (id("teller"), id("subject"));
+        let (trusting, wary) = (id("trusting"), id("wary"));
+        let mut store = ReputationStore::new();
+        store.observe(
+            &teller,
+            &subject,
+            &ReputationDelta::new().with_trusted(-0.8),
+            MemorySource::Self_,
+            day(1),
+        );
+        store.hear_from(&trusting, &teller, 0.9, day(2));
+        store.hear_from(&wary, &teller, 0.2, day(2));
+
+        let trusting_view = store.view(&trusting, &subject, day(2)).unwrap();
+        let wary_view = store.view(&wary, &subject, day(2)).unwrap();
+        assert!((trusting_view.confidence() - 0.4 * 0.9).abs() < 1e-6);
+        assert!(wary_view.confidence() < trusting_view.confidence());
+    }
+
+    #[test]
+    fn repeated_rumor_does_not_inflate_confidence() {
+        let (listener, subject) = (id("listener"), id("subject"));
+        let told = Reputation {
+            trusted: 0.6,
+            feared: 0.0,
+            hated: 0.0,
+            confidence: 1.0,
+        };
+        let mut store = ReputationStore::new();
+        store.hear(&listener, &subject, &told, 1.0, day(1));
+        store.hear(&listener, &subject, &told, 1.0, day(2));
+
+        let view = store.view(&listener, &subject, day(2)).unwrap();
+        assert!((view.confidence() - MemorySource::Rumor.confidence()).abs() < 1e-6);
+        assert!((view.trusted() - 0.6).abs() < 1e-6);
+    }
+
+    #[test]
+    fn views_only_count_evidence_up_to_their_timestamp() {
+        let (observer, subject) = (id("observer"), id("subject"));
+        let praise = ReputationDelta::new().with_trusted(0.5);
+        let scorn = ReputationDelta::new().with_trusted(-0.5);
+
+        let mut in_order = ReputationStore::new();
+        in_order.observe(&observer, &subject, &praise, MemorySource::Self_, day(1));
+        in_order.observe(&observer, &subject, &scorn, MemorySource::Witness, day(3));
+        let mut reversed = ReputationStore::new();
+        reversed.observe(&observer, &subject, &scorn, MemorySource::Witness, day(3));
+        reversed.observe(&observer, &subject, &praise, MemorySource::Self_, day(1));
+
+        let earlier = in_order.view(&observer, &subject, day(2)).unwrap();
+        assert!((earlier.trusted() - 0.5).abs() < 1e-6);
+        assert!(
+            in_order
+                .view(&observer, &subject, day(3))
+                .unwrap()
+                .trusted()
+                < 0.5
+        );
+        assert_eq!(
+            in_order.view(&observer, &subject, day(3)),
+            reversed.view(&observer, &subject, day(3))
+        );
+        assert!(in_order
+            .view(
+                &observer,
+                &subject,
+                Timestamp::from_ymd_hms(2023, 12, 31, 0, 0, 0)
+            )
+            .is_none());
+    }
+
+    #[test]
+    fn conversations_pass_on_what_the_teller_knew_then() {
+        let (teller, listener, subject) = (id("teller"), id("listener"), id("subject"));
+        let mut store = ReputationStore::new();
+        store.hear_from(&listener, &teller, 1.0, day(2));
+        store.observe(
+            &teller,
+            &subject,
+            &ReputationDelta::new().with_trusted(-0.8),
+            MemorySource::Self_,
+            day(3),
+        );
+        assert!(store.view(&listener, &subject, day(5)).is_none());
+
+        store.observe(
+            &teller,
+            &subject,
+            &ReputationDelta::new().with_trusted(0.4),
+            MemorySource::Self_,
+            day(1),
+        );
+        let heard = store.view(&listener, &subject, day(5)).unwrap();
+        assert!((heard.trusted() - 0.4).abs() < 1e-6);
+    }
+
+    #[test]
+    fn confident_listener_barely_moves() {
+        let mut view = Reputation::new();
+        view.apply_delta(&ReputationDelta::new().with_trusted(0.8), 1.0);
+        let rumor = Reputation {
+            trusted: -0.8,
+            feared: 0.0,
+            hated: 0.0,
+            confidence: 1.0,
+        };
+        view.blend(&rumor, 0.1);
+        assert!(view.trusted() > 0.6);
+    }
+
+    #[test]
+    fn group_view_weights_members_by_confidence() {
+        let group = GroupId::new("team").unwrap();
+        let (a, b, c, subject) = (id("a"), id("b"), id("c"), id("subject"));
+        let mut store = ReputationStore::new();
+        for member in [&a, &b, &c] {
+            store.add_group_member(group.clone(), member.clone());
+        }
+        store.observe(
+            &a,
+            &subject,
+            &ReputationDelta::new().with_feared(0.6),
+            MemorySource::Self_,
+            day(1),
+        );
+        store.observe(
+            &b,
+            &subject,
+            &ReputationDelta::new().with_feared(0.2),
+            MemorySource::Rumor,
+            day(1),
+        );
+
+        let aggregate = store.group_view(&group, &subject, day(1)).unwrap();
+        let expected = (0.6 * 1.0 + 0.2 * 0.4 * 0.4) / 1.4;
+        assert!((aggregate.feared() - expected).abs() < 1e-6);
+        assert!((aggregate.confidence() - 1.4 / 3.0).abs() < 1e-6);
+
+        // Carol has no view of her own and falls back on the group's.
+        let carol_view = store.effective_view(&c, &subject, day(1)).unwrap();
+        assert_eq!(carol_view, aggregate);
+        assert!(store.effective_view(&subject, &a, day(1)).is_none());
+    }
+
+    #[test]
+    fn shift_trust_follows_standing() {
+        let neutral = Reputation::new();
+        assert!((neutral.shift_trust(0.3, 1.0) - 0.3).abs() < f32::EPSILON);
+
+        let mut disliked = Reputation::new();
+        disliked.apply_delta(&ReputationDelta::new().with_hated(0.8), 1.0);
+        assert!(disliked.standing() < 0.0);
+        assert!(disliked.shift_trust(0.3, 1.0) < 0.3);
+
+        let mut admired = Reputation::new();
+        admired.apply_delta(&ReputationDelta::new().with_trusted(0.5), 0.5);
+        let shifted = admired.shift_trust(0.3, 1.0);
+        assert!((shifted - (0.3 + 0.7 * 0.25 * 0.5)).abs() < 1e-6);
+        assert!((admired.unshift_trust(shifted, 1.0) - 0.3).abs() < 1e-6);
+        let lowered = disliked.shift_trust(0.3, 1.0);
+        assert!((disliked.unshift_trust(lowered, 1.0) - 0.3).abs() < 1e-6);
+    }
+}
@@ -301,6 +301,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::clone_on_copy)]
     fn clone_copy() {
         let s1 = RelationshipStage::Established;
         let s2 = s1;
@@ -0,0 +1,424 @@
+//! Automatic relationship stage transitions.
+//!
+//! Stages advance and regress based on a closeness score built from the
+//! shared dimensions, the trust built up through antecedents and the
+//! interaction frequency. Promotion and demotion use separate thresholds
+//! (hysteresis) so a relationship hovering around a boundary does not
+//! flip between stages on every event.
+//!
+//! Estrangement bypasses the ladder: a severe betrayal between the pair,
+//! or tension that stays elevated for a sustained period, moves the
+//! relationship to `Estranged`. An estranged relationship is only
+//! reconciled (back to `Acquaintance`) once tension has subsided and
+//! closeness has recovered well above the acquaintance bar.
+
+use crate::enums::{Direction, EventType};
+use crate::event::Event;
+use crate::relationship::{Relationship, RelationshipStage};
+use crate::types::Timestamp;
+
+/// Closeness required to move from Stranger to Acquaintance.
+pub const ACQUAINTANCE_THRESHOLD: f32 = 0.15;
+
+/// Closeness required to move from Acquaintance to Established.
+pub const ESTABLISHED_THRESHOLD: f32 = 0.40;
+
+/// Closeness required to move from Established to Intimate.
+pub const INTIMATE_THRESHOLD: f32 = 0.65;
+
+/// Gap below a stage's entry threshold before the stage is lost.
+pub const STAGE_HYSTERESIS: f32 = 0.10;
+
+/// Minimum shared history before a relationship can become Established.
+const ESTABLISHED_MIN_HISTORY: f32 = 0.2;
+
+/// Minimum intimacy before a relationship can become Intimate.
+const INTIMATE_MIN_INTIMACY: f32 = 0.4;
+
+/// Minimum betrayal severity that estranges the relationship outright.
+pub const BETRAYAL_ESTRANGEMENT_SEVERITY: f64 = 0.5;
+
+/// Tension level considered elevated for estrangement purposes.
+pub const ELEVATED_TENSION_THRESHOLD: f32 = 0.6;
+
+/// Days tension must stay elevated before the relationship estranges.
+pub const SUSTAINED_TENSION_DAYS: f64 = 30.0;
+
+/// Tension must fall below this level before reconciliation.
+const RECONCILIATION_MAX_TENSION: f32 = 0.3;
+
+/// Why a relationship changed stage.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum StageTransitionCause {
+    /// Closeness rose past the next stage's entry threshold.
+    Progression,
+    /// Closeness fell below the current stage's exit threshold.
+    Regression,
+    /// A severe betrayal between the pair.
+    Betrayal,
+    /// Tension stayed elevated for a sustained period.
+    SustainedTension,
+    /// Tension subsided and closeness recovered after estrangement.
+    Reconciliation,
+    /// Closeness faded after a long period without contact.
+    Neglect,
+}
+
+impl StageTransitionCause {
+    /// Returns a human-readable name for this cause.
+    #[must_use]
+    pub const fn name(&self) -> &'static str {
+        match self {
+            StageTransitionCause::Progression => "Progression",
+            StageTransitionCause::Regression => "Regression",
+            StageTransitionCause::Betrayal => "Betrayal",
+            StageTransitionCause::SustainedTension => "SustainedTension",
+            StageTransitionCause::Reconciliation => "Reconciliation",
+            StageTransitionCause::Neglect => "Neglect",
+        }
+    }
+}
+
+impl std::fmt::Display for StageTransitionCause {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// A recorded change of relationship stage.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct StageTransition {
+    timestamp: Timestamp,
+    from: RelationshipStage,
+    to: RelationshipStage,
+    cause: StageTransitionCause,
+}
+
+impl StageTransition {
+    /// Creates a new stage transition record.
+    #[must_use]
+    pub fn new(
+        timestamp: Timestamp,
+        from: RelationshipStage,
+        to: RelationshipStage,
+        cause: StageTransitionCause,
+    ) -> Self {
+        StageTransition {
+            timestamp,
+            from,
+            to,
+            cause,
+        }
+    }
+
+    /// Returns when the transition happened.
+    #[must_use]
+    pub fn timestamp(&self) -> Timestamp {
+        self.timestamp
+    }
+
+    /// Returns the stage before the transition.
+    #[must_use]
+    pub fn from(&self) -> RelationshipStage {
+        self.from
+    }
+
+    /// Returns the stage after the transition.
+    #[must_use]
+    pub fn to(&self) -> RelationshipStage {
+        self.to
+    }
+
+    /// Returns why the transition happened.
+    #[must_use]
+    pub fn cause(&self) -> StageTransitionCause {
+        self.cause
+    }
+}
+
+/// Computes the closeness score that drives stage progression.
+///
+/// Combines intimacy (0.25), history (0.20), affinity (0.20), mutual
+/// trustworthiness (0.20) and interaction frequency (0.15), less a 0.2
+/// weighted tension penalty. Trustworthiness is recomputed from the
+/// antecedent history, so consistent positive experiences raise closeness
+/// and violations lower it.
+#[must_use]
+pub fn closeness_score(relationship: &Relationship) -> f32 {
+    let shared = relationship.shared();
+    let trust = (relationship.trustworthiness(Direction::AToB).overall()
+        + relationship.trustworthiness(Direction::BToA).overall())
+        / 2.0;
+    let frequency = relationship.pattern().frequency.clamp(0.0, 1.0);
+
+    let score = 0.25 * shared.intimacy_effective()
+        + 0.20 * shared.history_effective()
+        + 0.20 * shared.affinity_effective()
+        + 0.20 * trust
+        + 0.15 * frequency
+        - 0.20 * shared.tension_effective();
+    score.clamp(0.0, 1.0)
+}
+
+/// Returns the closeness needed to enter a stage on the positive ladder.
+///
+/// Stranger and Estranged have no entry threshold.
+#[must_use]
+pub const fn entry_threshold(stage: RelationshipStage) -> Option<f32> {
+    match stage {
+        RelationshipStage::Acquaintance => Some(ACQUAINTANCE_THRESHOLD),
+        RelationshipStage::Established => Some(ESTABLISHED_THRESHOLD),
+        RelationshipStage::Intimate => Some(INTIMATE_THRESHOLD),
+        RelationshipStage::Stranger | RelationshipStage::Estranged => None,
+    }
+}
+
+/// Evaluates whether the relationship should change stage.
+///
+/// `trigger` is the event that prompted the evaluation, if any. Returns
+/// the new stage and its cause, or `None` if the stage holds. At most one
+/// step is taken per evaluation.
+#[must_use]
+pub fn evaluate_stage_transition(
+    relationship: &Relationship,
+    timestamp: Timestamp,
+    trigger: Option<&Event>,
+) -> Option<(RelationshipStage, StageTransitionCause)> {
+    let current = relationship.stage();
+
+    if current != RelationshipStage::Estranged {
+        if trigger.is_some_and(|event| is_estranging_betrayal(relationship, event)) {
+            return Some((RelationshipStage::Estranged, StageTransitionCause::Betrayal));
+        }
+        if current.is_positive() && tension_is_sustained(relationship, timestamp) {
+            return Some((
+                RelationshipStage::Estranged,
+                StageTransitionCause::SustainedTension,
+            ));
+        }
+    }
+
+    let closeness = closeness_score(relationship);
+    let shared = relationship.shared();
+
+    match current {
+        RelationshipStage::Estranged => {
+            let recovered = closeness >= ESTABLISHED_THRESHOLD
+                && shared.tension_effective() < RECONCILIATION_MAX_TENSION;
+            recovered.then_some((
+                RelationshipStage::Acquaintance,
+                StageTransitionCause::Reconciliation,
+            ))
+        }
+        _ => {
+            if let Some(next) = next_stage(current) {
+                let gate_open = match next {
+                    RelationshipStage::Established => {
+                        shared.history_effective() >= ESTABLISHED_MIN_HISTORY
+                    }
+                    RelationshipStage::Intimate => {
+                        shared.intimacy_effective() >= INTIMATE_MIN_INTIMACY
+                    }
+                    _ => true,
+                };
+                if gate_open && entry_threshold(next).is_some_and(|t| closeness >= t) {
+                    return Some((next, StageTransitionCause::Progression));
+                }
+            }
+
+            let exit = entry_threshold(current)? - STAGE_HYSTERESIS;
+            (closeness < exit)
+                .then(|| previous_stage(current))
+                .flatten()
+                .map(|stage| (stage, StageTransitionCause::Regression))
+        }
+    }
+}
+
+const fn next_stage(stage: RelationshipStage) -> Option<RelationshipStage> {
+    match stage {
+        RelationshipStage::Stranger => Some(RelationshipStage::Acquaintance),
+        RelationshipStage::Acquaintance => Some(RelationshipStage::Established),
+        RelationshipStage::Established => Some(RelationshipStage::Intimate),
+        RelationshipStage::Intimate | RelationshipStage::Estranged => None,
+    }
+}
+
+const fn previous_stage(stage: RelationshipStage) -> Option<RelationshipStage> {
+    match stage {
+        RelationshipStage::Acquaintance => Some(RelationshipStage::Stranger),
+        RelationshipStage::Established => Some(RelationshipStage::Acquaintance),
+        RelationshipStage::Intimate => Some(RelationshipStage::Established),
+        RelationshipStage::Stranger | RelationshipStage::Estranged => None,
+    }
+}
+
+fn is_estranging_betrayal(relationship: &Relationship, event: &Event) -> bool {
+    if event.event_type() != EventType::Betrayal
+        || event.severity() < BETRAYAL_ESTRANGEMENT_SEVERITY
+    {
+        return false;
+    }
+    let (Some(source), Some(target)) = (event.source(), event.target()) else {
+        return false;
+    };
+    let a = relationship.entity_a();
+    let b = relationship.entity_b();
+    (source == a && target == b) || (source == b && target == a)
+}
+
+fn tension_is_sustained(relationship: &Relationship, timestamp: Timestamp) -> bool {
+    relationship.tension_elevated_since().is_some_and(|since| {
+        timestamp >= since && (timestamp - since).as_days_f64() >= SUSTAINED_TENSION_DAYS
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::event::EventBuilder;
+    use crate::types::EntityId;
+
+    fn relationship() -> Relationship {
+        Relationship::try_between(
+            EntityId::new("alice").unwrap(),
+            EntityId::new("bob").unwrap(),
+        )
+        .unwrap()
+    }
+
+    fn with_closeness(mut rel: Relationship, level: f32) -> Relationship {
+        let shared = rel.shared_mut();
+        shared.intimacy_mut().set_base(level);
+        shared.history_mut().set_base(level);
+        shared.affinity_mut().set_base(level);
+        rel.pattern_mut().frequency = level;
+        rel
+    }
+
+    #[test]
+    fn fresh_relationship_stays_stranger() {
+        let rel = relationship();
+        let ts = Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+        assert!(closeness_score(&rel) < ACQUAINTANCE_THRESHOLD);
+        assert_eq!(evaluate_stage_transition(&rel, ts, None), None);
+    }
+
+    #[test]
+    fn closeness_promotes_one_step() {
+        let rel = with_closeness(relationship(), 0.9);
+        let ts = Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+        assert_eq!(
+            evaluate_stage_transition(&rel, ts, None),
+            Some((
+                RelationshipStage::Acquaintance,
+                StageTransitionCause::Progression
+            ))
+        );
+    }
+
+    #[test]
+    fn hysteresis_holds_stage_just_below_entry() {
+        // Closeness sits between the exit and entry thresholds.
+        let rel = with_closeness(relationship(), 0.4).with_stage(RelationshipStage::Established);
+        let closeness = closeness_score(&rel);
+        assert!(closeness < ESTABLISHED_THRESHOLD);
+        assert!(closeness >= ESTABLISHED_THRESHOLD - STAGE_HYSTERESIS);
+
+        let ts = Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+        assert_eq!(evaluate_stage_transition(&rel, ts, None), None);
+    }
+
+    #[test]
+    fn low_closeness_regresses() {
+        let rel = relationship().with_stage(RelationshipStage::Intimate);
+        let ts = Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+        assert_eq!(
+            evaluate_stage_transition(&rel, ts, None),
+            Some((
+                RelationshipStage::Established,
+                StageTransitionCause::Regression
+            ))
+        );
+    }
+
+    #[test]
+    fn intimate_requires_intimacy() {
+        let mut rel =
+            with_closeness(relationship(), 1.0).with_stage(RelationshipStage::Established);
+        rel.shared_mut().intimacy_mut().set_base(0.2);
+        let ts = Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+        assert_eq!(evaluate_stage_transition(&rel, ts, None), None);
+    }
+
+    #[test]
+    fn betrayal_between_pair_estranges() {
+        let rel = with_closeness(relationship(), 0.9).with_stage(RelationshipStage::Intimate);
+        let betrayal = EventBuilder::new(EventType::Betrayal)
+            .source(EntityId::new("bob").unwrap())
+            .target(EntityId::new("alice").unwrap())
+            .severity(0.8)
+            .build()
+            .unwrap();
+        let ts = Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+        assert_eq!(
+            evaluate_stage_transition(&rel, ts, Some(&betrayal)),
+            Some((RelationshipStage::Estranged, StageTransitionCause::Betrayal))
+        );
+    }
+
+    #[test]
+    fn betrayal_by_third_party_does_not_estrange() {
+        let rel = with_closeness(relationship(), 0.9).with_stage(RelationshipStage::Intimate);
+        let betrayal = EventBuilder::new(EventType::Betrayal)
+            .source(EntityId::new("carol").unwrap())
+            .target(EntityId::new("alice").unwrap())
+            .severity(0.8)
+            .build()
+            .unwrap();
+        let ts = Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+        assert_eq!(evaluate_stage_transition(&rel, ts, Some(&betrayal)), None);
+    }
+
+    #[test]
+    fn sustained_tension_estranges() {
+        let mut rel = with_closeness(relationship(), 0.9).with_stage(RelationshipStage::Intimate);
+        rel.shared_mut().tension_mut().set_base(0.8);
+        let start = Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+        rel.update_stage(start, None);
+        assert_eq!(rel.stage(), RelationshipStage::Intimate);
+
+        let later = Timestamp::from_ymd_hms(2024, 2, 15, 0, 0, 0);
+        assert_eq!(
+            evaluate_stage_transition(&rel, later, None),
+            Some((
+                RelationshipStage::Estranged,
+                StageTransitionCause::SustainedTension
+            ))
+        );
+    }
+
+    #[test]
+    fn estranged_reconciles_only_after_recovery() {
+        let ts = Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+        let rel = relationship().with_stage(RelationshipStage::Estranged);
+        assert_eq!(evaluate_stage_transition(&rel, ts, None), None);
+
+        let rel = with_closeness(relationship(), 0.9).with_stage(RelationshipStage::Estranged);
+        assert_eq!(
+            evaluate_stage_transition(&rel, ts, None),
+            Some((
+                RelationshipStage::Acquaintance,
+                StageTransitionCause::Reconciliation
+            ))
+        );
+    }
+
+    #[test]
+    fn cause_display() {
+        assert_eq!(
+            format!("{}", StageTransitionCause::SustainedTension),
+            "SustainedTension"
+        );
+    }
+}
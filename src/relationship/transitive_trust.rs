@@ -0,0 +1,361 @@
+//! Transitive trust priors: starting trust inherited through mutual contacts.
+//!
+//! When two people meet, a trustor who already trusts people that know the
+//! newcomer borrows their judgement. Each path through the network is a
+//! referral:
+//!
+//! ```text
+//! trustor -> contact_1 -> ... -> contact_k -> newcomer
+//! ```
+//!
+//! A referral carries the last contact's perception of the newcomer,
+//! weighted by the trust along the path and discounted for every hop
+//! beyond a direct mutual contact. Direct contacts who know the newcomer
+//! well and think well of them also vouch for them, adding a small
+//! character bonus on top.
+//!
+//! The prior is recorded as trust antecedents so that its provenance is
+//! visible in the antecedent history and later evidence builds on it.
+
+use crate::relationship::{
+    AntecedentDirection, AntecedentType, TrustAntecedent, TrustworthinessFactors,
+};
+use crate::types::{EntityId, Timestamp};
+
+/// Longest path, in relationships, that can carry a referral.
+pub const MAX_TRANSITIVE_PATH_LENGTH: usize = 3;
+
+/// Discount applied per hop beyond a direct mutual contact.
+pub const TRANSITIVE_PATH_DISCOUNT: f32 = 0.5;
+
+/// Character bonus from a fully trusted contact vouching for the newcomer.
+pub const VOUCHING_BONUS: f32 = 0.05;
+
+/// Cap on the total vouching bonus.
+pub const MAX_VOUCHING_BONUS: f32 = 0.15;
+
+/// Minimum overall trust a contact must hold in the newcomer to vouch.
+pub const VOUCHING_TRUST_THRESHOLD: f32 = 0.5;
+
+/// Antecedent context for the network-derived prior.
+pub const TRANSITIVE_TRUST_CONTEXT: &str = "transitive_trust";
+
+/// Antecedent context for vouching.
+pub const VOUCHING_CONTEXT: &str = "vouching";
+
+/// Differences smaller than this are not recorded as antecedents.
+const MIN_PRIOR_SHIFT: f32 = 1e-3;
+
+/// One path through the network from a trustor to a newcomer.
+///
+/// # Examples
+///
+/// ```
+/// use eventsim_rs::relationship::{TrustReferral, TrustworthinessFactors};
+/// use eventsim_rs::types::EntityId;
+///
+/// let friend = EntityId::new("friend").unwrap();
+/// let view = TrustworthinessFactors::with_bases(0.6, 0.7, 0.8);
+/// let referral = TrustReferral::new(vec![friend], 0.8, &view);
+///
+/// assert!((referral.path_weight() - 0.8).abs() < f32::EPSILON);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct TrustReferral {
+    intermediaries: Vec<EntityId>,
+    path_trust: f32,
+    competence: f32,
+    benevolence: f32,
+    integrity: f32,
+    vouches: bool,
+}
+
+impl TrustReferral {
+    /// Creates a referral.
+    ///
+    /// # Arguments
+    ///
+    /// * `intermediaries` - Contacts along the path, nearest the trustor first
+    /// * `path_trust` - Product of overall trust along the path (0-1)
+    /// * `view` - The last contact's perception of the newcomer
+    #[must_use]
+    pub fn new(
+        intermediaries: Vec<EntityId>,
+        path_trust: f32,
+        view: &TrustworthinessFactors,
+    ) -> Self {
+        TrustReferral {
+            intermediaries,
+            path_trust: path_trust.clamp(0.0, 1.0),
+            competence: view.competence_effective(),
+            benevolence: view.benevolence_effective(),
+            integrity: view.integrity_effective(),
+            vouches: false,
+        }
+    }
+
+    /// Marks whether the contact vouches for the newcomer.
+    ///
+    /// Only direct mutual contacts can vouch; this is ignored for longer
+    /// paths.
+    #[must_use]
+    pub fn with_vouching(mut self, vouches: bool) -> Self {
+        self.vouches = vouches;
+        self
+    }
+
+    /// Returns the contacts along the path, nearest the trustor first.
+    #[must_use]
+    pub fn intermediaries(&self) -> &[EntityId] {
+        &self.intermediaries
+    }
+
+    /// Returns the trust along the path.
+    #[must_use]
+    pub fn path_trust(&self) -> f32 {
+        self.path_trust
+    }
+
+    /// Returns true if this referral vouches for the newcomer.
+    #[must_use]
+    pub fn vouches(&self) -> bool {
+        self.vouches && self.intermediaries.len() == 1
+    }
+
+    /// Returns the referral's weight after the path-length discount.
+    #[must_use]
+    pub fn path_weight(&self) -> f32 {
+        let extra_hops = self.intermediaries.len().saturating_sub(1) as i32;
+        self.path_trust * TRANSITIVE_PATH_DISCOUNT.powi(extra_hops)
+    }
+}
+
+/// A trustworthiness prior combined from referrals.
+///
+/// # Examples
+///
+/// ```
+/// use eventsim_rs::relationship::{
+///     TransitiveTrustPrior, TrustReferral, TrustworthinessFactors,
+/// };
+/// use eventsim_rs::types::{EntityId, Timestamp};
+///
+/// let friend = EntityId::new("friend").unwrap();
+/// let view = TrustworthinessFactors::with_bases(0.6, 0.7, 0.8);
+/// let referrals = vec![TrustReferral::new(vec![friend], 0.8, &view).with_vouching(true)];
+///
+/// let prior = TransitiveTrustPrior::from_referrals(&referrals).unwrap();
+/// let ts = Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+/// let antecedents = prior.antecedents(&TrustworthinessFactors::new(), ts);
+/// assert!(antecedents.iter().any(|a| a.context() == "vouching"));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TransitiveTrustPrior {
+    competence: f32,
+    benevolence: f32,
+    integrity: f32,
+    influence: f32,
+    vouching_bonus: f32,
+    referral_count: usize,
+}
+
+impl TransitiveTrustPrior {
+    /// Combines referrals into a prior.
+    ///
+    /// Values are the weighted mean of the referrals. Influence saturates
+    /// with total weight (`w / (w + 1)`), so one lukewarm contact nudges the
+    /// trustor while several trusted ones carry real weight. Returns `None`
+    /// when no referral carries any weight.
+    #[must_use]
+    pub fn from_referrals(referrals: &[TrustReferral]) -> Option<Self> {
+        let total_weight: f32 = referrals.iter().map(TrustReferral::path_weight).sum();
+        if total_weight <= 0.0 {
+            return None;
+        }
+
+        let weighted = |value: fn(&TrustReferral) -> f32| {
+            referrals
+                .iter()
+                .map(|referral| value(referral) * referral.path_weight())
+                .sum::<f32>()
+                / total_weight
+        };
+        let vouching_bonus = referrals
+            .iter()
+            .filter(|referral| referral.vouches())
+            .map(|referral| VOUCHING_BONUS * referral.path_trust)
+            .sum::<f32>()
+            .min(MAX_VOUCHING_BONUS);
+
+        Some(TransitiveTrustPrior {
+            competence: weighted(|r| r.competence),
+            benevolence: weighted(|r| r.benevolence),
+            integrity: weighted(|r| r.integrity),
+            influence: total_weight / (total_weight + 1.0),
+            vouching_bonus,
+            referral_count: referrals.len(),
+        })
+    }
+
+    /// Returns the prior competence.
+    #[must_use]
+    pub fn competence(&self) -> f32 {
+        self.competence
+    }
+
+    /// Returns the prior benevolence.
+    #[must_use]
+    pub fn benevolence(&self) -> f32 {
+        self.benevolence
+    }
+
+    /// Returns the prior integrity.
+    #[must_use]
+    pub fn integrity(&self) -> f32 {
+        self.integrity
+    }
+
+    /// Returns how strongly the prior pulls the trustor's perceptions (0-1).
+    #[must_use]
+    pub fn influence(&self) -> f32 {
+        self.influence
+    }
+
+    /// Returns the benevolence and integrity bonus from vouching.
+    #[must_use]
+    pub fn vouching_bonus(&self) -> f32 {
+        self.vouching_bonus
+    }
+
+    /// Returns the number of referrals combined.
+    #[must_use]
+    pub fn referral_count(&self) -> usize {
+        self.referral_count
+    }
+
+    /// Returns the antecedents that move `current` toward this prior.
+    ///
+    /// Each factor gets a `transitive_trust` antecedent for its share of the
+    /// difference; vouching adds positive `vouching` benevolence and
+    /// integrity antecedents.
+    #[must_use]
+    pub fn antecedents(
+        &self,
+        current: &TrustworthinessFactors,
+        timestamp: Timestamp,
+    ) -> Vec<TrustAntecedent> {
+        let shifts = [
+            (
+                AntecedentType::Ability,
+                self.competence - current.competence_effective(),
+            ),
+            (
+                AntecedentType::Benevolence,
+                self.benevolence - current.benevolence_effective(),
+            ),
+            (
+                AntecedentType::Integrity,
+                self.integrity - current.integrity_effective(),
+            ),
+        ];
+
+        let mut antecedents: Vec<TrustAntecedent> = shifts
+            .into_iter()
+            .map(|(kind, difference)| (kind, difference * self.influence))
+            .filter(|(_, shift)| shift.abs() >= MIN_PRIOR_SHIFT)
+            .map(|(kind, shift)| {
+                let direction = if shift > 0.0 {
+                    AntecedentDirection::Positive
+                } else {
+                    AntecedentDirection::Negative
+                };
+                TrustAntecedent::new(
+                    timestamp,
+                    kind,
+                    direction,
+                    shift.abs(),
+                    TRANSITIVE_TRUST_CONTEXT,
+                )
+            })
+            .collect();
+
+        if self.vouching_bonus >= MIN_PRIOR_SHIFT {
+            for kind in [AntecedentType::Benevolence, AntecedentType::Integrity] {
+                antecedents.push(TrustAntecedent::new(
+                    timestamp,
+                    kind,
+                    AntecedentDirection::Positive,
+                    self.vouching_bonus,
+                    VOUCHING_CONTEXT,
+                ));
+            }
+        }
+        antecedents
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn id(name: &str) -> EntityId {
+        EntityId::new(name).unwrap()
+    }
+
+    #[test]
+    fn longer_paths_count_for_less() {
+        let view = TrustworthinessFactors::with_bases(0.8, 0.8, 0.8);
+        let direct = TrustReferral::new(vec![id("a")], 0.6, &view);
+        let indirect = TrustReferral::new(vec![id("a"), id("b")], 0.6, &view);
+        assert!(
+            (indirect.path_weight() - direct.path_weight() * TRANSITIVE_PATH_DISCOUNT).abs() < 1e-6
+        );
+    }
+
+    #[test]
+    fn prior_is_weighted_mean_with_saturating_influence() {
+        let fan = TrustworthinessFactors::with_bases(0.9, 0.9, 0.9);
+        let critic = TrustworthinessFactors::with_bases(0.1, 0.1, 0.1);
+        let referrals = vec![
+            TrustReferral::new(vec![id("close")], 0.9, &fan),
+            TrustReferral::new(vec![id("distant")], 0.3, &critic),
+        ];
+        let prior = TransitiveTrustPrior::from_referrals(&referrals).unwrap();
+
+        let expected = (0.9 * 0.9 + 0.1 * 0.3) / 1.2;
+        assert!((prior.integrity() - expected).abs() < 1e-6);
+        assert!((prior.influence() - 1.2 / 2.2).abs() < 1e-6);
+        assert_eq!(prior.referral_count(), 2);
+    }
+
+    #[test]
+    fn only_direct_contacts_vouch_and_bonus_is_capped() {
+        let view = TrustworthinessFactors::with_bases(0.8, 0.8, 0.8);
+        let indirect = TrustReferral::new(vec![id("a"), id("b")], 1.0, &view).with_vouching(true);
+        assert!(!indirect.vouches());
+
+        let referrals: Vec<_> = ["a", "b", "c", "d", "e"]
+            .into_iter()
+            .map(|name| TrustReferral::new(vec![id(name)], 1.0, &view).with_vouching(true))
+            .collect();
+        let prior = TransitiveTrustPrior::from_referrals(&referrals).unwrap();
+        assert!((prior.vouching_bonus() - MAX_VOUCHING_BONUS).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn antecedents_point_toward_prior() {
+        let wary = TrustworthinessFactors::with_bases(0.1, 0.1, 0.1);
+        let referrals = vec![TrustReferral::new(vec![id("a")], 1.0, &wary)];
+        let prior = TransitiveTrustPrior::from_referrals(&referrals).unwrap();
+        let ts = Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+
+        let antecedents = prior.antecedents(&TrustworthinessFactors::new(), ts);
+        assert_eq!(antecedents.len(), 3);
+        assert!(antecedents.iter().all(|a| {
+            a.direction() == AntecedentDirection::Negative
+                && a.context() == TRANSITIVE_TRUST_CONTEXT
+                && (a.magnitude() - 0.1).abs() < 1e-6
+        }));
+        assert!(TransitiveTrustPrior::from_referrals(&[]).is_none());
+    }
+}
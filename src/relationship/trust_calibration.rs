@@ -0,0 +1,282 @@
+//! Ground-truth trustworthiness and trust calibration.
+//!
+//! `TrustworthinessFactors` record what a trustor believes. This module
+//! estimates how trustworthy a trustee actually is from personality and
+//! compares the two, so misplaced trust can be measured:
+//!
+//! - **Competence** follows Conscientiousness (diligence and reliability).
+//! - **Benevolence** follows Agreeableness, supported by Honesty-Humility.
+//! - **Integrity** follows Honesty-Humility, supported by Conscientiousness.
+//!
+//! Calibration error is perceived minus actual: positive values are
+//! over-trust (the trustor could be exploited), negative values are
+//! under-trust (the trustor forgoes cooperation that would have been safe).
+
+use std::fmt;
+
+use crate::relationship::TrustworthinessFactors;
+use crate::state::Hexaco;
+
+/// Errors within this distance of zero count as calibrated.
+pub const CALIBRATION_TOLERANCE: f32 = 0.1;
+
+/// Share of benevolence carried by Agreeableness (the rest is Honesty-Humility).
+const BENEVOLENCE_AGREEABLENESS_WEIGHT: f32 = 0.6;
+
+/// Share of integrity carried by Honesty-Humility (the rest is Conscientiousness).
+const INTEGRITY_HONESTY_WEIGHT: f32 = 0.7;
+
+/// Maps a HEXACO value (-1 to 1) onto the trustworthiness scale (0 to 1).
+fn to_unit(value: f32) -> f32 {
+    ((value + 1.0) / 2.0).clamp(0.0, 1.0)
+}
+
+/// How trustworthy an entity actually is, derived from personality.
+///
+/// # Examples
+///
+/// ```
+/// use eventsim_rs::relationship::ActualTrustworthiness;
+/// use eventsim_rs::state::Hexaco;
+///
+/// let honest = Hexaco::new().with_honesty_humility(0.8);
+/// let exploitative = Hexaco::new().with_honesty_humility(-0.8);
+///
+/// let a = ActualTrustworthiness::from_hexaco(&honest);
+/// let b = ActualTrustworthiness::from_hexaco(&exploitative);
+/// assert!(a.integrity() > b.integrity());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ActualTrustworthiness {
+    competence: f32,
+    benevolence: f32,
+    integrity: f32,
+}
+
+impl ActualTrustworthiness {
+    /// Creates ground truth from explicit values (each 0-1).
+    #[must_use]
+    pub fn new(competence: f32, benevolence: f32, integrity: f32) -> Self {
+        ActualTrustworthiness {
+            competence: competence.clamp(0.0, 1.0),
+            benevolence: benevolence.clamp(0.0, 1.0),
+            integrity: integrity.clamp(0.0, 1.0),
+        }
+    }
+
+    /// Derives ground truth from HEXACO traits.
+    #[must_use]
+    pub fn from_hexaco(hexaco: &Hexaco) -> Self {
+        let honesty = hexaco.honesty_humility();
+        let agreeableness = hexaco.agreeableness();
+        let conscientiousness = hexaco.conscientiousness();
+
+        let benevolence = BENEVOLENCE_AGREEABLENESS_WEIGHT * agreeableness
+            + (1.0 - BENEVOLENCE_AGREEABLENESS_WEIGHT) * honesty;
+        let integrity = INTEGRITY_HONESTY_WEIGHT * honesty
+            + (1.0 - INTEGRITY_HONESTY_WEIGHT) * conscientiousness;
+
+        ActualTrustworthiness {
+            competence: to_unit(conscientiousness),
+            benevolence: to_unit(benevolence),
+            integrity: to_unit(integrity),
+        }
+    }
+
+    /// Returns actual competence (0-1).
+    #[must_use]
+    pub fn competence(&self) -> f32 {
+        self.competence
+    }
+
+    /// Returns actual benevolence (0-1).
+    #[must_use]
+    pub fn benevolence(&self) -> f32 {
+        self.benevolence
+    }
+
+    /// Returns actual integrity (0-1).
+    #[must_use]
+    pub fn integrity(&self) -> f32 {
+        self.integrity
+    }
+
+    /// Returns the mean of the three factors.
+    #[must_use]
+    pub fn overall(&self) -> f32 {
+        (self.competence + self.benevolence + self.integrity) / 3.0
+    }
+}
+
+/// Whether a trustor trusts too much, too little, or about right.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CalibrationAssessment {
+    /// Perceived trustworthiness exceeds actual by more than the tolerance.
+    OverTrust,
+    /// Perceived trustworthiness is within the tolerance of actual.
+    Calibrated,
+    /// Perceived trustworthiness falls short of actual by more than the tolerance.
+    UnderTrust,
+}
+
+impl CalibrationAssessment {
+    /// Returns a human-readable name.
+    #[must_use]
+    pub const fn name(&self) -> &'static str {
+        match self {
+            CalibrationAssessment::OverTrust => "Over-trust",
+            CalibrationAssessment::Calibrated => "Calibrated",
+            CalibrationAssessment::UnderTrust => "Under-trust",
+        }
+    }
+
+    fn from_error(error: f32) -> Self {
+        if error > CALIBRATION_TOLERANCE {
+            CalibrationAssessment::OverTrust
+        } else if error < -CALIBRATION_TOLERANCE {
+            CalibrationAssessment::UnderTrust
+        } else {
+            CalibrationAssessment::Calibrated
+        }
+    }
+}
+
+impl fmt::Display for CalibrationAssessment {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// Perceived minus actual trustworthiness, per factor.
+///
+/// # Examples
+///
+/// ```
+/// use eventsim_rs::relationship::{
+///     ActualTrustworthiness, CalibrationAssessment, TrustCalibration, TrustworthinessFactors,
+/// };
+///
+/// let perceived = TrustworthinessFactors::with_bases(0.8, 0.8, 0.8);
+/// let actual = ActualTrustworthiness::new(0.5, 0.2, 0.1);
+/// let calibration = TrustCalibration::between(&perceived, &actual);
+///
+/// assert!(calibration.integrity_error() > 0.6);
+/// assert_eq!(calibration.assessment(), CalibrationAssessment::OverTrust);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TrustCalibration {
+    competence_error: f32,
+    benevolence_error: f32,
+    integrity_error: f32,
+}
+
+impl TrustCalibration {
+    /// Compares a trustor's perceptions with the trustee's actual trustworthiness.
+    #[must_use]
+    pub fn between(perceived: &TrustworthinessFactors, actual: &ActualTrustworthiness) -> Self {
+        TrustCalibration {
+            competence_error: perceived.competence_effective() - actual.competence,
+            benevolence_error: perceived.benevolence_effective() - actual.benevolence,
+            integrity_error: perceived.integrity_effective() - actual.integrity,
+        }
+    }
+
+    /// Returns the competence error (positive is over-trust).
+    #[must_use]
+    pub fn competence_error(&self) -> f32 {
+        self.competence_error
+    }
+
+    /// Returns the benevolence error (positive is over-trust).
+    #[must_use]
+    pub fn benevolence_error(&self) -> f32 {
+        self.benevolence_error
+    }
+
+    /// Returns the integrity error (positive is over-trust).
+    #[must_use]
+    pub fn integrity_error(&self) -> f32 {
+        self.integrity_error
+    }
+
+    /// Returns the mean signed error across factors.
+    #[must_use]
+    pub fn overall_error(&self) -> f32 {
+        (self.competence_error + self.benevolence_error + self.integrity_error) / 3.0
+    }
+
+    /// Returns the mean absolute error across factors.
+    ///
+    /// Unlike `overall_error`, over- and under-trust in different factors
+    /// do not cancel out.
+    #[must_use]
+    pub fn absolute_error(&self) -> f32 {
+        (self.competence_error.abs() + self.benevolence_error.abs() + self.integrity_error.abs())
+            / 3.0
+    }
+
+    /// Returns the overall assessment.
+    #[must_use]
+    pub fn assessment(&self) -> CalibrationAssessment {
+        CalibrationAssessment::from_error(self.overall_error())
+    }
+
+    /// Returns true if the trustor overestimates the trustee's character.
+    ///
+    /// Misjudged benevolence or integrity is what exposes a trustor to
+    /// exploitation, so competence is not considered.
+    #[must_use]
+    pub fn exposes_to_exploitation(&self) -> bool {
+        self.benevolence_error > CALIBRATION_TOLERANCE
+            || self.integrity_error > CALIBRATION_TOLERANCE
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn neutral_personality_is_midscale() {
+        let actual = ActualTrustworthiness::from_hexaco(&Hexaco::new());
+        assert!((actual.overall() - 0.5).abs() < 1e-6);
+    }
+
+    #[test]
+    fn traits_drive_their_factors() {
+        let agreeable = ActualTrustworthiness::from_hexaco(&Hexaco::new().with_agreeableness(1.0));
+        assert!((agreeable.benevolence() - 0.8).abs() < 1e-6);
+        assert!((agreeable.integrity() - 0.5).abs() < 1e-6);
+
+        let diligent =
+            ActualTrustworthiness::from_hexaco(&Hexaco::new().with_conscientiousness(1.0));
+        assert!((diligent.competence() - 1.0).abs() < 1e-6);
+        assert!((diligent.integrity() - 0.65).abs() < 1e-6);
+    }
+
+    #[test]
+    fn assessment_uses_tolerance() {
+        let perceived = TrustworthinessFactors::with_bases(0.5, 0.5, 0.5);
+        let near = ActualTrustworthiness::new(0.45, 0.45, 0.45);
+        let far = ActualTrustworthiness::new(0.8, 0.8, 0.8);
+
+        assert_eq!(
+            TrustCalibration::between(&perceived, &near).assessment(),
+            CalibrationAssessment::Calibrated
+        );
+        let under = TrustCalibration::between(&perceived, &far);
+        assert_eq!(under.assessment(), CalibrationAssessment::UnderTrust);
+        assert!(!under.exposes_to_exploitation());
+    }
+
+    #[test]
+    fn absolute_error_does_not_cancel() {
+        let perceived = TrustworthinessFactors::with_bases(0.9, 0.1, 0.5);
+        let actual = ActualTrustworthiness::new(0.1, 0.9, 0.5);
+        let calibration = TrustCalibration::between(&perceived, &actual);
+
+        assert!(calibration.overall_error().abs() < 1e-6);
+        assert!((calibration.absolute_error() - 1.6 / 3.0).abs() < 1e-6);
+        assert_eq!(CalibrationAssessment::OverTrust.to_string(), "Over-trust");
+    }
+}
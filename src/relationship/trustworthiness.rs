@@ -11,7 +11,9 @@
 use std::collections::HashMap;
 
 use crate::enums::{LifeDomain, TrustPath};
-use crate::relationship::{AntecedentDirection, AntecedentType, TrustAntecedent};
+use crate::relationship::{
+    AntecedentDirection, AntecedentType, Reputation, TrustAntecedent, REPUTATION_PRIOR_WEIGHT,
+};
 use crate::state::StateValue;
 use crate::types::{Duration, Timestamp};
 
@@ -30,6 +32,11 @@ const DEFAULT_BASE: f32 = 0.3;
 /// Exponential smoothing factor for antecedent recomputation.
 const ANTECEDENT_SMOOTHING_ALPHA: f32 = 0.4;
 
+/// Smoothing factor when positive antecedents pull a negative benevolence
+/// or integrity estimate back up. Lower than `ANTECEDENT_SMOOTHING_ALPHA`
+/// so damaged character judgements recover more slowly than they fell.
+const REPAIR_SMOOTHING_ALPHA: f32 = 0.15;
+
 /// Weight multiplier for negative antecedents.
 const NEGATIVE_ANTECEDENT_WEIGHT: f32 = 2.5;
 
@@ -150,6 +157,42 @@ impl TrustworthinessFactors {
         }
     }
 
+    /// Shifts benevolence and integrity bases by what a reputation suggests.
+    ///
+    /// Used when strangers meet: what the trustor has heard about the
+    /// trustee moves their starting character judgements, at
+    /// `REPUTATION_PRIOR_WEIGHT` (see `Reputation::shift_trust`).
+    /// Competence is left alone because reputation speaks to character,
+    /// not ability.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use eventsim_rs::memory::ReputationDelta;
+    /// use eventsim_rs::relationship::{Reputation, TrustworthinessFactors};
+    ///
+    /// let mut heard = Reputation::new();
+    /// heard.apply_delta(&ReputationDelta::new().with_trusted(-0.8), 1.0);
+    ///
+    /// let mut factors = TrustworthinessFactors::new();
+    /// factors.apply_reputation_prior(&heard);
+    /// assert!(factors.integrity_effective() < 0.3);
+    /// ```
+    pub fn apply_reputation_prior(&mut self, reputation: &Reputation) {
+        for sv in [&mut self.benevolence, &mut self.integrity] {
+            let base = sv.base();
+            sv.set_base(reputation.shift_trust(base, REPUTATION_PRIOR_WEIGHT));
+        }
+    }
+
+    /// Undoes `apply_reputation_prior` for the same reputation.
+    pub(crate) fn remove_reputation_prior(&mut self, reputation: &Reputation) {
+        for sv in [&mut self.benevolence, &mut self.integrity] {
+            let base = sv.base();
+            sv.set_base(reputation.unshift_trust(base, REPUTATION_PRIOR_WEIGHT));
+        }
+    }
+
     /// Creates a competence map with the given base value for all domains.
     fn create_domain_competence_map(base: f32) -> HashMap<LifeDomain, StateValue> {
         let domains = [
@@ -440,10 +483,10 @@ impl TrustworthinessFactors {
                     }
                 }
                 AntecedentType::Benevolence => {
-                    benevolence_ema = update_ema(benevolence_ema, signed);
+                    benevolence_ema = update_character_ema(benevolence_ema, signed);
                 }
                 AntecedentType::Integrity => {
-                    integrity_ema = update_ema(integrity_ema, signed);
+                    integrity_ema = update_character_ema(integrity_ema, signed);
                 }
             }
         }
@@ -483,6 +526,15 @@ fn update_ema(previous: f32, value: f32) -> f32 {
     (1.0 - ANTECEDENT_SMOOTHING_ALPHA) * previous + ANTECEDENT_SMOOTHING_ALPHA * value
 }
 
+/// EMA update for benevolence and integrity, recovering slowly from damage.
+fn update_character_ema(previous: f32, value: f32) -> f32 {
+    if previous < 0.0 && value > 0.0 {
+        (1.0 - REPAIR_SMOOTHING_ALPHA) * previous + REPAIR_SMOOTHING_ALPHA * value
+    } else {
+        update_ema(previous, value)
+    }
+}
+
 fn apply_ema_to_state_value(value: &mut StateValue, ema: f32) {
     let base = value.base();
     let target = (base + ema).clamp(0.0, 1.0);
@@ -685,6 +737,29 @@ mod tests {
         assert!(factors.benevolence().delta() > 0.0);
     }
 
+    #[test]
+    fn damaged_integrity_recovers_slower_than_competence() {
+        let violated = Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+        let repaired = Timestamp::from_ymd_hms(2024, 1, 2, 0, 0, 0);
+        let history = |kind| {
+            vec![
+                TrustAntecedent::new(violated, kind, AntecedentDirection::Negative, 0.2, "harm"),
+                TrustAntecedent::new(repaired, kind, AntecedentDirection::Positive, 0.5, "amends"),
+            ]
+        };
+
+        let mut factors = TrustworthinessFactors::new();
+        factors.recompute_from_antecedents(&history(AntecedentType::Integrity));
+        let integrity = factors.integrity().delta();
+
+        let mut factors = TrustworthinessFactors::new();
+        factors.recompute_from_antecedents(&history(AntecedentType::Ability));
+        let competence = factors.competence(LifeDomain::Work).unwrap().delta();
+
+        assert!(integrity < 0.0);
+        assert!(competence > integrity);
+    }
+
     #[test]
     fn competence_decays_over_30_days() {
         let mut factors = TrustworthinessFactors::new();
@@ -997,7 +1072,7 @@ mod tests {
 
         // Test with only the recent antecedent
         let mut recent_only = TrustworthinessFactors::new();
-        recent_only.recompute_from_antecedents(&[recent.clone()]);
+        recent_only.recompute_from_antecedents(std::slice::from_ref(&recent));
         let recent_impact = recent_only.benevolence_effective();
 
         // Test with only the old antecedent
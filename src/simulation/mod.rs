@@ -50,6 +50,11 @@ mod simulation;
 mod simulation_builder;
 mod state_query;
 
+pub use crate::processor::{
+    DimensionRegression, RegressionMethod, RegressionReport, ANCHOR_CONFIDENCE_HALF_LIFE,
+    APPROXIMATED_EVENT_CONFIDENCE_FACTOR, NO_SUPPORT_RELATIONSHIP_QUALITY, OBSERVATION_RESOLUTION,
+    RELATIONSHIP_COUPLING_WEIGHT, SPIRAL_CONFIDENCE_FACTOR, SUPPORT_RECENCY_HALF_LIFE_DAYS,
+};
 pub use simulation::{
     AnchoredEntity, RegressionQuality, Simulation, TimestampedEvent, TimestampedRelationship,
 };
@@ -4,14 +4,28 @@
 //! It holds entities, events, and relationships with their timestamps,
 //! enabling state queries at any point in time.
 
+use crate::context::{
+    HistoricalReach, MacrosystemDynamics, MacrosystemShift, Microsystem, MicrosystemClimate,
+    SharedHistoricalEvent,
+};
+use crate::decision::{decide, ActionOption, Decision, DecisionError};
 use crate::entity::Entity;
 use crate::enums::RelationshipSchema;
-use crate::event::Event;
+use crate::enums::{
+    BirthEra, Direction, EventPayload, EventScope, EventType, PolicyJurisdiction, SubsystemId,
+};
+use crate::event::{Event, EventBuilder};
+use crate::memory::{MemorySource, ReputationDelta};
 use crate::processor::process_event_to_relationships;
-use crate::relationship::Relationship;
+use crate::relationship::{
+    ActualTrustworthiness, Relationship, RelationshipStage, Reputation, ReputationStore,
+    TransitiveTrustPrior, TrustCalibration, TrustReferral, TrustworthinessFactors,
+    MAX_TRANSITIVE_PATH_LENGTH, VOUCHING_TRUST_THRESHOLD,
+};
 use crate::simulation::state_query::EntityQueryHandle;
-use crate::types::{EntityId, RelationshipId, Timestamp};
-use std::collections::HashMap;
+use crate::types::{EntityId, EventId, GroupId, MicrosystemId, RelationshipId, Timestamp};
+use std::collections::{HashMap, HashSet};
+use std::sync::{Mutex, MutexGuard, PoisonError};
 
 /// An entity with its anchor timestamp.
 ///
@@ -93,6 +107,10 @@ pub struct TimestampedRelationship {
     entity_b: EntityId,
     /// When the relationship was formed.
     formed_timestamp: Timestamp,
+    /// Entity A's view of B at formation, applied to A's perceptions.
+    reputation_prior_a_to_b: Option<Reputation>,
+    /// Entity B's view of A at formation, applied to B's perceptions.
+    reputation_prior_b_to_a: Option<Reputation>,
 }
 
 impl TimestampedRelationship {
@@ -109,15 +127,36 @@ impl TimestampedRelationship {
             entity_a,
             entity_b,
             formed_timestamp,
+            reputation_prior_a_to_b: None,
+            reputation_prior_b_to_a: None,
         }
     }
 
     /// Returns a reference to the relationship.
+    ///
+    /// This reflects every event added to the simulation, without neglect.
+    /// Use `relationship_at` for the relationship at a timestamp.
     #[must_use]
     pub fn relationship(&self) -> &Relationship {
         &self.relationship
     }
 
+    /// Returns the relationship as it stands at `timestamp`.
+    ///
+    /// Events after `timestamp` are left out (see `Relationship::as_of`),
+    /// and neglect is applied for the gaps without contact before it (see
+    /// `Relationship::apply_neglect`). Returns `None` if the relationship
+    /// had not formed yet.
+    #[must_use]
+    pub fn relationship_at(&self, timestamp: Timestamp) -> Option<Relationship> {
+        if timestamp < self.formed_timestamp {
+            return None;
+        }
+        let mut relationship = self.relationship.as_of(timestamp);
+        relationship.apply_neglect(timestamp, self.formed_timestamp);
+        Some(relationship)
+    }
+
     /// Returns a mutable reference to the relationship.
     pub fn relationship_mut(&mut self) -> &mut Relationship {
         &mut self.relationship
@@ -141,6 +180,31 @@ impl TimestampedRelationship {
         self.formed_timestamp
     }
 
+    /// Returns the reputation that seeded a direction's trustworthiness
+    /// perceptions at formation, if the trustor had heard of the trustee.
+    #[must_use]
+    pub fn reputation_prior(&self, direction: Direction) -> Option<&Reputation> {
+        match direction {
+            Direction::AToB => self.reputation_prior_a_to_b.as_ref(),
+            Direction::BToA => self.reputation_prior_b_to_a.as_ref(),
+        }
+    }
+
+    /// Replaces the reputation prior for a direction, undoing the previous
+    /// one on the relationship's trustworthiness bases.
+    fn set_reputation_prior(&mut self, direction: Direction, prior: Option<Reputation>) {
+        let slot = match direction {
+            Direction::AToB => &mut self.reputation_prior_a_to_b,
+            Direction::BToA => &mut self.reputation_prior_b_to_a,
+        };
+        if *slot == prior {
+            return;
+        }
+        self.relationship
+            .replace_reputation_prior(direction, slot.as_ref(), prior.as_ref());
+        *slot = prior;
+    }
+
     /// Returns true if the relationship involves the given entity.
     #[must_use]
     pub fn involves(&self, entity_id: &EntityId) -> bool {
@@ -195,6 +259,72 @@ pub struct Simulation {
     relationships: HashMap<RelationshipId, TimestampedRelationship>,
     /// Counter for generating relationship IDs.
     relationship_counter: u64,
+    /// What each entity believes about others' reputations.
+    reputations: ReputationStore,
+    /// Microsystems shared by their members, indexed by ID.
+    microsystems: HashMap<MicrosystemId, Microsystem>,
+    /// When each member belonged to each shared microsystem.
+    microsystem_members: HashMap<MicrosystemId, HashMap<EntityId, Vec<Membership>>>,
+    /// Shared microsystems whose climate emerges from their members.
+    emergent_microsystems: HashSet<MicrosystemId>,
+    /// Historical events declared for the whole simulation.
+    historical_events: Vec<SharedHistoricalEvent>,
+    /// How the population's macrosystem evolves, if it does.
+    macrosystem_dynamics: Option<MacrosystemDynamics>,
+    /// Whether entities follow their culture's normative schedule.
+    normative_schedules: bool,
+    /// Macrosystem shifts memoized until the simulation changes.
+    macrosystem_cache: MacrosystemCache,
+}
+
+/// Macrosystem results memoized until the simulation changes.
+///
+/// Kept behind a mutex so queries through a shared simulation can fill it
+/// and the simulation stays `Sync`.
+#[derive(Debug, Default)]
+struct MacrosystemCache(Mutex<MacrosystemMemo>);
+
+impl MacrosystemCache {
+    fn lock(&self) -> MutexGuard<'_, MacrosystemMemo> {
+        self.0.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn get_mut(&mut self) -> &mut MacrosystemMemo {
+        self.0.get_mut().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl Clone for MacrosystemCache {
+    fn clone(&self) -> Self {
+        MacrosystemCache(Mutex::new(self.lock().clone()))
+    }
+}
+
+/// Macrosystem results memoized per timestamp.
+#[derive(Debug, Clone, Default)]
+struct MacrosystemMemo {
+    /// Shifts by query timestamp.
+    shifts: HashMap<Timestamp, MacrosystemShift>,
+    /// Each member's (stress, acquired capability) by timestamp.
+    members: HashMap<EntityId, HashMap<Timestamp, (f64, f64)>>,
+}
+
+/// A span during which an entity belonged to a shared microsystem.
+///
+/// Entities listed in the microsystem itself belong from the start, with
+/// no join time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Membership {
+    joined: Option<Timestamp>,
+    left: Option<Timestamp>,
+}
+
+impl Membership {
+    /// Returns true if the entity belonged at `timestamp`.
+    fn covers(&self, timestamp: Timestamp) -> bool {
+        self.joined.is_none_or(|joined| joined <= timestamp)
+            && self.left.is_none_or(|left| timestamp < left)
+    }
 }
 
 impl Simulation {
@@ -221,6 +351,14 @@ impl Simulation {
             events: Vec::new(),
             relationships: HashMap::new(),
             relationship_counter: 0,
+            reputations: ReputationStore::new(),
+            microsystems: HashMap::new(),
+            microsystem_members: HashMap::new(),
+            emergent_microsystems: HashSet::new(),
+            historical_events: Vec::new(),
+            macrosystem_dynamics: None,
+            normative_schedules: false,
+            macrosystem_cache: MacrosystemCache::default(),
         }
     }
 
@@ -267,6 +405,7 @@ impl Simulation {
         let id = entity.id().clone();
         let anchored = AnchoredEntity::new(entity, anchor_timestamp);
         self.entities.insert(id.clone(), anchored);
+        self.invalidate_macrosystem_members(vec![id.clone()]);
         id
     }
 
@@ -327,6 +466,7 @@ impl Simulation {
 
     /// Returns a mutable reference to the anchored entity.
     pub fn get_anchored_entity_mut(&mut self, id: &EntityId) -> Option<&mut AnchoredEntity> {
+        self.invalidate_macrosystem_members(vec![id.clone()]);
         self.entities.get_mut(id)
     }
 
@@ -359,6 +499,24 @@ impl Simulation {
     /// sim.add_event(event, event_time);
     /// ```
     pub fn add_event(&mut self, event: Event, timestamp: Timestamp) {
+        match event_reach(&event) {
+            Some(reached) => self.invalidate_macrosystem_members(reached),
+            None => self.invalidate_macrosystem_cache(),
+        }
+        // Agreeableness only weighs repair attempts, so the target's state at
+        // the event is computed for those alone.
+        let trustor_agreeableness = event
+            .event_type()
+            .is_trust_repair()
+            .then(|| event.target().and_then(|target| self.entity(target)))
+            .flatten()
+            .map(|target| {
+                target
+                    .state_at(timestamp)
+                    .individual_state()
+                    .hexaco()
+                    .agreeableness()
+            });
         self.events.push(TimestampedEvent::new(event, timestamp));
 
         let last_event = self
@@ -370,8 +528,18 @@ impl Simulation {
                 continue;
             }
             let rel_slice = std::slice::from_mut(relationship.relationship_mut());
-            process_event_to_relationships(last_event.event(), last_event.timestamp(), rel_slice);
+            process_event_to_relationships(
+                last_event.event(),
+                last_event.timestamp(),
+                rel_slice,
+                trustor_agreeableness,
+            );
         }
+        let timestamp = last_event.timestamp();
+        let bystanders = self.bystanders(last_event.event(), timestamp);
+        self.reputations
+            .observe_event(last_event.event(), timestamp, &bystanders);
+        self.refresh_reputation_priors(Some(timestamp));
     }
 
     /// Returns all events that target the given entity.
@@ -402,6 +570,733 @@ impl Simulation {
         self.events.iter()
     }
 
+    // --- Shared Microsystems ---
+
+    /// Adds a microsystem shared by its members.
+    ///
+    /// Members see the simulation's copy rather than their own, so edits
+    /// made through `microsystem_mut` reach every member. Entities named in
+    /// the microsystem (see `Microsystem::listed_entities`) belong to it
+    /// from the start; others join and leave at a timestamp with
+    /// `join_microsystem` and `leave_microsystem`. Replacing an existing
+    /// microsystem keeps its members.
+    ///
+    /// A shared microsystem takes precedence over an entity's own
+    /// microsystem with the same ID.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use eventsim_rs::context::{Microsystem, WorkContext};
+    /// use eventsim_rs::simulation::Simulation;
+    /// use eventsim_rs::types::{EntityId, MicrosystemId, Timestamp};
+    ///
+    /// let mut sim = Simulation::new(Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0));
+    /// let office = MicrosystemId::new("office").unwrap();
+    /// let boss = EntityId::new("boss").unwrap();
+    ///
+    /// let work = WorkContext {
+    ///     supervisor_id: Some(boss.clone()),
+    ///     ..Default::default()
+    /// };
+    /// sim.add_microsystem(office.clone(), Microsystem::new_work(work));
+    /// let hired = Timestamp::from_ymd_hms(2024, 3, 1, 0, 0, 0);
+    /// sim.join_microsystem(&office, EntityId::new("clerk").unwrap(), hired);
+    ///
+    /// assert_eq!(sim.microsystem_members(&office).len(), 2);
+    /// assert_eq!(sim.microsystem_members_at(&office, sim.reference_date()), vec![boss]);
+    /// ```
+    pub fn add_microsystem(&mut self, id: MicrosystemId, microsystem: Microsystem) {
+        let members = self.microsystem_members.entry(id.clone()).or_default();
+        for listed in microsystem.listed_entities() {
+            let spans = members.entry(listed.clone()).or_default();
+            if !spans.iter().any(|span| span.joined.is_none()) {
+                spans.push(Membership {
+                    joined: None,
+                    left: None,
+                });
+            }
+        }
+        self.microsystems.insert(id.clone(), microsystem);
+        self.invalidate_macrosystem_members(self.sorted_members(&id, |_| true));
+    }
+
+    /// Returns a shared microsystem by ID.
+    #[must_use]
+    pub fn microsystem(&self, id: &MicrosystemId) -> Option<&Microsystem> {
+        self.microsystems.get(id)
+    }
+
+    /// Returns a mutable reference to a shared microsystem.
+    ///
+    /// Changes apply to every member.
+    pub fn microsystem_mut(&mut self, id: &MicrosystemId) -> Option<&mut Microsystem> {
+        self.invalidate_macrosystem_members(self.sorted_members(id, |_| true));
+        self.microsystems.get_mut(id)
+    }
+
+    /// Adds an entity to a shared microsystem from `timestamp` on.
+    ///
+    /// The microsystem is part of the entity's context, and the entity part
+    /// of its climate and scope, only from the join. Joining while already
+    /// a member changes nothing. Returns false if no shared microsystem has
+    /// the ID.
+    pub fn join_microsystem(
+        &mut self,
+        id: &MicrosystemId,
+        entity_id: EntityId,
+        timestamp: Timestamp,
+    ) -> bool {
+        let Some(members) = self.microsystem_members.get_mut(id) else {
+            return false;
+        };
+        let spans = members.entry(entity_id.clone()).or_default();
+        if spans.iter().all(|span| span.left.is_some()) {
+            spans.push(Membership {
+                joined: Some(timestamp),
+                left: None,
+            });
+            self.invalidate_macrosystem_members(vec![entity_id]);
+        }
+        true
+    }
+
+    /// Removes an entity from a shared microsystem from `timestamp` on.
+    ///
+    /// Returns true if the entity was a member.
+    pub fn leave_microsystem(
+        &mut self,
+        id: &MicrosystemId,
+        entity_id: &EntityId,
+        timestamp: Timestamp,
+    ) -> bool {
+        self.invalidate_macrosystem_members(vec![entity_id.clone()]);
+        let open = self
+            .microsystem_members
+            .get_mut(id)
+            .and_then(|members| members.get_mut(entity_id))
+            .and_then(|spans| spans.iter_mut().find(|span| span.left.is_none()));
+        match open {
+            Some(span) => {
+                span.left = Some(timestamp);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Returns the current members of a shared microsystem, sorted by ID.
+    ///
+    /// These are the entities that have not left, including those whose
+    /// join lies ahead of a given query (see `microsystem_members_at`).
+    #[must_use]
+    pub fn microsystem_members(&self, id: &MicrosystemId) -> Vec<EntityId> {
+        self.sorted_members(id, |span| span.left.is_none())
+    }
+
+    /// Returns the members of a shared microsystem at `timestamp`, sorted
+    /// by ID.
+    #[must_use]
+    pub fn microsystem_members_at(
+        &self,
+        id: &MicrosystemId,
+        timestamp: Timestamp,
+    ) -> Vec<EntityId> {
+        self.sorted_members(id, |span| span.covers(timestamp))
+    }
+
+    /// Returns true if the entity belonged to the shared microsystem at
+    /// `timestamp`.
+    fn is_member_at(&self, id: &MicrosystemId, entity_id: &EntityId, timestamp: Timestamp) -> bool {
+        self.microsystem_members
+            .get(id)
+            .and_then(|members| members.get(entity_id))
+            .is_some_and(|spans| spans.iter().any(|span| span.covers(timestamp)))
+    }
+
+    fn sorted_members(
+        &self,
+        id: &MicrosystemId,
+        belongs: impl Fn(&Membership) -> bool,
+    ) -> Vec<EntityId> {
+        let mut members: Vec<EntityId> = self
+            .microsystem_members
+            .get(id)
+            .into_iter()
+            .flatten()
+            .filter(|(_, spans)| spans.iter().any(&belongs))
+            .map(|(member, _)| member.clone())
+            .collect();
+        members.sort_by(|a, b| a.as_str().cmp(b.as_str()));
+        members
+    }
+
+    /// Enables or disables emergent climate for a shared microsystem.
+    ///
+    /// When enabled, members see the climate derived from their own
+    /// projected states (see `microsystem_at`) instead of the authored
+    /// warmth, hostility and workload stress. Returns false if no shared
+    /// microsystem has the ID.
+    pub fn set_emergent_climate(&mut self, id: &MicrosystemId, enabled: bool) -> bool {
+        self.invalidate_macrosystem_members(self.sorted_members(id, |_| true));
+        if !self.microsystems.contains_key(id) {
+            return false;
+        }
+        if enabled {
+            self.emergent_microsystems.insert(id.clone());
+        } else {
+            self.emergent_microsystems.remove(id);
+        }
+        true
+    }
+
+    /// Returns true if the shared microsystem's climate emerges from its members.
+    #[must_use]
+    pub fn emergent_climate_enabled(&self, id: &MicrosystemId) -> bool {
+        self.emergent_microsystems.contains(id)
+    }
+
+    /// Returns a shared microsystem as its members experience it at `timestamp`.
+    ///
+    /// For an emergent microsystem, hostility is the mean of members'
+    /// aggression and grievance, warmth is the mean warmth between members
+    /// in relationships formed by `timestamp`, and a work microsystem's
+    /// workload stress is its supervisor's stress (see `MicrosystemClimate`).
+    /// Member states are projected with authored climates and macrosystems,
+    /// so a member's state does not feed back into itself. Otherwise the
+    /// authored microsystem is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use eventsim_rs::context::{Microsystem, SocialContext};
+    /// use eventsim_rs::entity::EntityBuilder;
+    /// use eventsim_rs::enums::Species;
+    /// use eventsim_rs::simulation::Simulation;
+    /// use eventsim_rs::types::{EntityId, MicrosystemId, Timestamp};
+    ///
+    /// let reference = Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+    /// let mut sim = Simulation::new(reference);
+    /// let mut bully = EntityBuilder::new().id("bully").species(Species::Human).build().unwrap();
+    /// bully.individual_state_mut().disposition_mut().add_aggression_delta(0.7);
+    /// sim.add_entity(bully, reference);
+    ///
+    /// let club = MicrosystemId::new("club").unwrap();
+    /// sim.add_microsystem(club.clone(), Microsystem::new_social(SocialContext::default()));
+    /// sim.join_microsystem(&club, EntityId::new("bully").unwrap(), reference);
+    /// sim.set_emergent_climate(&club, true);
+    ///
+    /// let authored = sim.microsystem(&club).unwrap().hostility();
+    /// let emergent = sim.microsystem_at(&club, reference).unwrap().hostility();
+    /// assert!(emergent > authored);
+    /// ```
+    #[must_use]
+    pub fn microsystem_at(&self, id: &MicrosystemId, timestamp: Timestamp) -> Option<Microsystem> {
+        let mut microsystem = self.microsystems.get(id)?.clone();
+        if !self.emergent_microsystems.contains(id) {
+            return Some(microsystem);
+        }
+
+        let mut states = HashMap::new();
+        for member in self.microsystem_members_at(id, timestamp) {
+            if let Some(handle) = self.entity(&member) {
+                let state = handle
+                    .without_emergent_climate()
+                    .without_macrosystem_dynamics()
+                    .state_at(timestamp);
+                states.insert(member, state.individual_state().clone());
+            }
+        }
+
+        let mut climate = MicrosystemClimate::new();
+        let mut member_ids: Vec<&EntityId> = states.keys().collect();
+        member_ids.sort_by(|a, b| a.as_str().cmp(b.as_str()));
+        for member in member_ids {
+            climate = climate.with_member(&states[member]);
+        }
+        for record in self.relationships.values() {
+            let is_tie = self.is_member_at(id, record.entity_a(), timestamp)
+                && self.is_member_at(id, record.entity_b(), timestamp);
+            if !is_tie {
+                continue;
+            }
+            let Some(relationship) = record.relationship_at(timestamp) else {
+                continue;
+            };
+            for direction in [Direction::AToB, Direction::BToA] {
+                let warmth = relationship.directional(direction).warmth_effective();
+                climate = climate.with_tie_warmth(warmth);
+            }
+        }
+        let supervisor = microsystem
+            .work()
+            .and_then(|work| work.supervisor_id.as_ref())
+            .and_then(|supervisor| states.get(supervisor));
+        if let Some(supervisor) = supervisor {
+            climate = climate.with_supervisor(supervisor);
+        }
+
+        climate.apply_to(&mut microsystem);
+        Some(microsystem)
+    }
+
+    /// Returns the shared microsystems an entity belongs to at `timestamp`.
+    pub(crate) fn shared_microsystems_for<'a>(
+        &'a self,
+        entity_id: &'a EntityId,
+        timestamp: Timestamp,
+    ) -> impl Iterator<Item = (&'a MicrosystemId, &'a Microsystem)> + 'a {
+        self.microsystems
+            .iter()
+            .filter(move |(id, _)| self.is_member_at(id, entity_id, timestamp))
+    }
+
+    /// Returns the entities an event scope covers, sorted by ID.
+    ///
+    /// Groups are the reputation groups from `add_group_member`, and
+    /// microsystems are the shared microsystems. Entities named by an
+    /// individual scope are returned even if they are not in the simulation.
+    #[must_use]
+    pub fn resolve_scope(&self, scope: &EventScope) -> Vec<EntityId> {
+        let mut entities: Vec<EntityId> = match scope {
+            EventScope::Individual(id) => vec![id.clone()],
+            EventScope::Group(group) => self.reputations.group_members(group).cloned().collect(),
+            EventScope::Microsystem(id) => return self.microsystem_members(id),
+            EventScope::Global => self.entities.keys().cloned().collect(),
+        };
+        entities.sort_by(|a, b| a.as_str().cmp(b.as_str()));
+        entities
+    }
+
+    /// Adds a copy of the event for each entity in the scope.
+    ///
+    /// Each copy targets one entity, so a hostility spike at the office
+    /// reaches every member of the shared microsystem at `timestamp`.
+    pub fn dispatch_event(&mut self, event: Event, scope: &EventScope, timestamp: Timestamp) {
+        let targets = match scope {
+            EventScope::Microsystem(id) => self.microsystem_members_at(id, timestamp),
+            _ => self.resolve_scope(scope),
+        };
+        for entity_id in targets {
+            let mut copy = event.clone();
+            copy.set_target(Some(entity_id));
+            self.add_event(copy, timestamp);
+        }
+    }
+
+    // --- Historical Events ---
+
+    /// Declares a historical event for the whole simulation.
+    ///
+    /// Every entity the event reaches (see `HistoricalReach`) that is alive
+    /// at its start experiences an `EventType::HistoricalEvent` then. Its
+    /// severity is scaled by `SharedHistoricalEvent::exposure_weight` at
+    /// the entity's age, and `state_at` applies life-stage plasticity on
+    /// top. Entities born while it is underway take its era as their
+    /// `BirthEra`, unless their chronosystem already names one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use eventsim_rs::context::SharedHistoricalEvent;
+    /// use eventsim_rs::entity::EntityBuilder;
+    /// use eventsim_rs::enums::{BirthEra, HistoricalEventType, Species};
+    /// use eventsim_rs::simulation::Simulation;
+    /// use eventsim_rs::types::{EntityId, Timestamp};
+    ///
+    /// let reference = Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+    /// let mut sim = Simulation::new(reference);
+    /// let baby = EntityBuilder::new()
+    ///     .id("baby")
+    ///     .species(Species::Human)
+    ///     .birth_date(Timestamp::from_ymd_hms(2020, 6, 1, 0, 0, 0))
+    ///     .build()
+    ///     .unwrap();
+    /// sim.add_entity(baby, reference);
+    ///
+    /// sim.add_historical_event(SharedHistoricalEvent::new(
+    ///     "pandemic",
+    ///     HistoricalEventType::Pandemic,
+    ///     Timestamp::from_ymd_hms(2020, 3, 1, 0, 0, 0),
+    ///     0.7,
+    /// ));
+    ///
+    /// let baby = EntityId::new("baby").unwrap();
+    /// assert_eq!(sim.birth_era(&baby), Some(BirthEra::Crisis));
+    /// ```
+    pub fn add_historical_event(&mut self, event: SharedHistoricalEvent) {
+        self.invalidate_macrosystem_cache();
+        self.historical_events.push(event);
+    }
+
+    /// Returns the historical events declared for the simulation.
+    #[must_use]
+    pub fn historical_events(&self) -> &[SharedHistoricalEvent] {
+        &self.historical_events
+    }
+
+    /// Returns the historical events that reach an entity, by start.
+    ///
+    /// Regions are shared microsystems, and cohorts need a birth date.
+    /// Returns an empty vector if the entity is not in the simulation.
+    #[must_use]
+    pub fn historical_events_for(&self, entity_id: &EntityId) -> Vec<&SharedHistoricalEvent> {
+        let Some(anchored) = self.entities.get(entity_id) else {
+            return Vec::new();
+        };
+        let birth_date = anchored.entity().birth_date();
+        let mut events: Vec<&SharedHistoricalEvent> = self
+            .historical_events
+            .iter()
+            .filter(|event| match event.reach() {
+                HistoricalReach::Global => true,
+                HistoricalReach::Region(region) => {
+                    self.is_member_at(region, entity_id, event.start())
+                }
+                HistoricalReach::Cohort {
+                    born_from,
+                    born_until,
+                } => birth_date.is_some_and(|born| born >= *born_from && born < *born_until),
+            })
+            .collect();
+        events.sort_by_key(|event| event.start());
+        events
+    }
+
+    /// Returns an entity's birth era.
+    ///
+    /// An era set in the entity's chronosystem wins. Otherwise it is the
+    /// era of the earliest historical event underway at the entity's birth
+    /// that reaches it, or `BirthEra::Unknown`. Returns None if the entity
+    /// is not in the simulation.
+    #[must_use]
+    pub fn birth_era(&self, entity_id: &EntityId) -> Option<BirthEra> {
+        let entity = self.entities.get(entity_id)?.entity();
+        let authored = entity.context().chronosystem().cohort_effects().birth_era;
+        if authored != BirthEra::Unknown {
+            return Some(authored);
+        }
+        let Some(birth_date) = entity.birth_date() else {
+            return Some(BirthEra::Unknown);
+        };
+        let era = self
+            .historical_events_for(entity_id)
+            .into_iter()
+            .find(|event| event.is_underway(birth_date))
+            .map_or(BirthEra::Unknown, SharedHistoricalEvent::era);
+        Some(era)
+    }
+
+    /// Returns the per-entity events for the historical events that reach
+    /// an entity alive at their start.
+    pub(crate) fn historical_exposures(&self, entity_id: &EntityId) -> Vec<TimestampedEvent> {
+        let Some(anchored) = self.entities.get(entity_id) else {
+            return Vec::new();
+        };
+        let entity = anchored.entity();
+        let mut chronosystem = entity.context().chronosystem().clone();
+        chronosystem.cohort_effects_mut().birth_era =
+            self.birth_era(entity_id).unwrap_or(BirthEra::Unknown);
+
+        self.historical_events_for(entity_id)
+            .into_iter()
+            .filter_map(|historical| {
+                let start = historical.start();
+                let age = match entity.birth_date() {
+                    Some(birth_date) if birth_date > start => return None,
+                    Some(birth_date) => start - birth_date,
+                    None => entity.age(),
+                };
+                let weight = historical.exposure_weight(&chronosystem, age.as_years_f64());
+                let id = EventId::new(format!("historical_{}_{}", historical.name(), entity_id))
+                    .expect("historical exposure ID is non-empty");
+                let event = EventBuilder::new(EventType::HistoricalEvent)
+                    .id(id)
+                    .target(entity_id.clone())
+                    .severity((historical.severity() * weight).min(1.0))
+                    .payload(EventPayload::HistoricalEvent {
+                        event_type: historical.event_type(),
+                        scope: historical.reach().scope(),
+                    })
+                    .build()
+                    .expect("historical exposure has no base shifts");
+                Some(TimestampedEvent::new(event, start))
+            })
+            .collect()
+    }
+
+    // --- Macrosystem Dynamics ---
+
+    /// Enables or disables macrosystem evolution.
+    ///
+    /// While enabled, every entity's macrosystem evolves with the population
+    /// (see `macrosystem_shift_at`), and `state_at` applies macrosystem
+    /// effects from the evolved culture. Each entity's authored macrosystem
+    /// remains the base the shift applies to.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use eventsim_rs::context::MacrosystemDynamics;
+    /// use eventsim_rs::entity::EntityBuilder;
+    /// use eventsim_rs::enums::{EventType, Species};
+    /// use eventsim_rs::event::EventBuilder;
+    /// use eventsim_rs::simulation::Simulation;
+    /// use eventsim_rs::types::{EntityId, Timestamp};
+    ///
+    /// let reference = Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+    /// let mut sim = Simulation::new(reference);
+    /// let victim = EntityBuilder::new().id("victim").species(Species::Human).build().unwrap();
+    /// sim.add_entity(victim, reference);
+    /// sim.set_macrosystem_dynamics(Some(MacrosystemDynamics::new()));
+    ///
+    /// let assault = EventBuilder::new(EventType::Violence)
+    ///     .target(EntityId::new("victim").unwrap())
+    ///     .severity(0.8)
+    ///     .build()
+    ///     .unwrap();
+    /// sim.add_event(assault, Timestamp::from_ymd_hms(2024, 2, 1, 0, 0, 0));
+    ///
+    /// let before = sim.macrosystem_shift_at(reference).unwrap();
+    /// let after = sim
+    ///     .macrosystem_shift_at(Timestamp::from_ymd_hms(2024, 3, 1, 0, 0, 0))
+    ///     .unwrap();
+    /// let victim = sim.entity(&EntityId::new("victim").unwrap()).unwrap();
+    /// let authored = victim.context_at(reference).unwrap();
+    /// assert!(
+    ///     after.apply_to(authored.macrosystem()).collective_trauma
+    ///         > before.apply_to(authored.macrosystem()).collective_trauma
+    /// );
+    /// ```
+    pub fn set_macrosystem_dynamics(&mut self, dynamics: Option<MacrosystemDynamics>) {
+        self.invalidate_macrosystem_cache();
+        self.macrosystem_dynamics = dynamics;
+    }
+
+    /// Returns the macrosystem dynamics, if the macrosystem evolves.
+    #[must_use]
+    pub fn macrosystem_dynamics(&self) -> Option<&MacrosystemDynamics> {
+        self.macrosystem_dynamics.as_ref()
+    }
+
+    /// Returns how the population has shifted the macrosystem by `timestamp`.
+    ///
+    /// Every entity is part of the population. Events at or before the
+    /// timestamp, including exposures to historical events, contribute per
+    /// capita. Members' states are projected with the authored macrosystem,
+    /// so a member's state does not feed back into itself, and their mean
+    /// stress is sampled on a grid from the reference date so cultural
+    /// stress lags behind it. The shift is computed once per timestamp
+    /// until the simulation changes, and each member is projected once per
+    /// sample until a change reaches it. Returns None if dynamics are
+    /// disabled.
+    #[must_use]
+    pub fn macrosystem_shift_at(&self, timestamp: Timestamp) -> Option<MacrosystemShift> {
+        let dynamics = self.macrosystem_dynamics.as_ref()?;
+        let cached = self
+            .macrosystem_cache
+            .lock()
+            .shifts
+            .get(&timestamp)
+            .copied();
+        if cached.is_some() {
+            return cached;
+        }
+        let mut entity_ids: Vec<&EntityId> = self.entities.keys().collect();
+        entity_ids.sort_by(|a, b| a.as_str().cmp(b.as_str()));
+
+        let exposures: Vec<TimestampedEvent> = entity_ids
+            .iter()
+            .flat_map(|id| self.historical_exposures(id))
+            .collect();
+        let events = self
+            .events
+            .iter()
+            .chain(&exposures)
+            .map(|te| (te.timestamp(), te.event()));
+        let mut shift = dynamics.shift_at(events, entity_ids.len(), timestamp);
+        for id in &entity_ids {
+            if let Some((stress, capability)) = self.member_values_at(id, timestamp) {
+                shift = shift.with_member_values(stress, capability);
+            }
+        }
+
+        let samples = dynamics
+            .member_sample_times(self.reference_date, timestamp)
+            .into_iter()
+            .filter_map(|at| {
+                self.member_stress_at(&entity_ids, at)
+                    .map(|stress| (at, stress))
+            });
+        let lagged = dynamics.lagged_member_stress(samples, timestamp);
+        let shift = shift.with_lagged_member_stress(lagged);
+        self.macrosystem_cache
+            .lock()
+            .shifts
+            .insert(timestamp, shift);
+        Some(shift)
+    }
+
+    /// Returns members' mean stress at `timestamp`.
+    fn member_stress_at(&self, entity_ids: &[&EntityId], timestamp: Timestamp) -> Option<f64> {
+        let stress: Vec<f64> = entity_ids
+            .iter()
+            .filter_map(|id| self.member_values_at(id, timestamp))
+            .map(|(stress, _)| stress)
+            .collect();
+        (!stress.is_empty()).then(|| stress.iter().sum::<f64>() / stress.len() as f64)
+    }
+
+    /// Returns a member's stress and acquired capability at `timestamp`,
+    /// projected with its authored macrosystem and memoized.
+    fn member_values_at(&self, id: &EntityId, timestamp: Timestamp) -> Option<(f64, f64)> {
+        let cached = self
+            .macrosystem_cache
+            .lock()
+            .members
+            .get(id)
+            .and_then(|values| values.get(&timestamp))
+            .copied();
+        if cached.is_some() {
+            return cached;
+        }
+        let state = self
+            .entity(id)?
+            .without_macrosystem_dynamics()
+            .state_at(timestamp);
+        let individual = state.individual_state();
+        let values = (
+            f64::from(individual.needs().stress_effective()),
+            f64::from(individual.mental_health().acquired_capability_effective()),
+        );
+        self.macrosystem_cache
+            .lock()
+            .members
+            .entry(id.clone())
+            .or_default()
+            .insert(timestamp, values);
+        Some(values)
+    }
+
+    /// Drops every memoized macrosystem result.
+    fn invalidate_macrosystem_cache(&mut self) {
+        *self.macrosystem_cache.get_mut() = MacrosystemMemo::default();
+    }
+
+    /// Drops memoized shifts and the projections of every entity whose
+    /// state can depend on one of `changed`.
+    ///
+    /// Entities depend on each other through relationships, shared
+    /// microsystems and linked parents, so everything connected to a
+    /// changed entity through them is dropped. Other members keep their
+    /// projections.
+    fn invalidate_macrosystem_members(&mut self, changed: Vec<EntityId>) {
+        let mut links: HashMap<&EntityId, Vec<&EntityId>> = HashMap::new();
+        let mut pairs: Vec<(&EntityId, &EntityId)> = self
+            .relationships
+            .values()
+            .map(|record| record.relationship().entities())
+            .collect();
+        for members in self.microsystem_members.values() {
+            let mut members = members.keys();
+            if let Some(first) = members.next() {
+                pairs.extend(members.map(|member| (first, member)));
+            }
+        }
+        for (id, anchored) in &self.entities {
+            let parents = &anchored.entity().context().exosystem().parent_ids;
+            pairs.extend(parents.iter().map(|parent| (id, parent)));
+        }
+        for (a, b) in pairs {
+            links.entry(a).or_default().push(b);
+            links.entry(b).or_default().push(a);
+        }
+
+        let mut reached: HashSet<EntityId> = HashSet::new();
+        let mut pending = changed;
+        while let Some(id) = pending.pop() {
+            if reached.contains(&id) {
+                continue;
+            }
+            if let Some(linked) = links.get(&id) {
+                pending.extend(
+                    linked
+                        .iter()
+                        .filter(|other| !reached.contains(**other))
+                        .map(|other| (*other).clone()),
+                );
+            }
+            reached.insert(id);
+        }
+
+        let cache = self.macrosystem_cache.get_mut();
+        cache.shifts.clear();
+        cache.members.retain(|id, _| !reached.contains(id));
+    }
+
+    // --- Normative Schedules ---
+
+    /// Enables or disables normative transition scheduling.
+    ///
+    /// While enabled, every entity's chronosystem carries its culture's
+    /// `NormativeSchedule` (transitions it already has by name are kept),
+    /// and context transition events complete the `LifeTransition` they
+    /// realize at the entity's age. Off-time completions then add stress
+    /// from the event on, and `off_time_transitions_at` lists transitions
+    /// that are late or missing. Transitions whose window had closed by an
+    /// entity's anchor are not tracked for it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use eventsim_rs::context::{Microsystem, WorkContext};
+    /// use eventsim_rs::entity::EntityBuilder;
+    /// use eventsim_rs::enums::{EventPayload, EventType, Species};
+    /// use eventsim_rs::event::EventBuilder;
+    /// use eventsim_rs::simulation::Simulation;
+    /// use eventsim_rs::types::{EntityId, MicrosystemId, Timestamp};
+    ///
+    /// let reference = Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+    /// let mut sim = Simulation::new(reference);
+    /// let person = EntityBuilder::new()
+    ///     .id("person")
+    ///     .species(Species::Human)
+    ///     .birth_date(Timestamp::from_ymd_hms(2002, 1, 1, 0, 0, 0))
+    ///     .build()
+    ///     .unwrap();
+    /// sim.add_entity(person, reference);
+    /// let job = MicrosystemId::new("job").unwrap();
+    /// sim.add_microsystem(job.clone(), Microsystem::new_work(WorkContext::default()));
+    /// sim.set_normative_schedules(true);
+    ///
+    /// let hired = EventBuilder::new(EventType::ContextTransition)
+    ///     .target(EntityId::new("person").unwrap())
+    ///     .payload(EventPayload::ContextTransition {
+    ///         from: MicrosystemId::new("home").unwrap(),
+    ///         to: job,
+    ///     })
+    ///     .build()
+    ///     .unwrap();
+    /// sim.add_event(hired, Timestamp::from_ymd_hms(2029, 6, 1, 0, 0, 0));
+    ///
+    /// // Hired at 27, after the first job window (16 to 24) closed
+    /// let person = sim.entity(&EntityId::new("person").unwrap()).unwrap();
+    /// let later = Timestamp::from_ymd_hms(2029, 9, 1, 0, 0, 0);
+    /// let off_time = person.off_time_transitions_at(later).unwrap();
+    /// assert_eq!(off_time[0].name, "first_job");
+    /// assert!(off_time[0].is_late());
+    /// ```
+    pub fn set_normative_schedules(&mut self, enabled: bool) {
+        self.invalidate_macrosystem_cache();
+        self.normative_schedules = enabled;
+    }
+
+    /// Returns whether entities follow their culture's normative schedule.
+    #[must_use]
+    pub fn normative_schedules_enabled(&self) -> bool {
+        self.normative_schedules
+    }
+
     // --- Relationship Management ---
 
     fn resolve_schema_constraints(
@@ -433,6 +1328,12 @@ impl Simulation {
 
     /// Adds a relationship between two entities.
     ///
+    /// Each side's starting trustworthiness perceptions are shifted by what
+    /// they have heard of the other (see `reputation`). Entities with
+    /// transitive trust enabled in their model config also inherit a prior
+    /// from trusted mutual contacts; it is recorded in the antecedent history
+    /// under the `transitive_trust` and `vouching` contexts.
+    ///
     /// # Returns
     ///
     /// The relationship's ID.
@@ -476,18 +1377,155 @@ impl Simulation {
             .expect("Failed to create relationship")
             .with_schema(schema);
 
+        // Strangers start from what they have heard about each other
+        let mut timestamped = TimestampedRelationship::new(
+            relationship,
+            entity_a.clone(),
+            entity_b.clone(),
+            formed_timestamp,
+        );
+        for (observer, subject, direction) in [
+            (&entity_a, &entity_b, Direction::AToB),
+            (&entity_b, &entity_a, Direction::BToA),
+        ] {
+            let view = self
+                .reputations
+                .effective_view(observer, subject, formed_timestamp);
+            timestamped.set_reputation_prior(direction, view);
+        }
+        let relationship = timestamped.relationship_mut();
+
+        // Entities that opt in also inherit trust through mutual contacts
+        for (trustor, trustee, direction) in [
+            (&entity_a, &entity_b, Direction::AToB),
+            (&entity_b, &entity_a, Direction::BToA),
+        ] {
+            if !self.transitive_trust_enabled(trustor) {
+                continue;
+            }
+            let referrals = self.trust_referrals(trustor, trustee, formed_timestamp);
+            let Some(prior) = TransitiveTrustPrior::from_referrals(&referrals) else {
+                continue;
+            };
+            let antecedents =
+                prior.antecedents(relationship.trustworthiness(direction), formed_timestamp);
+            for antecedent in antecedents {
+                relationship.append_antecedent(direction, antecedent);
+            }
+            let history = relationship.antecedent_history(direction).to_vec();
+            relationship
+                .trustworthiness_mut(direction)
+                .recompute_from_antecedents(&history);
+        }
+
         // Generate ID
         self.relationship_counter += 1;
         let rel_id =
             RelationshipId::new(format!("rel_{:016x}", self.relationship_counter)).unwrap();
 
-        let timestamped =
-            TimestampedRelationship::new(relationship, entity_a, entity_b, formed_timestamp);
-
         self.relationships.insert(rel_id.clone(), timestamped);
+        self.invalidate_macrosystem_members(vec![entity_a, entity_b]);
         rel_id
     }
 
+    fn transitive_trust_enabled(&self, entity_id: &EntityId) -> bool {
+        self.entities.get(entity_id).is_some_and(|anchored| {
+            anchored
+                .entity()
+                .individual_state()
+                .config()
+                .transitive_trust_enabled()
+        })
+    }
+
+    /// Collects referrals from `trustor` to `newcomer` through relationships
+    /// formed by `as_of`.
+    ///
+    /// Paths follow relationships past the Stranger stage and not estranged,
+    /// up to `MAX_TRANSITIVE_PATH_LENGTH` relationships long. Referrals are
+    /// sorted by path so the combined prior does not depend on map order.
+    fn trust_referrals(
+        &self,
+        trustor: &EntityId,
+        newcomer: &EntityId,
+        as_of: Timestamp,
+    ) -> Vec<TrustReferral> {
+        // Each edge is (from, to, from's perception of to, stage)
+        let mut edges: Vec<(
+            &EntityId,
+            &EntityId,
+            &TrustworthinessFactors,
+            RelationshipStage,
+        )> = Vec::new();
+        let relationships: Vec<Relationship> = self
+            .relationships
+            .values()
+            .filter_map(|record| record.relationship_at(as_of))
+            .collect();
+        for relationship in &relationships {
+            if matches!(
+                relationship.stage(),
+                RelationshipStage::Stranger | RelationshipStage::Estranged
+            ) {
+                continue;
+            }
+            let (a, b) = relationship.entities();
+            edges.push((
+                a,
+                b,
+                relationship.trustworthiness(Direction::AToB),
+                relationship.stage(),
+            ));
+            edges.push((
+                b,
+                a,
+                relationship.trustworthiness(Direction::BToA),
+                relationship.stage(),
+            ));
+        }
+
+        let mut referrals = Vec::new();
+        let mut frontier: Vec<(&EntityId, Vec<EntityId>, f32)> = vec![(trustor, Vec::new(), 1.0)];
+        while let Some((current, path, path_trust)) = frontier.pop() {
+            for (from, to, view, stage) in &edges {
+                if *from != current {
+                    continue;
+                }
+                if *to == newcomer {
+                    if path.is_empty() {
+                        continue;
+                    }
+                    let vouches = matches!(
+                        stage,
+                        RelationshipStage::Established | RelationshipStage::Intimate
+                    ) && view.overall() >= VOUCHING_TRUST_THRESHOLD;
+                    referrals.push(
+                        TrustReferral::new(path.clone(), path_trust, view).with_vouching(vouches),
+                    );
+                } else if *to != trustor
+                    && !path.contains(to)
+                    && path.len() + 1 < MAX_TRANSITIVE_PATH_LENGTH
+                {
+                    let mut extended = path.clone();
+                    extended.push((*to).clone());
+                    frontier.push((to, extended, path_trust * view.overall()));
+                }
+            }
+        }
+
+        referrals.sort_by(|x, y| {
+            let key = |r: &TrustReferral| {
+                r.intermediaries()
+                    .iter()
+                    .map(EntityId::as_str)
+                    .collect::<Vec<_>>()
+                    .join("/")
+            };
+            key(x).cmp(&key(y))
+        });
+        referrals
+    }
+
     /// Returns all relationships involving the given entity.
     #[must_use]
     pub fn relationships_for(&self, entity_id: &EntityId) -> Vec<&TimestampedRelationship> {
@@ -503,11 +1541,349 @@ impl Simulation {
         self.relationships.get(id)
     }
 
+    /// Returns a mutable reference to the relationship with the given ID.
+    ///
+    /// Use this to set up relationship dimensions (warmth, trust, ...)
+    /// after `add_relationship`.
+    pub fn get_relationship_mut(
+        &mut self,
+        id: &RelationshipId,
+    ) -> Option<&mut TimestampedRelationship> {
+        if let Some(record) = self.relationships.get(id) {
+            let (a, b) = record.relationship().entities();
+            self.invalidate_macrosystem_members(vec![a.clone(), b.clone()]);
+        }
+        self.relationships.get_mut(id)
+    }
+
+    /// Returns the relationship with the given ID as it stands at
+    /// `timestamp`.
+    ///
+    /// Relationships whose pair has not interacted for a long time fade and
+    /// may regress a stage (see `TimestampedRelationship::relationship_at`).
+    /// Returns `None` for unknown relationships and relationships formed
+    /// after `timestamp`.
+    #[must_use]
+    pub fn relationship_at(
+        &self,
+        id: &RelationshipId,
+        timestamp: Timestamp,
+    ) -> Option<Relationship> {
+        self.relationships.get(id)?.relationship_at(timestamp)
+    }
+
     /// Returns the number of relationships.
     #[must_use]
     pub fn relationship_count(&self) -> usize {
         self.relationships.len()
     }
+
+    // --- Behavioral decisions ---
+
+    /// Ranks the options an entity could take at `timestamp`.
+    ///
+    /// Utilities are computed from the entity's state at `timestamp` (see
+    /// `decision::utility`). For options with a counterpart, the entity's
+    /// trust decision toward them comes from their relationship, or from a
+    /// stranger's perspective informed by reputation if they have none.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the entity is unknown or its model config does
+    /// not enable `SubsystemId::BehavioralDecision`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use eventsim_rs::decision::ActionOption;
+    /// use eventsim_rs::entity::EntityBuilder;
+    /// use eventsim_rs::enums::Species;
+    /// use eventsim_rs::simulation::Simulation;
+    /// use eventsim_rs::types::{EntityId, Timestamp};
+    ///
+    /// let reference = Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+    /// let mut sim = Simulation::new(reference);
+    /// let npc = EntityBuilder::new().id("npc").species(Species::Human).build().unwrap();
+    /// sim.add_entity(npc, reference);
+    ///
+    /// let options = [
+    ///     ActionOption::new("rest").with_valence(0.2),
+    ///     ActionOption::new("explore").with_effort(0.6).with_risk(0.3),
+    /// ];
+    /// let npc = EntityId::new("npc").unwrap();
+    /// let decision = sim.decide(&npc, reference, &options, 0.25).unwrap();
+    /// let chosen = decision.sample(42).unwrap();
+    /// ```
+    pub fn decide(
+        &self,
+        entity_id: &EntityId,
+        timestamp: Timestamp,
+        options: &[ActionOption],
+        temperature: f32,
+    ) -> Result<Decision, DecisionError> {
+        let handle = self
+            .entity(entity_id)
+            .ok_or_else(|| DecisionError::UnknownEntity(entity_id.clone()))?;
+        let state = handle.state_at(timestamp);
+        let state = state.individual_state();
+        if !state.config().is_active(SubsystemId::BehavioralDecision) {
+            return Err(DecisionError::SubsystemInactive(entity_id.clone()));
+        }
+        let propensity = state.disposition().trust_propensity_effective();
+
+        Ok(decide(
+            state,
+            options,
+            |option| {
+                let (counterpart, _, stakes) = option.counterpart()?;
+                let existing = self.relationships.values().find_map(|record| {
+                    let relationship = record.relationship_at(timestamp)?;
+                    let (a, b) = relationship.entities();
+                    if a == entity_id && b == counterpart {
+                        Some((relationship, Direction::AToB))
+                    } else if b == entity_id && a == counterpart {
+                        Some((relationship, Direction::BToA))
+                    } else {
+                        None
+                    }
+                });
+                let reputation = self.reputation(entity_id, counterpart, timestamp);
+                let decision = match existing {
+                    Some((relationship, direction)) => relationship
+                        .compute_trust_decision_with_reputation(
+                            direction,
+                            propensity,
+                            stakes,
+                            reputation.as_ref(),
+                        ),
+                    None => Relationship::try_between(entity_id.clone(), counterpart.clone())
+                        .ok()?
+                        .compute_trust_decision_with_reputation(
+                            Direction::AToB,
+                            propensity,
+                            stakes,
+                            reputation.as_ref(),
+                        ),
+                };
+                Some(decision)
+            },
+            temperature,
+        ))
+    }
+
+    // --- Trust calibration ---
+
+    /// Returns how trustworthy the entity actually is at `timestamp`.
+    ///
+    /// Derived from the entity's HEXACO traits as computed by `state_at`
+    /// (see `ActualTrustworthiness::from_hexaco`). Returns `None` for
+    /// entities not in the simulation.
+    #[must_use]
+    pub fn actual_trustworthiness(
+        &self,
+        entity_id: &EntityId,
+        timestamp: Timestamp,
+    ) -> Option<ActualTrustworthiness> {
+        let state = self.entity(entity_id)?.state_at(timestamp);
+        Some(ActualTrustworthiness::from_hexaco(
+            state.individual_state().hexaco(),
+        ))
+    }
+
+    /// Compares the trustor's perceptions with the trustee's actual
+    /// trustworthiness at `timestamp`.
+    ///
+    /// `direction` selects the trustor as for `Relationship::trustworthiness`
+    /// (`AToB` is A's view of B). Perceptions are the trustor's at
+    /// `timestamp`, derived from the trust antecedents up to it (see
+    /// `TimestampedRelationship::relationship_at`). Positive errors are
+    /// over-trust. Returns `None` if the relationship does not exist or had
+    /// not formed by `timestamp`, or the trustee is not in the simulation.
+    #[must_use]
+    pub fn trust_calibration(
+        &self,
+        relationship_id: &RelationshipId,
+        direction: Direction,
+        timestamp: Timestamp,
+    ) -> Option<TrustCalibration> {
+        let record = self.relationships.get(relationship_id)?;
+        let trustee = match direction {
+            Direction::AToB => record.entity_b(),
+            Direction::BToA => record.entity_a(),
+        };
+        let actual = self.actual_trustworthiness(trustee, timestamp)?;
+        let relationship = record.relationship_at(timestamp)?;
+        Some(TrustCalibration::between(
+            relationship.trustworthiness(direction),
+            &actual,
+        ))
+    }
+
+    // --- Reputation ---
+
+    /// Returns the reputation store.
+    ///
+    /// `add_event` records each event's implications for its source's
+    /// reputation in the eyes of its target and of bystanders: the other
+    /// members of the target's shared microsystems (see
+    /// `ReputationStore::observe_event`).
+    #[must_use]
+    pub fn reputations(&self) -> &ReputationStore {
+        &self.reputations
+    }
+
+    /// Records an observation of the subject's reputation at `timestamp`.
+    ///
+    /// Use this to seed reputations known before the simulation starts.
+    /// Relationships formed from `timestamp` on have their trustworthiness
+    /// priors recomputed (see `add_relationship`).
+    pub fn observe_reputation(
+        &mut self,
+        observer: &EntityId,
+        subject: &EntityId,
+        delta: &ReputationDelta,
+        source: MemorySource,
+        timestamp: Timestamp,
+    ) {
+        self.reputations
+            .observe(observer, subject, delta, source, timestamp);
+        self.refresh_reputation_priors(Some(timestamp));
+    }
+
+    /// Recomputes the reputation priors of relationships formed at or after
+    /// `since`, or of every relationship if `since` is `None`.
+    ///
+    /// Each prior is the trustor's effective view of the trustee at
+    /// formation, so evidence recorded later, in either sense, cannot reach
+    /// it.
+    fn refresh_reputation_priors(&mut self, since: Option<Timestamp>) {
+        for record in self.relationships.values_mut() {
+            let formed = record.formed_timestamp();
+            if since.is_some_and(|since| formed < since) {
+                continue;
+            }
+            let (a, b) = (record.entity_a().clone(), record.entity_b().clone());
+            for (observer, subject, direction) in
+                [(&a, &b, Direction::AToB), (&b, &a, Direction::BToA)]
+            {
+                let view = self.reputations.effective_view(observer, subject, formed);
+                record.set_reputation_prior(direction, view);
+            }
+        }
+    }
+
+    /// Returns the entities present at an event besides its source and
+    /// target: the other members of the shared microsystems the target
+    /// belonged to at `timestamp`, sorted by ID.
+    fn bystanders(&self, event: &Event, timestamp: Timestamp) -> Vec<EntityId> {
+        let Some(target) = event.target() else {
+            return Vec::new();
+        };
+        let mut bystanders: Vec<EntityId> = self
+            .microsystem_members
+            .keys()
+            .filter(|id| self.is_member_at(id, target, timestamp))
+            .flat_map(|id| self.microsystem_members_at(id, timestamp))
+            .filter(|member| member != target && Some(member) != event.source())
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .collect();
+        bystanders.sort_by(|a, b| a.as_str().cmp(b.as_str()));
+        bystanders
+    }
+
+    /// Returns the reputation the observer acts on for the subject at
+    /// `timestamp`.
+    ///
+    /// This is the observer's own view, or failing that the most confident
+    /// view among the observer's groups.
+    #[must_use]
+    pub fn reputation(
+        &self,
+        observer: &EntityId,
+        subject: &EntityId,
+        timestamp: Timestamp,
+    ) -> Option<Reputation> {
+        self.reputations
+            .effective_view(observer, subject, timestamp)
+    }
+
+    /// Adds an entity to a group for group-level reputation aggregates.
+    pub fn add_group_member(&mut self, group: GroupId, member: EntityId) {
+        self.reputations.add_group_member(group, member);
+        self.refresh_reputation_priors(None);
+    }
+
+    /// Returns a group's aggregate view of the subject's reputation at
+    /// `timestamp`.
+    #[must_use]
+    pub fn group_reputation(
+        &self,
+        group: &GroupId,
+        subject: &EntityId,
+        timestamp: Timestamp,
+    ) -> Option<Reputation> {
+        self.reputations.group_view(group, subject, timestamp)
+    }
+
+    /// Runs one round of gossip along relationships formed by `timestamp`.
+    ///
+    /// Each partner tells the other every view they hold at `timestamp`
+    /// with at least `GOSSIP_MIN_CONFIDENCE` (see
+    /// `ReputationStore::hear_from`). The listener discounts what they hear
+    /// by their perception of the teller's integrity at `timestamp`.
+    /// Strangers and estranged pairs do not gossip. A rumor travels one
+    /// relationship per round, and rounds at the same timestamp count as
+    /// one.
+    pub fn spread_gossip(&mut self, timestamp: Timestamp) {
+        let mut conversations: Vec<(EntityId, EntityId, f32)> = Vec::new();
+        for record in self.relationships.values() {
+            let Some(relationship) = record.relationship_at(timestamp) else {
+                continue;
+            };
+            if matches!(
+                relationship.stage(),
+                RelationshipStage::Stranger | RelationshipStage::Estranged
+            ) {
+                continue;
+            }
+            let (a, b) = relationship.entities();
+            // Direction is the listener's perception of the teller.
+            for (listener, teller, direction) in [(a, b, Direction::AToB), (b, a, Direction::BToA)]
+            {
+                let integrity = relationship
+                    .trustworthiness(direction)
+                    .integrity_effective();
+                conversations.push((listener.clone(), teller.clone(), integrity));
+            }
+        }
+
+        // Record in a fixed order so rounds are reproducible.
+        conversations
+            .sort_by(|x, y| (x.0.as_str(), x.1.as_str()).cmp(&(y.0.as_str(), y.1.as_str())));
+        for (listener, teller, integrity) in conversations {
+            self.reputations
+                .hear_from(&listener, &teller, integrity, timestamp);
+        }
+        self.refresh_reputation_priors(Some(timestamp));
+    }
+}
+
+/// Returns the entities whose projected state an event can change, or
+/// None if it can change everyone's.
+///
+/// An event reaches its target. A policy change also reaches every entity
+/// in its jurisdiction.
+fn event_reach(event: &Event) -> Option<Vec<EntityId>> {
+    let mut reached: Vec<EntityId> = event.target().into_iter().cloned().collect();
+    if let EventPayload::PolicyChange { jurisdiction, .. } = event.payload() {
+        match jurisdiction {
+            PolicyJurisdiction::Target => {}
+            PolicyJurisdiction::Entities(entities) => reached.extend(entities.iter().cloned()),
+            PolicyJurisdiction::Global => return None,
+        }
+    }
+    Some(reached)
 }
 
 #[cfg(test)]
@@ -516,6 +1892,7 @@ mod tests {
     use crate::entity::EntityBuilder;
     use crate::enums::{Direction, EventType, Species};
     use crate::event::EventBuilder;
+    use crate::relationship::{TRANSITIVE_TRUST_CONTEXT, VOUCHING_CONTEXT};
     use crate::types::Duration;
 
     fn create_simulation() -> Simulation {
@@ -617,6 +1994,171 @@ mod tests {
         assert!(!history.is_empty());
     }
 
+    #[test]
+    fn childhood_friends_drift_apart_without_contact() {
+        use crate::enums::BondType;
+        use crate::relationship::{RelationshipStage, StageTransitionCause};
+
+        let mut sim = create_simulation();
+        let alice = EntityId::new("alice").unwrap();
+        let bob = EntityId::new("bob").unwrap();
+        let formed = sim.reference_date();
+        let rel_id =
+            sim.add_relationship(alice.clone(), bob.clone(), RelationshipSchema::Peer, formed);
+        {
+            let rel = sim
+                .get_relationship_mut(&rel_id)
+                .unwrap()
+                .relationship_mut();
+            rel.add_bond(BondType::Friend);
+            rel.shared_mut().intimacy_mut().set_base(0.9);
+            rel.shared_mut().affinity_mut().set_base(0.9);
+            rel.shared_mut().history_mut().set_base(0.9);
+            rel.directional_mut(Direction::AToB)
+                .attachment_mut()
+                .set_base(0.8);
+            rel.pattern_mut().frequency = 0.9;
+            rel.set_stage(RelationshipStage::Intimate).unwrap();
+        }
+
+        // Forty years without contact.
+        let rel = sim
+            .relationship_at(&rel_id, formed + Duration::days(40 * 365))
+            .unwrap();
+        assert!(rel.shared().intimacy_effective() < 0.05);
+        // Shared history keeps them acquainted even after the closeness fades.
+        assert_eq!(rel.stage(), RelationshipStage::Acquaintance);
+        assert_eq!(rel.stage_transitions().len(), 2);
+        assert!(rel
+            .stage_transitions()
+            .iter()
+            .all(|t| t.cause() == StageTransitionCause::Neglect));
+    }
+
+    #[test]
+    fn neglect_is_slower_for_family_and_attachment_lingers() {
+        use crate::enums::BondType;
+
+        let mut sim = create_simulation();
+        let formed = sim.reference_date();
+        let friend_id = sim.add_relationship(
+            EntityId::new("alice").unwrap(),
+            EntityId::new("bob").unwrap(),
+            RelationshipSchema::Peer,
+            formed,
+        );
+        let sibling_id = sim.add_relationship(
+            EntityId::new("alice").unwrap(),
+            EntityId::new("carol").unwrap(),
+            RelationshipSchema::Family,
+            formed,
+        );
+        for (id, bond) in [
+            (&friend_id, BondType::Friend),
+            (&sibling_id, BondType::Sibling),
+        ] {
+            let rel = sim.get_relationship_mut(id).unwrap().relationship_mut();
+            rel.add_bond(bond);
+            rel.shared_mut().intimacy_mut().set_base(0.8);
+            let directional = rel.directional_mut(Direction::AToB);
+            directional.warmth_mut().set_base(0.8);
+            directional.attachment_mut().set_base(0.8);
+        }
+
+        let later = formed + Duration::days(5 * 365);
+        let friend = sim.relationship_at(&friend_id, later).unwrap();
+        let sibling = sim.relationship_at(&sibling_id, later).unwrap();
+        assert!(sibling.shared().intimacy_effective() > friend.shared().intimacy_effective());
+        let friend_view = friend.directional(Direction::AToB);
+        assert!(friend_view.attachment_effective() > friend_view.warmth_effective());
+    }
+
+    #[test]
+    fn recent_contact_defers_neglect() {
+        let mut sim = create_simulation();
+        let alice = EntityId::new("alice").unwrap();
+        let bob = EntityId::new("bob").unwrap();
+        let formed = sim.reference_date();
+        let rel_id =
+            sim.add_relationship(alice.clone(), bob.clone(), RelationshipSchema::Peer, formed);
+        sim.get_relationship_mut(&rel_id)
+            .unwrap()
+            .relationship_mut()
+            .shared_mut()
+            .intimacy_mut()
+            .set_base(0.8);
+
+        let event = EventBuilder::new(EventType::Interaction)
+            .source(alice)
+            .target(bob)
+            .build()
+            .unwrap();
+        sim.add_event(event, formed + Duration::days(60));
+
+        let rel = sim
+            .relationship_at(&rel_id, formed + Duration::days(120))
+            .unwrap();
+        assert!((rel.shared().intimacy_effective() - 0.8).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn neglect_does_not_depend_on_event_insertion_order() {
+        let alice = EntityId::new("alice").unwrap();
+        let bob = EntityId::new("bob").unwrap();
+        let carol = EntityId::new("carol").unwrap();
+        let interaction = |day: u64| {
+            let event = EventBuilder::new(EventType::Interaction)
+                .source(alice.clone())
+                .target(bob.clone())
+                .build()
+                .unwrap();
+            (event, day)
+        };
+        let unrelated = EventBuilder::new(EventType::Support)
+            .source(carol.clone())
+            .target(alice.clone())
+            .build()
+            .unwrap();
+
+        let simulate = |unrelated_first: bool| {
+            let mut sim = create_simulation();
+            let formed = sim.reference_date();
+            let rel_id =
+                sim.add_relationship(alice.clone(), bob.clone(), RelationshipSchema::Peer, formed);
+            sim.get_relationship_mut(&rel_id)
+                .unwrap()
+                .relationship_mut()
+                .shared_mut()
+                .intimacy_mut()
+                .set_base(0.8);
+            let year_ten = formed + Duration::days(10 * 365);
+            if unrelated_first {
+                sim.add_event(unrelated.clone(), year_ten);
+            }
+            for (event, day) in [interaction(400), interaction(1200)] {
+                sim.add_event(event, formed + Duration::days(day));
+            }
+            if !unrelated_first {
+                sim.add_event(unrelated.clone(), year_ten);
+            }
+            let early = sim
+                .relationship_at(&rel_id, formed + Duration::days(1000))
+                .unwrap();
+            let late = sim.relationship_at(&rel_id, year_ten).unwrap();
+            (
+                early.shared().intimacy_effective(),
+                late.shared().intimacy_effective(),
+            )
+        };
+
+        let chronological = simulate(false);
+        let unrelated_first = simulate(true);
+        assert!((chronological.0 - unrelated_first.0).abs() < f32::EPSILON);
+        assert!((chronological.1 - unrelated_first.1).abs() < f32::EPSILON);
+        // Neglect by day 1000 does not include the years after it.
+        assert!(chronological.0 > chronological.1);
+    }
+
     #[test]
     fn simulation_add_event_before_relationship_formed_is_ignored() {
         let mut sim = create_simulation();
@@ -644,6 +2186,83 @@ mod tests {
         assert!(history.is_empty());
     }
 
+    #[test]
+    fn repair_weighs_the_target_agreeableness_at_the_event() {
+        use crate::state::Hexaco;
+
+        let anchor = Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+        let repaired = anchor + Duration::years(30);
+        let alice = EntityId::new("alice").unwrap();
+        let bob = EntityId::new("bob").unwrap();
+        let apology_magnitude = |sim: &mut Simulation| {
+            let rel_id = sim.add_relationship(
+                alice.clone(),
+                bob.clone(),
+                RelationshipSchema::Peer,
+                repaired - Duration::days(30),
+            );
+            for (event_type, ts) in [
+                (EventType::Betrayal, repaired - Duration::days(7)),
+                (EventType::Apology, repaired),
+            ] {
+                let event = EventBuilder::new(event_type)
+                    .source(bob.clone())
+                    .target(alice.clone())
+                    .severity(0.8)
+                    .build()
+                    .unwrap();
+                sim.add_event(event, ts);
+            }
+            sim.get_relationship(&rel_id)
+                .unwrap()
+                .relationship()
+                .antecedent_history(Direction::AToB)
+                .last()
+                .unwrap()
+                .magnitude()
+        };
+
+        let mut aging = Simulation::new(anchor);
+        let mut young = EntityBuilder::new()
+            .id("alice")
+            .species(Species::Human)
+            .birth_date(anchor - Duration::years(18))
+            .build()
+            .unwrap();
+        let maturing = young
+            .individual_state()
+            .config()
+            .clone()
+            .with_normative_maturation(true);
+        *young.individual_state_mut().config_mut() = maturing;
+        let anchored_agreeableness = young.individual_state().hexaco().agreeableness();
+        aging.add_entity(young, anchor);
+        aging.add_entity(create_human("bob"), anchor);
+        let matured = aging
+            .entity(&alice)
+            .unwrap()
+            .state_at(repaired)
+            .individual_state()
+            .hexaco()
+            .agreeableness();
+        assert!((matured - anchored_agreeableness).abs() > 0.01);
+
+        let mut anchored_at_repair = Simulation::new(anchor);
+        let grown = EntityBuilder::new()
+            .id("alice")
+            .species(Species::Human)
+            .birth_date(anchor - Duration::years(18))
+            .hexaco(Hexaco::new().with_agreeableness(matured))
+            .build()
+            .unwrap();
+        anchored_at_repair.add_entity(grown, repaired);
+        anchored_at_repair.add_entity(create_human("bob"), anchor);
+
+        let aged = apology_magnitude(&mut aging);
+        let grown = apology_magnitude(&mut anchored_at_repair);
+        assert!((aged - grown).abs() < 1e-6);
+    }
+
     #[test]
     fn simulation_add_relationship_respects_macrosystem_constraints() {
         let mut sim = create_simulation();
@@ -1055,4 +2674,828 @@ mod tests {
         set.insert(RegressionQuality::Approximate);
         assert_eq!(set.len(), 2);
     }
+
+    #[test]
+    fn gossip_spreads_reputation_one_relationship_per_round() {
+        let mut sim = create_simulation();
+        let alice = EntityId::new("alice").unwrap();
+        let bob = EntityId::new("bob").unwrap();
+        let carol = EntityId::new("carol").unwrap();
+        let mallory = EntityId::new("mallory").unwrap();
+        let formed = sim.reference_date();
+
+        for (teller, listener) in [(&alice, &bob), (&bob, &carol)] {
+            let id = sim.add_relationship(
+                teller.clone(),
+                listener.clone(),
+                RelationshipSchema::Peer,
+                formed,
+            );
+            sim.get_relationship_mut(&id)
+                .unwrap()
+                .relationship_mut()
+                .set_stage(RelationshipStage::Established)
+                .unwrap();
+        }
+
+        let betrayal = EventBuilder::new(EventType::Betrayal)
+            .source(mallory.clone())
+            .target(alice.clone())
+            .severity(1.0)
+            .build()
+            .unwrap();
+        let when = Timestamp::from_ymd_hms(2024, 2, 1, 0, 0, 0);
+        sim.add_event(betrayal, when);
+        assert!(sim.reputation(&alice, &mallory, when).unwrap().trusted() < 0.0);
+
+        sim.spread_gossip(when);
+        let heard = sim.reputation(&bob, &mallory, when).unwrap();
+        assert!(heard.trusted() < 0.0);
+        assert!(heard.confidence() < sim.reputation(&alice, &mallory, when).unwrap().confidence());
+        assert!(sim.reputation(&carol, &mallory, when).is_none());
+
+        // A second round at the same timestamp is the same round
+        sim.spread_gossip(when);
+        assert!(sim.reputation(&carol, &mallory, when).is_none());
+
+        let later = when + Duration::days(1);
+        sim.spread_gossip(later);
+        let secondhand = sim.reputation(&carol, &mallory, later).unwrap();
+        assert!(secondhand.confidence() < heard.confidence());
+        assert!(sim.reputation(&carol, &mallory, when).is_none());
+    }
+
+    #[test]
+    fn reputation_seeds_stranger_trustworthiness() {
+        let mut sim = create_simulation();
+        let alice = EntityId::new("alice").unwrap();
+        let mallory = EntityId::new("mallory").unwrap();
+
+        let betrayal = EventBuilder::new(EventType::Betrayal)
+            .source(mallory.clone())
+            .target(alice.clone())
+            .severity(1.0)
+            .tag(crate::enums::EventTag::Witnessed)
+            .build()
+            .unwrap();
+        sim.add_event(betrayal, sim.reference_date());
+
+        let id = sim.add_relationship(
+            alice.clone(),
+            mallory.clone(),
+            RelationshipSchema::Peer,
+            Timestamp::from_ymd_hms(2024, 3, 1, 0, 0, 0),
+        );
+        let relationship = sim.get_relationship(&id).unwrap().relationship();
+        let wary = relationship.trustworthiness(Direction::AToB);
+        let unaware = relationship.trustworthiness(Direction::BToA);
+        assert!(wary.integrity_effective() < unaware.integrity_effective());
+        assert!(wary.benevolence_effective() < unaware.benevolence_effective());
+    }
+
+    #[test]
+    fn later_betrayal_leaves_earlier_prior_and_decision_alone() {
+        use crate::decision::ActionOption;
+        use crate::enums::TrustDomain;
+        use crate::relationship::StakesLevel;
+
+        let mut sim = create_simulation();
+        sim.add_entity(create_human("alice"), sim.reference_date());
+        let alice = EntityId::new("alice").unwrap();
+        let mallory = EntityId::new("mallory").unwrap();
+        sim.observe_reputation(
+            &alice,
+            &mallory,
+            &ReputationDelta::new().with_trusted(0.4),
+            MemorySource::Rumor,
+            sim.reference_date(),
+        );
+
+        let formed = Timestamp::from_ymd_hms(2024, 3, 1, 0, 0, 0);
+        let id = sim.add_relationship(
+            alice.clone(),
+            mallory.clone(),
+            RelationshipSchema::Peer,
+            formed,
+        );
+        let decided_at = Timestamp::from_ymd_hms(2024, 2, 1, 0, 0, 0);
+        let options = [
+            ActionOption::new("rest").with_valence(0.2),
+            ActionOption::new("confide").with_counterpart(
+                mallory.clone(),
+                TrustDomain::Disclosure,
+                StakesLevel::Medium,
+            ),
+        ];
+        let decide = |sim: &Simulation| sim.decide(&alice, decided_at, &options, 0.25).unwrap();
+        let record = sim.get_relationship(&id).unwrap();
+        let prior = *record.reputation_prior(Direction::AToB).unwrap();
+        let perceived = record
+            .relationship_at(formed)
+            .unwrap()
+            .trustworthiness(Direction::AToB)
+            .clone();
+        let decision = decide(&sim);
+
+        let betrayal = EventBuilder::new(EventType::Betrayal)
+            .source(mallory.clone())
+            .target(alice.clone())
+            .severity(1.0)
+            .build()
+            .unwrap();
+        let betrayed_at = Timestamp::from_ymd_hms(2024, 4, 1, 0, 0, 0);
+        sim.add_event(betrayal, betrayed_at);
+
+        assert!(
+            sim.reputation(&alice, &mallory, betrayed_at)
+                .unwrap()
+                .trusted()
+                < 0.0
+        );
+        let record = sim.get_relationship(&id).unwrap();
+        assert_eq!(record.reputation_prior(Direction::AToB), Some(&prior));
+        assert_eq!(
+            record
+                .relationship_at(formed)
+                .unwrap()
+                .trustworthiness(Direction::AToB),
+            &perceived
+        );
+        assert_eq!(decide(&sim), decision);
+    }
+
+    #[test]
+    fn reputation_prior_does_not_depend_on_add_order() {
+        let alice = EntityId::new("alice").unwrap();
+        let mallory = EntityId::new("mallory").unwrap();
+        let formed = Timestamp::from_ymd_hms(2024, 3, 1, 0, 0, 0);
+        let observe = |sim: &mut Simulation| {
+            sim.observe_reputation(
+                &alice,
+                &mallory,
+                &ReputationDelta::new().with_trusted(-0.6),
+                MemorySource::Witness,
+                sim.reference_date(),
+            );
+        };
+        let relate = |sim: &mut Simulation| {
+            sim.add_relationship(
+                alice.clone(),
+                mallory.clone(),
+                RelationshipSchema::Peer,
+                formed,
+            )
+        };
+
+        let mut seen_first = create_simulation();
+        observe(&mut seen_first);
+        let seen_first_id = relate(&mut seen_first);
+        let mut related_first = create_simulation();
+        let related_first_id = relate(&mut related_first);
+        observe(&mut related_first);
+
+        let perceptions = |sim: &Simulation, id: &RelationshipId| {
+            sim.get_relationship(id)
+                .unwrap()
+                .relationship()
+                .trustworthiness(Direction::AToB)
+                .clone()
+        };
+        let wary = perceptions(&seen_first, &seen_first_id);
+        assert!(wary.integrity_effective() < TrustworthinessFactors::new().integrity_effective());
+        let reordered = perceptions(&related_first, &related_first_id);
+        assert!((reordered.integrity_effective() - wary.integrity_effective()).abs() < 1e-6);
+        assert!((reordered.benevolence_effective() - wary.benevolence_effective()).abs() < 1e-6);
+    }
+
+    #[test]
+    fn coworkers_witness_a_betrayal_in_their_office() {
+        use crate::context::{Microsystem, WorkContext};
+
+        let mut sim = create_simulation();
+        let victim = EntityId::new("victim").unwrap();
+        let coworker = EntityId::new("coworker").unwrap();
+        let culprit = EntityId::new("culprit").unwrap();
+        let office = MicrosystemId::new("office").unwrap();
+        sim.add_microsystem(
+            office.clone(),
+            Microsystem::new_work(WorkContext::default()),
+        );
+        for member in [&victim, &coworker, &culprit] {
+            sim.join_microsystem(&office, member.clone(), sim.reference_date());
+        }
+        // Hired after the betrayal, so not there to see it
+        let newcomer = EntityId::new("newcomer").unwrap();
+        sim.join_microsystem(
+            &office,
+            newcomer.clone(),
+            sim.reference_date() + Duration::days(1),
+        );
+
+        let betrayal = EventBuilder::new(EventType::Betrayal)
+            .source(culprit.clone())
+            .target(victim.clone())
+            .severity(1.0)
+            .build()
+            .unwrap();
+        sim.add_event(betrayal, sim.reference_date());
+
+        let now = sim.reference_date() + Duration::days(2);
+        let coworker_view = sim.reputation(&coworker, &culprit, now).unwrap();
+        assert!(coworker_view.trusted() < 0.0);
+        assert!(sim.reputation(&newcomer, &culprit, now).is_none());
+        let victim_view = sim.reputation(&victim, &culprit, now).unwrap();
+        assert!(victim_view.confidence() > coworker_view.confidence());
+        assert!(sim
+            .reputation(&EntityId::new("outsider").unwrap(), &culprit, now)
+            .is_none());
+    }
+
+    fn befriend(
+        sim: &mut Simulation,
+        a: &EntityId,
+        b: &EntityId,
+        stage: RelationshipStage,
+        a_trusts_b: f32,
+    ) {
+        let id = sim.add_relationship(
+            a.clone(),
+            b.clone(),
+            RelationshipSchema::Peer,
+            sim.reference_date(),
+        );
+        let relationship = sim.get_relationship_mut(&id).unwrap().relationship_mut();
+        relationship.set_stage(stage).unwrap();
+        *relationship.trustworthiness_mut(Direction::AToB) =
+            TrustworthinessFactors::with_bases(a_trusts_b, a_trusts_b, a_trusts_b);
+    }
+
+    fn newcomer_trust(transitive: bool) -> (f32, Vec<String>) {
+        let mut sim = create_simulation();
+        let mut trustor = create_human("trustor");
+        trustor
+            .individual_state_mut()
+            .config_mut()
+            .set_transitive_trust_enabled(transitive);
+        sim.add_entity(trustor, sim.reference_date());
+
+        let trustor = EntityId::new("trustor").unwrap();
+        let friend = EntityId::new("friend").unwrap();
+        let newcomer = EntityId::new("newcomer").unwrap();
+        befriend(
+            &mut sim,
+            &trustor,
+            &friend,
+            RelationshipStage::Intimate,
+            0.9,
+        );
+        befriend(
+            &mut sim,
+            &friend,
+            &newcomer,
+            RelationshipStage::Established,
+            0.8,
+        );
+
+        let id = sim.add_relationship(
+            trustor,
+            newcomer,
+            RelationshipSchema::Peer,
+            Timestamp::from_ymd_hms(2024, 2, 1, 0, 0, 0),
+        );
+        let relationship = sim.get_relationship(&id).unwrap().relationship();
+        let contexts = relationship
+            .antecedent_history(Direction::AToB)
+            .iter()
+            .map(|antecedent| antecedent.context().to_string())
+            .collect();
+        (
+            relationship.trustworthiness(Direction::AToB).overall(),
+            contexts,
+        )
+    }
+
+    #[test]
+    fn trusted_mutual_friend_lends_trust_to_newcomer() {
+        let (default_trust, default_history) = newcomer_trust(false);
+        let (inherited_trust, history) = newcomer_trust(true);
+
+        assert!(default_history.is_empty());
+        assert!(inherited_trust > default_trust);
+        assert!(history.iter().any(|c| c == TRANSITIVE_TRUST_CONTEXT));
+        assert!(history.iter().any(|c| c == VOUCHING_CONTEXT));
+    }
+
+    #[test]
+    fn trust_calibration_flags_over_trust_in_exploitative_trustee() {
+        use crate::relationship::CalibrationAssessment;
+        use crate::state::Hexaco;
+
+        let mut sim = create_simulation();
+        let mut con_artist = create_human("con_artist");
+        *con_artist.individual_state_mut().hexaco_mut() = Hexaco::new()
+            .with_honesty_humility(-0.9)
+            .with_agreeableness(-0.6);
+        sim.add_entity(con_artist, sim.reference_date());
+
+        let victim = EntityId::new("victim").unwrap();
+        let con_artist = EntityId::new("con_artist").unwrap();
+        let id = sim.add_relationship(
+            victim,
+            con_artist.clone(),
+            RelationshipSchema::Peer,
+            sim.reference_date(),
+        );
+        *sim.get_relationship_mut(&id)
+            .unwrap()
+            .relationship_mut()
+            .trustworthiness_mut(Direction::AToB) =
+            TrustworthinessFactors::with_bases(0.7, 0.8, 0.8);
+
+        let ts = Timestamp::from_ymd_hms(2024, 3, 1, 0, 0, 0);
+        let calibration = sim.trust_calibration(&id, Direction::AToB, ts).unwrap();
+        assert_eq!(calibration.assessment(), CalibrationAssessment::OverTrust);
+        assert!(calibration.exposes_to_exploitation());
+
+        // The victim is not in the simulation, so the reverse has no ground truth
+        assert!(sim.trust_calibration(&id, Direction::BToA, ts).is_none());
+        assert!(
+            sim.actual_trustworthiness(&con_artist, ts)
+                .unwrap()
+                .integrity()
+                < 0.2
+        );
+    }
+
+    #[test]
+    fn trust_calibration_uses_perceptions_at_the_timestamp() {
+        use crate::state::Hexaco;
+
+        let mut sim = create_simulation();
+        let mut con_artist = create_human("con_artist");
+        *con_artist.individual_state_mut().hexaco_mut() = Hexaco::new().with_honesty_humility(-0.9);
+        sim.add_entity(con_artist, sim.reference_date());
+
+        let victim = EntityId::new("victim").unwrap();
+        let con_artist = EntityId::new("con_artist").unwrap();
+        let formed = sim.reference_date();
+        let id = sim.add_relationship(
+            victim.clone(),
+            con_artist.clone(),
+            RelationshipSchema::Peer,
+            formed,
+        );
+        *sim.get_relationship_mut(&id)
+            .unwrap()
+            .relationship_mut()
+            .trustworthiness_mut(Direction::AToB) =
+            TrustworthinessFactors::with_bases(0.7, 0.8, 0.8);
+
+        let early = formed + Duration::days(30);
+        let before = sim.trust_calibration(&id, Direction::AToB, early).unwrap();
+
+        let betrayal = EventBuilder::new(EventType::Betrayal)
+            .source(con_artist)
+            .target(victim)
+            .severity(0.9)
+            .build()
+            .unwrap();
+        let betrayed_at = formed + Duration::days(200);
+        sim.add_event(betrayal, betrayed_at);
+
+        let unchanged = sim.trust_calibration(&id, Direction::AToB, early).unwrap();
+        assert_eq!(unchanged, before);
+        let after = sim
+            .trust_calibration(&id, Direction::AToB, betrayed_at)
+            .unwrap();
+        assert!(after.integrity_error() < before.integrity_error());
+        assert!(sim
+            .trust_calibration(&id, Direction::AToB, formed - Duration::days(1))
+            .is_none());
+    }
+
+    #[test]
+    fn decide_prefers_confiding_in_trusted_friend_over_stranger() {
+        use crate::decision::ActionOption;
+        use crate::enums::TrustDomain;
+        use crate::relationship::StakesLevel;
+
+        let mut sim = create_simulation();
+        sim.add_entity(create_human("alice"), sim.reference_date());
+        let alice = EntityId::new("alice").unwrap();
+        let friend = EntityId::new("friend").unwrap();
+        let stranger = EntityId::new("stranger").unwrap();
+
+        let id = sim.add_relationship(
+            friend.clone(),
+            alice.clone(),
+            RelationshipSchema::Peer,
+            sim.reference_date(),
+        );
+        *sim.get_relationship_mut(&id)
+            .unwrap()
+            .relationship_mut()
+            .trustworthiness_mut(Direction::BToA) =
+            TrustworthinessFactors::with_bases(0.9, 0.9, 0.9);
+
+        let options = [
+            ActionOption::new("confide_in_stranger").with_counterpart(
+                stranger,
+                TrustDomain::Disclosure,
+                StakesLevel::Medium,
+            ),
+            ActionOption::new("confide_in_friend").with_counterpart(
+                friend,
+                TrustDomain::Disclosure,
+                StakesLevel::Medium,
+            ),
+        ];
+        let decision = sim
+            .decide(&alice, sim.reference_date(), &options, 0.25)
+            .unwrap();
+
+        let best = decision.best().unwrap();
+        assert_eq!(best.option_id(), "confide_in_friend");
+        assert!(best.probability() > 0.5);
+    }
+
+    #[test]
+    fn decide_requires_known_entity_with_subsystem() {
+        use crate::decision::{ActionOption, DecisionError};
+
+        let mut sim = create_simulation();
+        let mut passive = create_human("passive");
+        passive
+            .individual_state_mut()
+            .config_mut()
+            .deactivate_subsystem(SubsystemId::BehavioralDecision);
+        sim.add_entity(passive, sim.reference_date());
+
+        let options = [ActionOption::new("wait")];
+        let ts = sim.reference_date();
+        let unknown = EntityId::new("unknown").unwrap();
+        let passive = EntityId::new("passive").unwrap();
+
+        assert_eq!(
+            sim.decide(&unknown, ts, &options, 0.25),
+            Err(DecisionError::UnknownEntity(unknown.clone()))
+        );
+        assert_eq!(
+            sim.decide(&passive, ts, &options, 0.25),
+            Err(DecisionError::SubsystemInactive(passive.clone()))
+        );
+    }
+
+    #[test]
+    fn shared_microsystem_edits_reach_all_members() {
+        use crate::context::{Microsystem, WorkContext};
+        use crate::enums::{ContextPath, MicrosystemPath, WorkPath};
+
+        let mut sim = create_simulation();
+        let anchor = sim.reference_date();
+        for id in ["ana", "ben", "outsider"] {
+            sim.add_entity(create_human(id), anchor);
+        }
+        let ana = EntityId::new("ana").unwrap();
+        let ben = EntityId::new("ben").unwrap();
+        let office = MicrosystemId::new("office").unwrap();
+
+        let mut work = WorkContext {
+            peer_ids: vec![ana.clone()],
+            ..Default::default()
+        };
+        work.interaction_profile.interaction_frequency = 0.8;
+        work.interaction_profile.interaction_complexity = 0.8;
+        sim.add_microsystem(office.clone(), Microsystem::new_work(work));
+        assert!(sim.join_microsystem(&office, ben.clone(), anchor));
+        assert!(!sim.join_microsystem(
+            &MicrosystemId::new("nowhere").unwrap(),
+            ben.clone(),
+            anchor
+        ));
+        assert_eq!(
+            sim.microsystem_members(&office),
+            vec![ana.clone(), ben.clone()]
+        );
+
+        // New leadership makes the office stressful for everyone in it
+        sim.microsystem_mut(&office)
+            .unwrap()
+            .work_mut()
+            .unwrap()
+            .workload_stress = 0.8;
+
+        let query = Timestamp::from_ymd_hms(2024, 3, 1, 0, 0, 0);
+        let workload = ContextPath::Microsystem(
+            office.clone(),
+            MicrosystemPath::Work(WorkPath::WorkloadStress),
+        );
+        let stress = |id: &EntityId| {
+            sim.entity(id)
+                .unwrap()
+                .state_at(query)
+                .individual_state()
+                .needs()
+                .stress_effective()
+        };
+        for member in [&ana, &ben] {
+            let context = sim.entity(member).unwrap().context_at(query).unwrap();
+            assert!((context.get(&workload).unwrap() - 0.8).abs() < 1e-9);
+        }
+        let outsider = EntityId::new("outsider").unwrap();
+        assert!(stress(&ana) > stress(&outsider));
+        assert!((stress(&ana) - stress(&ben)).abs() < 1e-6);
+
+        assert!(sim.leave_microsystem(&office, &ben, query - Duration::days(1)));
+        assert!(sim
+            .entity(&ben)
+            .unwrap()
+            .context_at(query)
+            .unwrap()
+            .is_empty());
+        assert!(!sim
+            .entity(&ben)
+            .unwrap()
+            .context_at(query - Duration::days(2))
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn shared_microsystem_is_absent_before_the_join() {
+        use crate::context::{Microsystem, WorkContext};
+
+        let mut sim = create_simulation();
+        let anchor = sim.reference_date();
+        let hire = sim.add_entity(create_human("hire"), anchor);
+        let office = MicrosystemId::new("office").unwrap();
+        sim.add_microsystem(
+            office.clone(),
+            Microsystem::new_work(WorkContext {
+                workload_stress: 0.9,
+                ..Default::default()
+            }),
+        );
+        let hired = Timestamp::from_ymd_hms(2024, 3, 1, 0, 0, 0);
+        sim.join_microsystem(&office, hire.clone(), hired);
+
+        let handle = sim.entity(&hire).unwrap();
+        let before = hired - Duration::days(1);
+        assert!(handle.context_at(before).unwrap().is_empty());
+        assert!(handle
+            .context_at(hired)
+            .unwrap()
+            .get_microsystem(&office)
+            .is_some());
+        assert!(sim.microsystem_members_at(&office, before).is_empty());
+        assert_eq!(sim.microsystem_members_at(&office, hired), vec![hire]);
+    }
+
+    #[test]
+    fn dispatch_event_reaches_each_member_of_scope() {
+        use crate::context::{Microsystem, SocialContext};
+
+        let mut sim = create_simulation();
+        let anchor = sim.reference_date();
+        for id in ["a", "b", "c"] {
+            sim.add_entity(create_human(id), anchor);
+        }
+        let club = MicrosystemId::new("club").unwrap();
+        sim.add_microsystem(
+            club.clone(),
+            Microsystem::new_social(SocialContext::default()),
+        );
+        sim.join_microsystem(&club, EntityId::new("b").unwrap(), anchor);
+        sim.join_microsystem(&club, EntityId::new("a").unwrap(), anchor);
+        sim.join_microsystem(
+            &club,
+            EntityId::new("c").unwrap(),
+            anchor + Duration::days(1),
+        );
+
+        let event = EventBuilder::new(EventType::Humiliation)
+            .severity(0.5)
+            .build()
+            .unwrap();
+        sim.dispatch_event(event, &EventScope::Microsystem(club), anchor);
+
+        let targets: Vec<&str> = sim
+            .all_events()
+            .filter_map(|te| te.event().target().map(EntityId::as_str))
+            .collect();
+        assert_eq!(targets, vec!["a", "b"]);
+        assert_eq!(sim.resolve_scope(&EventScope::Global).len(), 3);
+    }
+
+    #[test]
+    fn emergent_climate_follows_member_states() {
+        use crate::context::{Microsystem, WorkContext};
+
+        let mut sim = create_simulation();
+        let anchor = sim.reference_date();
+        let boss = EntityId::new("boss").unwrap();
+        let clerk = EntityId::new("clerk").unwrap();
+
+        let mut stressed_boss = create_human("boss");
+        let boss_state = stressed_boss.individual_state_mut();
+        boss_state.needs_mut().stress_mut().set_base(0.9);
+        boss_state.disposition_mut().aggression_mut().set_base(0.8);
+        sim.add_entity(stressed_boss, anchor);
+        sim.add_entity(create_human("clerk"), anchor);
+
+        let office = MicrosystemId::new("office").unwrap();
+        let mut work = WorkContext {
+            supervisor_id: Some(boss.clone()),
+            peer_ids: vec![clerk.clone()],
+            ..Default::default()
+        };
+        work.interaction_profile.interaction_frequency = 0.8;
+        work.interaction_profile.interaction_complexity = 0.8;
+        sim.add_microsystem(office.clone(), Microsystem::new_work(work));
+        let id = sim.add_relationship(
+            boss.clone(),
+            clerk.clone(),
+            RelationshipSchema::Peer,
+            anchor,
+        );
+        sim.get_relationship_mut(&id)
+            .unwrap()
+            .relationship_mut()
+            .directional_mut(Direction::AToB)
+            .add_warmth_delta(0.6);
+
+        let query = Timestamp::from_ymd_hms(2024, 2, 1, 0, 0, 0);
+        let clerk_stress = |sim: &Simulation| {
+            sim.entity(&clerk)
+                .unwrap()
+                .state_at(query)
+                .individual_state()
+                .needs()
+                .stress_effective()
+        };
+        let authored_stress = clerk_stress(&sim);
+
+        assert!(!sim.set_emergent_climate(&MicrosystemId::new("none").unwrap(), true));
+        assert!(sim.set_emergent_climate(&office, true));
+        assert!(sim.emergent_climate_enabled(&office));
+
+        let authored = sim.microsystem(&office).unwrap().clone();
+        let emergent = sim.microsystem_at(&office, query).unwrap();
+        assert!(emergent.stress_level() > 0.8);
+        assert!(emergent.hostility() > authored.hostility());
+        // Mean of the boss's warmth (0.8) and the clerk's default warmth (0.2)
+        assert!((emergent.warmth() - 0.5).abs() < 1e-3);
+        assert!(clerk_stress(&sim) > authored_stress);
+
+        sim.set_emergent_climate(&office, false);
+        assert_eq!(sim.microsystem_at(&office, query).unwrap(), authored);
+    }
+
+    #[test]
+    fn historical_event_reaches_region_weighted_by_age() {
+        use crate::context::{Microsystem, NeighborhoodContext};
+        use crate::enums::HistoricalEventType;
+
+        let mut sim = create_simulation();
+        let anchor = sim.reference_date();
+        let born = |id: &str, year: i32| {
+            EntityBuilder::new()
+                .id(id)
+                .species(Species::Human)
+                .birth_date(Timestamp::from_ymd_hms(year, 1, 1, 0, 0, 0))
+                .build()
+                .unwrap()
+        };
+        sim.add_entity(born("child", 2019), anchor);
+        sim.add_entity(born("adult", 1974), anchor);
+        sim.add_entity(born("outsider", 1974), anchor);
+
+        let city = MicrosystemId::new("city").unwrap();
+        sim.add_microsystem(
+            city.clone(),
+            Microsystem::new_neighborhood(NeighborhoodContext::default()),
+        );
+        let child = EntityId::new("child").unwrap();
+        let adult = EntityId::new("adult").unwrap();
+        let outsider = EntityId::new("outsider").unwrap();
+        sim.join_microsystem(&city, child.clone(), anchor);
+        sim.join_microsystem(&city, adult.clone(), anchor);
+
+        let start = Timestamp::from_ymd_hms(2024, 2, 1, 0, 0, 0);
+        sim.add_historical_event(
+            SharedHistoricalEvent::new("pandemic", HistoricalEventType::Pandemic, start, 0.5)
+                .with_reach(HistoricalReach::Region(city)),
+        );
+        assert!(sim.historical_events_for(&outsider).is_empty());
+        assert_eq!(sim.historical_events_for(&child).len(), 1);
+
+        let query = start + Duration::hours(1);
+        let arousal = |id: &EntityId| {
+            sim.entity(id)
+                .unwrap()
+                .state_at(query)
+                .individual_state()
+                .mood()
+                .arousal_effective()
+        };
+        let baseline = arousal(&outsider);
+        assert!(arousal(&adult) > baseline);
+        assert!(arousal(&child) - baseline > arousal(&adult) - baseline);
+
+        let context = sim.entity(&child).unwrap().context_at(query).unwrap();
+        assert_eq!(context.chronosystem().non_normative_events().len(), 1);
+        let before = sim.entity(&child).unwrap().context_at(anchor).unwrap();
+        assert!(before.chronosystem().non_normative_events().is_empty());
+    }
+
+    #[test]
+    fn macrosystem_shift_is_memoized_until_the_simulation_changes() {
+        let mut sim = create_simulation();
+        let anchor = sim.reference_date();
+        sim.add_entity(create_human("person"), anchor);
+        sim.set_macrosystem_dynamics(Some(MacrosystemDynamics::new()));
+        let query = anchor + Duration::days(90);
+
+        let first = sim.macrosystem_shift_at(query).unwrap();
+        assert!(sim.macrosystem_cache.lock().shifts.contains_key(&query));
+        assert_eq!(sim.macrosystem_shift_at(query), Some(first));
+
+        let assault = EventBuilder::new(EventType::Violence)
+            .target(EntityId::new("person").unwrap())
+            .severity(0.9)
+            .build()
+            .unwrap();
+        sim.add_event(assault, anchor + Duration::days(30));
+        assert!(sim.macrosystem_cache.lock().shifts.is_empty());
+        assert_ne!(sim.macrosystem_shift_at(query), Some(first));
+    }
+
+    #[test]
+    fn simulation_is_send_and_sync() {
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<Simulation>();
+    }
+
+    #[test]
+    fn changes_keep_the_projections_of_unconnected_members() {
+        let mut sim = create_simulation();
+        let anchor = sim.reference_date();
+        let [a, b, c] = ["a", "b", "c"].map(|id| sim.add_entity(create_human(id), anchor));
+        sim.add_relationship(a.clone(), b.clone(), RelationshipSchema::Peer, anchor);
+        sim.set_macrosystem_dynamics(Some(MacrosystemDynamics::new()));
+        let query = anchor + Duration::days(90);
+        let projected =
+            |sim: &Simulation, id: &EntityId| sim.macrosystem_cache.lock().members.contains_key(id);
+
+        sim.macrosystem_shift_at(query).unwrap();
+        let assault = EventBuilder::new(EventType::Violence)
+            .target(a.clone())
+            .severity(0.9)
+            .build()
+            .unwrap();
+        sim.add_event(assault, anchor + Duration::days(30));
+
+        // Only a and the partner whose relationship it shares are projected again
+        assert!(!projected(&sim, &a));
+        assert!(!projected(&sim, &b));
+        assert!(projected(&sim, &c));
+        assert!(sim.macrosystem_cache.lock().shifts.is_empty());
+    }
+
+    #[test]
+    fn cultural_stress_lags_behind_member_stress() {
+        use crate::context::{ContextChange, MacrosystemContext, WorkContext};
+
+        let mut sim = create_simulation();
+        let anchor = sim.reference_date();
+        let hired = anchor + Duration::days(200);
+        for id in ["a", "b"] {
+            let mut person = create_human(id);
+            let office = Microsystem::new_work(WorkContext {
+                workload_stress: 0.9,
+                ..WorkContext::default()
+            });
+            person.context_timeline_mut().schedule(
+                hired,
+                ContextChange::AddMicrosystem(MicrosystemId::new("office").unwrap(), office),
+            );
+            sim.add_entity(person, anchor);
+        }
+        sim.set_macrosystem_dynamics(Some(MacrosystemDynamics::new()));
+
+        let base = MacrosystemContext::default();
+        let pull = |timestamp: Timestamp| {
+            let shift = sim.macrosystem_shift_at(timestamp).unwrap();
+            let members = shift.member_stress().unwrap();
+            let instant = base.cultural_stress + (members - base.cultural_stress) * 0.5;
+            let lagged = shift.apply_to(&base).cultural_stress;
+            (lagged - base.cultural_stress) / (instant - base.cultural_stress)
+        };
+
+        // Members are strained within months, the culture follows over years
+        let soon = pull(hired + Duration::days(60));
+        let later = pull(hired + Duration::years(6));
+        assert!(soon < 0.3, "soon {soon}");
+        assert!(later > 0.8, "later {later}");
+    }
 }
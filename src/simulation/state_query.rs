@@ -3,21 +3,31 @@
 //! This module provides `EntityQueryHandle` for querying entity state at
 //! any timestamp, and `ComputedState` as the result type.
 
-use crate::context::apply_context_effects;
+use crate::context::{
+    apply_context_effects, apply_context_timeline_effects, ContextChange, ContextTimeline,
+    EcologicalContext, LifeTransition, Microsystem, NormativeSchedule, NormativeTransition,
+    ParentWorkQuality,
+};
 use crate::entity::Entity;
-use crate::enums::{HexacoPath, LifeStage, StatePath};
+use crate::enums::{BirthEra, EventPayload, LifeStage, StatePath};
 use crate::memory::{apply_memory_consolidation, MemoryEntry};
 use crate::processor::{
-    advance_state, apply_developmental_effects, apply_interpreted_event_to_state, interpret_event,
-    regress_state, reverse_interpreted_event_from_state, InterpretedEvent,
+    advance_state, apply_developmental_effects, apply_interpreted_event_to_state,
+    apply_relationship_coupling, apply_social_support, derive_formative_shifts,
+    dimension_confidence, interpret_event_in_context, network_support, regress_state_with_report,
+    relationship_coupling, relationship_quality_from_support, relationship_support,
+    reverse_interpreted_event_with_report, InterpretedEvent,
+};
+use crate::simulation::{
+    RegressionMethod, RegressionQuality, RegressionReport, Simulation, TimestampedEvent,
 };
-use crate::simulation::{RegressionQuality, Simulation, TimestampedEvent};
 use crate::state::{
-    apply_formative_modifiers, effective_base_at, BaseShiftRecord, IndividualState,
-    StateInterpreter,
+    apply_baseline_modifiers, apply_normative_maturation, effective_base_at, BaseShiftRecord,
+    IndividualState, StateInterpreter,
 };
-use crate::types::{Alert, Duration, EntityId, Timestamp};
-use std::collections::HashMap;
+use crate::types::{Alert, Duration, EntityId, MicrosystemId, Timestamp};
+use std::borrow::Cow;
+use std::collections::{HashMap, HashSet};
 
 /// A handle for querying entity state at different timestamps.
 ///
@@ -49,6 +59,12 @@ use std::collections::HashMap;
 pub struct EntityQueryHandle<'a> {
     simulation: &'a Simulation,
     entity_id: EntityId,
+    /// Whether emergent microsystems use their member-derived climate.
+    emergent_climate: bool,
+    /// Whether parent work quality is derived from linked parent entities.
+    linked_parents: bool,
+    /// Whether the macrosystem evolves with the population.
+    macrosystem_dynamics: bool,
 }
 
 impl<'a> EntityQueryHandle<'a> {
@@ -57,9 +73,36 @@ impl<'a> EntityQueryHandle<'a> {
         EntityQueryHandle {
             simulation,
             entity_id,
+            emergent_climate: true,
+            linked_parents: true,
+            macrosystem_dynamics: true,
         }
     }
 
+    /// Uses authored climates for emergent microsystems.
+    ///
+    /// Emergent climates are computed from member states, so those member
+    /// states must be projected without them.
+    pub(crate) fn without_emergent_climate(mut self) -> Self {
+        self.emergent_climate = false;
+        self
+    }
+
+    /// Uses hand-entered parent work quality instead of linked parents.
+    pub(crate) fn without_linked_parents(mut self) -> Self {
+        self.linked_parents = false;
+        self
+    }
+
+    /// Uses the authored macrosystem even if the simulation evolves it.
+    ///
+    /// The macrosystem shift is computed from member states, so those member
+    /// states must be projected without it.
+    pub(crate) fn without_macrosystem_dynamics(mut self) -> Self {
+        self.macrosystem_dynamics = false;
+        self
+    }
+
     /// Returns the entity ID.
     #[must_use]
     pub fn entity_id(&self) -> &EntityId {
@@ -144,6 +187,8 @@ impl<'a> EntityQueryHandle<'a> {
                 age_at_timestamp,
                 life_stage,
                 regression_quality: RegressionQuality::Exact,
+                regression_report: RegressionReport::new(),
+                anchor_distance: Duration::zero(),
                 alerts: std::cell::OnceCell::new(),
                 interpretations: interpreter.interpretations().clone(),
                 summary: interpreter.summary().to_string(),
@@ -157,20 +202,27 @@ impl<'a> EntityQueryHandle<'a> {
         // Get events targeting this entity in the relevant time range
         // Forward: (anchor, target] - exclude anchor, include target
         // Backward: [target, anchor) - include target, exclude anchor
-        let events = self.get_sorted_events_for_range(anchor_timestamp, timestamp, is_forward);
+        // Exposures to simulation-wide historical events join them
+        let exposures = self.simulation.historical_exposures(&self.entity_id);
+        let mut events = self.get_sorted_events_for_range(anchor_timestamp, timestamp, is_forward);
+        events.extend(exposures.iter().filter(|te| {
+            is_in_event_range(te.timestamp(), anchor_timestamp, timestamp, is_forward)
+        }));
+        events.sort_by_key(|te| te.timestamp());
 
-        // Compute regression quality based on events
-        let regression_quality = if is_forward {
-            RegressionQuality::Exact
-        } else {
-            self.determine_regression_quality(&events)
-        };
+        // Per-dimension regression diagnostics (empty for forward projection)
+        let mut regression_report = RegressionReport::new();
 
         // Interpret events once using the anchor entity's personality
         // Personality (HEXACO) is stable, so using anchor state is appropriate
+        // Culture and authority figures are read from the context in force
+        // when each event occurred
         let interpreted_events: Vec<InterpretedEvent> = events
             .iter()
-            .map(|te| interpret_event(te.event(), entity))
+            .map(|te| {
+                let context = interpretation_context(entity, te.timestamp());
+                interpret_event_in_context(te.event(), entity, &context)
+            })
             .collect();
 
         // Collect base shift records from events that have formative shifts
@@ -234,7 +286,7 @@ impl<'a> EntityQueryHandle<'a> {
 
                 // Regress from cursor to this event's timestamp
                 let delta = cursor - te.timestamp();
-                state = regress_state(state, delta);
+                state = regress_state_with_report(state, delta, &mut regression_report);
 
                 // Apply developmental effects to scale event impact for reversal
                 // Compute entity's age at the time of this event
@@ -247,19 +299,25 @@ impl<'a> EntityQueryHandle<'a> {
                 let scaled_interpreted = interpreted.scaled_by(dev_factor);
 
                 // Reverse the scaled interpreted event using its actual deltas
-                state = reverse_interpreted_event_from_state(state, &scaled_interpreted);
+                state = reverse_interpreted_event_with_report(
+                    state,
+                    &scaled_interpreted,
+                    &mut regression_report,
+                );
                 // Move cursor backward
                 cursor = te.timestamp();
             }
 
             // Regress remaining time from cursor to target timestamp
             let remaining = cursor - timestamp;
-            state = regress_state(state, remaining);
+            state = regress_state_with_report(state, remaining, &mut regression_report);
         }
 
         // Apply hook points AFTER decay and events, in order:
         // 1. Context effects (ecological systems)
-        // 2. Memory consolidation (salience decay, layer transfer)
+        // 2. Social support from relationships at the target timestamp
+        //    (and, if enabled, relationship-derived TB/PB inputs)
+        // 3. Memory consolidation (salience decay, layer transfer)
         //
         // Developmental effects (plasticity, sensitive periods) are applied above
         // during event processing via apply_developmental_effects().
@@ -268,24 +326,87 @@ impl<'a> EntityQueryHandle<'a> {
         } else {
             anchor_timestamp - timestamp
         };
-        let relationship_quality = estimate_relationship_quality(entity);
+        let social_support = self.social_support_at(timestamp);
+        let relationship_quality = relationship_quality_from_support(social_support);
         let age_at_timestamp = self.compute_age_at_timestamp(entity, timestamp);
         let life_stage =
             LifeStage::from_age_years_for_species(&species, age_at_timestamp.as_years() as f64);
-        state = apply_context_effects(
-            state,
-            entity.context(),
-            relationship_quality,
-            total_duration,
-            life_stage,
-            timestamp,
-        );
+        let base_context = self.base_context(entity, timestamp);
+        let context_timeline = self.context_timeline(entity, &base_context);
+        state = if context_timeline.is_empty() {
+            apply_context_effects(
+                state,
+                &base_context,
+                relationship_quality,
+                total_duration,
+                life_stage,
+                timestamp,
+            )
+        } else {
+            let (start, end) = if is_forward {
+                (anchor_timestamp, timestamp)
+            } else {
+                (timestamp, anchor_timestamp)
+            };
+            apply_context_timeline_effects(
+                state,
+                &base_context,
+                &context_timeline,
+                relationship_quality,
+                start,
+                end,
+                life_stage,
+                timestamp,
+            )
+        };
+        state = apply_social_support(state, social_support, total_duration);
+        if entity
+            .individual_state()
+            .config()
+            .relationship_coupling_enabled()
+        {
+            let relationships: Vec<_> = self
+                .simulation
+                .relationships_for(&self.entity_id)
+                .into_iter()
+                .filter_map(|tr| Some((tr.relationship_at(timestamp)?, tr.formed_timestamp())))
+                .collect();
+            let coupling = relationship_coupling(
+                relationships
+                    .iter()
+                    .map(|(relationship, formed)| (relationship, *formed)),
+                &self.entity_id,
+                timestamp,
+            );
+            state = apply_relationship_coupling(state, &coupling);
+        }
         state = apply_memory_consolidation(state, entity.memories(), total_duration);
 
+        // Apply normative maturation (age-graded drift) to HEXACO traits,
+        // then compose formative base shifts on top of the matured traits
+        if entity
+            .individual_state()
+            .config()
+            .normative_maturation_enabled()
+        {
+            let anchor_age = self.compute_age_at_timestamp(entity, anchor_timestamp);
+            apply_normative_maturation(state.hexaco_mut(), anchor_age, age_at_timestamp, &species);
+        }
+
         // Apply formative base shifts to HEXACO personality traits
         // This computes effective base values for each trait based on accumulated shifts
         state = apply_base_shifts_to_state(state, &base_shift_records, timestamp);
 
+        // Regression is approximate only where the forward model discarded
+        // information. Clamping, numerical solves and error bounds stay in
+        // the per-dimension report
+        let regression_quality =
+            if regression_report.worst_method() == RegressionMethod::Irreversible {
+                RegressionQuality::Approximate
+            } else {
+                RegressionQuality::Exact
+            };
+
         let baseline_state = entity.individual_state();
         let interpreter = StateInterpreter::from_state_with_baseline(&state, baseline_state);
         ComputedState {
@@ -293,6 +414,8 @@ impl<'a> EntityQueryHandle<'a> {
             age_at_timestamp,
             life_stage,
             regression_quality,
+            regression_report,
+            anchor_distance: total_duration,
             alerts: std::cell::OnceCell::new(),
             interpretations: interpreter.interpretations().clone(),
             summary: interpreter.summary().to_string(),
@@ -300,6 +423,263 @@ impl<'a> EntityQueryHandle<'a> {
         }
     }
 
+    /// Computes network social support from the entity's relationships.
+    ///
+    /// Only relationships formed at or before the timestamp contribute,
+    /// each as it stood at the timestamp: later events are left out and
+    /// neglect is applied up to it.
+    fn social_support_at(&self, timestamp: Timestamp) -> f64 {
+        network_support(
+            self.simulation
+                .relationships_for(&self.entity_id)
+                .into_iter()
+                .filter_map(|tr| {
+                    let relationship = tr.relationship_at(timestamp)?;
+                    Some(relationship_support(
+                        &relationship,
+                        &self.entity_id,
+                        tr.formed_timestamp(),
+                        timestamp,
+                    ))
+                }),
+        )
+    }
+
+    /// Returns the entity's context timeline with its context transition
+    /// and policy change events merged in.
+    ///
+    /// An `EventPayload::ContextTransition` event targeting the entity
+    /// schedules a `ContextChange::Transition` at the event's timestamp.
+    /// An `EventPayload::PolicyChange` event with a nonzero magnitude
+    /// schedules a `ContextChange::ApplyPolicy` for every entity its
+    /// `PolicyJurisdiction` covers, by default only its target. Shared microsystems are
+    /// registered as of each transition into them, so the entity joins them
+    /// as they were then. With normative schedules enabled, transitions
+    /// that realize a life transition complete it (see
+    /// `schedule_life_transitions`).
+    fn context_timeline(&self, entity: &Entity, base: &EcologicalContext) -> ContextTimeline {
+        let mut timeline = entity.context_timeline().clone();
+        let mut changes: Vec<_> = self
+            .simulation
+            .events_for(&self.entity_id)
+            .into_iter()
+            .filter_map(|te| match te.event().payload() {
+                EventPayload::ContextTransition { from, to } => Some((
+                    te.timestamp(),
+                    ContextChange::Transition {
+                        from: from.clone(),
+                        to: to.clone(),
+                    },
+                )),
+                _ => None,
+            })
+            .collect();
+        let mut policies = HashSet::new();
+        for te in self.simulation.all_events() {
+            let EventPayload::PolicyChange {
+                policy_area,
+                magnitude,
+                jurisdiction,
+                ..
+            } = te.event().payload()
+            else {
+                continue;
+            };
+            let covered = jurisdiction.covers(te.event().target(), &self.entity_id);
+            // Copies of one policy dispatched to several entities apply once
+            if *magnitude != 0.0 && covered && policies.insert(te.event().id()) {
+                changes.push((
+                    te.timestamp(),
+                    ContextChange::ApplyPolicy {
+                        policy_area: *policy_area,
+                        magnitude: *magnitude,
+                    },
+                ));
+            }
+        }
+        changes.sort_by_key(|(ts, _)| *ts);
+        for (ts, change) in changes {
+            timeline.schedule(ts, change);
+        }
+        let joins: Vec<_> = timeline
+            .changes()
+            .filter_map(|(ts, change)| match change {
+                ContextChange::Transition { to, .. } => Some((ts, to.clone())),
+                _ => None,
+            })
+            .collect();
+        for (ts, id) in joins {
+            if let Some(microsystem) = self.shared_microsystem_at(&id, ts) {
+                timeline.register_microsystem_at(id, ts, microsystem);
+            }
+        }
+        if self.simulation.normative_schedules_enabled() {
+            self.schedule_life_transitions(entity, base, &mut timeline);
+        }
+        timeline
+    }
+
+    /// Schedules a `ContextChange::CompleteTransition` for each context
+    /// transition event that realizes a life transition.
+    ///
+    /// The completion takes effect at the event, at the entity's age then.
+    /// Retirement only counts from two timing windows before its expected
+    /// age, so an earlier gap between jobs is not taken for it.
+    fn schedule_life_transitions(
+        &self,
+        entity: &Entity,
+        base: &EcologicalContext,
+        timeline: &mut ContextTimeline,
+    ) {
+        let mut moves: Vec<_> = self
+            .simulation
+            .events_for(&self.entity_id)
+            .into_iter()
+            .filter_map(|te| match te.event().payload() {
+                EventPayload::ContextTransition { from, to } => Some((te.timestamp(), from, to)),
+                _ => None,
+            })
+            .collect();
+        moves.sort_by_key(|(ts, _, _)| *ts);
+
+        let mut completions = Vec::new();
+        for (ts, from, to) in moves {
+            let before = timeline.context_at(base, ts - Duration::seconds(1));
+            let after = timeline.context_at(base, ts);
+            let Some(transition) = LifeTransition::realized_by(from, to, &before, &after) else {
+                continue;
+            };
+            let age = self.compute_age_at_timestamp(entity, ts).as_years_f64();
+            if transition == LifeTransition::Retirement {
+                let earliest = base
+                    .chronosystem()
+                    .get_normative_transition(transition.name())
+                    .map(|t| t.expected_age - 2.0 * t.timing_window);
+                if earliest.is_none_or(|earliest| age < earliest) {
+                    continue;
+                }
+            }
+            completions.push((
+                ts,
+                ContextChange::CompleteTransition {
+                    name: transition.name().to_string(),
+                    age,
+                },
+            ));
+        }
+        for (ts, change) in completions {
+            timeline.schedule(ts, change);
+        }
+    }
+
+    /// Returns the entity's context with the shared microsystems it belongs
+    /// to in place of its own copies, with parent work quality derived
+    /// from linked parent entities, with the historical events that have
+    /// reached the entity by `timestamp` in its chronosystem, with the
+    /// population's macrosystem shift if the macrosystem evolves, and with
+    /// its culture's normative schedule if schedules are enabled.
+    ///
+    /// The schedule follows the authored macrosystem, and leaves out
+    /// transitions whose window had closed by the entity's anchor, since
+    /// they predate the simulated record.
+    fn base_context<'e>(
+        &self,
+        entity: &'e Entity,
+        timestamp: Timestamp,
+    ) -> Cow<'e, EcologicalContext> {
+        let mut context = Cow::Borrowed(entity.context());
+        for (id, _) in self
+            .simulation
+            .shared_microsystems_for(&self.entity_id, timestamp)
+        {
+            if let Some(microsystem) = self.shared_microsystem_at(id, timestamp) {
+                context.to_mut().add_microsystem(id.clone(), microsystem);
+            }
+        }
+        if self.linked_parents {
+            if let Some(parent_work) = self.parent_work_at(entity, timestamp) {
+                context.to_mut().exosystem_mut().parent_work_environment = Some(parent_work);
+            }
+        }
+        let birth_era = self
+            .simulation
+            .birth_era(&self.entity_id)
+            .unwrap_or(BirthEra::Unknown);
+        if birth_era != context.chronosystem().cohort_effects().birth_era {
+            context
+                .to_mut()
+                .chronosystem_mut()
+                .cohort_effects_mut()
+                .birth_era = birth_era;
+        }
+        for historical in self.simulation.historical_events_for(&self.entity_id) {
+            if historical.start() <= timestamp {
+                context
+                    .to_mut()
+                    .chronosystem_mut()
+                    .add_non_normative_event(historical.as_non_normative(timestamp));
+            }
+        }
+        if self.macrosystem_dynamics {
+            if let Some(shift) = self.simulation.macrosystem_shift_at(timestamp) {
+                context.to_mut().set_macrosystem_shift(Some(shift));
+            }
+        }
+        if self.simulation.normative_schedules_enabled() {
+            if let Some(anchored) = self.simulation.get_anchored_entity(&self.entity_id) {
+                let anchor_age = self
+                    .compute_age_at_timestamp(entity, anchored.anchor_timestamp())
+                    .as_years_f64();
+                let schedule = NormativeSchedule::for_culture(&context.macrosystem().resolved());
+                schedule.apply_to(context.to_mut().chronosystem_mut(), anchor_age);
+            }
+        }
+        context
+    }
+
+    /// Derives parent work quality from the entity's linked parents.
+    ///
+    /// Parents are projected to `timestamp` without their own linked
+    /// parents, so a cycle of links cannot recurse, and with their authored
+    /// macrosystem. Returns None if no linked parent is in the simulation.
+    fn parent_work_at(&self, entity: &Entity, timestamp: Timestamp) -> Option<ParentWorkQuality> {
+        let parents: Vec<ParentWorkQuality> = entity
+            .context()
+            .exosystem()
+            .parent_ids
+            .iter()
+            .filter(|id| **id != self.entity_id)
+            .filter_map(|id| self.simulation.entity(id))
+            .map(|handle| {
+                let mut handle = handle
+                    .without_linked_parents()
+                    .without_macrosystem_dynamics();
+                if !self.emergent_climate {
+                    handle = handle.without_emergent_climate();
+                }
+                let state = handle.state_at(timestamp);
+                let context = handle
+                    .context_at(timestamp)
+                    .expect("handle refers to an entity in the simulation");
+                ParentWorkQuality::from_parent(state.individual_state(), &context)
+            })
+            .collect();
+        ParentWorkQuality::average(&parents)
+    }
+
+    /// Returns a shared microsystem as this query sees it at `timestamp`.
+    fn shared_microsystem_at(
+        &self,
+        id: &MicrosystemId,
+        timestamp: Timestamp,
+    ) -> Option<Microsystem> {
+        if self.emergent_climate {
+            self.simulation.microsystem_at(id, timestamp)
+        } else {
+            self.simulation.microsystem(id).cloned()
+        }
+    }
+
     /// Gets events in the time range, sorted chronologically.
     ///
     /// # Boundary Rules
@@ -321,46 +701,13 @@ impl<'a> EntityQueryHandle<'a> {
             .simulation
             .events_for(&self.entity_id)
             .into_iter()
-            .filter(|te| {
-                let ts = te.timestamp();
-                if is_forward {
-                    // Forward: (anchor, target] - after anchor, up to and including target
-                    ts > anchor && ts <= target
-                } else {
-                    // Backward: (target, anchor] - after target, up to and including anchor
-                    ts > target && ts <= anchor
-                }
-            })
+            .filter(|te| is_in_event_range(te.timestamp(), anchor, target, is_forward))
             .collect();
 
         events.sort_by_key(|te| te.timestamp());
         events
     }
 
-    /// Determines regression quality based on events.
-    ///
-    /// Regression is approximate when:
-    /// - Trauma events are present (AC increases are not reversible)
-    /// - Events triggered feedback loops (spirals) - Phase 10+
-    fn determine_regression_quality(&self, events: &[&TimestampedEvent]) -> RegressionQuality {
-        use crate::enums::EventCategory;
-
-        for te in events {
-            let event = te.event();
-            let category = event.category();
-
-            // Trauma events have non-reversible Acquired Capability increases
-            if matches!(category, EventCategory::Trauma) {
-                return RegressionQuality::Approximate;
-            }
-
-            // Phase 10+: Check for spiral-triggering events
-            // This will involve checking if the event triggered a stress/depression spiral
-        }
-
-        RegressionQuality::Exact
-    }
-
     /// Computes the entity's age at a given timestamp.
     ///
     /// If the entity has a birth_date set, computes age as:
@@ -459,19 +806,97 @@ impl<'a> EntityQueryHandle<'a> {
             .cloned()
             .collect()
     }
-}
 
-fn estimate_relationship_quality(entity: &Entity) -> f64 {
-    let attached_count = entity
-        .relationship_slots()
-        .iter()
-        .filter(|slot| slot.is_attached())
-        .count();
+    /// Returns the ecological context in force at the given timestamp.
+    ///
+    /// This includes the shared microsystems the entity belongs to,
+    /// parent work quality derived from linked parents, and the historical
+    /// events that reach the entity (with the birth era they imply), then
+    /// applies the entity's context timeline and any context transition
+    /// and policy change events up to and including `timestamp`. With
+    /// normative schedules enabled, the chronosystem carries the culture's
+    /// schedule, with the life transitions those events completed. If a
+    /// time budget is then in force, interaction frequencies are derived
+    /// from it, and if the simulation's macrosystem evolves, the
+    /// macrosystem returned is the evolved one. Returns `None` if the
+    /// entity doesn't exist.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use eventsim_rs::context::{ContextChange, Microsystem, WorkContext};
+    /// use eventsim_rs::entity::EntityBuilder;
+    /// use eventsim_rs::enums::Species;
+    /// use eventsim_rs::simulation::Simulation;
+    /// use eventsim_rs::types::{EntityId, MicrosystemId, Timestamp};
+    ///
+    /// let reference = Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+    /// let mut sim = Simulation::new(reference);
+    ///
+    /// let mut entity = EntityBuilder::new()
+    ///     .id("person_001")
+    ///     .species(Species::Human)
+    ///     .build()
+    ///     .unwrap();
+    /// let job = MicrosystemId::new("work_acme").unwrap();
+    /// entity.context_timeline_mut().schedule(
+    ///     Timestamp::from_ymd_hms(2024, 3, 1, 0, 0, 0),
+    ///     ContextChange::AddMicrosystem(job.clone(), Microsystem::new_work(WorkContext::default())),
+    /// );
+    /// sim.add_entity(entity, reference);
+    ///
+    /// let handle = sim.entity(&EntityId::new("person_001").unwrap()).unwrap();
+    /// let context = handle.context_at(Timestamp::from_ymd_hms(2024, 6, 1, 0, 0, 0)).unwrap();
+    /// assert!(context.get_microsystem(&job).is_some());
+    /// ```
+    #[must_use]
+    pub fn context_at(&self, timestamp: Timestamp) -> Option<EcologicalContext> {
+        let anchored = self.simulation.get_anchored_entity(&self.entity_id)?;
+        let entity = anchored.entity();
+        let base_context = self.base_context(entity, timestamp);
+        let mut context = self
+            .context_timeline(entity, &base_context)
+            .context_at(&base_context, timestamp);
+        if let Some(budget) = context.time_budget().cloned() {
+            budget.apply_to(&mut context);
+        }
+        if let Some(shift) = context.macrosystem_shift().copied() {
+            *context.macrosystem_mut() = shift.apply_to(context.macrosystem());
+            context.set_macrosystem_shift(None);
+        }
+        Some(context)
+    }
 
-    if attached_count > 0 {
-        0.5 + 0.1 * (attached_count as f64).min(5.0)
-    } else {
-        0.3
+    /// Returns the normative transitions that are off-time at a timestamp.
+    ///
+    /// These are the transitions in the context in force at `timestamp`
+    /// that occurred late, or that have not occurred by the end of their
+    /// window at the entity's age then (see
+    /// `ChronosystemContext::off_time_transitions`). With normative
+    /// schedules enabled, this includes the culture's default schedule.
+    ///
+    /// Returns `None` if the entity doesn't exist.
+    #[must_use]
+    pub fn off_time_transitions_at(
+        &self,
+        timestamp: Timestamp,
+    ) -> Option<Vec<NormativeTransition>> {
+        let context = self.context_at(timestamp)?;
+        let entity = self
+            .simulation
+            .get_anchored_entity(&self.entity_id)?
+            .entity();
+        let age = self
+            .compute_age_at_timestamp(entity, timestamp)
+            .as_years_f64();
+        Some(
+            context
+                .chronosystem()
+                .off_time_transitions(age)
+                .into_iter()
+                .cloned()
+                .collect(),
+        )
     }
 }
 
@@ -517,6 +942,10 @@ pub struct ComputedState {
     pub life_stage: LifeStage,
     /// Quality indicator for backward regression.
     regression_quality: RegressionQuality,
+    /// Per-dimension method and error bounds for backward regression.
+    regression_report: RegressionReport,
+    /// Distance between the queried timestamp and the anchor.
+    anchor_distance: Duration,
     /// Cached alerts (lazy computed with interior mutability).
     alerts: std::cell::OnceCell<Vec<Alert>>,
     /// Human-readable interpretations of psychological dimensions.
@@ -549,13 +978,71 @@ impl ComputedState {
     /// Returns the regression quality indicator.
     ///
     /// This indicates whether the state was computed exactly or approximately.
-    /// Forward projections are always Exact. Backward regressions may be
-    /// Approximate if the time range contains spiral-triggering events.
+    /// Forward projections are always Exact. Backward regressions are
+    /// Approximate if any dimension in the regression report is
+    /// irreversible; clamped or numerically solved dimensions and error
+    /// bounds are reported per dimension by [`Self::regression_report`].
     #[must_use]
     pub fn regression_quality(&self) -> RegressionQuality {
         self.regression_quality
     }
 
+    /// Returns per-dimension regression diagnostics.
+    ///
+    /// Empty for forward projections and anchor queries. For backward
+    /// regressions, reports the method and error bound for each dimension.
+    #[must_use]
+    pub fn regression_report(&self) -> &RegressionReport {
+        &self.regression_report
+    }
+
+    /// Returns a reliability score in [0, 1] for a single dimension.
+    ///
+    /// 1.0 means the value was observed directly (query at the anchor).
+    /// The score drops with distance from the anchor, with the error bound
+    /// accumulated during regression, for irreversible or clamped
+    /// dimensions, for dimensions caught in a feedback spiral, and for each
+    /// event whose reversal had to be approximated. Computed paths combine
+    /// their inputs.
+    ///
+    /// Use this to flag individual values as extrapolations rather than
+    /// treating the whole state as approximate.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use eventsim_rs::simulation::Simulation;
+    /// use eventsim_rs::entity::EntityBuilder;
+    /// use eventsim_rs::types::{Duration, EntityId, Timestamp};
+    /// use eventsim_rs::enums::{MoodPath, Species, StatePath};
+    ///
+    /// let reference = Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+    /// let mut sim = Simulation::new(reference);
+    /// let entity = EntityBuilder::new()
+    ///     .id("person_001")
+    ///     .species(Species::Human)
+    ///     .build()
+    ///     .unwrap();
+    /// sim.add_entity(entity, reference);
+    ///
+    /// let handle = sim.entity(&EntityId::new("person_001").unwrap()).unwrap();
+    /// let path = StatePath::Mood(MoodPath::Valence);
+    ///
+    /// let observed = handle.state_at(reference).confidence(path);
+    /// let earlier = handle.state_at(reference - Duration::days(90)).confidence(path);
+    /// assert_eq!(observed, 1.0);
+    /// assert!(earlier < observed);
+    /// ```
+    #[must_use]
+    pub fn confidence(&self, path: StatePath) -> f64 {
+        f64::from(dimension_confidence(
+            &self.regression_report,
+            &self.individual_state,
+            path,
+            self.anchor_distance,
+        ))
+    }
+
     /// Returns alerts generated during state computation.
     ///
     /// This is lazily computed on first access. Alerts include threshold
@@ -723,6 +1210,8 @@ impl Clone for ComputedState {
             age_at_timestamp: self.age_at_timestamp,
             life_stage: self.life_stage,
             regression_quality: self.regression_quality,
+            regression_report: self.regression_report.clone(),
+            anchor_distance: self.anchor_distance,
             alerts: match self.alerts.get() {
                 Some(v) => {
                     let cell = std::cell::OnceCell::new();
@@ -738,6 +1227,37 @@ impl Clone for ComputedState {
     }
 }
 
+/// Returns the entity's authored context in force at `timestamp`, used to
+/// interpret events that occur then.
+///
+/// Only the entity's own context timeline is replayed; derived microsystems
+/// and budgets do not change how an event is appraised.
+fn interpretation_context(entity: &Entity, timestamp: Timestamp) -> Cow<'_, EcologicalContext> {
+    let timeline = entity.context_timeline();
+    if timeline.is_empty() {
+        Cow::Borrowed(entity.context())
+    } else {
+        Cow::Owned(timeline.context_at(entity.context(), timestamp))
+    }
+}
+
+/// Returns true if an event at `ts` falls in the query range.
+///
+/// Forward: (anchor, target] - after anchor, up to and including target.
+/// Backward: (target, anchor] - after target, up to and including anchor.
+fn is_in_event_range(
+    ts: Timestamp,
+    anchor: Timestamp,
+    target: Timestamp,
+    is_forward: bool,
+) -> bool {
+    if is_forward {
+        ts > anchor && ts <= target
+    } else {
+        ts > target && ts <= anchor
+    }
+}
+
 /// Collects base shift records from events that have formative personality shifts.
 ///
 /// For forward queries, collects shifts from events before the query timestamp.
@@ -758,14 +1278,21 @@ fn collect_base_shift_records(
         .unwrap_or_else(|| Timestamp::from_ymd_hms(1970, 1, 1, 0, 0, 0));
 
     let mut records = Vec::new();
-    let mut cumulative_positive: HashMap<HexacoPath, f32> = HashMap::new();
-    let mut cumulative_negative: HashMap<HexacoPath, f32> = HashMap::new();
+    let mut cumulative_positive: HashMap<StatePath, f32> = HashMap::new();
+    let mut cumulative_negative: HashMap<StatePath, f32> = HashMap::new();
+
+    let automatic = entity
+        .individual_state()
+        .config()
+        .formative_shift_policy()
+        .is_automatic();
 
     for te in events {
         let event = te.event();
 
-        // Skip events without base shifts
-        if !event.has_base_shifts() {
+        // Skip events without base shifts, unless the automatic policy
+        // may derive defaults for them
+        if !event.has_base_shifts() && !automatic {
             continue;
         }
 
@@ -792,19 +1319,28 @@ fn collect_base_shift_records(
             Duration::zero()
         };
 
+        // Explicit shifts override derived defaults for the whole event
+        let shifts: Vec<(StatePath, f32)> = if event.has_base_shifts() {
+            event.base_shifts().to_vec()
+        } else {
+            let life_stage =
+                LifeStage::from_age_years_for_species(entity.species(), f64::from(age_at_event));
+            derive_formative_shifts(event, &life_stage)
+        };
+
         // Process each base shift in the event
-        for (trait_path, raw_amount) in event.base_shifts() {
+        for (path, raw_amount) in &shifts {
             // Get existing cumulative in this direction
             let existing = if *raw_amount > 0.0 {
-                *cumulative_positive.get(trait_path).unwrap_or(&0.0)
+                *cumulative_positive.get(path).unwrap_or(&0.0)
             } else {
-                *cumulative_negative.get(trait_path).unwrap_or(&0.0)
+                *cumulative_negative.get(path).unwrap_or(&0.0)
             };
 
-            // Apply all modifiers: plasticity, trait stability, saturation, caps
-            let modified = apply_formative_modifiers(
+            // Apply all modifiers: plasticity, stability, saturation, caps
+            let modified = apply_baseline_modifiers(
                 *raw_amount,
-                *trait_path,
+                *path,
                 age_at_event,
                 existing,
                 entity.species(),
@@ -816,13 +1352,13 @@ fn collect_base_shift_records(
             }
 
             // Create the base shift record
-            let record = BaseShiftRecord::new(event_duration, *trait_path, modified);
+            let record = BaseShiftRecord::new(event_duration, *path, modified);
 
             // Update cumulative tracking
             if modified > 0.0 {
-                *cumulative_positive.entry(*trait_path).or_insert(0.0) += modified.abs();
+                *cumulative_positive.entry(*path).or_insert(0.0) += modified.abs();
             } else {
-                *cumulative_negative.entry(*trait_path).or_insert(0.0) += modified.abs();
+                *cumulative_negative.entry(*path).or_insert(0.0) += modified.abs();
             }
 
             records.push(record);
@@ -832,10 +1368,11 @@ fn collect_base_shift_records(
     records
 }
 
-/// Applies accumulated base shifts to HEXACO personality traits in the state.
+/// Applies accumulated base shifts to traits and baselines in the state.
 ///
-/// For each HEXACO trait, computes the effective base value using all
-/// applicable base shift records, then updates the state's HEXACO values.
+/// For each shifted path, computes the effective base value using all
+/// applicable base shift records, then updates the state's HEXACO value or
+/// the base of the corresponding state dimension (clamped to its bounds).
 fn apply_base_shifts_to_state(
     mut state: IndividualState,
     shift_records: &[BaseShiftRecord],
@@ -855,41 +1392,27 @@ fn apply_base_shifts_to_state(
         Duration::zero()
     };
 
-    // Process each HEXACO trait
-    for trait_path in HexacoPath::all() {
-        // Filter records for this trait
-        let trait_records: Vec<_> = shift_records
+    // Process each shiftable path
+    for path in StatePath::all() {
+        // Filter records for this path
+        let path_records: Vec<_> = shift_records
             .iter()
-            .filter(|r| r.trait_path() == trait_path)
+            .filter(|r| r.path() == path)
             .cloned()
             .collect();
 
-        // Skip if no records for this trait
-        if trait_records.is_empty() {
+        // Skip if no records for this path
+        if path_records.is_empty() {
             continue;
         }
 
-        // Get current base value
-        let current_base = match trait_path {
-            HexacoPath::Openness => state.hexaco().openness(),
-            HexacoPath::Conscientiousness => state.hexaco().conscientiousness(),
-            HexacoPath::Extraversion => state.hexaco().extraversion(),
-            HexacoPath::Agreeableness => state.hexaco().agreeableness(),
-            HexacoPath::Neuroticism => state.hexaco().neuroticism(),
-            HexacoPath::HonestyHumility => state.hexaco().honesty_humility(),
-        };
-
-        // Compute effective base with accumulated shifts
-        let effective = effective_base_at(current_base, &trait_records, query_duration);
-
-        // Update the trait value in state
-        match trait_path {
-            HexacoPath::Openness => state.hexaco_mut().set_openness(effective),
-            HexacoPath::Conscientiousness => state.hexaco_mut().set_conscientiousness(effective),
-            HexacoPath::Extraversion => state.hexaco_mut().set_extraversion(effective),
-            HexacoPath::Agreeableness => state.hexaco_mut().set_agreeableness(effective),
-            HexacoPath::Neuroticism => state.hexaco_mut().set_neuroticism(effective),
-            HexacoPath::HonestyHumility => state.hexaco_mut().set_honesty_humility(effective),
+        if let StatePath::Hexaco(trait_path) = path {
+            let current_base = state.hexaco().get(trait_path);
+            let effective = effective_base_at(current_base, &path_records, query_duration);
+            state.hexaco_mut().set(trait_path, effective);
+        } else if let Some(value) = state.get_mut(path) {
+            let effective = effective_base_at(value.base(), &path_records, query_duration);
+            value.set_base(effective.clamp(value.min_bound(), value.max_bound()));
         }
     }
 
@@ -899,9 +1422,14 @@ fn apply_base_shifts_to_state(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::entity::Entity;
     use crate::entity::EntityBuilder;
-    use crate::enums::{EventType, Species};
+    use crate::enums::{
+        DispositionPath, EventType, HexacoPath, MentalHealthPath, MoodPath, SocialCognitionPath,
+        Species,
+    };
     use crate::event::EventBuilder;
+    use crate::simulation::RegressionMethod;
 
     fn create_simulation() -> Simulation {
         let reference = Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0);
@@ -1031,13 +1559,13 @@ mod tests {
         let state = handle.state_at(anchor);
 
         let valence = state.get_effective(StatePath::Mood(crate::enums::MoodPath::Valence));
-        assert!(valence >= -1.0 && valence <= 1.0);
+        assert!((-1.0..=1.0).contains(&valence));
 
         let arousal = state.get_effective(StatePath::Mood(crate::enums::MoodPath::Arousal));
-        assert!(arousal >= -1.0 && arousal <= 1.0);
+        assert!((-1.0..=1.0).contains(&arousal));
 
         let dominance = state.get_effective(StatePath::Mood(crate::enums::MoodPath::Dominance));
-        assert!(dominance >= -1.0 && dominance <= 1.0);
+        assert!((-1.0..=1.0).contains(&dominance));
     }
 
     #[test]
@@ -1055,35 +1583,35 @@ mod tests {
         // get_effective now returns f64 directly - verify values are in valid range
         let loneliness =
             state.get_effective(StatePath::SocialCognition(SocialCognitionPath::Loneliness));
-        assert!(loneliness >= 0.0 && loneliness <= 1.0);
+        assert!((0.0..=1.0).contains(&loneliness));
 
         let prc = state.get_effective(StatePath::SocialCognition(
             SocialCognitionPath::PerceivedReciprocalCaring,
         ));
-        assert!(prc >= 0.0 && prc <= 1.0);
+        assert!((0.0..=1.0).contains(&prc));
 
         let liability = state.get_effective(StatePath::SocialCognition(
             SocialCognitionPath::PerceivedLiability,
         ));
-        assert!(liability >= 0.0 && liability <= 1.0);
+        assert!((0.0..=1.0).contains(&liability));
 
         let self_hate =
             state.get_effective(StatePath::SocialCognition(SocialCognitionPath::SelfHate));
-        assert!(self_hate >= 0.0 && self_hate <= 1.0);
+        assert!((0.0..=1.0).contains(&self_hate));
 
         let perceived_competence = state.get_effective(StatePath::SocialCognition(
             SocialCognitionPath::PerceivedCompetence,
         ));
-        assert!(perceived_competence >= 0.0 && perceived_competence <= 1.0);
+        assert!((0.0..=1.0).contains(&perceived_competence));
 
         let stress = state.get_effective(StatePath::Needs(NeedsPath::Stress));
-        assert!(stress >= 0.0 && stress <= 1.0);
+        assert!((0.0..=1.0).contains(&stress));
 
         let fatigue = state.get_effective(StatePath::Needs(NeedsPath::Fatigue));
-        assert!(fatigue >= 0.0 && fatigue <= 1.0);
+        assert!((0.0..=1.0).contains(&fatigue));
 
         let purpose = state.get_effective(StatePath::Needs(NeedsPath::Purpose));
-        assert!(purpose >= 0.0 && purpose <= 1.0);
+        assert!((0.0..=1.0).contains(&purpose));
     }
 
     #[test]
@@ -1100,33 +1628,33 @@ mod tests {
 
         // get_effective now returns f64 directly - verify values are in valid range
         let depression = state.get_effective(StatePath::MentalHealth(MentalHealthPath::Depression));
-        assert!(depression >= 0.0 && depression <= 1.0);
+        assert!((0.0..=1.0).contains(&depression));
 
         let ac = state.get_effective(StatePath::MentalHealth(
             MentalHealthPath::AcquiredCapability,
         ));
-        assert!(ac >= 0.0 && ac <= 1.0);
+        assert!((0.0..=1.0).contains(&ac));
 
         let ih = state.get_effective(StatePath::MentalHealth(
             MentalHealthPath::InterpersonalHopelessness,
         ));
-        assert!(ih >= 0.0 && ih <= 1.0);
+        assert!((0.0..=1.0).contains(&ih));
 
         let tb = state.get_effective(StatePath::MentalHealth(
             MentalHealthPath::ThwartedBelongingness,
         ));
-        assert!(tb >= 0.0 && tb <= 1.0);
+        assert!((0.0..=1.0).contains(&tb));
 
         let pb = state.get_effective(StatePath::MentalHealth(
             MentalHealthPath::PerceivedBurdensomeness,
         ));
-        assert!(pb >= 0.0 && pb <= 1.0);
+        assert!((0.0..=1.0).contains(&pb));
 
         let desire = state.get_effective(StatePath::MentalHealth(MentalHealthPath::SuicidalDesire));
-        assert!(desire >= 0.0 && desire <= 1.0);
+        assert!((0.0..=1.0).contains(&desire));
 
         let risk = state.get_effective(StatePath::MentalHealth(MentalHealthPath::AttemptRisk));
-        assert!(risk >= 0.0 && risk <= 1.0);
+        assert!((0.0..=1.0).contains(&risk));
     }
 
     #[test]
@@ -1143,22 +1671,22 @@ mod tests {
 
         // get_effective now returns f64 directly - HEXACO values are 0.0-1.0
         let hh = state.get_effective(StatePath::Hexaco(HexacoPath::HonestyHumility));
-        assert!(hh >= 0.0 && hh <= 1.0);
+        assert!((0.0..=1.0).contains(&hh));
 
         let n = state.get_effective(StatePath::Hexaco(HexacoPath::Neuroticism));
-        assert!(n >= 0.0 && n <= 1.0);
+        assert!((0.0..=1.0).contains(&n));
 
         let e = state.get_effective(StatePath::Hexaco(HexacoPath::Extraversion));
-        assert!(e >= 0.0 && e <= 1.0);
+        assert!((0.0..=1.0).contains(&e));
 
         let a = state.get_effective(StatePath::Hexaco(HexacoPath::Agreeableness));
-        assert!(a >= 0.0 && a <= 1.0);
+        assert!((0.0..=1.0).contains(&a));
 
         let c = state.get_effective(StatePath::Hexaco(HexacoPath::Conscientiousness));
-        assert!(c >= 0.0 && c <= 1.0);
+        assert!((0.0..=1.0).contains(&c));
 
         let o = state.get_effective(StatePath::Hexaco(HexacoPath::Openness));
-        assert!(o >= 0.0 && o <= 1.0);
+        assert!((0.0..=1.0).contains(&o));
     }
 
     #[test]
@@ -1175,13 +1703,13 @@ mod tests {
 
         // get_effective now returns f64 directly
         let empathy = state.get_effective(StatePath::Disposition(DispositionPath::Empathy));
-        assert!(empathy >= 0.0 && empathy <= 1.0);
+        assert!((0.0..=1.0).contains(&empathy));
 
         let aggression = state.get_effective(StatePath::Disposition(DispositionPath::Aggression));
-        assert!(aggression >= 0.0 && aggression <= 1.0);
+        assert!((0.0..=1.0).contains(&aggression));
 
         let grievance = state.get_effective(StatePath::Disposition(DispositionPath::Grievance));
-        assert!(grievance >= 0.0 && grievance <= 1.0);
+        assert!((0.0..=1.0).contains(&grievance));
     }
 
     #[test]
@@ -1200,17 +1728,17 @@ mod tests {
         let sc = state.get_effective(StatePath::PersonCharacteristics(
             PersonCharacteristicsPath::SocialCapital,
         ));
-        assert!(sc >= 0.0 && sc <= 1.0);
+        assert!((0.0..=1.0).contains(&sc));
 
         let ca = state.get_effective(StatePath::PersonCharacteristics(
             PersonCharacteristicsPath::CognitiveAbility,
         ));
-        assert!(ca >= 0.0 && ca <= 1.0);
+        assert!((0.0..=1.0).contains(&ca));
 
         let ms = state.get_effective(StatePath::PersonCharacteristics(
             PersonCharacteristicsPath::MaterialSecurity,
         ));
-        assert!(ms >= 0.0 && ms <= 1.0);
+        assert!((0.0..=1.0).contains(&ms));
     }
 
     #[test]
@@ -1460,8 +1988,13 @@ mod tests {
         let handle = sim.entity(&entity_id).unwrap();
         let computed = handle.state_at(past);
 
-        // Backward regression through events should still work
-        assert!(computed.regression_quality().is_exact());
+        // Backward regression through events should still work, with the
+        // uncertainty reported per dimension
+        let report = computed.regression_report();
+        assert!(!report.is_empty());
+        let loneliness = StatePath::SocialCognition(SocialCognitionPath::Loneliness);
+        assert!(report.error_bound(loneliness) > 0.0);
+        assert!(report.error_bound(loneliness) <= 1.0);
     }
 
     #[test]
@@ -1479,11 +2012,11 @@ mod tests {
         // Test all mental health paths including SelfWorth and Hopelessness
         // get_effective now returns f64 directly
         let self_worth = state.get_effective(StatePath::MentalHealth(MentalHealthPath::SelfWorth));
-        assert!(self_worth >= 0.0 && self_worth <= 1.0);
+        assert!((0.0..=1.0).contains(&self_worth));
 
         let hopelessness =
             state.get_effective(StatePath::MentalHealth(MentalHealthPath::Hopelessness));
-        assert!(hopelessness >= 0.0 && hopelessness <= 1.0);
+        assert!((0.0..=1.0).contains(&hopelessness));
     }
 
     #[test]
@@ -1500,13 +2033,13 @@ mod tests {
 
         // Test all disposition paths - get_effective now returns f64 directly
         let impulse = state.get_effective(StatePath::Disposition(DispositionPath::ImpulseControl));
-        assert!(impulse >= 0.0 && impulse <= 1.0);
+        assert!((0.0..=1.0).contains(&impulse));
 
         let reactance = state.get_effective(StatePath::Disposition(DispositionPath::Reactance));
-        assert!(reactance >= 0.0 && reactance <= 1.0);
+        assert!((0.0..=1.0).contains(&reactance));
 
         let trust = state.get_effective(StatePath::Disposition(DispositionPath::TrustPropensity));
-        assert!(trust >= 0.0 && trust <= 1.0);
+        assert!((0.0..=1.0).contains(&trust));
     }
 
     #[test]
@@ -1525,27 +2058,27 @@ mod tests {
         let era = state.get_effective(StatePath::PersonCharacteristics(
             PersonCharacteristicsPath::EmotionalRegulationAssets,
         ));
-        assert!(era >= 0.0 && era <= 1.0);
+        assert!((0.0..=1.0).contains(&era));
 
         let ed = state.get_effective(StatePath::PersonCharacteristics(
             PersonCharacteristicsPath::ExperienceDiversity,
         ));
-        assert!(ed >= 0.0 && ed <= 1.0);
+        assert!((0.0..=1.0).contains(&ed));
 
         let bm = state.get_effective(StatePath::PersonCharacteristics(
             PersonCharacteristicsPath::BaselineMotivation,
         ));
-        assert!(bm >= 0.0 && bm <= 1.0);
+        assert!((0.0..=1.0).contains(&bm));
 
         let pt = state.get_effective(StatePath::PersonCharacteristics(
             PersonCharacteristicsPath::PersistenceTendency,
         ));
-        assert!(pt >= 0.0 && pt <= 1.0);
+        assert!((0.0..=1.0).contains(&pt));
 
         let ct = state.get_effective(StatePath::PersonCharacteristics(
             PersonCharacteristicsPath::CuriosityTendency,
         ));
-        assert!(ct >= 0.0 && ct <= 1.0);
+        assert!((0.0..=1.0).contains(&ct));
 
         // Composite values can be any f64
         let _resource = state.get_effective(StatePath::PersonCharacteristics(
@@ -1743,91 +2276,570 @@ mod tests {
 
         // Regression through trauma should be approximate (AC not reversible)
         assert!(state.regression_quality().is_approximate());
+        let ac = StatePath::MentalHealth(MentalHealthPath::AcquiredCapability);
+        assert_eq!(
+            state.regression_report().method(ac),
+            RegressionMethod::Irreversible
+        );
+        assert!(state.regression_report().error_bound(ac) > 0.0);
     }
 
     #[test]
-    fn regression_without_trauma_is_exact() {
+    fn confidence_is_per_dimension_after_trauma() {
         let mut sim = create_simulation();
         let entity = create_human("person_001");
         let entity_id = EntityId::new("person_001").unwrap();
 
-        // Set anchor to a later date
         let anchor = Timestamp::from_ymd_hms(2024, 6, 1, 0, 0, 0);
         sim.add_entity(entity, anchor);
 
-        // Add a social exclusion event (not trauma) before the anchor
-        let social_event = EventBuilder::new(EventType::SocialExclusion)
+        let trauma_event = EventBuilder::new(EventType::Violence)
             .target(entity_id.clone())
-            .severity(0.5)
+            .severity(0.8)
             .build()
             .unwrap();
-        let event_time = Timestamp::from_ymd_hms(2024, 3, 1, 0, 0, 0);
-        sim.add_event(social_event, event_time);
+        sim.add_event(trauma_event, Timestamp::from_ymd_hms(2024, 5, 31, 0, 0, 0));
 
-        // Query state before the event (backward regression)
-        let past = Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0);
         let handle = sim.entity(&entity_id).unwrap();
-        let state = handle.state_at(past);
-
-        // Regression through non-trauma events should be exact
-        assert!(state.regression_quality().is_exact());
-    }
-
-    #[test]
-    fn estimate_relationship_quality_accounts_for_attached_slots() {
-        use crate::types::RelationshipId;
+        let state = handle.state_at(Timestamp::from_ymd_hms(2024, 5, 30, 0, 0, 0));
 
-        let mut entity = create_human("person_001");
-        let baseline = estimate_relationship_quality(&entity);
-        assert!((baseline - 0.3).abs() < f64::EPSILON);
+        let ac = StatePath::MentalHealth(MentalHealthPath::AcquiredCapability);
+        let openness = StatePath::Hexaco(HexacoPath::Openness);
+        let purpose = StatePath::Needs(crate::enums::NeedsPath::Purpose);
 
-        let rel_id = RelationshipId::new("rel_attached").unwrap();
-        entity.relationship_slots_mut()[0].attach(rel_id);
+        // Irreversible AC is less reliable than an untouched slow dimension
+        assert!(state.confidence(ac) < state.confidence(purpose));
+        assert!(state.confidence(purpose) < 1.0);
+        // HEXACO only loses confidence with distance
+        assert!(state.confidence(openness) > 0.99);
+        assert!((0.0..=1.0).contains(&state.confidence(ac)));
 
-        let attached = estimate_relationship_quality(&entity);
-        assert!(attached > baseline);
+        // Anchor query is fully observed
+        assert!((handle.state_at(anchor).confidence(ac) - 1.0).abs() < f64::EPSILON);
     }
 
     #[test]
-    fn computed_state_clone_with_cached_alerts() {
+    fn confidence_decreases_with_forward_distance() {
         let mut sim = create_simulation();
         let entity = create_human("person_001");
+        let entity_id = EntityId::new("person_001").unwrap();
         let anchor = sim.reference_date();
         sim.add_entity(entity, anchor);
 
-        let handle = sim.entity(&EntityId::new("person_001").unwrap()).unwrap();
-        let state = handle.state_at(anchor);
+        let handle = sim.entity(&entity_id).unwrap();
+        let valence = StatePath::Mood(MoodPath::Valence);
+        let near = handle
+            .state_at(anchor + Duration::days(1))
+            .confidence(valence);
+        let far = handle
+            .state_at(anchor + Duration::years(2))
+            .confidence(valence);
+
+        assert!(near > far);
+        assert!((far - 0.5).abs() < 0.01);
+        // Clones keep the distance
+        let cloned = handle.state_at(anchor + Duration::years(2)).clone();
+        assert!((cloned.confidence(valence) - far).abs() < f64::EPSILON);
+    }
 
-        // Compute and cache alerts first
-        let alerts1 = state.alerts();
-        assert!(alerts1.is_empty());
+    fn create_maturing_human(id: &str, maturation: bool) -> Entity {
+        let mut entity = EntityBuilder::new()
+            .id(id)
+            .species(Species::Human)
+            .birth_date(Timestamp::from_ymd_hms(2004, 1, 1, 0, 0, 0))
+            .build()
+            .unwrap();
+        entity
+            .individual_state_mut()
+            .config_mut()
+            .set_normative_maturation_enabled(maturation);
+        entity
+    }
 
-        // Clone the state with cached alerts
-        let cloned = state.clone();
+    #[test]
+    fn normative_maturation_drifts_personality_without_events() {
+        let mut sim = create_simulation();
+        let entity_id = EntityId::new("person_001").unwrap();
+        let anchor = sim.reference_date();
+        sim.add_entity(create_maturing_human("person_001", true), anchor);
 
-        // The cloned state should also have the cached alerts
-        let alerts2 = cloned.alerts();
-        assert!(alerts2.is_empty());
+        let handle = sim.entity(&entity_id).unwrap();
+        let at_20 = handle.state_at(anchor);
+        let at_70 = handle.state_at(Timestamp::from_ymd_hms(2074, 1, 1, 0, 0, 0));
+        let at_15 = handle.state_at(Timestamp::from_ymd_hms(2019, 1, 1, 0, 0, 0));
+
+        let c = StatePath::Hexaco(HexacoPath::Conscientiousness);
+        let n = StatePath::Hexaco(HexacoPath::Neuroticism);
+        assert!(at_70.get_effective(c) > at_20.get_effective(c) + 0.2);
+        assert!(at_70.get_effective(n) < at_20.get_effective(n) - 0.2);
+        // Backward queries undo the drift
+        assert!(at_15.get_effective(c) <= at_20.get_effective(c));
     }
 
     #[test]
-    fn computed_state_clone_without_cached_alerts() {
-        // Test cloning when alerts haven't been accessed yet (None branch)
+    fn normative_maturation_can_be_disabled() {
         let mut sim = create_simulation();
-        let entity = create_human("person_001");
+        let entity_id = EntityId::new("person_001").unwrap();
         let anchor = sim.reference_date();
-        sim.add_entity(entity, anchor);
+        sim.add_entity(create_maturing_human("person_001", false), anchor);
 
-        let handle = sim.entity(&EntityId::new("person_001").unwrap()).unwrap();
-        let state = handle.state_at(anchor);
+        let handle = sim.entity(&entity_id).unwrap();
+        let at_70 = handle.state_at(Timestamp::from_ymd_hms(2074, 1, 1, 0, 0, 0));
 
-        // Clone WITHOUT accessing alerts first
-        let cloned = state.clone();
+        let c = StatePath::Hexaco(HexacoPath::Conscientiousness);
+        assert!(at_70.get_effective(c).abs() < f64::from(f32::EPSILON));
+    }
 
-        // Both original and cloned should work independently
-        let alerts1 = state.alerts();
-        let alerts2 = cloned.alerts();
-        assert!(alerts1.is_empty());
+    #[test]
+    fn normative_maturation_composes_with_formative_shifts() {
+        let anchor = Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+        let query = Timestamp::from_ymd_hms(2054, 1, 1, 0, 0, 0);
+        let a = StatePath::Hexaco(HexacoPath::Agreeableness);
+
+        let agreeableness_at = |maturation: bool, with_event: bool| {
+            let mut sim = create_simulation();
+            let entity_id = EntityId::new("person_001").unwrap();
+            sim.add_entity(create_maturing_human("person_001", maturation), anchor);
+            if with_event {
+                let event = EventBuilder::new(EventType::Violence)
+                    .target(entity_id.clone())
+                    .severity(0.8)
+                    .with_base_shift(HexacoPath::Agreeableness, -0.15)
+                    .build()
+                    .unwrap();
+                sim.add_event(event, Timestamp::from_ymd_hms(2030, 1, 1, 0, 0, 0));
+            }
+            sim.entity(&entity_id)
+                .unwrap()
+                .state_at(query)
+                .get_effective(a)
+        };
+
+        let drift_only = agreeableness_at(true, false);
+        let shift_only = agreeableness_at(false, true);
+        let both = agreeableness_at(true, true);
+
+        assert!(drift_only > 0.0);
+        assert!(shift_only < 0.0);
+        assert!((both - (drift_only + shift_only)).abs() < 1e-4);
+    }
+
+    fn neuroticism_after_abuse(
+        policy: crate::enums::FormativeShiftPolicy,
+        explicit: Option<f32>,
+    ) -> f64 {
+        let mut sim = create_simulation();
+        let entity_id = EntityId::new("person_001").unwrap();
+        let mut entity = create_maturing_human("person_001", false);
+        entity
+            .individual_state_mut()
+            .config_mut()
+            .set_formative_shift_policy(policy);
+        sim.add_entity(entity, sim.reference_date());
+
+        let mut builder = EventBuilder::new(EventType::ChildhoodAbuse)
+            .target(entity_id.clone())
+            .severity(0.9);
+        if let Some(amount) = explicit {
+            builder = builder.with_base_shift(HexacoPath::Neuroticism, amount);
+        }
+        sim.add_event(
+            builder.build().unwrap(),
+            Timestamp::from_ymd_hms(2024, 6, 1, 0, 0, 0),
+        );
+
+        sim.entity(&entity_id)
+            .unwrap()
+            .state_at(Timestamp::from_ymd_hms(2026, 1, 1, 0, 0, 0))
+            .get_effective(StatePath::Hexaco(HexacoPath::Neuroticism))
+    }
+
+    #[test]
+    fn automatic_formative_shifts_are_opt_in() {
+        use crate::enums::FormativeShiftPolicy;
+
+        let explicit_only = neuroticism_after_abuse(FormativeShiftPolicy::ExplicitOnly, None);
+        let automatic = neuroticism_after_abuse(FormativeShiftPolicy::Automatic, None);
+
+        assert!(explicit_only.abs() < f64::from(f32::EPSILON));
+        assert!(automatic > 0.05);
+        assert!(automatic <= f64::from(crate::state::MAX_SINGLE_EVENT_SHIFT));
+    }
+
+    #[test]
+    fn explicit_formative_shifts_override_automatic() {
+        use crate::enums::FormativeShiftPolicy;
+
+        let explicit = neuroticism_after_abuse(FormativeShiftPolicy::ExplicitOnly, Some(-0.1));
+        let both = neuroticism_after_abuse(FormativeShiftPolicy::Automatic, Some(-0.1));
+
+        assert!(explicit < 0.0);
+        assert!((both - explicit).abs() < f64::from(f32::EPSILON));
+    }
+
+    fn baseline_after_event(
+        event_type: EventType,
+        policy: crate::enums::FormativeShiftPolicy,
+        explicit: Option<(StatePath, f32)>,
+        path: StatePath,
+    ) -> (f32, f32) {
+        let mut sim = create_simulation();
+        let entity_id = EntityId::new("person_001").unwrap();
+        let mut entity = create_maturing_human("person_001", false);
+        entity
+            .individual_state_mut()
+            .config_mut()
+            .set_formative_shift_policy(policy);
+        let before = entity.individual_state().get(path).unwrap().base();
+        sim.add_entity(entity, sim.reference_date());
+
+        let mut builder = EventBuilder::new(event_type)
+            .target(entity_id.clone())
+            .severity(0.9);
+        if let Some((shift_path, amount)) = explicit {
+            builder = builder.with_base_shift(shift_path, amount);
+        }
+        sim.add_event(
+            builder.build().unwrap(),
+            Timestamp::from_ymd_hms(2024, 6, 1, 0, 0, 0),
+        );
+
+        let after = sim
+            .entity(&entity_id)
+            .unwrap()
+            .state_at(Timestamp::from_ymd_hms(2026, 1, 1, 0, 0, 0))
+            .individual_state()
+            .get(path)
+            .unwrap()
+            .base();
+        (before, after)
+    }
+
+    #[test]
+    fn explicit_baseline_shift_lowers_trust_propensity() {
+        use crate::enums::FormativeShiftPolicy;
+
+        let trust = StatePath::Disposition(DispositionPath::TrustPropensity);
+        let (before, after) = baseline_after_event(
+            EventType::Betrayal,
+            FormativeShiftPolicy::ExplicitOnly,
+            Some((trust, -0.5)),
+            trust,
+        );
+
+        assert!(after < before);
+        assert!(before - after <= crate::state::MAX_SINGLE_EVENT_SHIFT + f32::EPSILON);
+    }
+
+    #[test]
+    fn automatic_baseline_shifts_follow_profile() {
+        use crate::enums::FormativeShiftPolicy;
+
+        let loneliness = StatePath::SocialCognition(SocialCognitionPath::Loneliness);
+        let (before, after) = baseline_after_event(
+            EventType::Bereavement,
+            FormativeShiftPolicy::Automatic,
+            None,
+            loneliness,
+        );
+        assert!(after > before);
+
+        let self_worth = StatePath::MentalHealth(MentalHealthPath::SelfWorth);
+        let (before, after) = baseline_after_event(
+            EventType::ChildhoodAbuse,
+            FormativeShiftPolicy::Automatic,
+            None,
+            self_worth,
+        );
+        assert!(after < before);
+
+        let (before, after) = baseline_after_event(
+            EventType::ChildhoodAbuse,
+            FormativeShiftPolicy::ExplicitOnly,
+            None,
+            self_worth,
+        );
+        assert!((after - before).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn regression_without_events_is_exact() {
+        let mut sim = create_simulation();
+        let mut entity = create_human("person_001");
+        entity
+            .individual_state_mut()
+            .mood_mut()
+            .add_valence_delta(0.1);
+        let entity_id = EntityId::new("person_001").unwrap();
+
+        let anchor = Timestamp::from_ymd_hms(2024, 6, 1, 12, 0, 0);
+        sim.add_entity(entity, anchor);
+
+        let past = Timestamp::from_ymd_hms(2024, 6, 1, 6, 0, 0);
+        let state = sim.entity(&entity_id).unwrap().state_at(past);
+
+        // One valence half-life back: the delta doubles in closed form
+        assert!(state.regression_quality().is_exact());
+        assert!((state.individual_state().mood().valence_delta() - 0.2).abs() < 1e-5);
+        let valence = StatePath::Mood(MoodPath::Valence);
+        let bound = state.regression_report().error_bound(valence);
+        assert!(bound > 0.0 && bound < 0.01);
+    }
+
+    #[test]
+    fn regression_without_trauma_is_exact() {
+        let mut sim = create_simulation();
+        let entity = create_human("person_001");
+        let entity_id = EntityId::new("person_001").unwrap();
+
+        // Set anchor to a later date
+        let anchor = Timestamp::from_ymd_hms(2024, 6, 1, 0, 0, 0);
+        sim.add_entity(entity, anchor);
+
+        // Add a social exclusion event (not trauma) before the anchor
+        let social_event = EventBuilder::new(EventType::SocialExclusion)
+            .target(entity_id.clone())
+            .severity(0.5)
+            .build()
+            .unwrap();
+        let event_time = Timestamp::from_ymd_hms(2024, 3, 1, 0, 0, 0);
+        sim.add_event(social_event, event_time);
+
+        // Query state before the event (backward regression)
+        let past = Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+        let handle = sim.entity(&entity_id).unwrap();
+        let state = handle.state_at(past);
+
+        // Regression through non-trauma events should be exact
+        assert!(state.regression_quality().is_exact());
+    }
+
+    fn loneliness_with_friend(warmth: Option<f32>) -> (f64, f64, f64) {
+        use crate::enums::{Direction, RelationshipSchema};
+
+        let mut sim = create_simulation();
+        let person = EntityId::new("person_001").unwrap();
+        let friend = EntityId::new("friend_001").unwrap();
+        let anchor = sim.reference_date();
+        sim.add_entity(create_human("person_001"), anchor);
+        sim.add_entity(create_human("friend_001"), anchor);
+
+        if let Some(warmth) = warmth {
+            let rel_id =
+                sim.add_relationship(person.clone(), friend, RelationshipSchema::Peer, anchor);
+            let rel = sim
+                .get_relationship_mut(&rel_id)
+                .unwrap()
+                .relationship_mut();
+            rel.directional_mut(Direction::BToA)
+                .warmth_mut()
+                .set_base(warmth);
+            rel.shared_mut().intimacy_mut().set_base(warmth);
+        }
+
+        let state = sim
+            .entity(&person)
+            .unwrap()
+            .state_at(anchor + Duration::days(30));
+        (
+            state.get_effective(StatePath::SocialCognition(SocialCognitionPath::Loneliness)),
+            state.get_effective(StatePath::Needs(crate::enums::NeedsPath::Stress)),
+            state.get_effective(StatePath::SocialCognition(
+                SocialCognitionPath::PerceivedReciprocalCaring,
+            )),
+        )
+    }
+
+    #[test]
+    fn supportive_friend_buffers_projected_state() {
+        let (alone_lonely, alone_stress, alone_caring) = loneliness_with_friend(None);
+        let (cool_lonely, _, _) = loneliness_with_friend(Some(0.1));
+        let (warm_lonely, warm_stress, warm_caring) = loneliness_with_friend(Some(0.9));
+
+        assert!(warm_lonely < cool_lonely);
+        assert!(cool_lonely < alone_lonely);
+        assert!(warm_stress < alone_stress);
+        assert!(warm_caring > alone_caring);
+    }
+
+    fn tb_with_partner(coupled: bool, estranged: bool) -> (f64, f64) {
+        use crate::enums::{Direction, RelationshipSchema};
+        use crate::relationship::RelationshipStage;
+
+        let mut sim = create_simulation();
+        let person = EntityId::new("person_001").unwrap();
+        let anchor = sim.reference_date();
+        let mut entity = create_human("person_001");
+        entity
+            .individual_state_mut()
+            .config_mut()
+            .set_relationship_coupling_enabled(coupled);
+        sim.add_entity(entity, anchor);
+
+        let rel_id = sim.add_relationship(
+            person.clone(),
+            EntityId::new("partner_001").unwrap(),
+            RelationshipSchema::Romantic,
+            anchor,
+        );
+        let rel = sim
+            .get_relationship_mut(&rel_id)
+            .unwrap()
+            .relationship_mut();
+        if estranged {
+            rel.set_stage(RelationshipStage::Estranged).unwrap();
+            rel.directional_mut(Direction::BToA)
+                .warmth_mut()
+                .set_base(0.05);
+        } else {
+            rel.set_stage(RelationshipStage::Intimate).unwrap();
+            rel.directional_mut(Direction::BToA)
+                .warmth_mut()
+                .set_base(0.9);
+            rel.shared_mut().intimacy_mut().set_base(0.9);
+        }
+
+        let state = sim
+            .entity(&person)
+            .unwrap()
+            .state_at(anchor + Duration::days(7));
+        (
+            state.get_effective(StatePath::SocialCognition(SocialCognitionPath::Loneliness)),
+            state.get_effective(StatePath::MentalHealth(
+                MentalHealthPath::ThwartedBelongingness,
+            )),
+        )
+    }
+
+    #[test]
+    fn relationship_coupling_makes_tb_respond_to_estrangement() {
+        let (close_lonely, close_tb) = tb_with_partner(true, false);
+        let (estranged_lonely, estranged_tb) = tb_with_partner(true, true);
+        assert!(estranged_lonely > close_lonely);
+        assert!(estranged_tb > close_tb);
+
+        // Without coupling, estrangement only removes support buffering
+        let (uncoupled_close, _) = tb_with_partner(false, false);
+        let (uncoupled_estranged, _) = tb_with_partner(false, true);
+        assert!(
+            estranged_lonely - close_lonely > uncoupled_estranged - uncoupled_close,
+            "coupling should amplify the loneliness response"
+        );
+    }
+
+    #[test]
+    fn later_betrayal_leaves_earlier_support_unchanged() {
+        use crate::enums::{Direction, RelationshipSchema};
+
+        let mut sim = create_simulation();
+        let person = EntityId::new("person_001").unwrap();
+        let friend = EntityId::new("friend_001").unwrap();
+        let anchor = sim.reference_date();
+        sim.add_entity(create_human("person_001"), anchor);
+        let rel_id = sim.add_relationship(
+            person.clone(),
+            friend.clone(),
+            RelationshipSchema::Peer,
+            anchor,
+        );
+        let rel = sim
+            .get_relationship_mut(&rel_id)
+            .unwrap()
+            .relationship_mut();
+        rel.directional_mut(Direction::BToA)
+            .warmth_mut()
+            .set_base(0.8);
+        rel.shared_mut().intimacy_mut().set_base(0.8);
+        let support = EventBuilder::new(EventType::Support)
+            .source(friend.clone())
+            .target(person.clone())
+            .severity(0.6)
+            .build()
+            .unwrap();
+        sim.add_event(support, anchor + Duration::days(5));
+
+        let query = anchor + Duration::days(25);
+        let before = sim.entity(&person).unwrap().state_at(query);
+
+        let betrayal = EventBuilder::new(EventType::Betrayal)
+            .source(friend)
+            .target(person.clone())
+            .severity(0.9)
+            .build()
+            .unwrap();
+        sim.add_event(betrayal, anchor + Duration::days(200));
+        let after = sim.entity(&person).unwrap().state_at(query);
+
+        assert_eq!(before.individual_state(), after.individual_state());
+        let then = sim.relationship_at(&rel_id, query).unwrap();
+        assert!(then
+            .antecedent_history(Direction::AToB)
+            .iter()
+            .all(|antecedent| antecedent.timestamp() <= query));
+    }
+
+    #[test]
+    fn relationship_formed_after_query_does_not_support() {
+        use crate::enums::RelationshipSchema;
+
+        let mut sim = create_simulation();
+        let person = EntityId::new("person_001").unwrap();
+        let anchor = sim.reference_date();
+        sim.add_entity(create_human("person_001"), anchor);
+        let query = anchor + Duration::days(30);
+        let baseline = sim.entity(&person).unwrap().state_at(query);
+
+        sim.add_relationship(
+            person.clone(),
+            EntityId::new("friend_001").unwrap(),
+            RelationshipSchema::Peer,
+            anchor + Duration::days(60),
+        );
+        let later = sim.entity(&person).unwrap().state_at(query);
+
+        assert_eq!(baseline.individual_state(), later.individual_state());
+    }
+
+    #[test]
+    fn computed_state_clone_with_cached_alerts() {
+        let mut sim = create_simulation();
+        let entity = create_human("person_001");
+        let anchor = sim.reference_date();
+        sim.add_entity(entity, anchor);
+
+        let handle = sim.entity(&EntityId::new("person_001").unwrap()).unwrap();
+        let state = handle.state_at(anchor);
+
+        // Compute and cache alerts first
+        let alerts1 = state.alerts();
+        assert!(alerts1.is_empty());
+
+        // Clone the state with cached alerts
+        let cloned = state.clone();
+
+        // The cloned state should also have the cached alerts
+        let alerts2 = cloned.alerts();
+        assert!(alerts2.is_empty());
+    }
+
+    #[test]
+    fn computed_state_clone_without_cached_alerts() {
+        // Test cloning when alerts haven't been accessed yet (None branch)
+        let mut sim = create_simulation();
+        let entity = create_human("person_001");
+        let anchor = sim.reference_date();
+        sim.add_entity(entity, anchor);
+
+        let handle = sim.entity(&EntityId::new("person_001").unwrap()).unwrap();
+        let state = handle.state_at(anchor);
+
+        // Clone WITHOUT accessing alerts first
+        let cloned = state.clone();
+
+        // Both original and cloned should work independently
+        let alerts1 = state.alerts();
+        let alerts2 = cloned.alerts();
+        assert!(alerts1.is_empty());
         assert!(alerts2.is_empty());
     }
 
@@ -1902,7 +2914,7 @@ mod tests {
 
         // State should be valid (developmental effects applied correctly)
         let valence = state.get_effective(StatePath::Mood(crate::enums::MoodPath::Valence));
-        assert!(valence >= -1.0 && valence <= 1.0);
+        assert!((-1.0..=1.0).contains(&valence));
     }
 
     #[test]
@@ -2321,7 +3333,7 @@ mod tests {
 
         // Just verify it doesn't panic and produces valid state
         let openness = state.get_effective(StatePath::Hexaco(HexacoPath::Openness));
-        assert!(openness >= -1.0 && openness <= 1.0);
+        assert!((-1.0..=1.0).contains(&openness));
     }
 
     #[test]
@@ -2435,10 +3447,33 @@ mod tests {
                 HexacoPath::Neuroticism => result.hexaco().neuroticism(),
                 HexacoPath::HonestyHumility => result.hexaco().honesty_humility(),
             };
-            assert!(value >= -1.0 && value <= 1.0);
+            assert!((-1.0..=1.0).contains(&value));
         }
     }
 
+    #[test]
+    fn apply_base_shifts_clamps_baseline_to_bounds() {
+        use crate::state::BaseShiftRecord;
+
+        let path = StatePath::SocialCognition(SocialCognitionPath::Loneliness);
+        let records = vec![
+            BaseShiftRecord::new(Duration::days(1), path, 0.15),
+            BaseShiftRecord::new(Duration::days(2), path, -0.15),
+            BaseShiftRecord::new(Duration::days(3), path, -0.15),
+        ];
+        let mut state = IndividualState::new();
+        state.get_mut(path).unwrap().set_base(0.1);
+
+        let result = apply_base_shifts_to_state(
+            state,
+            &records,
+            Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0),
+        );
+
+        let value = result.get(path).unwrap();
+        assert!((value.base() - value.min_bound()).abs() < f32::EPSILON);
+    }
+
     #[test]
     fn collect_base_shifts_event_after_query_timestamp_skipped() {
         // Direct test: event with base shift AFTER query timestamp should be skipped (line 828)
@@ -2683,4 +3718,560 @@ mod tests {
         // Should still work (uses Duration::zero for query)
         assert!(result.hexaco().openness() >= -1.0 && result.hexaco().openness() <= 1.0);
     }
+
+    fn stressful_office() -> crate::context::Microsystem {
+        let mut work = crate::context::WorkContext {
+            workload_stress: 0.8,
+            ..Default::default()
+        };
+        work.interaction_profile.interaction_frequency = 0.8;
+        work.interaction_profile.interaction_complexity = 0.8;
+        crate::context::Microsystem::new_work(work)
+    }
+
+    fn stress_at(sim: &Simulation, id: &str, timestamp: Timestamp) -> f32 {
+        sim.entity(&EntityId::new(id).unwrap())
+            .unwrap()
+            .state_at(timestamp)
+            .individual_state()
+            .needs()
+            .stress_effective()
+    }
+
+    #[test]
+    fn context_timeline_applies_change_only_after_it_takes_effect() {
+        use crate::context::ContextChange;
+        use crate::types::MicrosystemId;
+
+        let mut sim = create_simulation();
+        let office = MicrosystemId::new("office").unwrap();
+        let anchor = sim.reference_date();
+
+        let mut veteran = create_human("veteran");
+        veteran
+            .context_mut()
+            .add_microsystem(office.clone(), stressful_office());
+        sim.add_entity(veteran, anchor);
+
+        let mut new_hire = create_human("new_hire");
+        new_hire.context_timeline_mut().schedule(
+            Timestamp::from_ymd_hms(2024, 3, 1, 0, 0, 0),
+            ContextChange::AddMicrosystem(office.clone(), stressful_office()),
+        );
+        sim.add_entity(new_hire, anchor);
+        sim.add_entity(create_human("unemployed"), anchor);
+
+        let query = Timestamp::from_ymd_hms(2024, 5, 1, 0, 0, 0);
+        let veteran_stress = stress_at(&sim, "veteran", query);
+        let new_hire_stress = stress_at(&sim, "new_hire", query);
+        let unemployed_stress = stress_at(&sim, "unemployed", query);
+        assert!(veteran_stress > new_hire_stress);
+        assert!(new_hire_stress > unemployed_stress);
+
+        let handle = sim.entity(&EntityId::new("new_hire").unwrap()).unwrap();
+        let february = Timestamp::from_ymd_hms(2024, 2, 1, 0, 0, 0);
+        assert!(handle.context_at(february).unwrap().is_empty());
+        assert!(handle
+            .context_at(query)
+            .unwrap()
+            .get_microsystem(&office)
+            .is_some());
+    }
+
+    #[test]
+    fn context_transition_event_swaps_microsystems() {
+        use crate::enums::{EventPayload, EventType};
+        use crate::event::EventBuilder;
+        use crate::types::MicrosystemId;
+
+        let mut sim = create_simulation();
+        let anchor = sim.reference_date();
+        let office = MicrosystemId::new("office").unwrap();
+        let home = MicrosystemId::new("home").unwrap();
+        for id in ["quitter", "stayer"] {
+            let mut entity = create_human(id);
+            entity
+                .context_mut()
+                .add_microsystem(office.clone(), stressful_office());
+            sim.add_entity(entity, anchor);
+        }
+
+        // Both experience a transition event, but only the quitter leaves the office
+        let transitions = [
+            ("quitter", office.clone()),
+            ("stayer", MicrosystemId::new("gym").unwrap()),
+        ];
+        for (id, from) in transitions {
+            let event = EventBuilder::new(EventType::ContextTransition)
+                .target(EntityId::new(id).unwrap())
+                .severity(0.3)
+                .payload(EventPayload::ContextTransition {
+                    from,
+                    to: home.clone(),
+                })
+                .build()
+                .unwrap();
+            sim.add_event(event, Timestamp::from_ymd_hms(2024, 2, 1, 0, 0, 0));
+        }
+
+        let query = Timestamp::from_ymd_hms(2024, 5, 1, 0, 0, 0);
+        let quitter = sim.entity(&EntityId::new("quitter").unwrap()).unwrap();
+        let context = quitter.context_at(query).unwrap();
+        assert!(context.get_microsystem(&office).is_none());
+        assert!(stress_at(&sim, "quitter", query) < stress_at(&sim, "stayer", query));
+    }
+
+    #[test]
+    fn transition_joins_shared_microsystem_as_it_was_then() {
+        use crate::context::{Microsystem, SocialContext};
+        use crate::enums::{Direction, EventPayload, EventType, RelationshipSchema};
+        use crate::event::EventBuilder;
+        use crate::types::MicrosystemId;
+
+        let mut sim = create_simulation();
+        let anchor = sim.reference_date();
+        let club = MicrosystemId::new("club").unwrap();
+        sim.add_microsystem(
+            club.clone(),
+            Microsystem::new_social(SocialContext::default()),
+        );
+        for id in ["host", "regular", "newcomer"] {
+            sim.add_entity(create_human(id), anchor);
+        }
+        for id in ["host", "regular"] {
+            sim.join_microsystem(&club, EntityId::new(id).unwrap(), anchor);
+        }
+        sim.set_emergent_climate(&club, true);
+
+        // The members only warm to each other after the newcomer arrives
+        let joined = Timestamp::from_ymd_hms(2024, 2, 1, 0, 0, 0);
+        let bonded = Timestamp::from_ymd_hms(2024, 4, 1, 0, 0, 0);
+        let tie = sim.add_relationship(
+            EntityId::new("host").unwrap(),
+            EntityId::new("regular").unwrap(),
+            RelationshipSchema::Peer,
+            bonded,
+        );
+        sim.get_relationship_mut(&tie)
+            .unwrap()
+            .relationship_mut()
+            .directional_mut(Direction::AToB)
+            .add_warmth_delta(0.6);
+        let arrival = EventBuilder::new(EventType::ContextTransition)
+            .target(EntityId::new("newcomer").unwrap())
+            .payload(EventPayload::ContextTransition {
+                from: MicrosystemId::new("home").unwrap(),
+                to: club.clone(),
+            })
+            .build()
+            .unwrap();
+        sim.add_event(arrival, joined);
+
+        let query = Timestamp::from_ymd_hms(2024, 6, 1, 0, 0, 0);
+        let then = sim.microsystem_at(&club, joined).unwrap();
+        let now = sim.microsystem_at(&club, query).unwrap();
+        assert!(now.warmth() > then.warmth());
+
+        let newcomer = sim.entity(&EntityId::new("newcomer").unwrap()).unwrap();
+        let context = newcomer.context_at(query).unwrap();
+        let seen = context.get_microsystem(&club).unwrap();
+        assert!((seen.warmth() - then.warmth()).abs() < 1e-9);
+    }
+
+    #[test]
+    fn healthcare_expansion_event_widens_access_from_its_timestamp() {
+        use crate::enums::{EventPayload, EventType, PolicyArea, PolicyJurisdiction};
+        use crate::event::EventBuilder;
+
+        let mut sim = create_simulation();
+        let anchor = sim.reference_date();
+        for id in ["covered", "uncovered"] {
+            let mut entity = create_human(id);
+            entity.context_mut().exosystem_mut().health_system_access = 0.1;
+            sim.add_entity(entity, anchor);
+        }
+
+        let expansion = EventBuilder::new(EventType::PolicyChange)
+            .target(EntityId::new("covered").unwrap())
+            .severity(0.2)
+            .payload(EventPayload::PolicyChange {
+                policy_area: PolicyArea::Healthcare,
+                favorability: 0.8,
+                magnitude: 0.6,
+                jurisdiction: PolicyJurisdiction::Target,
+            })
+            .build()
+            .unwrap();
+        sim.add_event(expansion, Timestamp::from_ymd_hms(2024, 2, 1, 0, 0, 0));
+
+        let covered = sim.entity(&EntityId::new("covered").unwrap()).unwrap();
+        let january = Timestamp::from_ymd_hms(2024, 1, 15, 0, 0, 0);
+        let access = covered
+            .context_at(january)
+            .unwrap()
+            .exosystem()
+            .health_system_access;
+        assert!((access - 0.1).abs() < 1e-9);
+
+        let query = Timestamp::from_ymd_hms(2024, 8, 1, 0, 0, 0);
+        let access = covered
+            .context_at(query)
+            .unwrap()
+            .exosystem()
+            .health_system_access;
+        assert!((access - 0.7).abs() < 1e-9);
+        assert!(stress_at(&sim, "covered", query) < stress_at(&sim, "uncovered", query));
+    }
+
+    #[test]
+    fn policy_change_reaches_every_entity_in_its_jurisdiction_once() {
+        use crate::enums::{EventPayload, EventScope, EventType, PolicyArea, PolicyJurisdiction};
+        use crate::event::EventBuilder;
+
+        let mut sim = create_simulation();
+        let anchor = sim.reference_date();
+        for id in ["mayor", "resident", "neighbor"] {
+            let mut entity = create_human(id);
+            entity
+                .context_mut()
+                .exosystem_mut()
+                .educational_system_quality = 0.3;
+            sim.add_entity(entity, anchor);
+        }
+        let education_policy = |magnitude: f64, jurisdiction: PolicyJurisdiction| {
+            EventBuilder::new(EventType::PolicyChange)
+                .target(EntityId::new("mayor").unwrap())
+                .payload(EventPayload::PolicyChange {
+                    policy_area: PolicyArea::Education,
+                    favorability: 0.5,
+                    magnitude,
+                    jurisdiction,
+                })
+                .build()
+                .unwrap()
+        };
+        let quality = |sim: &Simulation, id: &str| {
+            sim.entity(&EntityId::new(id).unwrap())
+                .unwrap()
+                .context_at(Timestamp::from_ymd_hms(2024, 6, 1, 0, 0, 0))
+                .unwrap()
+                .exosystem()
+                .educational_system_quality
+        };
+
+        // A targeted policy only changes its target's institutions
+        let pilot = education_policy(0.1, PolicyJurisdiction::Target);
+        sim.add_event(pilot, Timestamp::from_ymd_hms(2024, 1, 15, 0, 0, 0));
+        assert!((quality(&sim, "mayor") - 0.4).abs() < 1e-9);
+        assert!((quality(&sim, "resident") - 0.3).abs() < 1e-9);
+
+        // Announced to the mayor, a global reform reaches everyone
+        let reform = education_policy(0.2, PolicyJurisdiction::Global);
+        sim.add_event(reform, Timestamp::from_ymd_hms(2024, 2, 1, 0, 0, 0));
+        assert!((quality(&sim, "mayor") - 0.6).abs() < 1e-9);
+        assert!((quality(&sim, "resident") - 0.5).abs() < 1e-9);
+        assert!((quality(&sim, "neighbor") - 0.5).abs() < 1e-9);
+
+        // District funding covers one resident, however many copies go out
+        let district = vec![EntityId::new("resident").unwrap()];
+        let funding = education_policy(0.1, PolicyJurisdiction::Entities(district));
+        sim.dispatch_event(
+            funding,
+            &EventScope::Global,
+            Timestamp::from_ymd_hms(2024, 3, 1, 0, 0, 0),
+        );
+        assert!((quality(&sim, "resident") - 0.6).abs() < 1e-9);
+        assert!((quality(&sim, "mayor") - 0.6).abs() < 1e-9);
+        assert!((quality(&sim, "neighbor") - 0.5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn late_first_job_is_completed_off_time_from_its_event() {
+        use crate::context::WorkContext;
+        use crate::enums::{EventPayload, EventType};
+        use crate::event::EventBuilder;
+        use crate::types::MicrosystemId;
+
+        let job = MicrosystemId::new("job").unwrap();
+        let simulation = |schedules: bool| {
+            let mut sim = create_simulation();
+            let anchor = sim.reference_date();
+            for (id, hired) in [("on_time", 2024), ("late", 2030)] {
+                let entity = EntityBuilder::new()
+                    .id(id)
+                    .species(Species::Human)
+                    .birth_date(Timestamp::from_ymd_hms(2004, 1, 1, 0, 0, 0))
+                    .build()
+                    .unwrap();
+                sim.add_entity(entity, anchor);
+                let event = EventBuilder::new(EventType::ContextTransition)
+                    .target(EntityId::new(id).unwrap())
+                    .payload(EventPayload::ContextTransition {
+                        from: MicrosystemId::new("home").unwrap(),
+                        to: job.clone(),
+                    })
+                    .build()
+                    .unwrap();
+                sim.add_event(event, Timestamp::from_ymd_hms(hired, 3, 1, 0, 0, 0));
+            }
+            sim.add_microsystem(job.clone(), Microsystem::new_work(WorkContext::default()));
+            sim.set_normative_schedules(schedules);
+            sim
+        };
+        let sim = simulation(true);
+        let late = sim.entity(&EntityId::new("late").unwrap()).unwrap();
+        let off_time = |year: i32| -> Vec<String> {
+            late.off_time_transitions_at(Timestamp::from_ymd_hms(year, 6, 1, 0, 0, 0))
+                .unwrap()
+                .into_iter()
+                .map(|t| t.name)
+                .collect()
+        };
+
+        // Missing once the window closes at 24, late once completed at 26
+        assert!(off_time(2026).is_empty());
+        assert_eq!(off_time(2029), vec!["first_job"]);
+        assert_eq!(off_time(2030), vec!["first_job"]);
+        let context = late
+            .context_at(Timestamp::from_ymd_hms(2030, 6, 1, 0, 0, 0))
+            .unwrap();
+        let first_job = context
+            .chronosystem()
+            .get_normative_transition("first_job")
+            .unwrap();
+        assert!(first_job.is_late());
+
+        let on_time = sim.entity(&EntityId::new("on_time").unwrap()).unwrap();
+        let query = Timestamp::from_ymd_hms(2030, 6, 1, 0, 0, 0);
+        assert!(on_time.off_time_transitions_at(query).unwrap().is_empty());
+
+        // Off-time stress accrues from the hire on
+        let unscheduled = simulation(false);
+        let hired = Timestamp::from_ymd_hms(2030, 3, 1, 0, 0, 0);
+        assert_eq!(
+            stress_at(&sim, "late", hired),
+            stress_at(&unscheduled, "late", hired)
+        );
+        let after_hire = Timestamp::from_ymd_hms(2030, 4, 1, 0, 0, 0);
+        assert!(stress_at(&sim, "late", after_hire) > stress_at(&unscheduled, "late", after_hire));
+        assert_eq!(
+            stress_at(&sim, "on_time", query),
+            stress_at(&unscheduled, "on_time", query)
+        );
+    }
+
+    #[test]
+    fn culture_in_force_at_event_moderates_its_appraisal() {
+        use crate::context::{ContextChange, MacrosystemContext};
+        use crate::enums::EventType;
+        use crate::event::EventBuilder;
+
+        let mut sim = create_simulation();
+        let anchor = sim.reference_date();
+        let mut collectivist = MacrosystemContext::default();
+        collectivist.cultural_orientation.individualism_collectivism = -0.8;
+
+        // Both move into a collectivist culture, one before the shaming and
+        // one after it
+        let moves = [("early", 2), ("late", 4)];
+        for (id, month) in moves {
+            let mut entity = create_human(id);
+            entity.context_timeline_mut().schedule(
+                Timestamp::from_ymd_hms(2024, month, 1, 0, 0, 0),
+                ContextChange::ReplaceMacrosystem(collectivist.clone()),
+            );
+            sim.add_entity(entity, anchor);
+
+            let event = EventBuilder::new(EventType::ShamingEvent)
+                .target(EntityId::new(id).unwrap())
+                .severity(0.6)
+                .build()
+                .unwrap();
+            sim.add_event(event, Timestamp::from_ymd_hms(2024, 3, 1, 0, 0, 0));
+        }
+
+        let query = Timestamp::from_ymd_hms(2024, 5, 1, 0, 0, 0);
+        let liability = |id: &str| {
+            sim.entity(&EntityId::new(id).unwrap())
+                .unwrap()
+                .state_at(query)
+                .individual_state()
+                .social_cognition()
+                .perceived_liability_effective()
+        };
+        assert!(liability("early") > liability("late"));
+    }
+
+    #[test]
+    fn evolving_culture_carries_violence_to_bystanders_and_recovers() {
+        use crate::context::{MacrosystemContext, MacrosystemDynamics};
+        use crate::enums::EventType;
+
+        let build = |assault: bool| {
+            let mut sim = create_simulation();
+            let anchor = sim.reference_date();
+            for id in ["victim", "bystander"] {
+                sim.add_entity(create_human(id), anchor);
+            }
+            sim.set_macrosystem_dynamics(Some(MacrosystemDynamics::new()));
+            if assault {
+                let event = EventBuilder::new(EventType::Violence)
+                    .target(EntityId::new("victim").unwrap())
+                    .severity(0.9)
+                    .build()
+                    .unwrap();
+                sim.add_event(event, Timestamp::from_ymd_hms(2024, 2, 1, 0, 0, 0));
+            }
+            sim
+        };
+        let (violent, peaceful) = (build(true), build(false));
+
+        let query = Timestamp::from_ymd_hms(2024, 6, 1, 0, 0, 0);
+        assert!(stress_at(&violent, "bystander", query) > stress_at(&peaceful, "bystander", query));
+
+        let bystander = violent
+            .entity(&EntityId::new("bystander").unwrap())
+            .unwrap();
+        let soon = bystander.context_at(query).unwrap();
+        let decade = bystander
+            .context_at(Timestamp::from_ymd_hms(2034, 6, 1, 0, 0, 0))
+            .unwrap();
+        let authored = MacrosystemContext::default();
+        assert!(soon.macrosystem().collective_trauma > authored.collective_trauma);
+        assert!(soon.macrosystem_shift().is_none());
+        let rule_of_law =
+            |context: &EcologicalContext| context.macrosystem().institutional_structure.rule_of_law;
+        assert!(rule_of_law(&soon) < rule_of_law(&decade));
+        assert!(rule_of_law(&decade) < authored.institutional_structure.rule_of_law);
+    }
+
+    #[test]
+    fn parent_job_loss_reaches_child_through_exosystem() {
+        use crate::context::{Microsystem, WorkContext};
+        use crate::enums::{EventType, FormativeShiftPolicy};
+        use crate::event::EventBuilder;
+        use crate::types::MicrosystemId;
+
+        let mut sim = create_simulation();
+        let anchor = sim.reference_date();
+        for (parent, child) in [("mom_a", "kid_a"), ("mom_b", "kid_b")] {
+            let mut mom = create_human(parent);
+            mom.individual_state_mut()
+                .config_mut()
+                .set_formative_shift_policy(FormativeShiftPolicy::Automatic);
+            mom.context_mut().add_microsystem(
+                MicrosystemId::new("job").unwrap(),
+                Microsystem::new_work(WorkContext::default()),
+            );
+            sim.add_entity(mom, anchor);
+
+            let mut kid = EntityBuilder::new()
+                .id(child)
+                .species(Species::Human)
+                .age(Duration::years(8))
+                .build()
+                .unwrap();
+            kid.context_mut().exosystem_mut().parent_ids = vec![EntityId::new(parent).unwrap()];
+            sim.add_entity(kid, anchor);
+        }
+
+        let job_loss = EventBuilder::new(EventType::JobLoss)
+            .target(EntityId::new("mom_a").unwrap())
+            .severity(0.9)
+            .build()
+            .unwrap();
+        sim.add_event(job_loss, Timestamp::from_ymd_hms(2024, 1, 10, 0, 0, 0));
+
+        let query = Timestamp::from_ymd_hms(2024, 3, 1, 0, 0, 0);
+        let parent_work = |child: &str| {
+            sim.entity(&EntityId::new(child).unwrap())
+                .unwrap()
+                .context_at(query)
+                .unwrap()
+                .exosystem()
+                .parent_work_environment
+                .clone()
+                .unwrap()
+        };
+        assert!(parent_work("kid_a").income_stability < parent_work("kid_b").income_stability);
+
+        let caring = |child: &str| {
+            sim.entity(&EntityId::new(child).unwrap())
+                .unwrap()
+                .state_at(query)
+                .individual_state()
+                .social_cognition()
+                .perceived_reciprocal_caring_effective()
+        };
+        assert!(caring("kid_a") < caring("kid_b"));
+    }
+
+    #[test]
+    fn overwork_budget_starves_family_and_adds_fatigue() {
+        use crate::context::{
+            ContextChange, FamilyContext, Microsystem, MicrosystemType, TimeBudget, WorkContext,
+            INTERACTION_FREQUENCY_THRESHOLD,
+        };
+        use crate::types::MicrosystemId;
+
+        let mut sim = create_simulation();
+        let anchor = sim.reference_date();
+        let family = MicrosystemId::new("family").unwrap();
+        let balanced = TimeBudget::new()
+            .with_hours(MicrosystemType::Work, 40.0)
+            .with_hours(MicrosystemType::Family, 28.0)
+            .with_hours(MicrosystemType::Social, 10.0);
+        let overwork = balanced.clone().with_hours(MicrosystemType::Work, 80.0);
+
+        for id in ["balanced", "overworked"] {
+            let mut entity = create_human(id);
+            let context = entity.context_mut();
+            context.add_microsystem(
+                MicrosystemId::new("job").unwrap(),
+                Microsystem::new_work(WorkContext::default()),
+            );
+            let mut home = FamilyContext {
+                warmth: 0.9,
+                family_satisfaction: 0.9,
+                ..Default::default()
+            };
+            home.interaction_profile.interaction_complexity = 0.8;
+            context.add_microsystem(family.clone(), Microsystem::new_family(home));
+            context.set_time_budget(Some(balanced.clone()));
+            if id == "overworked" {
+                entity.context_timeline_mut().schedule(
+                    Timestamp::from_ymd_hms(2024, 2, 1, 0, 0, 0),
+                    ContextChange::SetTimeBudget(Some(overwork.clone())),
+                );
+            }
+            sim.add_entity(entity, anchor);
+        }
+
+        let handle = sim.entity(&EntityId::new("overworked").unwrap()).unwrap();
+        let family_frequency = |timestamp| {
+            handle
+                .context_at(timestamp)
+                .unwrap()
+                .get_microsystem(&family)
+                .unwrap()
+                .interaction_frequency()
+        };
+        assert!(
+            (family_frequency(Timestamp::from_ymd_hms(2024, 1, 15, 0, 0, 0)) - 1.0).abs() < 1e-9
+        );
+        let query = Timestamp::from_ymd_hms(2024, 5, 1, 0, 0, 0);
+        assert!(family_frequency(query) < INTERACTION_FREQUENCY_THRESHOLD);
+
+        let needs = |id: &str| {
+            sim.entity(&EntityId::new(id).unwrap())
+                .unwrap()
+                .state_at(query)
+                .individual_state()
+                .needs()
+                .clone()
+        };
+        let (rested, overworked) = (needs("balanced"), needs("overworked"));
+        assert!(overworked.fatigue_effective() > rested.fatigue_effective());
+        assert!(overworked.stress_effective() > rested.stress_effective());
+    }
 }
@@ -6,7 +6,7 @@
 use serde::{Deserialize, Serialize};
 
 /// Demand characteristics for observable social signals.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
 pub struct DemandCharacteristics {
     /// Gender identity or presentation.
     pub gender: String,
@@ -21,17 +21,6 @@ pub struct DemandCharacteristics {
     pub observable_signals: Vec<String>,
 }
 
-impl Default for DemandCharacteristics {
-    fn default() -> Self {
-        DemandCharacteristics {
-            gender: String::new(),
-            ethnicity: String::new(),
-            appearance: String::new(),
-            observable_signals: Vec::new(),
-        }
-    }
-}
-
 impl DemandCharacteristics {
     /// Creates a new demand characteristics record with empty defaults.
     #[must_use]
@@ -4,7 +4,7 @@
 //! and other entity-type-specific configuration. Different entity types
 //! (Human, Animal) have different subsystem requirements.
 
-use crate::enums::{Species, SubsystemId};
+use crate::enums::{FormativeShiftPolicy, Species, SubsystemId};
 use serde::{Deserialize, Serialize};
 use std::collections::HashSet;
 
@@ -52,6 +52,27 @@ pub struct EntityModelConfig {
     /// Effects are blocked when complexity is below this threshold.
     /// Default: 0.3
     proximal_process_complexity_threshold: f64,
+
+    /// Whether HEXACO traits drift with age (normative maturation).
+    /// Default: false
+    #[serde(default)]
+    normative_maturation_enabled: bool,
+
+    /// Where formative HEXACO base shifts come from.
+    /// Default: ExplicitOnly
+    #[serde(default)]
+    formative_shift_policy: FormativeShiftPolicy,
+
+    /// Whether TB/PB inputs are derived from relationship dimensions.
+    /// Default: false
+    #[serde(default)]
+    relationship_coupling_enabled: bool,
+
+    /// Whether new relationships start from a prior inherited through
+    /// mutual contacts.
+    /// Default: false
+    #[serde(default)]
+    transitive_trust_enabled: bool,
 }
 
 /// Default proximal process frequency threshold.
@@ -83,6 +104,10 @@ impl EntityModelConfig {
             time_scale: 1.0,
             proximal_process_frequency_threshold: DEFAULT_PROXIMAL_FREQUENCY_THRESHOLD,
             proximal_process_complexity_threshold: DEFAULT_PROXIMAL_COMPLEXITY_THRESHOLD,
+            normative_maturation_enabled: false,
+            formative_shift_policy: FormativeShiftPolicy::ExplicitOnly,
+            relationship_coupling_enabled: false,
+            transitive_trust_enabled: false,
         }
     }
 
@@ -115,6 +140,10 @@ impl EntityModelConfig {
             time_scale: 1.0,
             proximal_process_frequency_threshold: DEFAULT_PROXIMAL_FREQUENCY_THRESHOLD,
             proximal_process_complexity_threshold: DEFAULT_PROXIMAL_COMPLEXITY_THRESHOLD,
+            normative_maturation_enabled: false,
+            formative_shift_policy: FormativeShiftPolicy::ExplicitOnly,
+            relationship_coupling_enabled: false,
+            transitive_trust_enabled: false,
         }
     }
 
@@ -186,6 +215,10 @@ impl EntityModelConfig {
             time_scale: 1.0,
             proximal_process_frequency_threshold: DEFAULT_PROXIMAL_FREQUENCY_THRESHOLD,
             proximal_process_complexity_threshold: DEFAULT_PROXIMAL_COMPLEXITY_THRESHOLD,
+            normative_maturation_enabled: false,
+            formative_shift_policy: FormativeShiftPolicy::ExplicitOnly,
+            relationship_coupling_enabled: false,
+            transitive_trust_enabled: false,
         }
     }
 
@@ -206,6 +239,10 @@ impl EntityModelConfig {
             time_scale: 1.0,
             proximal_process_frequency_threshold: DEFAULT_PROXIMAL_FREQUENCY_THRESHOLD,
             proximal_process_complexity_threshold: DEFAULT_PROXIMAL_COMPLEXITY_THRESHOLD,
+            normative_maturation_enabled: false,
+            formative_shift_policy: FormativeShiftPolicy::ExplicitOnly,
+            relationship_coupling_enabled: false,
+            transitive_trust_enabled: false,
         }
     }
 
@@ -260,6 +297,34 @@ impl EntityModelConfig {
         self
     }
 
+    /// Enables or disables normative personality maturation.
+    #[must_use]
+    pub fn with_normative_maturation(mut self, enabled: bool) -> Self {
+        self.normative_maturation_enabled = enabled;
+        self
+    }
+
+    /// Sets the formative shift policy.
+    #[must_use]
+    pub fn with_formative_shift_policy(mut self, policy: FormativeShiftPolicy) -> Self {
+        self.formative_shift_policy = policy;
+        self
+    }
+
+    /// Enables or disables coupling of TB/PB inputs to relationships.
+    #[must_use]
+    pub fn with_relationship_coupling(mut self, enabled: bool) -> Self {
+        self.relationship_coupling_enabled = enabled;
+        self
+    }
+
+    /// Enables or disables transitive trust priors for new relationships.
+    #[must_use]
+    pub fn with_transitive_trust(mut self, enabled: bool) -> Self {
+        self.transitive_trust_enabled = enabled;
+        self
+    }
+
     // Accessors
 
     /// Checks if a subsystem is active.
@@ -319,6 +384,40 @@ impl EntityModelConfig {
         self.proximal_process_complexity_threshold
     }
 
+    /// Returns true if HEXACO traits drift with age.
+    ///
+    /// Maturation only applies when personality modeling is also enabled.
+    #[must_use]
+    pub fn normative_maturation_enabled(&self) -> bool {
+        self.normative_maturation_enabled && self.personality_enabled
+    }
+
+    /// Returns the formative shift policy.
+    #[must_use]
+    pub fn formative_shift_policy(&self) -> FormativeShiftPolicy {
+        self.formative_shift_policy
+    }
+
+    /// Returns true if TB/PB inputs are derived from relationship dimensions.
+    ///
+    /// When enabled, perceived reciprocal caring follows incoming warmth,
+    /// perceived liability follows dependence/obligation strain, and
+    /// loneliness follows the entity's close ties.
+    #[must_use]
+    pub fn relationship_coupling_enabled(&self) -> bool {
+        self.relationship_coupling_enabled
+    }
+
+    /// Returns true if new relationships inherit trust through mutual contacts.
+    ///
+    /// When enabled, this entity's starting perceptions of a newcomer are
+    /// drawn toward the perceptions of contacts it trusts who already know
+    /// the newcomer.
+    #[must_use]
+    pub fn transitive_trust_enabled(&self) -> bool {
+        self.transitive_trust_enabled
+    }
+
     /// Checks whether proximal process criteria are met.
     ///
     /// Returns true if both frequency and complexity meet or exceed thresholds.
@@ -364,6 +463,26 @@ impl EntityModelConfig {
     pub fn set_proximal_complexity_threshold(&mut self, threshold: f64) {
         self.proximal_process_complexity_threshold = threshold.clamp(0.0, 1.0);
     }
+
+    /// Sets whether normative personality maturation is enabled.
+    pub fn set_normative_maturation_enabled(&mut self, enabled: bool) {
+        self.normative_maturation_enabled = enabled;
+    }
+
+    /// Sets the formative shift policy.
+    pub fn set_formative_shift_policy(&mut self, policy: FormativeShiftPolicy) {
+        self.formative_shift_policy = policy;
+    }
+
+    /// Sets whether TB/PB inputs are derived from relationship dimensions.
+    pub fn set_relationship_coupling_enabled(&mut self, enabled: bool) {
+        self.relationship_coupling_enabled = enabled;
+    }
+
+    /// Sets whether new relationships inherit trust through mutual contacts.
+    pub fn set_transitive_trust_enabled(&mut self, enabled: bool) {
+        self.transitive_trust_enabled = enabled;
+    }
 }
 
 impl Default for EntityModelConfig {
@@ -627,6 +746,86 @@ mod tests {
         assert!((config.proximal_complexity_threshold() - 0.0).abs() < f64::EPSILON);
     }
 
+    // --- Normative maturation tests ---
+
+    #[test]
+    fn normative_maturation_is_opt_in() {
+        assert!(!EntityModelConfig::human_default().normative_maturation_enabled());
+        assert!(!EntityModelConfig::animal_simple().normative_maturation_enabled());
+        assert!(!EntityModelConfig::for_species(&Species::Human).normative_maturation_enabled());
+        assert!(!EntityModelConfig::new().normative_maturation_enabled());
+    }
+
+    #[test]
+    fn configs_serialized_before_newer_fields_still_load() {
+        let mut json = serde_json::to_value(EntityModelConfig::human_default()).unwrap();
+        let fields = json.as_object_mut().unwrap();
+        for field in [
+            "normative_maturation_enabled",
+            "formative_shift_policy",
+            "relationship_coupling_enabled",
+            "transitive_trust_enabled",
+        ] {
+            assert!(fields.remove(field).is_some());
+        }
+
+        let config: EntityModelConfig = serde_json::from_value(json).unwrap();
+        assert_eq!(config, EntityModelConfig::human_default());
+    }
+
+    #[test]
+    fn normative_maturation_can_be_switched() {
+        let config = EntityModelConfig::human_default().with_normative_maturation(true);
+        assert!(config.normative_maturation_enabled());
+
+        let mut config = config;
+        config.set_normative_maturation_enabled(false);
+        assert!(!config.normative_maturation_enabled());
+        config.set_normative_maturation_enabled(true);
+
+        // Requires personality modeling
+        config.set_personality_enabled(false);
+        assert!(!config.normative_maturation_enabled());
+    }
+
+    #[test]
+    fn formative_shift_policy_is_opt_in() {
+        let config = EntityModelConfig::human_default();
+        assert_eq!(
+            config.formative_shift_policy(),
+            FormativeShiftPolicy::ExplicitOnly
+        );
+
+        let config = config.with_formative_shift_policy(FormativeShiftPolicy::Automatic);
+        assert!(config.formative_shift_policy().is_automatic());
+
+        let mut config = config;
+        config.set_formative_shift_policy(FormativeShiftPolicy::ExplicitOnly);
+        assert!(!config.formative_shift_policy().is_automatic());
+    }
+
+    #[test]
+    fn relationship_coupling_is_opt_in() {
+        assert!(!EntityModelConfig::human_default().relationship_coupling_enabled());
+
+        let config = EntityModelConfig::human_default().with_relationship_coupling(true);
+        assert!(config.relationship_coupling_enabled());
+
+        let mut config = config;
+        config.set_relationship_coupling_enabled(false);
+        assert!(!config.relationship_coupling_enabled());
+    }
+
+    #[test]
+    fn transitive_trust_is_opt_in() {
+        assert!(!EntityModelConfig::human_default().transitive_trust_enabled());
+
+        let mut config = EntityModelConfig::human_default().with_transitive_trust(true);
+        assert!(config.transitive_trust_enabled());
+        config.set_transitive_trust_enabled(false);
+        assert!(!config.transitive_trust_enabled());
+    }
+
     // --- for_species tests ---
 
     #[test]
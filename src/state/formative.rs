@@ -15,12 +15,17 @@
 //! # Key Concepts
 //!
 //! - **Trait Stability**: Each HEXACO trait has a resistance to change (0.60-0.85)
+//! - **Baseline Shifts**: Other stable baselines (trust propensity, loneliness,
+//!   self-worth, ...) can shift too, each with its own stability coefficient
 //! - **Age Plasticity**: Younger individuals show more personality change
 //! - **Sensitive Periods**: Trait-specific windows of heightened plasticity
 //! - **Diminishing Returns**: Repeated shifts approach asymptotic limits
 //! - **Partial Recovery**: Severe shifts partially settle over time
 
-use crate::enums::{HexacoPath, Species};
+use crate::enums::{
+    DispositionPath, EventType, HexacoPath, MentalHealthPath, PersonCharacteristicsPath,
+    SocialCognitionPath, Species, StatePath,
+};
 use crate::types::Duration;
 use serde::{Deserialize, Serialize};
 
@@ -42,10 +47,16 @@ pub const SATURATION_CONSTANT: f32 = 0.50;
 /// Cumulative maximum shift in any direction per trait.
 pub const CUMULATIVE_CAP: f32 = 1.0;
 
-/// A record of a personality base shift from a formative event.
+/// Minimum event severity for automatically derived base shifts.
 ///
-/// Base shifts are stored as timestamped records rather than modifying
-/// the anchor state directly. The effective base at any timestamp is
+/// Only applies under `FormativeShiftPolicy::Automatic`.
+pub const AUTOMATIC_SHIFT_SEVERITY_THRESHOLD: f64 = 0.7;
+
+/// A record of a base shift from a formative event.
+///
+/// Base shifts target a HEXACO trait or any other dimension with a stable
+/// baseline (see [`StatePath::supports_base_shift`]). They are stored as
+/// timestamped records rather than modifying the anchor state directly. The effective base at any timestamp is
 /// computed by summing applicable shifts.
 ///
 /// # Settling Behavior
@@ -57,8 +68,8 @@ pub struct BaseShiftRecord {
     /// When the shift occurred.
     timestamp: Duration,
 
-    /// Which HEXACO trait was shifted.
-    trait_path: HexacoPath,
+    /// Which trait or baseline was shifted.
+    path: StatePath,
 
     /// Initial shift magnitude (what happens immediately).
     immediate: f32,
@@ -76,12 +87,12 @@ impl BaseShiftRecord {
     /// # Arguments
     ///
     /// * `timestamp` - When the shift occurred
-    /// * `trait_path` - Which HEXACO trait to shift
+    /// * `path` - Which trait or baseline to shift
     /// * `shift_amount` - Raw shift amount (will be processed for settling)
     ///
     /// Shifts exceeding [`SEVERE_SHIFT_THRESHOLD`] will have settling behavior.
     #[must_use]
-    pub fn new(timestamp: Duration, trait_path: HexacoPath, shift_amount: f32) -> Self {
+    pub fn new(timestamp: Duration, path: impl Into<StatePath>, shift_amount: f32) -> Self {
         let abs_shift = shift_amount.abs();
         let is_severe = abs_shift > SEVERE_SHIFT_THRESHOLD;
 
@@ -93,7 +104,7 @@ impl BaseShiftRecord {
 
         BaseShiftRecord {
             timestamp,
-            trait_path,
+            path: path.into(),
             immediate: shift_amount,
             settled,
             settling_days,
@@ -106,10 +117,19 @@ impl BaseShiftRecord {
         self.timestamp
     }
 
-    /// Returns which trait this shift affects.
+    /// Returns which trait or baseline this shift affects.
     #[must_use]
-    pub fn trait_path(&self) -> HexacoPath {
-        self.trait_path
+    pub fn path(&self) -> StatePath {
+        self.path
+    }
+
+    /// Returns the HEXACO trait this shift affects, if it targets one.
+    #[must_use]
+    pub fn trait_path(&self) -> Option<HexacoPath> {
+        match self.path {
+            StatePath::Hexaco(trait_path) => Some(trait_path),
+            _ => None,
+        }
     }
 
     /// Returns the immediate shift magnitude.
@@ -203,6 +223,73 @@ pub fn trait_modifier(trait_path: HexacoPath) -> f32 {
     1.0 - stability_coefficient(trait_path)
 }
 
+/// Returns the stability coefficient for any shiftable baseline.
+///
+/// HEXACO traits use [`stability_coefficient`]. Other baselines are
+/// generally less stable than broad traits: learned beliefs about others
+/// and the self sit in the middle, and situational resources are the most
+/// malleable. Paths without a stable baseline (mood, needs, computed
+/// dimensions) return 1.0, so they never shift.
+///
+/// | Dimension | Stability |
+/// |-----------|-----------|
+/// | Cognitive Ability | 0.90 |
+/// | Impulse Control, Persistence, Curiosity | 0.75 |
+/// | Trust Propensity, Empathy, Emotional Regulation, Motivation, Acquired Capability | 0.70 |
+/// | Aggression, Self Worth | 0.65 |
+/// | Reactance, Self Hate, Perceived Competence, Social Capital, Experience Diversity | 0.60 |
+/// | Loneliness, Reciprocal Caring, Liability, Hopelessness (both) | 0.55 |
+/// | Grievance, Depression, Material Security | 0.50 |
+#[must_use]
+pub fn baseline_stability_coefficient(path: StatePath) -> f32 {
+    if !path.supports_base_shift() {
+        return 1.0;
+    }
+
+    match path {
+        StatePath::Hexaco(trait_path) => stability_coefficient(trait_path),
+        StatePath::Disposition(p) => match p {
+            DispositionPath::ImpulseControl => 0.75,
+            DispositionPath::TrustPropensity | DispositionPath::Empathy => 0.70,
+            DispositionPath::Aggression => 0.65,
+            DispositionPath::Reactance => 0.60,
+            DispositionPath::Grievance => 0.50,
+        },
+        StatePath::SocialCognition(p) => match p {
+            SocialCognitionPath::SelfHate | SocialCognitionPath::PerceivedCompetence => 0.60,
+            SocialCognitionPath::Loneliness
+            | SocialCognitionPath::PerceivedReciprocalCaring
+            | SocialCognitionPath::PerceivedLiability => 0.55,
+        },
+        StatePath::MentalHealth(p) => match p {
+            MentalHealthPath::AcquiredCapability => 0.70,
+            MentalHealthPath::SelfWorth => 0.65,
+            MentalHealthPath::Hopelessness | MentalHealthPath::InterpersonalHopelessness => 0.55,
+            _ => 0.50,
+        },
+        StatePath::PersonCharacteristics(p) => match p {
+            PersonCharacteristicsPath::CognitiveAbility => 0.90,
+            PersonCharacteristicsPath::PersistenceTendency
+            | PersonCharacteristicsPath::CuriosityTendency => 0.75,
+            PersonCharacteristicsPath::EmotionalRegulationAssets
+            | PersonCharacteristicsPath::BaselineMotivation => 0.70,
+            PersonCharacteristicsPath::SocialCapital
+            | PersonCharacteristicsPath::ExperienceDiversity => 0.60,
+            _ => 0.50,
+        },
+        StatePath::Mood(_) | StatePath::Needs(_) => 1.0,
+    }
+}
+
+/// Returns the change modifier for any shiftable baseline.
+///
+/// This is `1.0 - baseline_stability_coefficient`, the baseline analogue
+/// of [`trait_modifier`].
+#[must_use]
+pub fn baseline_modifier(path: StatePath) -> f32 {
+    1.0 - baseline_stability_coefficient(path)
+}
+
 /// Returns the age plasticity modifier for a given age.
 ///
 /// | Age Range | Modifier |
@@ -300,15 +387,52 @@ pub fn apply_formative_modifiers(
     age_years: u16,
     existing_cumulative: f32,
     species: &Species,
+) -> f32 {
+    apply_baseline_modifiers(
+        shift_request,
+        StatePath::Hexaco(trait_path),
+        age_years,
+        existing_cumulative,
+        species,
+    )
+}
+
+/// Applies all formative modifiers to a raw shift request on any baseline.
+///
+/// HEXACO traits get their trait-specific sensitive periods; other
+/// baselines use [`age_plasticity`] alone. Stability comes from
+/// [`baseline_stability_coefficient`].
+///
+/// # Arguments
+///
+/// * `shift_request` - The raw shift amount requested
+/// * `path` - Which trait or baseline is being shifted
+/// * `age_years` - Entity's age at the time of the shift
+/// * `existing_cumulative` - Sum of existing shifts in the same direction
+/// * `species` - Entity's species (affects base plasticity)
+///
+/// # Returns
+///
+/// The modified shift amount after applying all constraints.
+#[must_use]
+pub fn apply_baseline_modifiers(
+    shift_request: f32,
+    path: StatePath,
+    age_years: u16,
+    existing_cumulative: f32,
+    species: &Species,
 ) -> f32 {
     // 1. Base plasticity from species
     let species_plasticity = species_plasticity_modifier(species);
 
     // 2. Combined age/sensitive period plasticity
-    let plasticity = combined_plasticity(trait_path, age_years);
+    let plasticity = match path {
+        StatePath::Hexaco(trait_path) => combined_plasticity(trait_path, age_years),
+        _ => age_plasticity(age_years),
+    };
 
-    // 3. Trait modifier (inverse of stability)
-    let trait_mod = trait_modifier(trait_path);
+    // 3. Baseline modifier (inverse of stability)
+    let trait_mod = baseline_modifier(path);
 
     // 4. Saturation from existing shifts
     let saturation = saturation_factor(existing_cumulative);
@@ -345,6 +469,74 @@ pub fn species_plasticity_modifier(species: &Species) -> f32 {
     }
 }
 
+/// Returns the default raw shift profile for an event type.
+///
+/// Each entry is a (path, raw shift per unit severity) pair. Raw amounts
+/// are shift requests of the same kind an author would pass to
+/// `EventBuilder::with_base_shift`; they still pass through
+/// [`apply_baseline_modifiers`]. Event types with no well-established
+/// long-term effect return an empty slice.
+///
+/// | Event Type | Shifts |
+/// |------------|--------|
+/// | ChildhoodAbuse | N +0.40, A -0.25, X -0.15, Self Worth -0.30, Trust -0.20 |
+/// | Violence, ViolenceExposure, TraumaticExposure, CombatExposure | N +0.25, A -0.10, Trust -0.10 |
+/// | PhysicalInjury | N +0.10 |
+/// | Bereavement, SuicidalLoss | N +0.20, Loneliness +0.20 |
+/// | Betrayal | A -0.20, N +0.10, Trust -0.30 |
+/// | RelationshipEnd | N +0.15, Loneliness +0.10 |
+/// | Rejection, SocialIsolation, GroupExclusion | X -0.10, N +0.10, Loneliness +0.15 |
+/// | Humiliation, ShamingEvent | N +0.20, X -0.10, Self Worth -0.15 |
+/// | JobLoss, FinancialBurden | N +0.15, Material Security -0.15 |
+/// | ChronicIllnessOnset | N +0.15, X -0.10 |
+/// | Achievement | C +0.20, N -0.05, Perceived Competence +0.15 |
+/// | Empowerment | X +0.15, N -0.10 |
+/// | Support | A +0.10, Trust +0.10 |
+#[must_use]
+pub fn formative_shift_profile(event_type: EventType) -> &'static [(StatePath, f32)] {
+    const A: StatePath = StatePath::Hexaco(HexacoPath::Agreeableness);
+    const C: StatePath = StatePath::Hexaco(HexacoPath::Conscientiousness);
+    const N: StatePath = StatePath::Hexaco(HexacoPath::Neuroticism);
+    const X: StatePath = StatePath::Hexaco(HexacoPath::Extraversion);
+    const TRUST: StatePath = StatePath::Disposition(DispositionPath::TrustPropensity);
+    const LONELINESS: StatePath = StatePath::SocialCognition(SocialCognitionPath::Loneliness);
+    const COMPETENCE: StatePath =
+        StatePath::SocialCognition(SocialCognitionPath::PerceivedCompetence);
+    const SELF_WORTH: StatePath = StatePath::MentalHealth(MentalHealthPath::SelfWorth);
+    const MATERIAL: StatePath =
+        StatePath::PersonCharacteristics(PersonCharacteristicsPath::MaterialSecurity);
+
+    match event_type {
+        EventType::ChildhoodAbuse => &[
+            (N, 0.40),
+            (A, -0.25),
+            (X, -0.15),
+            (SELF_WORTH, -0.30),
+            (TRUST, -0.20),
+        ],
+        EventType::Violence
+        | EventType::ViolenceExposure
+        | EventType::TraumaticExposure
+        | EventType::CombatExposure => &[(N, 0.25), (A, -0.10), (TRUST, -0.10)],
+        EventType::PhysicalInjury => &[(N, 0.10)],
+        EventType::Bereavement | EventType::SuicidalLoss => &[(N, 0.20), (LONELINESS, 0.20)],
+        EventType::Betrayal => &[(A, -0.20), (N, 0.10), (TRUST, -0.30)],
+        EventType::RelationshipEnd => &[(N, 0.15), (LONELINESS, 0.10)],
+        EventType::Rejection | EventType::SocialIsolation | EventType::GroupExclusion => {
+            &[(X, -0.10), (N, 0.10), (LONELINESS, 0.15)]
+        }
+        EventType::Humiliation | EventType::ShamingEvent => {
+            &[(N, 0.20), (X, -0.10), (SELF_WORTH, -0.15)]
+        }
+        EventType::JobLoss | EventType::FinancialBurden => &[(N, 0.15), (MATERIAL, -0.15)],
+        EventType::ChronicIllnessOnset => &[(N, 0.15), (X, -0.10)],
+        EventType::Achievement => &[(C, 0.20), (N, -0.05), (COMPETENCE, 0.15)],
+        EventType::Empowerment => &[(X, 0.15), (N, -0.10)],
+        EventType::Support => &[(A, 0.10), (TRUST, 0.10)],
+        _ => &[],
+    }
+}
+
 /// Enforces the cumulative cap, preventing shifts that would exceed the limit.
 #[must_use]
 fn enforce_cumulative_cap(proposed_shift: f32, existing_cumulative: f32) -> f32 {
@@ -365,8 +557,8 @@ fn enforce_cumulative_cap(proposed_shift: f32, existing_cumulative: f32) -> f32
 ///
 /// # Arguments
 ///
-/// * `anchor_value` - The original personality trait value
-/// * `shifts` - All base shift records for this trait
+/// * `anchor_value` - The original trait or baseline value
+/// * `shifts` - All base shift records for this trait or baseline
 /// * `query_timestamp` - The timestamp to compute the value for
 ///
 /// # Returns
@@ -402,6 +594,66 @@ pub fn cumulative_in_direction(shifts: &[BaseShiftRecord], positive: bool) -> f3
 mod tests {
     use super::*;
 
+    // Formative shift profile tests
+
+    #[test]
+    fn childhood_abuse_profile_raises_neuroticism_and_lowers_agreeableness() {
+        let profile = formative_shift_profile(EventType::ChildhoodAbuse);
+        assert!(profile
+            .iter()
+            .any(|(t, a)| *t == StatePath::Hexaco(HexacoPath::Neuroticism) && *a > 0.0));
+        assert!(profile
+            .iter()
+            .any(|(t, a)| *t == StatePath::Hexaco(HexacoPath::Agreeableness) && *a < 0.0));
+    }
+
+    #[test]
+    fn achievement_profile_raises_conscientiousness() {
+        let profile = formative_shift_profile(EventType::Achievement);
+        assert!(profile
+            .iter()
+            .any(|(t, a)| *t == StatePath::Hexaco(HexacoPath::Conscientiousness) && *a > 0.0));
+    }
+
+    #[test]
+    fn neutral_events_have_no_profile() {
+        assert!(formative_shift_profile(EventType::Interaction).is_empty());
+        assert!(formative_shift_profile(EventType::PolicyChange).is_empty());
+    }
+
+    #[test]
+    fn profiles_shift_non_hexaco_baselines() {
+        let has = |event_type, path: StatePath, positive: bool| {
+            formative_shift_profile(event_type)
+                .iter()
+                .any(|(p, a)| *p == path && (*a > 0.0) == positive)
+        };
+
+        assert!(has(
+            EventType::Betrayal,
+            StatePath::Disposition(DispositionPath::TrustPropensity),
+            false
+        ));
+        assert!(has(
+            EventType::Bereavement,
+            StatePath::SocialCognition(SocialCognitionPath::Loneliness),
+            true
+        ));
+        assert!(has(
+            EventType::ChildhoodAbuse,
+            StatePath::MentalHealth(MentalHealthPath::SelfWorth),
+            false
+        ));
+    }
+
+    #[test]
+    fn base_shift_record_on_baseline_path() {
+        let path = StatePath::SocialCognition(SocialCognitionPath::Loneliness);
+        let shift = BaseShiftRecord::new(Duration::days(1), path, 0.1);
+        assert_eq!(shift.path(), path);
+        assert!(shift.trait_path().is_none());
+    }
+
     // BaseShiftRecord tests
 
     #[test]
@@ -450,7 +702,8 @@ mod tests {
         let shift = BaseShiftRecord::new(ts, HexacoPath::Extraversion, 0.10);
 
         assert_eq!(shift.timestamp(), ts);
-        assert_eq!(shift.trait_path(), HexacoPath::Extraversion);
+        assert_eq!(shift.trait_path(), Some(HexacoPath::Extraversion));
+        assert_eq!(shift.path(), StatePath::Hexaco(HexacoPath::Extraversion));
     }
 
     #[test]
@@ -521,6 +774,57 @@ mod tests {
         }
     }
 
+    #[test]
+    fn baseline_stability_matches_hexaco_for_traits() {
+        for trait_path in HexacoPath::all() {
+            let path = StatePath::Hexaco(trait_path);
+            assert!(
+                (baseline_stability_coefficient(path) - stability_coefficient(trait_path)).abs()
+                    < f32::EPSILON
+            );
+        }
+    }
+
+    #[test]
+    fn baseline_stability_in_range_for_shiftable_paths() {
+        for path in StatePath::all() {
+            let stability = baseline_stability_coefficient(path);
+            if path.supports_base_shift() {
+                assert!((0.5..=0.9).contains(&stability), "{path}: {stability}");
+                assert!(baseline_modifier(path) > 0.0);
+            } else {
+                assert!((stability - 1.0).abs() < f32::EPSILON);
+                assert!(baseline_modifier(path).abs() < f32::EPSILON);
+            }
+        }
+    }
+
+    #[test]
+    fn apply_baseline_modifiers_matches_formative_for_traits() {
+        let trait_result =
+            apply_formative_modifiers(0.2, HexacoPath::Neuroticism, 16, 0.1, &Species::Human);
+        let path_result = apply_baseline_modifiers(
+            0.2,
+            StatePath::Hexaco(HexacoPath::Neuroticism),
+            16,
+            0.1,
+            &Species::Human,
+        );
+        assert!((trait_result - path_result).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn apply_baseline_modifiers_uses_baseline_stability() {
+        let trust = StatePath::Disposition(DispositionPath::TrustPropensity);
+        let result = apply_baseline_modifiers(-0.5, trust, 25, 0.0, &Species::Human);
+        // -0.5 * 1.0 species * 1.0 age * 0.30 modifier * 1.0 saturation
+        assert!((result - (-0.15)).abs() < 1e-6);
+
+        let valence = StatePath::Mood(crate::enums::MoodPath::Valence);
+        let result = apply_baseline_modifiers(-0.5, valence, 25, 0.0, &Species::Human);
+        assert!(result.abs() < f32::EPSILON);
+    }
+
     // Age plasticity tests
 
     #[test]
@@ -13,7 +13,7 @@
 //! - Zero indicates average trait level
 //! - Positive values indicate high trait presence
 
-use crate::enums::PersonalityProfile;
+use crate::enums::{HexacoPath, PersonalityProfile};
 use serde::{Deserialize, Serialize};
 
 /// HEXACO personality factors.
@@ -273,6 +273,33 @@ impl Hexaco {
     pub fn set_honesty_humility(&mut self, value: f32) {
         self.honesty_humility = value.clamp(-1.0, 1.0);
     }
+
+    // Path-based access
+
+    /// Returns the factor identified by a HEXACO path.
+    #[must_use]
+    pub fn get(&self, path: HexacoPath) -> f32 {
+        match path {
+            HexacoPath::Openness => self.openness,
+            HexacoPath::Conscientiousness => self.conscientiousness,
+            HexacoPath::Extraversion => self.extraversion,
+            HexacoPath::Agreeableness => self.agreeableness,
+            HexacoPath::Neuroticism => self.neuroticism,
+            HexacoPath::HonestyHumility => self.honesty_humility,
+        }
+    }
+
+    /// Sets the factor identified by a HEXACO path (clamped to -1.0..=1.0).
+    pub fn set(&mut self, path: HexacoPath, value: f32) {
+        match path {
+            HexacoPath::Openness => self.set_openness(value),
+            HexacoPath::Conscientiousness => self.set_conscientiousness(value),
+            HexacoPath::Extraversion => self.set_extraversion(value),
+            HexacoPath::Agreeableness => self.set_agreeableness(value),
+            HexacoPath::Neuroticism => self.set_neuroticism(value),
+            HexacoPath::HonestyHumility => self.set_honesty_humility(value),
+        }
+    }
 }
 
 impl Default for Hexaco {
@@ -285,6 +312,21 @@ impl Default for Hexaco {
 mod tests {
     use super::*;
 
+    #[test]
+    fn get_and_set_by_path() {
+        let mut hexaco = Hexaco::new();
+        for (i, path) in HexacoPath::all().into_iter().enumerate() {
+            hexaco.set(path, i as f32 * 0.1);
+        }
+        assert!((hexaco.get(HexacoPath::Openness) - hexaco.openness()).abs() < f32::EPSILON);
+        for (i, path) in HexacoPath::all().into_iter().enumerate() {
+            assert!((hexaco.get(path) - i as f32 * 0.1).abs() < f32::EPSILON);
+        }
+
+        hexaco.set(HexacoPath::Neuroticism, 3.0);
+        assert!((hexaco.neuroticism() - 1.0).abs() < f32::EPSILON);
+    }
+
     #[test]
     fn new_creates_neutral_factors() {
         let hexaco = Hexaco::new();
@@ -14,6 +14,10 @@
 //!
 //! This is the primary container for an entity's internal state.
 
+use crate::enums::{
+    DispositionPath, MentalHealthPath, MoodPath, NeedsPath, PersonCharacteristicsPath,
+    SocialCognitionPath, StatePath,
+};
 use crate::state::{
     DemandCharacteristics, Demographical, Disposition, EntityModelConfig, Hexaco, MentalHealth,
     Mood, Needs, PersonCharacteristics, SocialCognition, StateValue,
@@ -308,6 +312,170 @@ impl IndividualState {
         &mut self.config
     }
 
+    // Path-based access
+
+    /// Gets a StateValue reference by path.
+    ///
+    /// Returns None for HEXACO traits (not StateValues) and for computed
+    /// or composite paths.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use eventsim_rs::state::IndividualState;
+    /// use eventsim_rs::enums::{HexacoPath, NeedsPath, StatePath};
+    ///
+    /// let state = IndividualState::new();
+    /// assert!(state.get(StatePath::Needs(NeedsPath::Stress)).is_some());
+    /// assert!(state.get(StatePath::Hexaco(HexacoPath::Openness)).is_none());
+    /// ```
+    #[must_use]
+    pub fn get(&self, path: StatePath) -> Option<&StateValue> {
+        match path {
+            StatePath::Hexaco(_) => None,
+            StatePath::Mood(p) => Some(match p {
+                MoodPath::Valence => self.mood.valence(),
+                MoodPath::Arousal => self.mood.arousal(),
+                MoodPath::Dominance => self.mood.dominance(),
+            }),
+            StatePath::Needs(p) => Some(match p {
+                NeedsPath::Fatigue => self.needs.fatigue(),
+                NeedsPath::Stress => self.needs.stress(),
+                NeedsPath::Purpose => self.needs.purpose(),
+            }),
+            StatePath::SocialCognition(p) => Some(match p {
+                SocialCognitionPath::Loneliness => self.social_cognition.loneliness(),
+                SocialCognitionPath::PerceivedReciprocalCaring => {
+                    self.social_cognition.perceived_reciprocal_caring()
+                }
+                SocialCognitionPath::PerceivedLiability => {
+                    self.social_cognition.perceived_liability()
+                }
+                SocialCognitionPath::SelfHate => self.social_cognition.self_hate(),
+                SocialCognitionPath::PerceivedCompetence => {
+                    self.social_cognition.perceived_competence()
+                }
+            }),
+            StatePath::MentalHealth(p) => match p {
+                MentalHealthPath::Depression => Some(self.mental_health.depression()),
+                MentalHealthPath::SelfWorth => Some(self.mental_health.self_worth()),
+                MentalHealthPath::Hopelessness => Some(self.mental_health.hopelessness()),
+                MentalHealthPath::InterpersonalHopelessness => {
+                    Some(self.mental_health.interpersonal_hopelessness())
+                }
+                MentalHealthPath::AcquiredCapability => {
+                    Some(self.mental_health.acquired_capability())
+                }
+                _ => None,
+            },
+            StatePath::Disposition(p) => Some(match p {
+                DispositionPath::ImpulseControl => self.disposition.impulse_control(),
+                DispositionPath::Empathy => self.disposition.empathy(),
+                DispositionPath::Aggression => self.disposition.aggression(),
+                DispositionPath::Grievance => self.disposition.grievance(),
+                DispositionPath::Reactance => self.disposition.reactance(),
+                DispositionPath::TrustPropensity => self.disposition.trust_propensity(),
+            }),
+            StatePath::PersonCharacteristics(p) => {
+                let pc = &self.person_characteristics;
+                match p {
+                    PersonCharacteristicsPath::CognitiveAbility => Some(pc.cognitive_ability()),
+                    PersonCharacteristicsPath::EmotionalRegulationAssets => {
+                        Some(pc.emotional_regulation_assets())
+                    }
+                    PersonCharacteristicsPath::SocialCapital => Some(pc.social_capital()),
+                    PersonCharacteristicsPath::MaterialSecurity => Some(pc.material_security()),
+                    PersonCharacteristicsPath::ExperienceDiversity => {
+                        Some(pc.experience_diversity())
+                    }
+                    PersonCharacteristicsPath::BaselineMotivation => Some(pc.baseline_motivation()),
+                    PersonCharacteristicsPath::PersistenceTendency => {
+                        Some(pc.persistence_tendency())
+                    }
+                    PersonCharacteristicsPath::CuriosityTendency => Some(pc.curiosity_tendency()),
+                    PersonCharacteristicsPath::Resource | PersonCharacteristicsPath::Force => None,
+                }
+            }
+        }
+    }
+
+    /// Gets a mutable StateValue reference by path.
+    ///
+    /// Returns None for HEXACO traits (not StateValues) and for computed
+    /// or composite paths.
+    pub fn get_mut(&mut self, path: StatePath) -> Option<&mut StateValue> {
+        match path {
+            StatePath::Hexaco(_) => None,
+            StatePath::Mood(p) => Some(match p {
+                MoodPath::Valence => self.mood.valence_mut(),
+                MoodPath::Arousal => self.mood.arousal_mut(),
+                MoodPath::Dominance => self.mood.dominance_mut(),
+            }),
+            StatePath::Needs(p) => Some(match p {
+                NeedsPath::Fatigue => self.needs.fatigue_mut(),
+                NeedsPath::Stress => self.needs.stress_mut(),
+                NeedsPath::Purpose => self.needs.purpose_mut(),
+            }),
+            StatePath::SocialCognition(p) => Some(match p {
+                SocialCognitionPath::Loneliness => self.social_cognition.loneliness_mut(),
+                SocialCognitionPath::PerceivedReciprocalCaring => {
+                    self.social_cognition.perceived_reciprocal_caring_mut()
+                }
+                SocialCognitionPath::PerceivedLiability => {
+                    self.social_cognition.perceived_liability_mut()
+                }
+                SocialCognitionPath::SelfHate => self.social_cognition.self_hate_mut(),
+                SocialCognitionPath::PerceivedCompetence => {
+                    self.social_cognition.perceived_competence_mut()
+                }
+            }),
+            StatePath::MentalHealth(p) => match p {
+                MentalHealthPath::Depression => Some(self.mental_health.depression_mut()),
+                MentalHealthPath::SelfWorth => Some(self.mental_health.self_worth_mut()),
+                MentalHealthPath::Hopelessness => Some(self.mental_health.hopelessness_mut()),
+                MentalHealthPath::InterpersonalHopelessness => {
+                    Some(self.mental_health.interpersonal_hopelessness_mut())
+                }
+                MentalHealthPath::AcquiredCapability => {
+                    Some(self.mental_health.acquired_capability_mut())
+                }
+                _ => None,
+            },
+            StatePath::Disposition(p) => Some(match p {
+                DispositionPath::ImpulseControl => self.disposition.impulse_control_mut(),
+                DispositionPath::Empathy => self.disposition.empathy_mut(),
+                DispositionPath::Aggression => self.disposition.aggression_mut(),
+                DispositionPath::Grievance => self.disposition.grievance_mut(),
+                DispositionPath::Reactance => self.disposition.reactance_mut(),
+                DispositionPath::TrustPropensity => self.disposition.trust_propensity_mut(),
+            }),
+            StatePath::PersonCharacteristics(p) => {
+                let pc = &mut self.person_characteristics;
+                match p {
+                    PersonCharacteristicsPath::CognitiveAbility => Some(pc.cognitive_ability_mut()),
+                    PersonCharacteristicsPath::EmotionalRegulationAssets => {
+                        Some(pc.emotional_regulation_assets_mut())
+                    }
+                    PersonCharacteristicsPath::SocialCapital => Some(pc.social_capital_mut()),
+                    PersonCharacteristicsPath::MaterialSecurity => Some(pc.material_security_mut()),
+                    PersonCharacteristicsPath::ExperienceDiversity => {
+                        Some(pc.experience_diversity_mut())
+                    }
+                    PersonCharacteristicsPath::BaselineMotivation => {
+                        Some(pc.baseline_motivation_mut())
+                    }
+                    PersonCharacteristicsPath::PersistenceTendency => {
+                        Some(pc.persistence_tendency_mut())
+                    }
+                    PersonCharacteristicsPath::CuriosityTendency => {
+                        Some(pc.curiosity_tendency_mut())
+                    }
+                    PersonCharacteristicsPath::Resource | PersonCharacteristicsPath::Force => None,
+                }
+            }
+        }
+    }
+
     // Unified operations
 
     /// Applies decay to all state components over the specified duration.
@@ -462,6 +630,31 @@ mod tests {
         assert!((state.needs().stress().delta() - 0.2).abs() < f32::EPSILON);
     }
 
+    #[test]
+    fn get_by_path_matches_component_accessors() {
+        let mut state = IndividualState::new();
+        state.needs_mut().add_stress_delta(0.3);
+
+        let stress = state.get(StatePath::Needs(NeedsPath::Stress)).unwrap();
+        assert!((stress.delta() - 0.3).abs() < f32::EPSILON);
+
+        state
+            .get_mut(StatePath::Disposition(DispositionPath::Grievance))
+            .unwrap()
+            .add_delta(0.2);
+        assert!((state.disposition().grievance().delta() - 0.2).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn get_by_path_covers_every_stored_path() {
+        let mut state = IndividualState::new();
+        for path in StatePath::all() {
+            let stored = !path.is_computed() && !matches!(path, StatePath::Hexaco(_));
+            assert_eq!(state.get(path).is_some(), stored, "{path}");
+            assert_eq!(state.get_mut(path).is_some(), stored, "{path}");
+        }
+    }
+
     #[test]
     fn apply_decay_affects_all_decaying_components() {
         let mut state = IndividualState::new();
@@ -0,0 +1,337 @@
+//! Normative personality maturation.
+//!
+//! Personality traits show systematic mean-level change across the lifespan
+//! even in the absence of notable life events. This module models that
+//! age-graded drift so that an entity's HEXACO traits mature with age,
+//! independent of (and composable with) formative base shifts.
+//!
+//! # Theory Background
+//!
+//! - Roberts, Walton & Viechtbauer (2006): Meta-analysis of mean-level change
+//! - Caspi, Roberts & Shiner (2005): The maturity principle
+//! - Ashton & Lee (2016): Age trends in HEXACO-PI-R self-reports
+//!
+//! # Key Concepts
+//!
+//! - **Maturity Principle**: Conscientiousness, agreeableness and emotional
+//!   stability increase through young and middle adulthood
+//! - **Honesty-Humility**: Rises steadily with age in HEXACO samples
+//! - **Openness**: Increases in adolescence, declines in late life
+//! - **Human-Equivalent Age**: Non-human species mature on a compressed
+//!   timeline scaled by their maturity age
+//!
+//! Drift rates are per human-equivalent year and are scaled by
+//! [`combined_plasticity`] so that change concentrates in sensitive periods
+//! and slows in later life.
+
+use crate::enums::{HexacoPath, Species};
+use crate::state::{combined_plasticity, Hexaco};
+use crate::types::Duration;
+
+/// Age (human-equivalent years) before which no normative drift applies.
+pub const MATURATION_ONSET_YEARS: u16 = 12;
+
+/// Human maturity age used to convert species ages to human-equivalent ages.
+const HUMAN_MATURITY_YEARS: f64 = 25.0;
+
+/// Returns the base annual drift for a trait at a human-equivalent age.
+///
+/// Values are in trait units (-1.0 to 1.0 scale) per year before
+/// plasticity scaling. Over the adult lifespan they produce roughly one
+/// standard deviation of change for conscientiousness and emotional
+/// stability, matching meta-analytic estimates.
+///
+/// | Trait | 12-17 | 18-59 | 60-69 | 70+ |
+/// |-------|-------|-------|-------|-----|
+/// | Conscientiousness | 0 | +0.007 | +0.007 | 0 |
+/// | Agreeableness | 0 | +0.005 | +0.005 | +0.005 |
+/// | Neuroticism | 0 | -0.007 | -0.004 | 0 |
+/// | Honesty-Humility | 0 | +0.006 | +0.006 | +0.006 |
+/// | Extraversion | 0 | -0.002 | -0.002 | -0.002 |
+/// | Openness | +0.004 (to 24) | 0 (25-59) | -0.003 | -0.003 |
+#[must_use]
+pub fn base_drift_rate(trait_path: HexacoPath, age_years: u16) -> f32 {
+    if age_years < MATURATION_ONSET_YEARS {
+        return 0.0;
+    }
+
+    match trait_path {
+        HexacoPath::Conscientiousness => match age_years {
+            18..=69 => 0.007,
+            _ => 0.0,
+        },
+        HexacoPath::Agreeableness => match age_years {
+            0..=17 => 0.0,
+            _ => 0.005,
+        },
+        HexacoPath::Neuroticism => match age_years {
+            18..=59 => -0.007,
+            60..=69 => -0.004,
+            _ => 0.0,
+        },
+        HexacoPath::HonestyHumility => match age_years {
+            0..=17 => 0.0,
+            _ => 0.006,
+        },
+        HexacoPath::Extraversion => match age_years {
+            0..=17 => 0.0,
+            _ => -0.002,
+        },
+        HexacoPath::Openness => match age_years {
+            0..=24 => 0.004,
+            25..=59 => 0.0,
+            _ => -0.003,
+        },
+    }
+}
+
+/// Returns the plasticity-scaled annual drift for a trait.
+///
+/// This is [`base_drift_rate`] multiplied by [`combined_plasticity`], so
+/// drift is strongest during a trait's sensitive period.
+#[must_use]
+pub fn normative_drift_rate(trait_path: HexacoPath, age_years: u16) -> f32 {
+    base_drift_rate(trait_path, age_years) * combined_plasticity(trait_path, age_years)
+}
+
+/// Converts a species age to a human-equivalent age in years.
+///
+/// Ages are scaled by the ratio of human maturity age (25) to the
+/// species' maturity age. Species that mature in under a year are treated
+/// as maturing at one year.
+///
+/// # Examples
+///
+/// ```
+/// use eventsim_rs::state::human_equivalent_age_years;
+/// use eventsim_rs::enums::Species;
+/// use eventsim_rs::types::Duration;
+///
+/// // A dog matures at 2, so a 2-year-old dog is a 25-year-old human
+/// let age = human_equivalent_age_years(Duration::years(2), &Species::Dog);
+/// assert!((age - 25.0).abs() < 0.01);
+/// ```
+#[must_use]
+pub fn human_equivalent_age_years(age: Duration, species: &Species) -> f64 {
+    let maturity = f64::from(species.maturity_age_years().max(1));
+    age.as_years_f64() * HUMAN_MATURITY_YEARS / maturity
+}
+
+/// Computes the cumulative normative drift for a trait between two ages.
+///
+/// The result is signed by direction: moving from a younger to an older
+/// age yields the forward drift, and moving backward yields its negation,
+/// so forward and backward queries stay consistent.
+///
+/// # Arguments
+///
+/// * `trait_path` - The trait to compute drift for
+/// * `from_age` - Age at the anchor
+/// * `to_age` - Age at the queried timestamp
+/// * `species` - Entity's species (sets the maturation timeline)
+///
+/// # Examples
+///
+/// ```
+/// use eventsim_rs::state::normative_drift;
+/// use eventsim_rs::enums::{HexacoPath, Species};
+/// use eventsim_rs::types::Duration;
+///
+/// let forward = normative_drift(
+///     HexacoPath::Conscientiousness,
+///     Duration::years(20),
+///     Duration::years(40),
+///     &Species::Human,
+/// );
+/// assert!(forward > 0.0);
+///
+/// let backward = normative_drift(
+///     HexacoPath::Conscientiousness,
+///     Duration::years(40),
+///     Duration::years(20),
+///     &Species::Human,
+/// );
+/// assert!((forward + backward).abs() < 1e-6);
+/// ```
+#[must_use]
+pub fn normative_drift(
+    trait_path: HexacoPath,
+    from_age: Duration,
+    to_age: Duration,
+    species: &Species,
+) -> f32 {
+    let from = human_equivalent_age_years(from_age, species);
+    let to = human_equivalent_age_years(to_age, species);
+
+    if from <= to {
+        integrate_drift(trait_path, from, to)
+    } else {
+        -integrate_drift(trait_path, to, from)
+    }
+}
+
+/// Integrates the piecewise-constant annual drift rate over [start, end].
+fn integrate_drift(trait_path: HexacoPath, start: f64, end: f64) -> f32 {
+    let mut total = 0.0_f64;
+    let mut age = start;
+
+    while age < end {
+        let year = age.floor();
+        let segment_end = (year + 1.0).min(end);
+        let year_index = year.min(f64::from(u16::MAX)) as u16;
+        total += f64::from(normative_drift_rate(trait_path, year_index)) * (segment_end - age);
+        age = segment_end;
+    }
+
+    total as f32
+}
+
+/// Applies normative drift between two ages to every HEXACO trait.
+///
+/// Traits are clamped to the valid -1.0 to 1.0 range.
+pub fn apply_normative_maturation(
+    hexaco: &mut Hexaco,
+    from_age: Duration,
+    to_age: Duration,
+    species: &Species,
+) {
+    if from_age == to_age {
+        return;
+    }
+
+    for trait_path in HexacoPath::all() {
+        let drift = normative_drift(trait_path, from_age, to_age, species);
+        hexaco.set(trait_path, hexaco.get(trait_path) + drift);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn no_drift_before_onset() {
+        for trait_path in HexacoPath::all() {
+            assert!(base_drift_rate(trait_path, 5).abs() < f32::EPSILON);
+            let drift = normative_drift(
+                trait_path,
+                Duration::years(1),
+                Duration::years(11),
+                &Species::Human,
+            );
+            assert!(drift.abs() < f32::EPSILON);
+        }
+    }
+
+    #[test]
+    fn maturity_principle_directions() {
+        let young = Duration::years(20);
+        let old = Duration::years(60);
+        let drift = |t| normative_drift(t, young, old, &Species::Human);
+
+        assert!(drift(HexacoPath::Conscientiousness) > 0.0);
+        assert!(drift(HexacoPath::Agreeableness) > 0.0);
+        assert!(drift(HexacoPath::HonestyHumility) > 0.0);
+        assert!(drift(HexacoPath::Neuroticism) < 0.0);
+        assert!(drift(HexacoPath::Extraversion) < 0.0);
+    }
+
+    #[test]
+    fn lifetime_drift_is_about_one_standard_deviation() {
+        let drift = normative_drift(
+            HexacoPath::Conscientiousness,
+            Duration::years(18),
+            Duration::years(70),
+            &Species::Human,
+        );
+        // One SD on the -1..1 scale is roughly a third of the range
+        assert!(drift > 0.25 && drift < 0.5);
+    }
+
+    #[test]
+    fn openness_rises_then_declines() {
+        let rise = normative_drift(
+            HexacoPath::Openness,
+            Duration::years(14),
+            Duration::years(24),
+            &Species::Human,
+        );
+        let decline = normative_drift(
+            HexacoPath::Openness,
+            Duration::years(60),
+            Duration::years(80),
+            &Species::Human,
+        );
+        assert!(rise > 0.0);
+        assert!(decline < 0.0);
+    }
+
+    #[test]
+    fn sensitive_period_accelerates_drift() {
+        // Neuroticism sensitive period is 12-25 (1.4x)
+        let in_period = normative_drift_rate(HexacoPath::Neuroticism, 20);
+        let after = normative_drift_rate(HexacoPath::Neuroticism, 26);
+        assert!(in_period.abs() > after.abs());
+    }
+
+    #[test]
+    fn fractional_years_are_integrated() {
+        let half = normative_drift(
+            HexacoPath::Conscientiousness,
+            Duration::years(30),
+            Duration::days(30 * 365 + 182),
+            &Species::Human,
+        );
+        let full = normative_drift(
+            HexacoPath::Conscientiousness,
+            Duration::years(30),
+            Duration::years(31),
+            &Species::Human,
+        );
+        assert!((half * 2.0 - full).abs() < 0.0005);
+    }
+
+    #[test]
+    fn species_mature_on_compressed_timeline() {
+        let dog = normative_drift(
+            HexacoPath::Conscientiousness,
+            Duration::years(2),
+            Duration::years(4),
+            &Species::Dog,
+        );
+        let human = normative_drift(
+            HexacoPath::Conscientiousness,
+            Duration::years(25),
+            Duration::years(50),
+            &Species::Human,
+        );
+        assert!((dog - human).abs() < 1e-4);
+
+        // Sub-year maturity is treated as one year
+        let mouse = human_equivalent_age_years(Duration::years(1), &Species::Mouse);
+        assert!((mouse - 25.0).abs() < 0.1);
+    }
+
+    #[test]
+    fn apply_normative_maturation_updates_and_clamps() {
+        let mut hexaco = Hexaco::new().with_conscientiousness(0.99);
+        apply_normative_maturation(
+            &mut hexaco,
+            Duration::years(20),
+            Duration::years(60),
+            &Species::Human,
+        );
+
+        assert!((hexaco.conscientiousness() - 1.0).abs() < f32::EPSILON);
+        assert!(hexaco.neuroticism() < 0.0);
+
+        let before = hexaco.clone();
+        apply_normative_maturation(
+            &mut hexaco,
+            Duration::years(40),
+            Duration::years(40),
+            &Species::Human,
+        );
+        assert_eq!(hexaco, before);
+    }
+}
@@ -690,9 +690,9 @@ mod tests {
     #[test]
     fn threshold_constants_defined() {
         // Verify constants are accessible and reasonable
-        assert!(TB_PRESENT_THRESHOLD > 0.0 && TB_PRESENT_THRESHOLD <= 1.0);
-        assert!(PB_PRESENT_THRESHOLD > 0.0 && PB_PRESENT_THRESHOLD <= 1.0);
-        assert!(HOPELESSNESS_THRESHOLD > 0.0 && HOPELESSNESS_THRESHOLD <= 1.0);
+        const { assert!(TB_PRESENT_THRESHOLD > 0.0 && TB_PRESENT_THRESHOLD <= 1.0) };
+        const { assert!(PB_PRESENT_THRESHOLD > 0.0 && PB_PRESENT_THRESHOLD <= 1.0) };
+        const { assert!(HOPELESSNESS_THRESHOLD > 0.0 && HOPELESSNESS_THRESHOLD <= 1.0) };
     }
 
     #[test]
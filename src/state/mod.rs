@@ -16,6 +16,7 @@
 //! - [`Demographical`] - Demographical metadata
 //! - [`DemandCharacteristics`] - Observable social signals
 //! - [`EntityModelConfig`] - Subsystem activation flags
+//! - [`normative_drift`] - Age-graded personality maturation
 //! - [`IndividualState`] - Aggregate container for all state
 
 mod demand_characteristics;
@@ -25,6 +26,7 @@ mod entity_model_config;
 mod formative;
 mod hexaco;
 mod individual_state;
+mod maturation;
 mod mental_health;
 mod mood;
 mod needs;
@@ -38,13 +40,19 @@ pub use demographical::Demographical;
 pub use disposition::Disposition;
 pub use entity_model_config::EntityModelConfig;
 pub use formative::{
-    age_plasticity, apply_formative_modifiers, combined_plasticity, cumulative_in_direction,
-    effective_base_at, saturation_factor, sensitive_period_modifier, species_plasticity_modifier,
-    stability_coefficient, trait_modifier, BaseShiftRecord, CUMULATIVE_CAP, MAX_SINGLE_EVENT_SHIFT,
+    age_plasticity, apply_baseline_modifiers, apply_formative_modifiers, baseline_modifier,
+    baseline_stability_coefficient, combined_plasticity, cumulative_in_direction,
+    effective_base_at, formative_shift_profile, saturation_factor, sensitive_period_modifier,
+    species_plasticity_modifier, stability_coefficient, trait_modifier, BaseShiftRecord,
+    AUTOMATIC_SHIFT_SEVERITY_THRESHOLD, CUMULATIVE_CAP, MAX_SINGLE_EVENT_SHIFT,
     SATURATION_CONSTANT, SETTLING_DAYS, SEVERE_SHIFT_RETENTION, SEVERE_SHIFT_THRESHOLD,
 };
 pub use hexaco::Hexaco;
 pub use individual_state::IndividualState;
+pub use maturation::{
+    apply_normative_maturation, base_drift_rate, human_equivalent_age_years, normative_drift,
+    normative_drift_rate, MATURATION_ONSET_YEARS,
+};
 pub use mental_health::{
     MentalHealth, HOPELESSNESS_THRESHOLD, PB_PRESENT_THRESHOLD, TB_PRESENT_THRESHOLD,
 };
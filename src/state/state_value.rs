@@ -240,12 +240,46 @@ impl StateValue {
         self.delta + self.chronic_delta
     }
 
+    /// Returns the acute component of delta.
+    #[must_use]
+    pub fn acute_delta(&self) -> f32 {
+        self.delta
+    }
+
+    /// Returns the chronic component of delta.
+    #[must_use]
+    pub fn chronic_delta(&self) -> f32 {
+        self.chronic_delta
+    }
+
+    /// Returns the minimum bound for the effective value.
+    #[must_use]
+    pub fn min_bound(&self) -> f32 {
+        self.min_bound
+    }
+
+    /// Returns the maximum bound for the effective value.
+    #[must_use]
+    pub fn max_bound(&self) -> f32 {
+        self.max_bound
+    }
+
     /// Returns the decay half-life, or None if this value never decays.
     #[must_use]
     pub fn decay_half_life(&self) -> Option<Duration> {
         self.decay_half_life
     }
 
+    /// Returns the half-life applied to the chronic delta component.
+    ///
+    /// Chronic deltas decay more slowly than acute deltas. Returns None if
+    /// this value never decays.
+    #[must_use]
+    pub fn chronic_decay_half_life(&self) -> Option<Duration> {
+        self.decay_half_life
+            .map(|half_life| half_life * CHRONIC_HALF_LIFE_MULTIPLIER)
+    }
+
     /// Returns true if this value decays over time.
     #[must_use]
     pub fn decays(&self) -> bool {
@@ -351,6 +385,15 @@ impl StateValue {
         self.chronic_delta = 0.0;
     }
 
+    /// Sets the acute and chronic delta components directly.
+    ///
+    /// Unlike `set_delta`, this preserves the split between components,
+    /// which decay at different rates.
+    pub fn set_delta_components(&mut self, acute: f32, chronic: f32) {
+        self.delta = acute;
+        self.chronic_delta = chronic;
+    }
+
     /// Applies decay to the delta value over the specified duration.
     ///
     /// Uses exponential decay based on the half-life.
@@ -460,6 +503,27 @@ mod tests {
         assert!((value.delta() - 0.2).abs() < f32::EPSILON);
     }
 
+    #[test]
+    fn delta_components_are_tracked_separately() {
+        let mut value = StateValue::new(0.5).with_bounds(-1.0, 1.0);
+        value.set_delta_components(0.2, -0.1);
+
+        assert!((value.acute_delta() - 0.2).abs() < f32::EPSILON);
+        assert!((value.chronic_delta() + 0.1).abs() < f32::EPSILON);
+        assert!((value.delta() - 0.1).abs() < f32::EPSILON);
+        assert!((value.min_bound() + 1.0).abs() < f32::EPSILON);
+        assert!((value.max_bound() - 1.0).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn chronic_half_life_is_scaled() {
+        let value = StateValue::new(0.5).with_decay_half_life(Duration::days(2));
+        assert_eq!(value.chronic_decay_half_life(), Some(Duration::days(8)));
+
+        let no_decay = StateValue::new_no_decay(0.0);
+        assert!(no_decay.chronic_decay_half_life().is_none());
+    }
+
     #[test]
     fn add_chronic_delta_accumulates() {
         let mut value = StateValue::new(0.5);
@@ -283,6 +283,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::vec_init_then_push)]
     fn entity_can_accumulate_alerts() {
         // This test verifies the pattern - actual entity test is in entity.rs
         let mut alerts: Vec<Alert> = Vec::new();
@@ -726,6 +726,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::clone_on_copy)]
     fn clone_duration() {
         let d = Duration::days(5);
         let cloned = d.clone();
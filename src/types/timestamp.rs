@@ -467,6 +467,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::clone_on_copy)]
     fn timestamp_clone_and_copy() {
         let ts1 = Timestamp::from_ymd_hms(2024, 1, 15, 14, 30, 0);
         let ts2 = ts1; // Copy
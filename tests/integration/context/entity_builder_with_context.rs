@@ -20,19 +20,25 @@ fn entity_builder_with_context() {
 
     // Add multiple microsystems
     let work_id = MicrosystemId::new("work_primary").unwrap();
-    let mut work = WorkContext::default();
-    work.workload_stress = 0.6;
-    work.role_satisfaction = 0.8;
+    let work = WorkContext {
+        workload_stress: 0.6,
+        role_satisfaction: 0.8,
+        ..Default::default()
+    };
     context.add_microsystem(work_id.clone(), Microsystem::new_work(work));
 
     let family_id = MicrosystemId::new("family_primary").unwrap();
-    let mut family = FamilyContext::default();
-    family.warmth = 0.9;
+    let family = FamilyContext {
+        warmth: 0.9,
+        ..Default::default()
+    };
     context.add_microsystem(family_id.clone(), Microsystem::new_family(family));
 
     let social_id = MicrosystemId::new("friends").unwrap();
-    let mut social = SocialContext::default();
-    social.warmth = 0.7;
+    let social = SocialContext {
+        warmth: 0.7,
+        ..Default::default()
+    };
     context.add_microsystem(social_id.clone(), Microsystem::new_social(social));
 
     // Modify other context layers
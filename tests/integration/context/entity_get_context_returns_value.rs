@@ -70,8 +70,10 @@ fn entity_get_context_returns_microsystem_value() {
 
     // Add a work microsystem with specific stress
     let work_id = MicrosystemId::new("work_primary").unwrap();
-    let mut work = WorkContext::default();
-    work.workload_stress = 0.65;
+    let work = WorkContext {
+        workload_stress: 0.65,
+        ..Default::default()
+    };
     context.add_microsystem(work_id.clone(), Microsystem::new_work(work));
 
     let entity = EntityBuilder::new()
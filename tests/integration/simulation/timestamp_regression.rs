@@ -200,6 +200,9 @@ fn regression_without_events_is_exact() {
     let past = reference - Duration::days(30);
     let state = handle.state_at(past);
 
-    // Pure time regression is exact
+    // Pure time regression is exact; uncertainty grown over the month is
+    // reported as an error bound instead
     assert_eq!(state.regression_quality(), RegressionQuality::Exact);
+    let valence = StatePath::Mood(MoodPath::Valence);
+    assert!(state.regression_report().error_bound(valence) > 0.0);
 }
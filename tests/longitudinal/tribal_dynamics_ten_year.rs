@@ -49,6 +49,7 @@
 // - RoboticStateless: No decay, only event-driven changes
 // - Animal: Faster cycles, simpler memory systems
 
+use eventsim_rs::context::MacrosystemDynamics;
 use eventsim_rs::entity::EntityBuilder;
 use eventsim_rs::enums::{
     EventType, MentalHealthPath, MoodPath, NeedsPath, PersonalityProfile, SocialCognitionPath,
@@ -1007,6 +1008,61 @@ fn tribal_dynamics_over_ten_years() {
         );
     }
 
+    // ========================================================================
+    // STAGE 12: MACROSYSTEM EVOLUTION - THE GROUP'S CULTURE
+    // What we're testing: With macrosystem dynamics enabled, the group's
+    // shared culture carries the assault and the pandemic. Collective trauma
+    // rises after violence, institutional trust recovers as it recedes, and
+    // cultural stress spikes with the pandemic.
+    // ========================================================================
+
+    println!("========================================");
+    println!("MACROSYSTEM EVOLUTION: The Group's Culture");
+    println!("========================================\n");
+
+    sim.set_macrosystem_dynamics(Some(MacrosystemDynamics::new()));
+
+    {
+        // Chen was a bystander to the assault
+        let handle = sim.entity(&chen_id).unwrap();
+        let culture_at =
+            |timestamp: Timestamp| handle.context_at(timestamp).unwrap().macrosystem().clone();
+
+        let before_assault = culture_at(violence_date - Duration::days(1));
+        let after_assault = culture_at(violence_date + Duration::days(30));
+        let before_layoffs = culture_at(year_7_start);
+        let before_pandemic = culture_at(pandemic_date - Duration::days(1));
+        let after_pandemic = culture_at(pandemic_date + Duration::days(30));
+
+        println!(
+            "  Collective trauma: {:.3} -> {:.3} after the assault",
+            before_assault.collective_trauma, after_assault.collective_trauma
+        );
+        println!(
+            "  Rule of law: {:.3} after the assault, {:.3} by 2017",
+            after_assault.institutional_structure.rule_of_law,
+            before_layoffs.institutional_structure.rule_of_law
+        );
+        println!(
+            "  Cultural stress: {:.3} -> {:.3} with the pandemic\n",
+            before_pandemic.cultural_stress, after_pandemic.cultural_stress
+        );
+
+        assert!(
+            after_assault.collective_trauma > before_assault.collective_trauma,
+            "Violence should add to the group's collective trauma"
+        );
+        assert!(
+            before_layoffs.institutional_structure.rule_of_law
+                > after_assault.institutional_structure.rule_of_law,
+            "Institutional trust should recover as the assault recedes"
+        );
+        assert!(
+            after_pandemic.cultural_stress > before_pandemic.cultural_stress,
+            "The pandemic should raise cultural stress"
+        );
+    }
+
     // ========================================================================
     // FINAL SUMMARY
     // ========================================================================
@@ -1032,7 +1088,7 @@ fn tribal_dynamics_over_ten_years() {
     println!("\nTheoretical Coverage:");
     println!("  - ITS: All 3 proximal causes (TB, PB, AC) demonstrated");
     println!("  - PAD: Valence, arousal, dominance tracked throughout");
-    println!("  - Bronfenbrenner: Micro/meso/exo/chronosystem effects shown");
+    println!("  - Bronfenbrenner: Micro/meso/exo/macro/chronosystem effects shown");
     println!("  - Trust: Formation, erosion, asymmetric patterns modeled");
     println!("\nThis test demonstrates how to:");
     println!("  1. Map domain events to behavioral pathways events");
@@ -48,7 +48,7 @@ fn burden_relief_without_self_hate_reduction_keeps_pb_low() {
             .severity(0.8)
             .build()
             .unwrap();
-        sim.add_event(event, anchor + Duration::days(i * 1));
+        sim.add_event(event, anchor + Duration::days(i));
     }
 
     // Apply failures to increase self-hate
@@ -58,7 +58,7 @@ fn burden_relief_without_self_hate_reduction_keeps_pb_low() {
             .severity(0.8)
             .build()
             .unwrap();
-        sim.add_event(event, anchor + Duration::days(i * 1));
+        sim.add_event(event, anchor + Duration::days(i));
     }
 
     let pb_elevated_timestamp = anchor + Duration::days(5);
@@ -120,7 +120,7 @@ fn burden_relief_without_self_hate_reduction_keeps_pb_low() {
             .severity(0.7)
             .build()
             .unwrap();
-        sim.add_event(event, anchor + Duration::days(5 + i * 1));
+        sim.add_event(event, anchor + Duration::days(5 + i));
     }
 
     let post_relief_timestamp = anchor + Duration::days(13);
@@ -228,7 +228,7 @@ fn burden_relief_without_self_hate_reduction_keeps_pb_low() {
             .severity(0.9)
             .build()
             .unwrap();
-        sim.add_event(event, anchor + Duration::days(30 + i * 1));
+        sim.add_event(event, anchor + Duration::days(30 + i));
     }
 
     let final_timestamp = anchor + Duration::days(33);
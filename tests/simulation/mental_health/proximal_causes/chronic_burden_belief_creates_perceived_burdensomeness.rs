@@ -86,7 +86,7 @@ fn chronic_burden_belief_creates_perceived_burdensomeness() {
             .severity(0.8)
             .build()
             .unwrap();
-        sim.add_event(event, anchor + Duration::days(i * 1));
+        sim.add_event(event, anchor + Duration::days(i));
     }
 
     let liability_only_timestamp = anchor + Duration::days(3);
@@ -143,7 +143,7 @@ fn chronic_burden_belief_creates_perceived_burdensomeness() {
             .severity(0.8)
             .build()
             .unwrap();
-        sim.add_event(event, anchor + Duration::days(3 + i * 1));
+        sim.add_event(event, anchor + Duration::days(3 + i));
     }
 
     let both_elevated_timestamp = anchor + Duration::days(7);
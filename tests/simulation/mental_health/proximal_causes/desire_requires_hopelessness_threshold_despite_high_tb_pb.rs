@@ -67,7 +67,7 @@ fn desire_requires_hopelessness_threshold_despite_high_tb_pb() {
             .severity(0.8)
             .build()
             .unwrap();
-        sim.add_event(event, anchor + Duration::days(i * 1));
+        sim.add_event(event, anchor + Duration::days(i));
     }
 
     let high_tb_timestamp = anchor + Duration::days(6);
@@ -105,14 +105,14 @@ fn desire_requires_hopelessness_threshold_despite_high_tb_pb() {
             .severity(0.8)
             .build()
             .unwrap();
-        sim.add_event(burden_event, anchor + Duration::days(6 + i * 1));
+        sim.add_event(burden_event, anchor + Duration::days(6 + i));
 
         let failure_event = EventBuilder::new(EventType::Failure)
             .target(entity_id.clone())
             .severity(0.8)
             .build()
             .unwrap();
-        sim.add_event(failure_event, anchor + Duration::days(6 + i * 1));
+        sim.add_event(failure_event, anchor + Duration::days(6 + i));
     }
 
     let high_tb_pb_timestamp = anchor + Duration::days(10);
@@ -170,7 +170,7 @@ fn desire_requires_hopelessness_threshold_despite_high_tb_pb() {
             .severity(0.9)
             .build()
             .unwrap();
-        sim.add_event(event, anchor + Duration::days(10 + i * 1));
+        sim.add_event(event, anchor + Duration::days(10 + i));
     }
 
     let all_factors_timestamp = anchor + Duration::days(15);
@@ -167,28 +167,28 @@ fn healthcare_worker_dormant_capability_with_low_desire() {
             .severity(0.8)
             .build()
             .unwrap();
-        sim.add_event(exclusion_event, anchor + Duration::days(365 + i * 1));
+        sim.add_event(exclusion_event, anchor + Duration::days(365 + i));
 
         let burden_event = EventBuilder::new(EventType::BurdenFeedback)
             .target(entity_id.clone())
             .severity(0.8)
             .build()
             .unwrap();
-        sim.add_event(burden_event, anchor + Duration::days(365 + i * 1));
+        sim.add_event(burden_event, anchor + Duration::days(365 + i));
 
         let failure_event = EventBuilder::new(EventType::Failure)
             .target(entity_id.clone())
             .severity(0.8)
             .build()
             .unwrap();
-        sim.add_event(failure_event, anchor + Duration::days(365 + i * 1));
+        sim.add_event(failure_event, anchor + Duration::days(365 + i));
 
         let hopelessness_event = EventBuilder::new(EventType::Realization)
             .target(entity_id.clone())
             .severity(0.9)
             .build()
             .unwrap();
-        sim.add_event(hopelessness_event, anchor + Duration::days(365 + i * 1));
+        sim.add_event(hopelessness_event, anchor + Duration::days(365 + i));
     }
 
     let crisis_timestamp = anchor + Duration::days(370);
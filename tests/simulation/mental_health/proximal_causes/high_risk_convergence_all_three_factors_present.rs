@@ -110,7 +110,7 @@ fn high_risk_convergence_all_three_factors_present() {
             .severity(0.8)
             .build()
             .unwrap();
-        sim.add_event(event, anchor + Duration::days(42 + i * 1));
+        sim.add_event(event, anchor + Duration::days(42 + i));
     }
 
     let tb_added_timestamp = anchor + Duration::days(48);
@@ -148,14 +148,14 @@ fn high_risk_convergence_all_three_factors_present() {
             .severity(0.8)
             .build()
             .unwrap();
-        sim.add_event(burden_event, anchor + Duration::days(48 + i * 1));
+        sim.add_event(burden_event, anchor + Duration::days(48 + i));
 
         let failure_event = EventBuilder::new(EventType::Failure)
             .target(entity_id.clone())
             .severity(0.8)
             .build()
             .unwrap();
-        sim.add_event(failure_event, anchor + Duration::days(48 + i * 1));
+        sim.add_event(failure_event, anchor + Duration::days(48 + i));
     }
 
     let pb_added_timestamp = anchor + Duration::days(53);
@@ -206,7 +206,7 @@ fn high_risk_convergence_all_three_factors_present() {
             .severity(0.9)
             .build()
             .unwrap();
-        sim.add_event(event, anchor + Duration::days(53 + i * 1));
+        sim.add_event(event, anchor + Duration::days(53 + i));
     }
 
     let all_factors_timestamp = anchor + Duration::days(58);
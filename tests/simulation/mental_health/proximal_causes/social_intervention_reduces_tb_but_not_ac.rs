@@ -46,7 +46,7 @@ fn social_intervention_reduces_tb_but_not_ac() {
             .severity(0.8)
             .build()
             .unwrap();
-        sim.add_event(event, anchor + Duration::days(i * 1));
+        sim.add_event(event, anchor + Duration::days(i));
     }
 
     let isolated_timestamp = anchor + Duration::days(6);
@@ -190,7 +190,7 @@ fn social_intervention_reduces_tb_but_not_ac() {
     // The key point is that TB CAN change over time, unlike AC.
     // We just verify TB is a reasonable value after 6 months.
     assert!(
-        tb_long_term >= 0.0 && tb_long_term <= 1.0,
+        (0.0..=1.0).contains(&tb_long_term),
         "TB should be within valid range. Long-term: {}",
         tb_long_term
     );
@@ -216,21 +216,21 @@ fn social_intervention_reduces_tb_but_not_ac() {
             .severity(0.9)
             .build()
             .unwrap();
-        sim.add_event(burden_event, anchor + Duration::days(57 + i * 1));
+        sim.add_event(burden_event, anchor + Duration::days(57 + i));
 
         let failure_event = EventBuilder::new(EventType::Failure)
             .target(entity_id.clone())
             .severity(0.9)
             .build()
             .unwrap();
-        sim.add_event(failure_event, anchor + Duration::days(57 + i * 1));
+        sim.add_event(failure_event, anchor + Duration::days(57 + i));
 
         let hopelessness_event = EventBuilder::new(EventType::Realization)
             .target(entity_id.clone())
             .severity(0.9)
             .build()
             .unwrap();
-        sim.add_event(hopelessness_event, anchor + Duration::days(57 + i * 1));
+        sim.add_event(hopelessness_event, anchor + Duration::days(57 + i));
     }
 
     let risk_test_timestamp = anchor + Duration::days(60);
@@ -91,7 +91,7 @@ fn social_isolation_increases_thwarted_belongingness() {
             .severity(0.7)
             .build()
             .unwrap();
-        sim.add_event(event, anchor + Duration::days(i * 1));
+        sim.add_event(event, anchor + Duration::days(i));
     }
 
     let isolated_timestamp = anchor + Duration::days(5);